@@ -0,0 +1,2 @@
+/// Content codings decoded without registering a codec.
+pub(crate) const BUILTIN_ENCODINGS: [&str; 3] = ["gzip", "deflate", "br"];
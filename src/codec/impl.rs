@@ -0,0 +1,140 @@
+use super::*;
+
+impl CodecRegistry {
+    /// Registers a codec for its coding token.
+    ///
+    /// Replaces any codec registered for the same token. Registering a built-in
+    /// token such as `gzip` overrides the built-in decoder.
+    ///
+    /// # Arguments
+    ///
+    /// - `C` - The codec to register.
+    pub fn register<C>(codec: C)
+    where
+        C: ContentCodec + 'static,
+    {
+        let token: String = codec.encoding().trim().to_ascii_lowercase();
+        if let Ok(mut codecs) = CODECS.write() {
+            codecs.insert(token, Arc::new(codec));
+        }
+    }
+
+    /// Removes the codec registered for a coding token.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The coding token.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if a codec was registered for the token.
+    pub fn unregister(encoding: &str) -> bool {
+        CODECS.write().is_ok_and(|mut codecs| {
+            codecs
+                .remove(&encoding.trim().to_ascii_lowercase())
+                .is_some()
+        })
+    }
+
+    /// Gets the codec registered for a coding token.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The coding token, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<dyn ContentCodec>>` - The codec, or `None` if none is registered.
+    pub fn get(encoding: &str) -> Option<Arc<dyn ContentCodec>> {
+        CODECS
+            .read()
+            .ok()?
+            .get(&encoding.trim().to_ascii_lowercase())
+            .cloned()
+    }
+
+    /// Lists every coding that responses can be decoded from.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The built-in codings followed by the registered ones, sorted.
+    pub fn get_encodings() -> Vec<String> {
+        let mut registered: Vec<String> = CODECS.read().map_or(Vec::new(), |codecs| {
+            codecs
+                .keys()
+                .filter(|token| !BUILTIN_ENCODINGS.contains(&token.as_str()))
+                .cloned()
+                .collect()
+        });
+        registered.sort();
+        BUILTIN_ENCODINGS
+            .iter()
+            .map(|token| token.to_string())
+            .chain(registered)
+            .collect()
+    }
+
+    /// Formats the supported codings as an `Accept-Encoding` header value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The codings separated by `, `.
+    pub fn get_accept_encoding() -> String {
+        Self::get_encodings().join(", ")
+    }
+
+    /// Decodes a body according to its `Content-Encoding` header.
+    ///
+    /// Codings are undone in reverse order of application. Registered codecs handle
+    /// their tokens; the built-in decoder handles the rest. Decoding stops at the first
+    /// coding that fails or is unknown, leaving the body as decoded so far.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HashMapXxHash3_64<String, String>` - The response headers.
+    /// - `&[u8]` - The encoded body.
+    /// - `usize` - The buffer size for the built-in decoder.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The decoded body.
+    pub(crate) fn decode_body(
+        headers: &HashMapXxHash3_64<String, String>,
+        body: &[u8],
+        buffer_size: usize,
+    ) -> Vec<u8> {
+        let encodings: Vec<String> = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(CONTENT_ENCODING))
+            .map_or(Vec::new(), |(_, value)| {
+                value
+                    .split(',')
+                    .map(|token| token.trim().to_ascii_lowercase())
+                    .filter(|token| !token.is_empty() && token != IDENTITY_ENCODING)
+                    .collect()
+            });
+        if encodings.iter().all(|token| Self::get(token).is_none()) {
+            return Compress::from(headers)
+                .decode(body, buffer_size)
+                .into_owned();
+        }
+        let mut data: Vec<u8> = body.to_vec();
+        for token in encodings.iter().rev() {
+            if let Some(codec) = Self::get(token) {
+                match codec.decode(&data) {
+                    Ok(decoded) => data = decoded,
+                    Err(_) => break,
+                }
+            } else if BUILTIN_ENCODINGS.contains(&token.as_str()) {
+                let mut single: HashMapXxHash3_64<String, String> = hash_map_xx_hash3_64();
+                single.insert(CONTENT_ENCODING.to_string(), token.clone());
+                data = Compress::from(&single)
+                    .decode(&data, buffer_size)
+                    .into_owned();
+            } else {
+                break;
+            }
+        }
+        data
+    }
+}
@@ -0,0 +1,11 @@
+mod r#const;
+mod r#impl;
+mod r#static;
+mod r#struct;
+mod r#trait;
+
+pub use {r#struct::*, r#trait::*};
+
+pub(crate) use {r#const::*, r#static::*};
+
+use super::*;
@@ -0,0 +1,5 @@
+use super::*;
+
+/// Content codecs registered through `CodecRegistry::register`, keyed by lowercase token.
+pub(crate) static CODECS: LazyLock<RwLock<HashMapXxHash3_64<String, Arc<dyn ContentCodec>>>> =
+    LazyLock::new(|| RwLock::new(hash_map_xx_hash3_64()));
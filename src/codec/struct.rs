@@ -0,0 +1,5 @@
+/// Process-wide registry of custom content codecs.
+///
+/// Registered codecs take part in automatic response decoding for their
+/// `Content-Encoding` token, next to the built-in gzip, deflate and br support.
+pub struct CodecRegistry;
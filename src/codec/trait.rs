@@ -0,0 +1,35 @@
+use super::*;
+
+/// A content coding such as lz4 or snappy, identified by its `Content-Encoding` token.
+///
+/// Register implementations with `CodecRegistry::register`.
+pub trait ContentCodec: Send + Sync {
+    /// Gets the token identifying the coding in `Content-Encoding` and `Accept-Encoding`.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The coding token, matched case-insensitively.
+    fn encoding(&self) -> &str;
+
+    /// Compresses data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The data to compress.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The compressed data.
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>, RequestError>;
+
+    /// Decompresses data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The compressed data.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The decompressed data.
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>, RequestError>;
+}
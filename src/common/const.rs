@@ -1,2 +1,38 @@
 /// The application name used for user agent and logging.
 pub const APP_NAME: &str = "http-request";
+
+/// The content type used for binary protobuf request bodies.
+pub const APPLICATION_X_PROTOBUF: &str = "application/x-protobuf";
+
+/// The content type used for URL-encoded form request bodies.
+pub const APPLICATION_X_WWW_FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+
+/// The header carrying the base64 encoded MD5 digest of a response body.
+pub const CONTENT_MD5: &str = "Content-MD5";
+
+/// The `Content-Encoding` token for an uncompressed body.
+pub const IDENTITY_ENCODING: &str = "identity";
+
+/// The header naming the transfer codings applied to a message body.
+pub const TRANSFER_ENCODING: &str = "Transfer-Encoding";
+
+/// The header announcing the fields sent in the trailer section of a chunked body.
+pub const TRAILER: &str = "Trailer";
+
+/// The header carrying cookies, whose values are sent on a single line.
+pub const COOKIE: &str = "Cookie";
+
+/// The header announcing that the client waits for an interim response before sending the body.
+pub const EXPECT: &str = "Expect";
+
+/// The `Expect` value asking the server for a `100 Continue` response.
+pub const EXPECT_100_CONTINUE: &str = "100-continue";
+
+/// Error message for a reader body that was already sent.
+pub const BODY_READER_CONSUMED: &str = "Request body reader was already consumed";
+
+/// The transfer coding sending a body as a series of chunks.
+pub const CHUNKED: &str = "chunked";
+
+/// The last chunk and empty trailer section ending a chunked body.
+pub const CHUNKED_BODY_END: &[u8] = b"0\r\n\r\n";
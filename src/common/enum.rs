@@ -2,13 +2,21 @@ use super::*;
 
 /// Represents the body content of an HTTP request/response.
 ///
-/// Can be text, JSON or binary data.
+/// Can be text, JSON or binary data, or a reader streamed to the connection.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum Body {
     /// Text content body.
     Text(BodyText),
     /// JSON content body.
+    #[cfg(feature = "json")]
     Json(BodyJson),
     /// Binary data body.
     Binary(BodyBinary),
+    /// Body streamed from a blocking reader.
+    Reader(BodyReaderSource),
+    /// Body streamed from an asynchronous reader.
+    #[cfg(feature = "async")]
+    AsyncReader(AsyncBodyReaderSource),
+    /// Body that could not be encoded, failing the send with the encoding error.
+    Invalid(String),
 }
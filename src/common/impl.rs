@@ -26,12 +26,16 @@ impl Display for Body {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Text(text) => write!(f, "{text}"),
+            #[cfg(feature = "json")]
             Self::Json(json) => write!(
                 f,
                 "{}",
                 serde_json::to_string(json).unwrap_or_else(|_| String::from("{}"))
             ),
             Self::Binary(binary) => write!(f, "{binary:?}"),
+            Self::Reader(_) | Self::Invalid(_) => Ok(()),
+            #[cfg(feature = "async")]
+            Self::AsyncReader(_) => Ok(()),
         }
     }
 }
@@ -52,8 +56,104 @@ impl Serialize for Body {
     {
         match self {
             Self::Text(text) => text.serialize(serializer),
+            #[cfg(feature = "json")]
             Self::Json(json) => json.serialize(serializer),
             Self::Binary(binary) => binary.serialize(serializer),
+            Self::Reader(_) | Self::Invalid(_) => serializer.serialize_unit(),
+            #[cfg(feature = "async")]
+            Self::AsyncReader(_) => serializer.serialize_unit(),
         }
     }
 }
+
+impl BodyReaderSource {
+    /// Wraps a blocking reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `R` - The reader (must implement Read + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `BodyReaderSource` - The shareable reader source.
+    pub(crate) fn new<R: Read + Send + 'static>(reader: R) -> Self {
+        Self(Arc::new(StdMutex::new(Some(Box::new(reader)))))
+    }
+
+    /// Takes the reader out so it can be streamed.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxBodyRead, RequestError>` - The reader, or an error if it was already sent.
+    pub(crate) fn take(&self) -> Result<BoxBodyRead, RequestError> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|mut reader| reader.take())
+            .ok_or_else(|| RequestError::Request(BODY_READER_CONSUMED.to_string()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncBodyReaderSource {
+    /// Wraps an asynchronous reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `R` - The reader (must implement AsyncRead + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `AsyncBodyReaderSource` - The shareable reader source.
+    pub(crate) fn new<R: AsyncRead + Send + 'static>(reader: R) -> Self {
+        Self(Arc::new(StdMutex::new(Some(Box::pin(reader)))))
+    }
+
+    /// Takes the reader out so it can be streamed.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxBodyAsyncRead, RequestError>` - The reader, or an error if it was already sent.
+    pub(crate) fn take(&self) -> Result<BoxBodyAsyncRead, RequestError> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|mut reader| reader.take())
+            .ok_or_else(|| RequestError::Request(BODY_READER_CONSUMED.to_string()))
+    }
+}
+
+/// Formats the BodyReaderSource for debugging without the reader itself.
+impl Debug for BodyReaderSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("BodyReaderSource")
+    }
+}
+
+/// Formats the AsyncBodyReaderSource for debugging without the reader itself.
+#[cfg(feature = "async")]
+impl Debug for AsyncBodyReaderSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("AsyncBodyReaderSource")
+    }
+}
+
+/// Compares reader sources by identity.
+impl PartialEq for BodyReaderSource {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for BodyReaderSource {}
+
+/// Compares reader sources by identity.
+#[cfg(feature = "async")]
+impl PartialEq for AsyncBodyReaderSource {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Eq for AsyncBodyReaderSource {}
@@ -1,8 +1,10 @@
 mod r#const;
 mod r#enum;
 mod r#impl;
+mod r#struct;
+mod r#trait;
 mod r#type;
 
-pub(crate) use {r#const::*, r#enum::*, r#type::*};
+pub(crate) use {r#const::*, r#enum::*, r#struct::*, r#trait::*, r#type::*};
 
 use super::*;
@@ -0,0 +1,14 @@
+use super::*;
+
+/// A blocking reader streamed as a request body.
+///
+/// The reader is taken out when the body is sent, so it can only be sent once.
+#[derive(Clone)]
+pub(crate) struct BodyReaderSource(pub(crate) Arc<StdMutex<Option<BoxBodyRead>>>);
+
+/// An asynchronous reader streamed as a request body.
+///
+/// The reader is taken out when the body is sent, so it can only be sent once.
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub(crate) struct AsyncBodyReaderSource(pub(crate) Arc<StdMutex<Option<BoxBodyAsyncRead>>>);
@@ -0,0 +1,5 @@
+/// Restricts the implementations of a public trait to the types of this crate.
+///
+/// The trait is public but never exported, so other crates cannot name it and
+/// cannot implement the traits requiring it, leaving those traits free to grow.
+pub trait Sealed {}
@@ -4,6 +4,7 @@ use super::*;
 pub(crate) type ArcRwLock<T> = Arc<RwLock<T>>;
 
 /// JSON body content represented as a hash map with string keys.
+#[cfg(feature = "json")]
 pub(crate) type BodyJson = HashMapXxHash3_64<String, serde_json::Value>;
 
 /// Text body content represented as a string.
@@ -11,3 +12,17 @@ pub(crate) type BodyText = String;
 
 /// Binary body content represented as a byte vector.
 pub(crate) type BodyBinary = Vec<u8>;
+
+/// Blocking reader streamed as a request body.
+pub(crate) type BoxBodyRead = Box<dyn Read + Send>;
+
+/// Asynchronous reader streamed as a request body.
+#[cfg(feature = "async")]
+pub(crate) type BoxBodyAsyncRead = Pin<Box<dyn AsyncRead + Send>>;
+
+/// Blocking writer receiving a copy of a response body.
+pub(crate) type BoxBodyWrite = Box<dyn Write + Send>;
+
+/// Asynchronous writer receiving a copy of a response body.
+#[cfg(feature = "async")]
+pub(crate) type BoxBodyAsyncWrite = Pin<Box<dyn AsyncWrite + Send>>;
@@ -0,0 +1,117 @@
+use super::*;
+
+/// Errors that can occur while building, sending, or reading an HTTP request.
+///
+/// Socket-level failures are classified by their `io::ErrorKind` so callers can
+/// tell transient failures (reset, broken pipe, timeout) from permanent ones
+/// (refused, unreachable) without inspecting error strings. They carry an
+/// `IoError` with the phase of the request they happened in and the original
+/// I/O error as their `source`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequestError {
+    /// Generic request failure described by a message.
+    Request(String),
+    /// The remote host actively refused the connection.
+    ConnectionRefused(IoError),
+    /// The connection was reset or aborted by the remote host.
+    ConnectionReset(IoError),
+    /// The remote host or network could not be reached.
+    HostUnreachable(IoError),
+    /// The connection was closed by the remote host while writing.
+    BrokenPipe(IoError),
+    /// A socket operation did not complete within the configured timeout.
+    Timeout(IoError),
+    /// Any other I/O failure, such as a host name that did not resolve.
+    Io(IoError),
+    /// The TLS session could not be set up or failed.
+    Tls(TlsError),
+    /// A redirect could not be followed.
+    Redirect(RedirectError),
+    /// The response was rejected by strict parsing.
+    InvalidResponse(ResponseViolation),
+    /// The proxy requires authentication, with the challenges it sent.
+    ///
+    /// Raised when no credentials are configured for a challenged scheme, or when
+    /// the proxy rejected the credentials sent on the retry.
+    ProxyAuthenticationRequired(Vec<AuthChallenge>),
+    /// The connection through a proxy failed, with the phase it failed in.
+    Proxy(ProxyError),
+    /// The response sent more header lines than allowed, with the configured maximum.
+    TooManyHeaders(usize),
+    /// The response announced a `Content-Length` above the allowed body size, with the maximum.
+    ContentLengthTooLarge(usize),
+    /// The connection ended before the body announced by `Content-Length` was read.
+    IncompleteBody {
+        /// The body length announced by `Content-Length`.
+        bytes_expected: usize,
+        /// The number of body bytes received.
+        bytes_received: usize,
+    },
+    /// The response header section exceeded the allowed size, with the configured maximum in bytes.
+    HeaderSectionTooLarge(usize),
+    /// A response header line exceeded the allowed length, with the configured maximum in bytes.
+    HeaderLineTooLong(usize),
+    /// The response body received exceeded the allowed size, with the configured maximum in bytes.
+    BodyTooLarge(usize),
+    /// The request was aborted through its `CancellationToken`.
+    RequestAborted,
+}
+
+/// A response framing problem rejected by strict parsing.
+///
+/// Each of these lets a client and an intermediary disagree on where a response
+/// ends, which RFC 9112 warns can be abused for response splitting and smuggling.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResponseViolation {
+    /// A header value is continued on a line starting with whitespace (obs-fold).
+    ObsoleteLineFolding,
+    /// `Content-Length` is invalid or given several times with different values.
+    ConflictingContentLength,
+    /// Both `Content-Length` and `Transfer-Encoding` are present.
+    ContentLengthWithTransferEncoding,
+}
+
+/// Why a redirect could not be followed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RedirectErrorKind {
+    /// The response redirected but following redirects is disabled.
+    NotEnabled,
+    /// The redirect target was already visited.
+    DeadLoop,
+    /// The maximum number of redirects was reached.
+    MaxRedirectTimes,
+    /// The redirect leads from HTTPS to HTTP and the redirect policy forbids it.
+    HttpsDowngrade,
+    /// The redirect was denied by the `RequestBuilder::on_redirect` hook.
+    Denied,
+}
+
+/// The phase of a proxy connection in which it failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProxyErrorPhase {
+    /// Connecting to the proxy, including the TLS handshake with an HTTPS proxy.
+    Connect,
+    /// Negotiating the SOCKS5 version and authentication method.
+    Greeting,
+    /// Authenticating with the proxy.
+    Auth,
+    /// Asking the proxy to connect to the target, with CONNECT or a SOCKS5 command.
+    ConnectCommand,
+    /// The TLS handshake with the target through the tunnel.
+    TlsToTarget,
+}
+
+/// The phase of a request in which an I/O error occurred.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorPhase {
+    /// Resolving the host name.
+    Resolve,
+    /// Establishing the TCP connection.
+    Connect,
+    /// Performing the TLS handshake.
+    Tls,
+    /// Writing the request.
+    Write,
+    /// Waiting for or reading the response.
+    Read,
+}
@@ -0,0 +1,687 @@
+use super::*;
+
+/// Formats the RequestError for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for RequestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Request(message) => write!(f, "{message}"),
+            Self::ConnectionRefused(message) => write!(f, "Connection refused: {message}"),
+            Self::ConnectionReset(message) => write!(f, "Connection reset: {message}"),
+            Self::HostUnreachable(message) => write!(f, "Host unreachable: {message}"),
+            Self::BrokenPipe(message) => write!(f, "Broken pipe: {message}"),
+            Self::Timeout(message) => write!(f, "Timeout: {message}"),
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Tls(error) => write!(f, "TLS error: {error}"),
+            Self::Redirect(error) => write!(f, "{error}"),
+            Self::InvalidResponse(violation) => write!(f, "Invalid response: {violation}"),
+            Self::ProxyAuthenticationRequired(challenges) => {
+                f.write_str("Proxy authentication required")?;
+                for (index, challenge) in challenges.iter().enumerate() {
+                    let separator: &str = if index == 0 { ": " } else { ", " };
+                    write!(f, "{separator}{challenge}")?;
+                }
+                Ok(())
+            }
+            Self::Proxy(error) => write!(f, "{error}"),
+            Self::TooManyHeaders(max) => write!(f, "Too many response headers: more than {max}"),
+            Self::ContentLengthTooLarge(max) => {
+                write!(f, "Content-Length too large: more than {max} bytes")
+            }
+            Self::IncompleteBody {
+                bytes_expected,
+                bytes_received,
+            } => write!(
+                f,
+                "Incomplete body: received {bytes_received} of {bytes_expected} bytes"
+            ),
+            Self::HeaderSectionTooLarge(max) => {
+                write!(
+                    f,
+                    "Response header section too large: more than {max} bytes"
+                )
+            }
+            Self::HeaderLineTooLong(max) => {
+                write!(f, "Response header line too long: more than {max} bytes")
+            }
+            Self::BodyTooLarge(max) => write!(f, "Response body too large: more than {max} bytes"),
+            Self::RequestAborted => write!(f, "Request aborted"),
+        }
+    }
+}
+
+/// Formats the ResponseViolation for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for ResponseViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let message: &str = match self {
+            Self::ObsoleteLineFolding => "obsolete line folding in header section",
+            Self::ConflictingContentLength => "invalid or conflicting Content-Length",
+            Self::ContentLengthWithTransferEncoding => {
+                "both Content-Length and Transfer-Encoding present"
+            }
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for RequestError {
+    /// Gets the underlying I/O or rustls error.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&(dyn std::error::Error + 'static)>` - The source error, if any.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Tls(error) => std::error::Error::source(error),
+            error => error
+                .get_io_error()
+                .and_then(|error: &IoError| std::error::Error::source(error)),
+        }
+    }
+}
+
+/// Classifies an I/O error into the matching RequestError variant.
+///
+/// I/O errors wrapping a rustls error become `RequestError::Tls`.
+///
+/// # Arguments
+///
+/// - `std::io::Error` - The I/O error raised by a connect, read, or write operation.
+///
+/// # Returns
+///
+/// - `RequestError` - The classified request error.
+impl From<std::io::Error> for RequestError {
+    fn from(error: std::io::Error) -> Self {
+        #[cfg(feature = "tls")]
+        if let Some(tls_error) = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+        {
+            return Self::from(tls_error.clone());
+        }
+        let kind: ErrorKind = error.kind();
+        let io_error: IoError = IoError {
+            kind,
+            phase: None,
+            message: error.to_string(),
+            source: Some(Arc::new(error)),
+        };
+        match kind {
+            ErrorKind::ConnectionRefused => Self::ConnectionRefused(io_error),
+            ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted => {
+                Self::ConnectionReset(io_error)
+            }
+            ErrorKind::HostUnreachable | ErrorKind::NetworkUnreachable => {
+                Self::HostUnreachable(io_error)
+            }
+            ErrorKind::BrokenPipe => Self::BrokenPipe(io_error),
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => Self::Timeout(io_error),
+            _ => Self::Io(io_error),
+        }
+    }
+}
+
+/// Converts a rustls error into a RequestError.
+///
+/// # Arguments
+///
+/// - `rustls::Error` - The error raised while setting up or using a TLS session.
+///
+/// # Returns
+///
+/// - `RequestError` - A TLS request error.
+#[cfg(feature = "tls")]
+impl From<rustls::Error> for RequestError {
+    fn from(error: rustls::Error) -> Self {
+        Self::Tls(TlsError {
+            message: error.to_string(),
+            source: Some(error),
+        })
+    }
+}
+
+/// Converts an invalid TLS server name into a RequestError.
+///
+/// # Arguments
+///
+/// - `InvalidDnsNameError` - The error raised for a host that is not a valid server name.
+///
+/// # Returns
+///
+/// - `RequestError` - A TLS request error.
+#[cfg(feature = "tls")]
+impl From<InvalidDnsNameError> for RequestError {
+    fn from(error: InvalidDnsNameError) -> Self {
+        Self::tls(error)
+    }
+}
+
+/// Converts a JSON deserialization error into a RequestError.
+///
+/// # Arguments
+///
+/// - `serde_json::Error` - The error raised while deserializing a JSON body.
+///
+/// # Returns
+///
+/// - `RequestError` - A request error describing the JSON error.
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for RequestError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Request(format!("JSON decode error: {error}"))
+    }
+}
+
+impl RequestError {
+    /// Creates a proxy error for a failed phase.
+    ///
+    /// # Arguments
+    ///
+    /// - `ProxyErrorPhase` - The phase the connection failed in.
+    /// - `T` - The failure description (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The proxy error.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn proxy<T: ToString>(phase: ProxyErrorPhase, detail: T) -> Self {
+        Self::Proxy(ProxyError {
+            phase,
+            detail: detail.to_string(),
+        })
+    }
+
+    /// Gets the description of the failure without the variant prefix.
+    ///
+    /// Used when the error becomes the detail of another error, such as a proxy error.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The failure description.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn get_detail(&self) -> String {
+        match self {
+            Self::ConnectionRefused(error)
+            | Self::ConnectionReset(error)
+            | Self::HostUnreachable(error)
+            | Self::BrokenPipe(error)
+            | Self::Timeout(error)
+            | Self::Io(error) => error.to_string(),
+            Self::Tls(error) => error.to_string(),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Checks whether the error is likely transient and the request may succeed if retried.
+    ///
+    /// Resets, broken pipes and timeouts are considered transient; refused connections,
+    /// unreachable hosts and generic request errors are not.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if retrying the request may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionReset(_) | Self::BrokenPipe(_) | Self::Timeout(_)
+        )
+    }
+
+    /// Creates a timeout error for a phase.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorPhase` - The phase that timed out.
+    /// - `T` - The failure description (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The timeout error.
+    pub(crate) fn timeout<T: ToString>(phase: ErrorPhase, message: T) -> Self {
+        Self::Timeout(IoError::new(ErrorKind::TimedOut, message).in_phase(phase))
+    }
+
+    /// Creates the error for a host name that resolved to no address.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The host, with its port if any (must implement Display).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The resolution error.
+    pub(crate) fn unresolved<T: Display>(target: T) -> Self {
+        Self::Io(
+            IoError::new(
+                ErrorKind::NotFound,
+                format!("No address resolved for {target}"),
+            )
+            .in_phase(ErrorPhase::Resolve),
+        )
+    }
+
+    /// Creates a TLS error without an underlying rustls error.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The failure description (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The TLS error.
+    pub(crate) fn tls<T: ToString>(message: T) -> Self {
+        Self::Tls(TlsError {
+            message: message.to_string(),
+            #[cfg(feature = "tls")]
+            source: None,
+        })
+    }
+
+    /// Sets the phase of an I/O error that does not know it yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorPhase` - The phase the error happened in.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The error, with the phase set if it carries an `IoError`.
+    pub(crate) fn with_phase(mut self, phase: ErrorPhase) -> Self {
+        if let Self::ConnectionRefused(error)
+        | Self::ConnectionReset(error)
+        | Self::HostUnreachable(error)
+        | Self::BrokenPipe(error)
+        | Self::Timeout(error)
+        | Self::Io(error) = &mut self
+        {
+            error.phase.get_or_insert(phase);
+        }
+        self
+    }
+
+    /// Gets the details of an I/O failure.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&IoError>` - The I/O error, or None if the error was not raised by I/O.
+    pub fn get_io_error(&self) -> Option<&IoError> {
+        match self {
+            Self::ConnectionRefused(error)
+            | Self::ConnectionReset(error)
+            | Self::HostUnreachable(error)
+            | Self::BrokenPipe(error)
+            | Self::Timeout(error)
+            | Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Gets the kind of an I/O failure.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ErrorKind>` - The I/O error kind, or None if the error was not raised by I/O.
+    pub fn get_io_kind(&self) -> Option<ErrorKind> {
+        self.get_io_error().map(IoError::get_kind)
+    }
+
+    /// Gets the phase of the request an I/O or TLS failure happened in.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ErrorPhase>` - The phase, or None if it is unknown.
+    pub fn get_phase(&self) -> Option<ErrorPhase> {
+        match self {
+            Self::Tls(_) => Some(ErrorPhase::Tls),
+            error => error.get_io_error().and_then(IoError::get_phase),
+        }
+    }
+
+    /// Checks whether the request timed out.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for a timeout in any phase.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
+    /// Checks whether the connection to the server or proxy could not be established.
+    ///
+    /// Covers host names that did not resolve, refused connections, unreachable hosts,
+    /// connection timeouts, and failures to connect to a proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the request failed before it was connected.
+    pub fn is_connect(&self) -> bool {
+        match self {
+            Self::ConnectionRefused(_) | Self::HostUnreachable(_) => true,
+            Self::Proxy(error) => error.get_phase() == ProxyErrorPhase::Connect,
+            error => matches!(
+                error.get_phase(),
+                Some(ErrorPhase::Resolve | ErrorPhase::Connect)
+            ),
+        }
+    }
+
+    /// Checks whether the TLS session could not be set up or failed.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for TLS failures, including timeouts during the handshake.
+    pub fn is_tls(&self) -> bool {
+        match self {
+            Self::Proxy(error) => error.get_phase() == ProxyErrorPhase::TlsToTarget,
+            error => error.get_phase() == Some(ErrorPhase::Tls),
+        }
+    }
+}
+
+impl IoError {
+    /// Creates an I/O error without an underlying `std::io::Error`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorKind` - The kind of the error.
+    /// - `T` - The failure description (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `IoError` - The error, with an unknown phase.
+    pub(crate) fn new<T: ToString>(kind: ErrorKind, message: T) -> Self {
+        Self {
+            kind,
+            phase: None,
+            message: message.to_string(),
+            source: None,
+        }
+    }
+
+    /// Sets the phase the error happened in.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorPhase` - The phase.
+    ///
+    /// # Returns
+    ///
+    /// - `IoError` - The error with the phase set.
+    pub(crate) fn in_phase(mut self, phase: ErrorPhase) -> Self {
+        self.phase = Some(phase);
+        self
+    }
+
+    /// Gets the kind of the I/O error.
+    ///
+    /// # Returns
+    ///
+    /// - `ErrorKind` - The error kind.
+    pub fn get_kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Gets the phase of the request the error happened in.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ErrorPhase>` - The phase, or None if it is unknown.
+    pub fn get_phase(&self) -> Option<ErrorPhase> {
+        self.phase
+    }
+
+    /// Gets the description of the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The failure description.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Formats the IoError for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for IoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.phase == other.phase && self.message == other.message
+    }
+}
+
+impl Eq for IoError {}
+
+impl std::error::Error for IoError {
+    /// Gets the original I/O error.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&(dyn std::error::Error + 'static)>` - The I/O error, if any.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|error: &std::io::Error| error as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl TlsError {
+    /// Gets the description of the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The failure description.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the rustls error that caused the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&rustls::Error>` - The rustls error, or None if the failure was not raised by rustls.
+    #[cfg(feature = "tls")]
+    pub fn get_rustls_error(&self) -> Option<&rustls::Error> {
+        self.source.as_ref()
+    }
+}
+
+/// Formats the TlsError for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for TlsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl PartialEq for TlsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl Eq for TlsError {}
+
+impl std::error::Error for TlsError {
+    /// Gets the original rustls error.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&(dyn std::error::Error + 'static)>` - The rustls error, if any.
+    #[cfg(feature = "tls")]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error: &rustls::Error| error as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl RedirectError {
+    /// Gets why the redirect was rejected.
+    ///
+    /// # Returns
+    ///
+    /// - `RedirectErrorKind` - The failure kind.
+    pub fn get_kind(&self) -> RedirectErrorKind {
+        self.kind
+    }
+
+    /// Gets the URLs requested before the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `&[String]` - The redirect chain, starting with the original URL.
+    pub fn get_visited(&self) -> &[String] {
+        &self.visited
+    }
+
+    /// Gets the `Location` target that was rejected.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The rejected redirect URL.
+    pub fn get_location(&self) -> &str {
+        &self.location
+    }
+
+    /// Gets the number of redirects followed before the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The redirect count.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Formats the RedirectError for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for RedirectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let reason: &str = match self.kind {
+            RedirectErrorKind::NotEnabled => "Redirect Not Enabled",
+            RedirectErrorKind::DeadLoop => "Redirect URL Dead Loop",
+            RedirectErrorKind::MaxRedirectTimes => "Max Redirect Times Exceeded",
+            RedirectErrorKind::HttpsDowngrade => "Redirect From HTTPS To HTTP Refused",
+            RedirectErrorKind::Denied => "Redirect Denied",
+        };
+        write!(
+            f,
+            "{reason} after {} redirects: {} -> {}",
+            self.count,
+            self.visited.join(" -> "),
+            self.location
+        )
+    }
+}
+
+impl ProxyError {
+    /// Creates the error for a failed SOCKS5 connect command.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The reply code sent by the proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The proxy error describing the reply.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn socks5_reply(reply: u8) -> RequestError {
+        let reason: &str = match reply {
+            0x01 => "general SOCKS server failure",
+            0x02 => "connection not allowed by ruleset",
+            0x03 => "network unreachable",
+            0x04 => "host unreachable",
+            0x05 => "connection refused",
+            0x06 => "TTL expired",
+            0x07 => "command not supported",
+            0x08 => "address type not supported",
+            _ => "unknown reply",
+        };
+        RequestError::proxy(
+            ProxyErrorPhase::ConnectCommand,
+            format!("SOCKS5 reply {reply:#04x}: {reason}"),
+        )
+    }
+
+    /// Gets the phase the connection failed in.
+    ///
+    /// # Returns
+    ///
+    /// - `ProxyErrorPhase` - The failed phase.
+    pub fn get_phase(&self) -> ProxyErrorPhase {
+        self.phase
+    }
+
+    /// Gets the description of the failure.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The failure description.
+    pub fn get_detail(&self) -> &str {
+        &self.detail
+    }
+}
+
+/// Formats the ProxyError for display.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for ProxyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let phase: &str = match self.phase {
+            ProxyErrorPhase::Connect => "connect",
+            ProxyErrorPhase::Greeting => "greeting",
+            ProxyErrorPhase::Auth => "authentication",
+            ProxyErrorPhase::ConnectCommand => "connect command",
+            ProxyErrorPhase::TlsToTarget => "TLS to target",
+        };
+        write!(f, "Proxy {phase} failed: {}", self.detail)
+    }
+}
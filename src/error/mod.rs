@@ -0,0 +1,7 @@
+mod r#enum;
+mod r#impl;
+mod r#struct;
+
+pub use {r#enum::*, r#struct::*};
+
+use super::*;
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Details of a redirect that could not be followed.
+///
+/// Carries the redirect chain so loops and long chains can be diagnosed from
+/// the error alone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedirectError {
+    /// Why the redirect was rejected.
+    pub(crate) kind: RedirectErrorKind,
+    /// URLs requested so far, starting with the original URL.
+    pub(crate) visited: Vec<String>,
+    /// The `Location` target that was rejected.
+    pub(crate) location: String,
+    /// Number of redirects followed before the failure.
+    pub(crate) count: usize,
+}
+
+/// Details of a failed connection through a proxy.
+///
+/// The phase tells an unreachable proxy from rejected credentials and from a
+/// target the proxy could not reach.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyError {
+    /// The phase the connection failed in.
+    pub(crate) phase: ProxyErrorPhase,
+    /// Description of the failure.
+    pub(crate) detail: String,
+}
+
+/// Details of a failed I/O operation.
+///
+/// Keeps the `io::ErrorKind`, the request phase it happened in when known, and
+/// the original error, which is returned as the `source`. Errors compare equal
+/// when their kind, phase, and message match.
+#[derive(Clone, Debug)]
+pub struct IoError {
+    /// The kind of the I/O error.
+    pub(crate) kind: ErrorKind,
+    /// The phase of the request the error happened in, if known.
+    pub(crate) phase: Option<ErrorPhase>,
+    /// Description of the failure.
+    pub(crate) message: String,
+    /// The original I/O error, if the failure was raised by one.
+    pub(crate) source: Option<Arc<std::io::Error>>,
+}
+
+/// Details of a failed TLS session.
+///
+/// Carries the rustls error when there is one, which is returned as the `source`.
+/// Errors compare equal when their messages match.
+#[derive(Clone, Debug)]
+pub struct TlsError {
+    /// Description of the failure.
+    pub(crate) message: String,
+    /// The original rustls error, if the failure was raised by rustls.
+    #[cfg(feature = "tls")]
+    pub(crate) source: Option<rustls::Error>,
+}
@@ -11,20 +11,29 @@
 //! or standard "HTTP" requests, the library is optimized for performance,
 //! minimal resource usage, and easy integration into Rust projects.
 
+mod codec;
 mod common;
+mod error;
+mod mime;
 mod request;
 mod response;
 mod utils;
 
-pub use {request::*, response::*};
+pub mod prelude;
+pub mod wire;
 
-pub use {
-    http_type::{HashMapXxHash3_64, RequestError, hash_map_xx_hash3_64},
-    serde_json::{
-        Deserializer, Error, Map, Number, StreamDeserializer, Value, from_reader, from_slice,
-        from_str, from_value, to_string, to_string_pretty, to_value, to_vec, to_vec_pretty,
-        to_writer, to_writer_pretty, value,
-    },
+pub use {codec::*, error::*, mime::*, request::*, response::*};
+
+pub use http_type::{
+    Compress, HashMapXxHash3_64, HttpVersion, Method, ResponseHeaders, ResponseStatusCode,
+    hash_map_xx_hash3_64,
+};
+
+#[cfg(feature = "json")]
+pub use serde_json::{
+    Deserializer, Error, Map, Number, StreamDeserializer, Value, from_reader, from_slice, from_str,
+    from_value, to_string, to_string_pretty, to_value, to_vec, to_vec_pretty, to_writer,
+    to_writer_pretty, value,
 };
 
 use {common::*, utils::*};
@@ -32,46 +41,115 @@ use {common::*, utils::*};
 use std::{
     borrow::Cow,
     collections::{HashSet, VecDeque},
+    env,
     fmt::{self, Debug, Display, Formatter},
-    io::{Read, Write},
-    net::{Ipv4Addr, Ipv6Addr, TcpStream},
+    fs::{self, File, OpenOptions},
+    hash::{BuildHasher, DefaultHasher, Hasher, RandomState},
+    io::{Chain, Cursor, ErrorKind, Read, Write},
+    net::{IpAddr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs},
+    path::{Path, PathBuf},
     pin::Pin,
-    str::from_utf8,
+    str::{FromStr, from_utf8},
     string::FromUtf8Error,
     sync::{
-        Arc, RwLock, RwLockReadGuard,
-        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, LazyLock, Mutex as StdMutex, MutexGuard as StdMutexGuard, RwLock,
+        RwLockReadGuard, RwLockWriteGuard,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{Receiver, RecvTimeoutError, Sender, channel},
     },
-    task::{Context, Poll},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     vec::IntoIter,
 };
 
 use {
-    futures::{Future, Sink, SinkExt, Stream, StreamExt},
+    encoding_rs::{Encoding, UTF_8, UTF_16BE, UTF_16LE},
     http_type::{
-        ACCEPT, ACCEPT_ANY, BR_BYTES, COLON_U8, CONNECTION, CONTENT_LENGTH, CONTENT_TYPE, Compress,
-        ContentType, DEFAULT_BUFFER_SIZE, DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS, DEFAULT_HTTP_PATH,
-        DEFAULT_MAX_REDIRECT_TIMES, EMPTY_STR, HOST, HTTP_BR_BYTES, HttpStatus, HttpUrlComponents,
-        HttpVersion, LOCATION, Method, Protocol, QUERY, RequestBody, RequestBodyString,
-        RequestHeaders, ResponseHeaders, ResponseStatusCode, SEC_WEBSOCKET_KEY,
-        SEC_WEBSOCKET_VERSION, SPACE_U8, TAB_U8, UPGRADE, USER_AGENT,
-        tokio::{
-            io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf},
-            runtime::Runtime,
-            sync::Mutex,
-            time::timeout,
-        },
+        ACCEPT, ACCEPT_ANY, ACCEPT_ENCODING, BR_BYTES, COLON_U8, CONTENT_ENCODING, CONTENT_LENGTH,
+        CONTENT_TYPE, ContentType, DEFAULT_BUFFER_SIZE, DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+        DEFAULT_HTTP_PATH, DEFAULT_MAX_REDIRECT_TIMES, EMPTY_STR, HOST, HTTP_BR_BYTES, HttpStatus,
+        HttpUrlComponents, LOCATION, Protocol, QUERY, RequestBody, RequestBodyString,
+        RequestHeaders, SPACE_U8, TAB_U8, USER_AGENT,
     },
+    md5::Md5,
+    serde::{Serialize, Serializer},
+    sha2::{Digest, Sha256},
+};
+
+#[cfg(feature = "json")]
+use {serde::de::DeserializeOwned, serde_json::de::SliceRead, std::marker::PhantomData};
+
+#[cfg(any(feature = "proxy", feature = "tls"))]
+use std::net::Ipv4Addr;
+
+#[cfg(any(feature = "proxy", feature = "trace-context"))]
+use std::cell::RefCell;
+
+#[cfg(feature = "compress")]
+use {
+    brotli::DecompressorWriter,
+    flate2::write::{MultiGzDecoder, ZlibDecoder},
+};
+
+#[cfg(feature = "tls")]
+use {
     rustls::{
-        ClientConfig, ClientConnection, RootCertStore, StreamOwned,
-        pki_types::{InvalidDnsNameError, ServerName},
+        ClientConfig, ClientConnection, DigitallySignedStruct, RootCertStore, SignatureScheme,
+        StreamOwned,
+        client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        crypto::{
+            WebPkiSupportedAlgorithms, ring::default_provider, verify_tls12_signature,
+            verify_tls13_signature,
+        },
+        pki_types::{CertificateDer, InvalidDnsNameError, ServerName, UnixTime},
     },
-    serde::{Serialize, Serializer},
-    tokio_rustls::{TlsConnector, client::TlsStream},
+    webpki_roots::TLS_SERVER_ROOTS,
+};
+
+#[cfg(feature = "async")]
+use {
+    futures::{
+        Future, Stream,
+        future::{Either, select, try_join_all},
+        stream::unfold,
+    },
+    http_type::tokio::{
+        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf},
+        net::{TcpStream as AsyncTcpStream, lookup_host},
+        sync::{Notify, futures::Notified},
+        task::{JoinError, spawn_blocking},
+        time::{sleep, timeout},
+    },
+    std::{
+        io::{Seek, SeekFrom},
+        pin::pin,
+        sync::atomic::AtomicBool,
+        task::{Context, Poll},
+    },
+};
+
+#[cfg(all(feature = "async", feature = "tls"))]
+use tokio_rustls::{TlsConnector, client::TlsStream};
+
+#[cfg(feature = "websocket")]
+use {
+    futures::{
+        Sink, SinkExt, StreamExt,
+        stream::{SplitSink, SplitStream},
+    },
+    http_type::{
+        CONNECTION, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, UPGRADE,
+        tokio::{runtime::Runtime, sync::Mutex},
+    },
+    std::sync::atomic::AtomicUsize,
     tokio_tungstenite::{
         MaybeTlsStream, WebSocketStream, client_async_with_config, connect_async_with_config,
-        tungstenite::Message, tungstenite::handshake::client::Request,
+        tungstenite::Message,
+        tungstenite::handshake::client::Request,
+        tungstenite::protocol::frame::coding::CloseCode,
+        tungstenite::protocol::{CloseFrame, WebSocketConfig as TungsteniteWebSocketConfig},
     },
-    webpki_roots::TLS_SERVER_ROOTS,
 };
+
+#[cfg(feature = "prost")]
+use prost::Message as ProtobufMessage;
@@ -0,0 +1,328 @@
+use super::*;
+
+impl Mime {
+    /// Creates a media type without parameters.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The top-level type.
+    /// - `&str` - The subtype.
+    ///
+    /// # Returns
+    ///
+    /// - `Mime` - The media type.
+    pub fn new(r#type: &str, subtype: &str) -> Self {
+        Self {
+            r#type: r#type.trim().to_ascii_lowercase(),
+            subtype: subtype.trim().to_ascii_lowercase(),
+            parameters: Vec::new(),
+        }
+    }
+
+    /// Gets the media type of a file name extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The extension without the leading dot, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The media type, or None if the extension is not known.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        MIME_TYPES_BY_EXTENSION
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(extension))
+            .and_then(|(_, essence)| essence.split_once('/'))
+            .map(|(r#type, subtype)| Self::new(r#type, subtype))
+    }
+
+    /// Adds a parameter, replacing any existing parameter with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter name.
+    /// - `&str` - The parameter value.
+    ///
+    /// # Returns
+    ///
+    /// - `Mime` - The media type with the parameter set.
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Self {
+        let name: String = name.trim().to_ascii_lowercase();
+        self.parameters.retain(|(key, _)| *key != name);
+        self.parameters.push((name, value.to_string()));
+        self
+    }
+
+    /// Gets the top-level type.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The lowercase type.
+    pub fn get_type(&self) -> &str {
+        &self.r#type
+    }
+
+    /// Gets the subtype.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The lowercase subtype.
+    pub fn get_subtype(&self) -> &str {
+        &self.subtype
+    }
+
+    /// Gets all parameters in declaration order.
+    ///
+    /// # Returns
+    ///
+    /// - `&[(String, String)]` - The parameter name and value pairs.
+    pub fn get_parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Gets a parameter value, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The parameter value, or None if absent.
+    pub fn get_parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Gets the `charset` parameter.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The charset, or None if absent.
+    pub fn get_charset(&self) -> Option<&str> {
+        self.get_parameter("charset")
+    }
+
+    /// Gets the type and subtype without parameters, such as `application/json`.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The essence of the media type.
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.r#type, self.subtype)
+    }
+
+    /// Checks if this media type matches another, honoring `*` wildcards on either side.
+    ///
+    /// Parameters are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Mime` - The media type to compare with.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the types match, false otherwise.
+    pub fn matches(&self, other: &Mime) -> bool {
+        let type_matches: bool =
+            self.r#type == "*" || other.r#type == "*" || self.r#type == other.r#type;
+        let subtype_matches: bool =
+            self.subtype == "*" || other.subtype == "*" || self.subtype == other.subtype;
+        type_matches && subtype_matches
+    }
+
+    /// Checks if this media type is acceptable under weighted media ranges.
+    ///
+    /// The most specific matching range decides: an exact type beats `type/*`,
+    /// which beats `*/*`. A quality of zero marks the type as not acceptable.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<f32>` - The quality of the deciding range, or None if no range matches.
+    pub fn quality_in(&self, accepts: &[(&str, f32)]) -> Option<f32> {
+        accepts
+            .iter()
+            .filter_map(|(range, quality)| {
+                let range: Mime = range.parse::<Mime>().ok()?;
+                if !range.matches(self) {
+                    return None;
+                }
+                let specificity: u8 = match (range.r#type.as_str(), range.subtype.as_str()) {
+                    ("*", _) => 0,
+                    (_, "*") => 1,
+                    _ => 2,
+                };
+                Some((specificity, quality.clamp(0.0, 1.0)))
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, quality)| quality)
+    }
+
+    /// Formats weighted media ranges as an `Accept` header value.
+    ///
+    /// Quality values are clamped to `0.0..=1.0`, rounded to three decimals, and
+    /// omitted when equal to 1.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The header value, such as `application/json, text/html;q=0.5`.
+    pub(crate) fn format_accept(accepts: &[(&str, f32)]) -> String {
+        accepts
+            .iter()
+            .map(|(range, quality)| {
+                let millis: u32 = (quality.clamp(0.0, 1.0) * 1000.0).round() as u32;
+                if millis == 1000 {
+                    return range.trim().to_string();
+                }
+                let quality: String = format!("0.{millis:03}");
+                let quality: &str = quality.trim_end_matches('0').trim_end_matches('.');
+                format!("{};q={quality}", range.trim())
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Splits a header value on a separator that is not inside a quoted string.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    /// - `char` - The separator.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<&str>` - The segments between separators.
+    pub(crate) fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+        let mut segments: Vec<&str> = Vec::new();
+        let mut start: usize = 0;
+        let mut in_quotes: bool = false;
+        let mut escaped: bool = false;
+        for (index, ch) in value.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if in_quotes && ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_quotes = !in_quotes;
+            } else if ch == separator && !in_quotes {
+                segments.push(&value[start..index]);
+                start = index + ch.len_utf8();
+            }
+        }
+        segments.push(&value[start..]);
+        segments
+    }
+
+    /// Removes the quotes and escapes from a parameter value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The raw parameter value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The unquoted value.
+    fn unquote(value: &str) -> String {
+        let Some(inner) = value
+            .strip_prefix('"')
+            .and_then(|rest: &str| rest.strip_suffix('"'))
+        else {
+            return value.to_string();
+        };
+        let mut result: String = String::with_capacity(inner.len());
+        let mut escaped: bool = false;
+        for ch in inner.chars() {
+            if !escaped && ch == '\\' {
+                escaped = true;
+                continue;
+            }
+            escaped = false;
+            result.push(ch);
+        }
+        result
+    }
+
+    /// Checks if a parameter value can be written without quotes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter value.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the value is a valid token, false otherwise.
+    fn is_token(value: &str) -> bool {
+        !value.is_empty()
+            && value
+                .bytes()
+                .all(|byte: u8| byte.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?={}".contains(&byte))
+    }
+}
+
+impl FromStr for Mime {
+    type Err = RequestError;
+
+    /// Parses a media type such as `text/html; charset=utf-8`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Mime, RequestError>` - The parsed media type, or an error if the type or subtype is missing.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = Self::split_unquoted(value, ';');
+        let essence: &str = segments[0].trim();
+        let (r#type, subtype) = essence
+            .split_once('/')
+            .filter(|(r#type, subtype)| {
+                Self::is_token(r#type.trim()) && Self::is_token(subtype.trim())
+            })
+            .ok_or_else(|| RequestError::Request(format!("Invalid media type: {value}")))?;
+        let mut mime: Mime = Mime::new(r#type, subtype);
+        for segment in &segments[1..] {
+            if let Some((name, raw_value)) = segment.split_once('=') {
+                let name: &str = name.trim();
+                if !name.is_empty() {
+                    mime = mime.with_parameter(name, &Self::unquote(raw_value.trim()));
+                }
+            }
+        }
+        Ok(mime)
+    }
+}
+
+impl Display for Mime {
+    /// Formats the media type as a header value, quoting parameter values when needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - Result of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.r#type, self.subtype)?;
+        for (name, value) in &self.parameters {
+            if Self::is_token(value) {
+                write!(f, "; {name}={value}")?;
+            } else {
+                let escaped: String = value.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(f, "; {name}=\"{escaped}\"")?;
+            }
+        }
+        Ok(())
+    }
+}
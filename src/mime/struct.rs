@@ -0,0 +1,15 @@
+use super::*;
+
+/// A parsed media type, as carried by the `Content-Type` and `Accept` headers.
+///
+/// The type, subtype, and parameter names are stored lowercase; parameter
+/// values keep their original case.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Mime {
+    /// The top-level type, such as `text` or `application`.
+    pub(crate) r#type: String,
+    /// The subtype, such as `html` or `json`.
+    pub(crate) subtype: String,
+    /// The parameters in declaration order, such as `charset=utf-8`.
+    pub(crate) parameters: Vec<(String, String)>,
+}
@@ -0,0 +1,19 @@
+//! The traits and types most programs need.
+//!
+//! `use http_request::prelude::*;` brings the request, response, and WebSocket
+//! traits into scope together with the builders and error types, without
+//! importing everything the crate root exports.
+
+pub use crate::{
+    BoxRequestTrait, BoxResponseTrait, HttpResponseBinary, HttpResponseText, RequestBuilder,
+    RequestError, RequestTrait, ResponseTrait,
+};
+
+#[cfg(feature = "async")]
+pub use crate::{AsyncRequestTrait, BoxAsyncRequestTrait};
+
+#[cfg(feature = "websocket")]
+pub use crate::{
+    AsyncWebSocketTrait, BoxAsyncWebSocketTrait, BoxWebSocketTrait, WebSocket, WebSocketBuilder,
+    WebSocketError, WebSocketMessage, WebSocketTrait,
+};
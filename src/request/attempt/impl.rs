@@ -0,0 +1,69 @@
+use super::*;
+
+impl RequestAttempt<'_> {
+    /// Gets the URL the attempt is sent to.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The request URL, which changes on redirect hops.
+    pub fn get_url(&self) -> &str {
+        &self.request.url
+    }
+
+    /// Gets the request method.
+    ///
+    /// # Returns
+    ///
+    /// - `Method` - The HTTP method.
+    pub fn get_method(&self) -> Method {
+        self.request.get_methods()
+    }
+
+    /// Gets the request headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&RequestHeaders` - The headers sent with the attempt.
+    pub fn get_headers(&self) -> &RequestHeaders {
+        &self.request.header
+    }
+
+    /// Sets a request header, replacing any existing value regardless of case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The attempt for method chaining.
+    pub fn set_header(&mut self, key: &str, value: &str) -> &mut Self {
+        self.remove_header(key);
+        let mut value_deque: VecDeque<String> = VecDeque::new();
+        value_deque.push_front(value.to_string());
+        Arc::make_mut(&mut self.request.header).insert(key.to_string(), value_deque);
+        self
+    }
+
+    /// Removes a request header, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The attempt for method chaining.
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        Arc::make_mut(&mut self.request.header)
+            .retain(|existing_key: &String, _| !existing_key.eq_ignore_ascii_case(key));
+        self
+    }
+}
+
+impl Debug for AttemptHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("AttemptHook")
+    }
+}
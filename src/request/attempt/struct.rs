@@ -0,0 +1,15 @@
+use super::*;
+
+/// Mutable view of a request about to be sent, passed to `RequestBuilder::on_attempt` hooks,
+/// middleware, and interceptors.
+///
+/// Changes made through it apply to the attempt being prepared and to every
+/// later attempt, such as redirect hops.
+pub struct RequestAttempt<'a> {
+    /// The request being sent.
+    pub(crate) request: &'a mut HttpRequest,
+}
+
+/// Hook invoked before every network attempt of a request.
+#[derive(Clone)]
+pub(crate) struct AttemptHook(pub(crate) AttemptHookFn);
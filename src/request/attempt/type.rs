@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared callback invoked before every network attempt of a request.
+pub(crate) type AttemptHookFn = Arc<dyn Fn(usize, &mut RequestAttempt<'_>) + Send + Sync>;
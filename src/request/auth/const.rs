@@ -0,0 +1,20 @@
+/// Header carrying the authentication challenges of a proxy.
+#[cfg(feature = "proxy")]
+pub(crate) const PROXY_AUTHENTICATE: &str = "Proxy-Authenticate";
+
+/// Header carrying the authentication challenges of a server.
+pub(crate) const WWW_AUTHENTICATE: &str = "WWW-Authenticate";
+
+/// Header carrying the credentials for a proxy.
+pub(crate) const PROXY_AUTHORIZATION: &str = "Proxy-Authorization";
+
+/// The Basic authentication scheme.
+#[cfg(feature = "proxy")]
+pub(crate) const BASIC_AUTH_SCHEME: &str = "Basic";
+
+/// Status code of a server requiring authentication.
+pub(crate) const UNAUTHORIZED: usize = 401;
+
+/// Status code of a proxy requiring authentication.
+#[cfg(feature = "proxy")]
+pub(crate) const PROXY_AUTHENTICATION_REQUIRED: usize = 407;
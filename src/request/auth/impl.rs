@@ -0,0 +1,237 @@
+use super::*;
+
+impl AuthChallenge {
+    /// Parses the challenges of an authentication header value.
+    ///
+    /// A header value may list several challenges separated by commas, each starting
+    /// with its scheme and followed by a token68 value or comma-separated parameters.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<AuthChallenge>` - The challenges in the order they were sent.
+    pub(crate) fn parse(value: &str) -> Vec<Self> {
+        let mut challenges: Vec<Self> = Vec::new();
+        for item in Self::split_items(value) {
+            let item: &str = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            if let Some((scheme, rest)) = item.split_once(char::is_whitespace)
+                && !scheme.contains('=')
+            {
+                let mut challenge: Self = Self {
+                    scheme: scheme.to_owned(),
+                    ..Self::default()
+                };
+                challenge.add_item(rest.trim());
+                challenges.push(challenge);
+            } else if item.contains('=')
+                && let Some(challenge) = challenges.last_mut()
+            {
+                challenge.add_item(item);
+            } else {
+                challenges.push(Self {
+                    scheme: item.to_owned(),
+                    ..Self::default()
+                });
+            }
+        }
+        challenges
+    }
+
+    /// Parses the challenges of every header with the given name in a header section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<AuthChallenge>` - The challenges of all matching headers.
+    pub(crate) fn from_headers(headers_bytes: &[u8], name: &str) -> Vec<Self> {
+        SharedResponseHandler::get_header_values(headers_bytes, name)
+            .iter()
+            .flat_map(|value: &String| Self::parse(value))
+            .collect()
+    }
+
+    /// Splits a header value on commas outside quoted strings.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<&str>` - The comma-separated items.
+    fn split_items(value: &str) -> Vec<&str> {
+        let mut items: Vec<&str> = Vec::new();
+        let mut start: usize = 0;
+        let mut quoted: bool = false;
+        let mut escaped: bool = false;
+        for (index, character) in value.char_indices() {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' if quoted => escaped = true,
+                '"' => quoted = !quoted,
+                ',' if !quoted => {
+                    items.push(&value[start..index]);
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        items.push(&value[start..]);
+        items
+    }
+
+    /// Adds a parameter or token68 value to the challenge.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - A `name=value` parameter or a token68 value.
+    fn add_item(&mut self, item: &str) {
+        if item.is_empty() {
+            return;
+        }
+        match item.split_once('=') {
+            Some((name, value)) if !value.trim_start().chars().all(|c: char| c == '=') => {
+                self.params
+                    .push((name.trim().to_owned(), Self::unquote(value.trim())));
+            }
+            _ => self.token68 = Some(item.to_owned()),
+        }
+    }
+
+    /// Removes the quotes and escapes of a quoted-string value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The unquoted value, or the value itself if it is not quoted.
+    fn unquote(value: &str) -> String {
+        let Some(inner) = value
+            .strip_prefix('"')
+            .and_then(|value: &str| value.strip_suffix('"'))
+        else {
+            return value.to_owned();
+        };
+        let mut unquoted: String = String::with_capacity(inner.len());
+        let mut escaped: bool = false;
+        for character in inner.chars() {
+            if !escaped && character == '\\' {
+                escaped = true;
+                continue;
+            }
+            escaped = false;
+            unquoted.push(character);
+        }
+        unquoted
+    }
+
+    /// Gets the authentication scheme.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The scheme, such as `Basic` or `Digest`.
+    pub fn get_scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Gets the token68 value of the challenge.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The token68 value, or None if the challenge carries parameters.
+    pub fn get_token68(&self) -> Option<&str> {
+        self.token68.as_deref()
+    }
+
+    /// Gets the parameters of the challenge.
+    ///
+    /// # Returns
+    ///
+    /// - `&[(String, String)]` - The parameter names and unquoted values.
+    pub fn get_params(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    /// Gets the value of a parameter.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The unquoted value, or None if the parameter is absent.
+    pub fn get_param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Gets the realm of the challenge.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The realm, or None if the challenge has none.
+    pub fn get_realm(&self) -> Option<&str> {
+        self.get_param("realm")
+    }
+
+    /// Checks if the challenge uses the given scheme.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The scheme, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the challenge uses the scheme, false otherwise.
+    pub fn is_scheme(&self, scheme: &str) -> bool {
+        self.scheme.eq_ignore_ascii_case(scheme)
+    }
+}
+
+/// Formats the AuthChallenge as a header value.
+///
+/// # Arguments
+///
+/// - `&mut Formatter<'_>` - The formatter to write to.
+///
+/// # Returns
+///
+/// - `fmt::Result` - Result of the formatting operation.
+impl Display for AuthChallenge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.scheme)?;
+        if let Some(token68) = &self.token68 {
+            return write!(f, " {token68}");
+        }
+        for (index, (name, value)) in self.params.iter().enumerate() {
+            let separator: &str = if index == 0 { " " } else { ", " };
+            write!(
+                f,
+                "{separator}{name}=\"{}\"",
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for AuthHandlerHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("AuthHandlerHook")
+    }
+}
@@ -0,0 +1,11 @@
+mod r#const;
+mod r#impl;
+mod r#struct;
+mod r#trait;
+mod r#type;
+
+pub use {r#struct::*, r#trait::*};
+
+pub(crate) use {r#const::*, r#type::*};
+
+use super::*;
@@ -0,0 +1,19 @@
+use super::*;
+
+/// An authentication challenge sent in a `WWW-Authenticate` or `Proxy-Authenticate` header.
+///
+/// A challenge names an authentication scheme and carries either a token68 value
+/// or a list of parameters such as the realm.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuthChallenge {
+    /// The authentication scheme, such as `Basic` or `Digest`.
+    pub(crate) scheme: String,
+    /// The token68 value of the challenge, if it carries one instead of parameters.
+    pub(crate) token68: Option<String>,
+    /// The parameters of the challenge in the order they were sent, with unquoted values.
+    pub(crate) params: Vec<(String, String)>,
+}
+
+/// Handler answering the 401 challenges of a request.
+#[derive(Clone)]
+pub(crate) struct AuthHandlerHook(pub(crate) ArcAuthHandler);
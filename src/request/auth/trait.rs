@@ -0,0 +1,21 @@
+use super::*;
+
+/// Handler answering the authentication challenges of a 401 response.
+///
+/// Invoked with the challenges of the `WWW-Authenticate` headers, a handler can
+/// compute credentials for any scheme and set them on the request, typically as an
+/// `Authorization` header. The request is then sent once more; a second 401 is
+/// returned as it is.
+pub trait AuthHandler: Send + Sync {
+    /// Answers the challenges of a 401 response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[AuthChallenge]` - The challenges in the order they were sent.
+    /// - `&mut RequestAttempt<'_>` - The request, to set the credentials on.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True to send the request again, false to return the 401 response.
+    fn authorize(&self, challenges: &[AuthChallenge], request: &mut RequestAttempt<'_>) -> bool;
+}
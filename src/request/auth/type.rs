@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared handler answering the authentication challenges of a request.
+pub(crate) type ArcAuthHandler = Arc<dyn AuthHandler>;
@@ -0,0 +1,17 @@
+/// Default maximum number of events coalesced into one batch.
+pub(crate) const DEFAULT_BEACON_MAX_BATCH_SIZE: usize = 32;
+
+/// Default maximum size in bytes of a batch body.
+pub(crate) const DEFAULT_BEACON_MAX_BATCH_BYTES: usize = 64 * 1024;
+
+/// Default time in milliseconds a batch may wait before it is sent.
+pub(crate) const DEFAULT_BEACON_FLUSH_INTERVAL_MS: u64 = 5000;
+
+/// Default number of retries for a batch whose delivery failed.
+pub(crate) const DEFAULT_BEACON_MAX_RETRIES: usize = 3;
+
+/// Default delay in milliseconds before the first retry, doubled for each further retry.
+pub(crate) const DEFAULT_BEACON_RETRY_DELAY_MS: u64 = 200;
+
+/// Separator placed between the events of a batch body.
+pub(crate) const BEACON_EVENT_SEPARATOR: u8 = b'\n';
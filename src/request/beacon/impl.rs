@@ -0,0 +1,378 @@
+use super::*;
+
+impl BeaconBatch {
+    /// Checks if the batch is due to be sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `Instant` - The current time.
+    /// - `Duration` - The time a batch may wait before it is sent.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the batch is full or has waited for the flush interval.
+    fn is_ready(&self, now: Instant, flush_interval: Duration) -> bool {
+        self.full || now.duration_since(self.created_at) >= flush_interval
+    }
+}
+
+impl BeaconShared {
+    /// Builds the POST request delivering a batch.
+    ///
+    /// The request uses the headers and configuration of the template request, with
+    /// its own copy of the configuration and processing state.
+    ///
+    /// # Arguments
+    ///
+    /// - `&BeaconBatch` - The batch to deliver.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpRequest` - The request carrying the batch body.
+    fn build_request(&self, batch: &BeaconBatch) -> HttpRequest {
+        let config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        let mut http_request: HttpRequest = self.http_request.clone();
+        http_request.methods = Arc::new(Method::Post);
+        http_request.url = Arc::new(batch.url.clone());
+        http_request.body = Arc::new(Body::Binary(batch.body.clone()));
+        http_request.config = Arc::new(RwLock::new(config));
+        http_request.tmp = Arc::new(RwLock::new(Tmp::default()));
+        http_request.response = Arc::new(RwLock::new(HttpResponseBinary::default()));
+        http_request
+    }
+
+    /// Sends a batch, retrying failed deliveries with exponential backoff.
+    ///
+    /// Connection errors, 408, 429, and 5xx responses are retried. Other 4xx
+    /// responses are treated as permanent failures.
+    ///
+    /// # Arguments
+    ///
+    /// - `&BeaconBatch` - The batch to deliver.
+    /// - `usize` - The number of retries.
+    /// - `Duration` - The delay before the first retry.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the batch was delivered, false if it was dropped.
+    fn deliver(&self, batch: &BeaconBatch, max_retries: usize, retry_delay: Duration) -> bool {
+        for attempt in 0..=max_retries {
+            if attempt > 0 {
+                thread::sleep(retry_delay.saturating_mul(1 << (attempt - 1).min(16)));
+            }
+            let status_code: ResponseStatusCode = match self.build_request(batch).send_sync() {
+                Ok(response) => response.binary().get_status_code(),
+                Err(_) => continue,
+            };
+            if (200..=399).contains(&status_code) {
+                return true;
+            }
+            if status_code != 408 && status_code != 429 && status_code < 500 {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Takes the batches due to be sent, waiting until at least one is.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(Vec<BeaconBatch>, usize, Duration)>` - The batches with the retry
+    ///   settings, or None once the beacon has shut down and its queue is empty.
+    fn take_ready(&self) -> Option<(Vec<BeaconBatch>, usize, Duration)> {
+        let mut state: StdMutexGuard<'_, BeaconState> = self.state.lock().ok()?;
+        loop {
+            let now: Instant = Instant::now();
+            let drain: bool = state.shutdown || state.flush_requested;
+            if drain && state.batches.is_empty() {
+                if state.shutdown {
+                    return None;
+                }
+                state.flush_requested = false;
+            }
+            let flush_interval: Duration = state.flush_interval;
+            let (ready, waiting): (Vec<BeaconBatch>, Vec<BeaconBatch>) = state
+                .batches
+                .drain(..)
+                .partition(|batch: &BeaconBatch| drain || batch.is_ready(now, flush_interval));
+            state.batches = waiting;
+            if !ready.is_empty() {
+                return Some((ready, state.max_retries, state.retry_delay));
+            }
+            state = match state.batches.first() {
+                Some(batch) => {
+                    let wait: Duration =
+                        (batch.created_at + flush_interval).saturating_duration_since(now);
+                    self.condvar.wait_timeout(state, wait).ok()?.0
+                }
+                None => self.condvar.wait(state).ok()?,
+            };
+        }
+    }
+
+    /// Runs the background sender until the beacon shuts down.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<BeaconShared>` - The shared beacon state.
+    fn run(shared: Arc<Self>) {
+        while let Some((batches, max_retries, retry_delay)) = shared.take_ready() {
+            for batch in batches {
+                let delivered: bool = shared.deliver(&batch, max_retries, retry_delay);
+                if let Ok(mut state) = shared.state.lock() {
+                    state.pending = state.pending.saturating_sub(batch.events);
+                    if delivered {
+                        state.delivered += batch.events;
+                    } else {
+                        state.dropped += batch.events;
+                    }
+                }
+                shared.condvar.notify_all();
+            }
+        }
+    }
+}
+
+impl Beacon {
+    /// Creates a beacon and starts its background sender.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpRequest` - The template request providing headers and configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Beacon` - A beacon with an empty queue.
+    pub(crate) fn new(http_request: HttpRequest) -> Self {
+        let shared: Arc<BeaconShared> = Arc::new(BeaconShared {
+            http_request,
+            state: StdMutex::new(BeaconState {
+                batches: Vec::new(),
+                max_batch_size: DEFAULT_BEACON_MAX_BATCH_SIZE,
+                max_batch_bytes: DEFAULT_BEACON_MAX_BATCH_BYTES,
+                flush_interval: Duration::from_millis(DEFAULT_BEACON_FLUSH_INTERVAL_MS),
+                max_retries: DEFAULT_BEACON_MAX_RETRIES,
+                retry_delay: Duration::from_millis(DEFAULT_BEACON_RETRY_DELAY_MS),
+                pending: 0,
+                delivered: 0,
+                dropped: 0,
+                flush_requested: false,
+                shutdown: false,
+            }),
+            condvar: Condvar::new(),
+        });
+        let worker_shared: Arc<BeaconShared> = shared.clone();
+        let worker: JoinHandle<()> = thread::spawn(move || BeaconShared::run(worker_shared));
+        Self {
+            shared,
+            worker: Some(worker),
+        }
+    }
+
+    /// Updates the beacon settings and wakes the background sender.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - A closure modifying the beacon state.
+    fn update<F: FnOnce(&mut BeaconState)>(&mut self, update: F) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            update(&mut state);
+        }
+        self.shared.condvar.notify_all();
+    }
+
+    /// Sets the maximum number of events coalesced into one batch.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum batch size, at least 1.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Beacon` - The beacon for method chaining.
+    pub fn max_batch_size(&mut self, max_batch_size: usize) -> &mut Self {
+        self.update(|state: &mut BeaconState| state.max_batch_size = max_batch_size.max(1));
+        self
+    }
+
+    /// Sets the maximum size in bytes of a batch body.
+    ///
+    /// An event larger than this limit is sent in a batch of its own.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum batch size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Beacon` - The beacon for method chaining.
+    pub fn max_batch_bytes(&mut self, max_batch_bytes: usize) -> &mut Self {
+        self.update(|state: &mut BeaconState| state.max_batch_bytes = max_batch_bytes);
+        self
+    }
+
+    /// Sets the time a batch may wait before it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The flush interval in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Beacon` - The beacon for method chaining.
+    pub fn flush_interval(&mut self, flush_interval: u64) -> &mut Self {
+        self.update(|state: &mut BeaconState| {
+            state.flush_interval = Duration::from_millis(flush_interval)
+        });
+        self
+    }
+
+    /// Sets the number of retries for a batch whose delivery failed.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of retries.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Beacon` - The beacon for method chaining.
+    pub fn max_retries(&mut self, max_retries: usize) -> &mut Self {
+        self.update(|state: &mut BeaconState| state.max_retries = max_retries);
+        self
+    }
+
+    /// Sets the delay before the first retry, doubled for each further retry.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The retry delay in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Beacon` - The beacon for method chaining.
+    pub fn retry_delay(&mut self, retry_delay: u64) -> &mut Self {
+        self.update(|state: &mut BeaconState| {
+            state.retry_delay = Duration::from_millis(retry_delay)
+        });
+        self
+    }
+
+    /// Queues an event for delivery to a URL.
+    ///
+    /// The event is appended to the open batch for the URL, or starts a new batch
+    /// if there is none or the open batch would exceed its limits.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL the event is posted to.
+    /// - `T` - The event body (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the event was queued, false if the beacon is shutting down.
+    pub fn send<T: Into<Vec<u8>>>(&self, url: &str, body: T) -> bool {
+        let body: Vec<u8> = body.into();
+        let Ok(mut state) = self.shared.state.lock() else {
+            return false;
+        };
+        if state.shutdown {
+            return false;
+        }
+        let max_batch_size: usize = state.max_batch_size;
+        let max_batch_bytes: usize = state.max_batch_bytes;
+        let open_batch: Option<&mut BeaconBatch> = state
+            .batches
+            .iter_mut()
+            .find(|batch: &&mut BeaconBatch| !batch.full && batch.url == url);
+        match open_batch {
+            Some(batch) if batch.body.len() + 1 + body.len() <= max_batch_bytes => {
+                batch.body.push(BEACON_EVENT_SEPARATOR);
+                batch.body.extend_from_slice(&body);
+                batch.events += 1;
+                batch.full = batch.events >= max_batch_size || batch.body.len() >= max_batch_bytes;
+            }
+            open_batch => {
+                if let Some(batch) = open_batch {
+                    batch.full = true;
+                }
+                let full: bool = max_batch_size <= 1 || body.len() >= max_batch_bytes;
+                state.batches.push(BeaconBatch {
+                    url: url.to_owned(),
+                    body,
+                    events: 1,
+                    full,
+                    created_at: Instant::now(),
+                });
+            }
+        }
+        state.pending += 1;
+        drop(state);
+        self.shared.condvar.notify_all();
+        true
+    }
+
+    /// Sends all queued events and waits until their delivery has finished.
+    ///
+    /// Events that could not be delivered after all retries are dropped.
+    pub fn flush(&self) {
+        let Ok(mut state) = self.shared.state.lock() else {
+            return;
+        };
+        if state.pending == 0 {
+            return;
+        }
+        state.flush_requested = true;
+        self.shared.condvar.notify_all();
+        while state.pending > 0 {
+            state = match self.shared.condvar.wait(state) {
+                Ok(state) => state,
+                Err(_) => return,
+            };
+        }
+    }
+
+    /// Gets the number of events queued or being sent.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of pending events.
+    pub fn get_pending(&self) -> usize {
+        self.shared.state.lock().map_or(0, |state| state.pending)
+    }
+
+    /// Gets the number of events delivered successfully.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of delivered events.
+    pub fn get_delivered(&self) -> usize {
+        self.shared.state.lock().map_or(0, |state| state.delivered)
+    }
+
+    /// Gets the number of events dropped after failed deliveries.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of dropped events.
+    pub fn get_dropped(&self) -> usize {
+        self.shared.state.lock().map_or(0, |state| state.dropped)
+    }
+}
+
+impl Drop for Beacon {
+    /// Flushes all queued events and stops the background sender.
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.shutdown = true;
+        }
+        self.shared.condvar.notify_all();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
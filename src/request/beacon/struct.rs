@@ -0,0 +1,69 @@
+use super::*;
+
+/// Fire-and-forget queue coalescing small events into batched POST requests.
+///
+/// Events queued for the same URL are joined with newlines into a single request
+/// body. A batch is sent by a background thread once it reaches the event count or
+/// size limit, or once it has waited for the flush interval. Failed deliveries are
+/// retried with exponential backoff, and every queued event is flushed before the
+/// beacon is dropped.
+#[derive(Debug)]
+pub struct Beacon {
+    /// State shared with the background sender thread.
+    pub(crate) shared: Arc<BeaconShared>,
+    /// Handle of the background sender thread.
+    pub(crate) worker: Option<JoinHandle<()>>,
+}
+
+/// State shared between a beacon and its background sender thread.
+#[derive(Debug)]
+pub(crate) struct BeaconShared {
+    /// Template request providing headers and configuration.
+    pub(crate) http_request: HttpRequest,
+    /// Queue and settings guarded by a mutex.
+    pub(crate) state: StdMutex<BeaconState>,
+    /// Signals queue changes, flush requests, and completed deliveries.
+    pub(crate) condvar: Condvar,
+}
+
+/// Queue and settings of a beacon.
+#[derive(Debug)]
+pub(crate) struct BeaconState {
+    /// Batches waiting to be sent, oldest first.
+    pub(crate) batches: Vec<BeaconBatch>,
+    /// Maximum number of events in a batch.
+    pub(crate) max_batch_size: usize,
+    /// Maximum size in bytes of a batch body.
+    pub(crate) max_batch_bytes: usize,
+    /// Time a batch may wait before it is sent.
+    pub(crate) flush_interval: Duration,
+    /// Number of retries for a failed delivery.
+    pub(crate) max_retries: usize,
+    /// Delay before the first retry.
+    pub(crate) retry_delay: Duration,
+    /// Number of events queued or being sent.
+    pub(crate) pending: usize,
+    /// Number of events delivered successfully.
+    pub(crate) delivered: usize,
+    /// Number of events dropped after failed deliveries.
+    pub(crate) dropped: usize,
+    /// Whether all batches should be sent without waiting.
+    pub(crate) flush_requested: bool,
+    /// Whether the beacon is shutting down.
+    pub(crate) shutdown: bool,
+}
+
+/// Events to one URL coalesced into a single request body.
+#[derive(Clone, Debug)]
+pub(crate) struct BeaconBatch {
+    /// Target URL of the batch.
+    pub(crate) url: String,
+    /// Newline-separated event bodies.
+    pub(crate) body: Vec<u8>,
+    /// Number of events in the batch.
+    pub(crate) events: usize,
+    /// Whether the batch accepts no further events.
+    pub(crate) full: bool,
+    /// When the first event was queued.
+    pub(crate) created_at: Instant,
+}
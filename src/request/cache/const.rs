@@ -0,0 +1,40 @@
+/// Header carrying the caching directives of a request or response.
+pub(crate) const CACHE_CONTROL: &str = "Cache-Control";
+
+/// Header carrying the entity tag of a response.
+pub(crate) const ETAG: &str = "ETag";
+
+/// Header carrying the last modification time of a response.
+pub(crate) const LAST_MODIFIED: &str = "Last-Modified";
+
+/// Header carrying the time a response was generated.
+pub(crate) const DATE: &str = "Date";
+
+/// Header carrying the time after which a response is stale.
+pub(crate) const EXPIRES: &str = "Expires";
+
+/// Header carrying the seconds a response spent in caches upstream.
+pub(crate) const AGE: &str = "Age";
+
+/// Header listing the request headers a response varies on.
+pub(crate) const VARY: &str = "Vary";
+
+/// Header revalidating a cached response by entity tag.
+pub(crate) const IF_NONE_MATCH: &str = "If-None-Match";
+
+/// Header revalidating a cached response by modification time.
+pub(crate) const IF_MODIFIED_SINCE: &str = "If-Modified-Since";
+
+/// Status code of a response confirming a cached response is still valid.
+pub(crate) const NOT_MODIFIED: usize = 304;
+
+/// Status codes whose responses may be stored.
+pub(crate) const CACHEABLE_STATUS_CODES: [usize; 11] =
+    [200, 203, 204, 300, 301, 308, 404, 405, 410, 414, 501];
+
+/// Headers of a 304 response that never replace the stored ones.
+pub(crate) const NOT_MODIFIED_SKIPPED_HEADERS: [&str; 3] =
+    ["content-length", "content-encoding", "transfer-encoding"];
+
+/// Number of responses kept by the in-memory cache of the default policy.
+pub(crate) const DEFAULT_CACHE_CAPACITY: usize = 256;
@@ -0,0 +1,432 @@
+use super::*;
+
+impl Default for CachePolicy {
+    /// Creates a policy storing responses in a new in-memory cache.
+    ///
+    /// # Returns
+    ///
+    /// - `CachePolicy` - The default policy.
+    fn default() -> Self {
+        Self {
+            storage: Arc::new(MemoryCache::new(DEFAULT_CACHE_CAPACITY)),
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Creates a policy storing responses in the given storage.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn CacheStorage>` - The storage, which may be shared between requests.
+    ///
+    /// # Returns
+    ///
+    /// - `CachePolicy` - The policy.
+    pub fn new(storage: Arc<dyn CacheStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// Gets the storage holding the responses.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn CacheStorage>` - The storage.
+    pub fn get_storage(&self) -> Arc<dyn CacheStorage> {
+        self.storage.clone()
+    }
+
+    /// Gets the stored response matching a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The cache key.
+    /// - `&RequestHeaders` - The request headers, compared with the headers the response varies on.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<CachedResponse>` - The stored response, or None if there is none or it was
+    ///   stored for a request with different varying headers.
+    pub(crate) fn lookup(&self, key: &str, headers: &RequestHeaders) -> Option<CachedResponse> {
+        self.storage
+            .get(key)
+            .filter(|cached: &CachedResponse| cached.matches(headers))
+    }
+}
+
+impl Debug for CachePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("CachePolicy")
+    }
+}
+
+impl CacheControl {
+    /// Parses the directives of `Cache-Control` header values.
+    ///
+    /// Unknown directives are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[String]` - The header values.
+    ///
+    /// # Returns
+    ///
+    /// - `CacheControl` - The directives.
+    pub(crate) fn parse(values: &[String]) -> Self {
+        let mut control: Self = Self::default();
+        for directive in values.iter().flat_map(|value: &String| value.split(',')) {
+            let (name, argument): (&str, Option<&str>) = match directive.split_once('=') {
+                Some((name, argument)) => (name, Some(argument.trim().trim_matches('"'))),
+                None => (directive, None),
+            };
+            match name.trim().to_ascii_lowercase().as_str() {
+                "no-store" => control.no_store = true,
+                "no-cache" => control.no_cache = true,
+                "max-age" => {
+                    control.max_age = argument.and_then(|argument: &str| argument.parse().ok())
+                }
+                "s-maxage" => {
+                    control.s_maxage = argument.and_then(|argument: &str| argument.parse().ok())
+                }
+                "private" => control.private = true,
+                "public" => control.public = true,
+                "must-revalidate" => control.must_revalidate = true,
+                _ => {}
+            }
+        }
+        control
+    }
+}
+
+impl CachedResponse {
+    /// Prepares a response for storage if it may be cached.
+    ///
+    /// Responses are stored when their status code is cacheable, they are not marked
+    /// `no-store` or `private`, do not vary on every header, are held in memory, and
+    /// are either fresh or carry a validator to revalidate them with. As the storage
+    /// may be shared, responses to authorized requests are only stored when marked
+    /// `public`, `s-maxage`, or `must-revalidate`.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpResponseBinary` - The response.
+    /// - `&RequestHeaders` - The headers of the request that produced it.
+    /// - `bool` - Whether the request carried credentials.
+    /// - `i64` - The current Unix seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<CachedResponse>` - The response to store, or None if it may not be cached.
+    pub(crate) fn new(
+        response: HttpResponseBinary,
+        headers: &RequestHeaders,
+        authorized: bool,
+        now: i64,
+    ) -> Option<Self> {
+        let control: CacheControl = CacheControl::parse(&response.get_header_all(CACHE_CONTROL));
+        let shareable: bool =
+            control.public || control.s_maxage.is_some() || control.must_revalidate;
+        if !CACHEABLE_STATUS_CODES.contains(&response.get_status_code())
+            || control.no_store
+            || control.private
+            || (authorized && !shareable)
+            || response.is_spooled()
+        {
+            return None;
+        }
+        let mut vary: Vec<(String, Option<String>)> = Vec::new();
+        for name in response
+            .get_header_all(VARY)
+            .iter()
+            .flat_map(|value: &String| value.split(','))
+        {
+            let name: String = name.trim().to_ascii_lowercase();
+            if name == "*" {
+                return None;
+            }
+            if !name.is_empty() {
+                let value: Option<String> = Self::get_request_header(headers, &name);
+                vary.push((name, value));
+            }
+        }
+        let (freshness_lifetime, initial_age): (i64, i64) =
+            Self::get_freshness(&response, &control, now);
+        let has_validator: bool =
+            response.get_header(ETAG).is_some() || response.get_header(LAST_MODIFIED).is_some();
+        (freshness_lifetime > 0 || has_validator).then_some(Self {
+            response,
+            stored_at: now,
+            freshness_lifetime,
+            initial_age,
+            vary,
+        })
+    }
+
+    /// Gets the stored response.
+    ///
+    /// # Returns
+    ///
+    /// - `&HttpResponseBinary` - The response.
+    pub fn get_response(&self) -> &HttpResponseBinary {
+        &self.response
+    }
+
+    /// Gets the time the response was stored or last revalidated.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - Seconds since the Unix epoch.
+    pub fn get_stored_at(&self) -> i64 {
+        self.stored_at
+    }
+
+    /// Gets how long the response stays fresh.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - The freshness lifetime in seconds, counted from the generation of the response.
+    pub fn get_freshness_lifetime(&self) -> i64 {
+        self.freshness_lifetime
+    }
+
+    /// Gets the age of the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `i64` - The current Unix seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - The seconds since the response was generated.
+    pub fn get_age(&self, now: i64) -> i64 {
+        self.initial_age + (now - self.stored_at).max(0)
+    }
+
+    /// Checks whether the response may be used without revalidation.
+    ///
+    /// # Arguments
+    ///
+    /// - `i64` - The current Unix seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the response is younger than its freshness lifetime.
+    pub fn is_fresh(&self, now: i64) -> bool {
+        self.get_age(now) < self.freshness_lifetime
+    }
+
+    /// Checks whether the response was stored for a request with the same varying headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestHeaders` - The headers of the new request.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if every header the response varies on has the same value.
+    pub(crate) fn matches(&self, headers: &RequestHeaders) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value): &(String, Option<String>)| {
+                Self::get_request_header(headers, name) == *value
+            })
+    }
+
+    /// Adds the validators of the response to a request revalidating it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestHeaders` - The headers of the revalidation request.
+    pub(crate) fn add_validators(&self, headers: &mut RequestHeaders) {
+        for (header, validator) in [(IF_NONE_MATCH, ETAG), (IF_MODIFIED_SINCE, LAST_MODIFIED)] {
+            if let Some(value) = self.response.get_header(validator) {
+                let mut value_deque: VecDeque<String> = VecDeque::new();
+                value_deque.push_front(value);
+                headers.insert(header.to_owned(), value_deque);
+            }
+        }
+    }
+
+    /// Updates the response with the headers of a 304 response revalidating it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The 304 response.
+    /// - `i64` - The current Unix seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `CachedResponse` - The refreshed response.
+    pub(crate) fn revalidate(&self, not_modified: &HttpResponseBinary, now: i64) -> Self {
+        let mut headers: ResponseHeaders = self.response.get_headers();
+        for (name, values) in not_modified.get_headers() {
+            if NOT_MODIFIED_SKIPPED_HEADERS
+                .iter()
+                .any(|skipped: &&str| skipped.eq_ignore_ascii_case(&name))
+            {
+                continue;
+            }
+            headers.retain(|key: &String, _| !key.eq_ignore_ascii_case(&name));
+            headers.insert(name, values);
+        }
+        let response: HttpResponseBinary = HttpResponseBinary {
+            headers: Arc::new(RwLock::new(headers)),
+            ..self.response.clone()
+        };
+        let control: CacheControl = CacheControl::parse(&response.get_header_all(CACHE_CONTROL));
+        let (freshness_lifetime, initial_age): (i64, i64) =
+            Self::get_freshness(&response, &control, now);
+        Self {
+            response,
+            stored_at: now,
+            freshness_lifetime,
+            initial_age,
+            vary: self.vary.clone(),
+        }
+    }
+
+    /// Computes the freshness lifetime and the age of a response.
+    ///
+    /// The lifetime comes from `max-age`, or else from `Expires` relative to `Date`.
+    /// Responses marked `no-cache` or without either are stale at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The response.
+    /// - `&CacheControl` - The caching directives of the response.
+    /// - `i64` - The current Unix seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `(i64, i64)` - The freshness lifetime and the current age in seconds.
+    fn get_freshness(
+        response: &HttpResponseBinary,
+        control: &CacheControl,
+        now: i64,
+    ) -> (i64, i64) {
+        let date: i64 = response
+            .get_header(DATE)
+            .and_then(|date: String| parse_http_date(&date))
+            .unwrap_or(now);
+        let freshness_lifetime: i64 = if control.no_cache {
+            0
+        } else if let Some(max_age) = control.max_age {
+            max_age
+        } else {
+            response
+                .get_header(EXPIRES)
+                .and_then(|expires: String| parse_http_date(&expires))
+                .map_or(0, |expires: i64| expires - date)
+        };
+        let age: i64 = response
+            .get_header(AGE)
+            .and_then(|age: String| age.trim().parse().ok())
+            .unwrap_or(0);
+        (freshness_lifetime, age.max(now - date).max(0))
+    }
+
+    /// Gets the values of a request header joined with commas.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestHeaders` - The request headers.
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The joined values, or None if the header is absent.
+    fn get_request_header(headers: &RequestHeaders, name: &str) -> Option<String> {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, values)| {
+                values
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            })
+    }
+}
+
+impl MemoryCache {
+    /// Creates an empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The largest number of responses kept, at least one.
+    ///
+    /// # Returns
+    ///
+    /// - `MemoryCache` - The cache.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: StdMutex::new(MemoryCacheEntries::default()),
+        }
+    }
+
+    /// Gets the number of stored responses.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of responses.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map_or(0, |entries| entries.responses.len())
+    }
+
+    /// Checks whether the cache holds no responses.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every stored response.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.responses.clear();
+        }
+    }
+}
+
+impl CacheStorage for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries: StdMutexGuard<'_, MemoryCacheEntries> = self.entries.lock().ok()?;
+        let tick: u64 = entries.tick;
+        let (last_used, cached) = entries.responses.get_mut(key)?;
+        *last_used = tick;
+        let cached: CachedResponse = cached.clone();
+        entries.tick += 1;
+        Some(cached)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if !entries.responses.contains_key(key)
+            && entries.responses.len() >= self.capacity
+            && let Some(oldest) = entries
+                .responses
+                .iter()
+                .min_by_key(|(_, (last_used, _))| *last_used)
+                .map(|(key, _)| key.clone())
+        {
+            entries.responses.remove(&oldest);
+        }
+        let tick: u64 = entries.tick;
+        entries.responses.insert(key.to_string(), (tick, response));
+        entries.tick += 1;
+    }
+
+    fn remove(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.responses.remove(key);
+        }
+    }
+}
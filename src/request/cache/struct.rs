@@ -0,0 +1,63 @@
+use super::*;
+
+/// Settings of the response cache used by a request.
+///
+/// The default policy stores responses in a new in-memory LRU cache. Clones of a
+/// policy share its storage, so one policy can serve many requests.
+#[derive(Clone)]
+pub struct CachePolicy {
+    /// The storage holding the responses.
+    pub(crate) storage: ArcCacheStorage,
+}
+
+/// Response kept by a cache along with what is needed to judge its freshness.
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    /// The stored response.
+    pub(crate) response: HttpResponseBinary,
+    /// Unix seconds at which the response was stored or last revalidated.
+    pub(crate) stored_at: i64,
+    /// Seconds the response stays fresh, counted from its generation.
+    pub(crate) freshness_lifetime: i64,
+    /// Age of the response in seconds when it was stored.
+    pub(crate) initial_age: i64,
+    /// Lowercase names and values of the request headers the response varies on.
+    pub(crate) vary: Vec<(String, Option<String>)>,
+}
+
+/// In-memory cache evicting the least recently used response once full.
+#[derive(Debug)]
+pub struct MemoryCache {
+    /// The largest number of responses kept.
+    pub(crate) capacity: usize,
+    /// The stored responses.
+    pub(crate) entries: StdMutex<MemoryCacheEntries>,
+}
+
+/// Responses of a memory cache with their recency.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryCacheEntries {
+    /// The responses and the tick of their last use, keyed by cache key.
+    pub(crate) responses: HashMapXxHash3_64<String, (u64, CachedResponse)>,
+    /// The tick given to the next use.
+    pub(crate) tick: u64,
+}
+
+/// Caching directives of a `Cache-Control` header.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CacheControl {
+    /// Whether the message must not be stored.
+    pub(crate) no_store: bool,
+    /// Whether a stored response must be revalidated before use.
+    pub(crate) no_cache: bool,
+    /// The greatest age in seconds a response may be used at.
+    pub(crate) max_age: Option<i64>,
+    /// The greatest age in seconds a response may be used at by shared caches.
+    pub(crate) s_maxage: Option<i64>,
+    /// Whether the response is meant for a single user and must not be shared.
+    pub(crate) private: bool,
+    /// Whether the response may be stored even if it would not be by default.
+    pub(crate) public: bool,
+    /// Whether a stale response must be revalidated before use.
+    pub(crate) must_revalidate: bool,
+}
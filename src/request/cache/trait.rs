@@ -0,0 +1,34 @@
+use super::*;
+
+/// Storage backing a response cache.
+///
+/// Keys identify a request by its URL and whether its response is decoded. The
+/// storage decides how many responses to keep and which ones to evict; freshness
+/// and revalidation are handled by the client.
+pub trait CacheStorage: Send + Sync {
+    /// Gets a stored response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The cache key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<CachedResponse>` - The stored response, or None if there is none.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores a response, replacing any response stored under the same key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The cache key.
+    /// - `CachedResponse` - The response to store.
+    fn put(&self, key: &str, response: CachedResponse);
+
+    /// Removes a stored response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The cache key.
+    fn remove(&self, key: &str);
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared storage holding the responses of a cache.
+pub(crate) type ArcCacheStorage = Arc<dyn CacheStorage>;
@@ -0,0 +1,68 @@
+use super::*;
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled.
+    ///
+    /// # Returns
+    ///
+    /// - `CancellationToken` - The new token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token, aborting every request it is attached to.
+    ///
+    /// Requests in flight stop at their next await point, such as while connecting,
+    /// during the TLS handshake, or while reading the body, and fail with
+    /// `RequestError::RequestAborted`. Requests sent later with the token fail
+    /// immediately. Cancelling an already cancelled token has no effect.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        self.0.notify.notify_waiters();
+    }
+
+    /// Checks if the token was cancelled.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True once `cancel` was called on the token or one of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        let notified: Notified<'_> = self.0.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+
+    /// Runs a request future, aborting it when the token is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<CancellationToken>` - The token, or None to run the future to completion.
+    /// - `F` - The request future.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The output of the future, or
+    ///   `RequestError::RequestAborted` if the token was cancelled first.
+    pub(crate) async fn run<T, F>(token: Option<Self>, future: F) -> Result<T, RequestError>
+    where
+        F: Future<Output = Result<T, RequestError>>,
+    {
+        let Some(token) = token else {
+            return future.await;
+        };
+        if token.is_cancelled() {
+            return Err(RequestError::RequestAborted);
+        }
+        match select(pin!(token.cancelled()), pin!(future)).await {
+            Either::Left(_) => Err(RequestError::RequestAborted),
+            Either::Right((result, _)) => result,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// A handle aborting the asynchronous requests it is attached to.
+///
+/// Clones share the same state, so a token can be handed to a request and kept by
+/// the caller, such as a UI cancel button or a supervising task.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(pub(crate) Arc<CancellationState>);
+
+/// State shared by the clones of a cancellation token.
+#[derive(Debug, Default)]
+pub(crate) struct CancellationState {
+    /// Whether the token was cancelled.
+    pub(crate) cancelled: AtomicBool,
+    /// Wakes the requests waiting for the token to be cancelled.
+    pub(crate) notify: Notify,
+}
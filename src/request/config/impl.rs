@@ -14,10 +14,435 @@ impl Default for Config {
             redirect: false,
             max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES,
             redirect_times: 0,
+            redirect_policy: RedirectPolicy::default(),
             http_version: HttpVersion::default(),
             buffer: DEFAULT_BUFFER_SIZE,
             decode: true,
+            #[cfg(feature = "proxy")]
             proxy: None,
+            #[cfg(feature = "proxy")]
+            proxy_keep_alive: false,
+            #[cfg(feature = "proxy")]
+            proxy_absolute_form: false,
+            request_target: RequestTarget::default(),
+            strict_parsing: false,
+            max_response_headers: DEFAULT_MAX_RESPONSE_HEADERS,
+            max_response_header_size: DEFAULT_MAX_RESPONSE_HEADER_SIZE,
+            max_response_header_line: DEFAULT_MAX_RESPONSE_HEADER_LINE,
+            max_response_body: None,
+            removed_default_headers: HashSet::new(),
+            host_header: None,
+            resolved_addrs: Vec::new(),
+            dns_overrides: Vec::new(),
+            request_compression: None,
+            spool_threshold: None,
+            query: Vec::new(),
+            path_params: Vec::new(),
+            insecure_skip_tls_verify: false,
+            single_flight: false,
+            trailers: Vec::new(),
+            header_order: Vec::new(),
+            encode_url: true,
+            expect_continue: None,
+            tls_server_name: None,
         }
     }
 }
+
+impl Config {
+    /// Gets the name used for SNI and certificate verification when connecting to a host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host being connected to.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The configured TLS server name, or the host if none is set.
+    pub(crate) fn get_tls_server_name(&self, host: &str) -> String {
+        self.tls_server_name
+            .clone()
+            .unwrap_or_else(|| host.to_string())
+    }
+
+    /// Records a header name in the order headers are sent in, unless already recorded.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    pub(crate) fn push_header_order(&mut self, name: &str) {
+        let name: String = name.to_ascii_lowercase();
+        if !self.header_order.contains(&name) {
+            self.header_order.push(name);
+        }
+    }
+
+    /// Checks the header section at the start of the response bytes against the header limits.
+    ///
+    /// The header section may still be incomplete, in which case the bytes and header
+    /// lines received so far are checked, so an oversized section or line is rejected
+    /// before it has been read entirely.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The response bytes received so far.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the header section is within the limits,
+    ///   `RequestError::TooManyHeaders`, `RequestError::HeaderSectionTooLarge`, or
+    ///   `RequestError::HeaderLineTooLong` otherwise.
+    pub(crate) fn check_header_limits(&self, response_bytes: &[u8]) -> Result<(), RequestError> {
+        let headers_end: Option<usize> =
+            SharedResponseHandler::find_double_crlf(response_bytes, 0).map(|pos: usize| pos + 4);
+        let headers_bytes: &[u8] = &response_bytes[..headers_end.unwrap_or(response_bytes.len())];
+        if headers_bytes.len() > self.max_response_header_size {
+            return Err(RequestError::HeaderSectionTooLarge(
+                self.max_response_header_size,
+            ));
+        }
+        let lines: usize = headers_bytes
+            .windows(BR_BYTES.len())
+            .filter(|window: &&[u8]| *window == BR_BYTES)
+            .count();
+        let non_header_lines: usize = if headers_end.is_some() { 2 } else { 1 };
+        if lines.saturating_sub(non_header_lines) > self.max_response_headers {
+            return Err(RequestError::TooManyHeaders(self.max_response_headers));
+        }
+        if headers_bytes
+            .split(|byte: &u8| *byte == b'\n')
+            .any(|line: &[u8]| {
+                line.strip_suffix(b"\r").unwrap_or(line).len() > self.max_response_header_line
+            })
+        {
+            return Err(RequestError::HeaderLineTooLong(
+                self.max_response_header_line,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the `Content-Length` announced by a response against the body limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The announced body length.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the body fits, `RequestError::ContentLengthTooLarge` otherwise.
+    pub(crate) fn check_content_length(&self, content_length: usize) -> Result<(), RequestError> {
+        match self.max_response_body {
+            Some(max) if content_length > max => Err(RequestError::ContentLengthTooLarge(max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the number of body bytes received so far against the body limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The body bytes received, including chunk framing.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the body fits, `RequestError::BodyTooLarge` otherwise.
+    pub(crate) fn check_body_size(&self, received: usize) -> Result<(), RequestError> {
+        match self.max_response_body {
+            Some(max) if received > max => Err(RequestError::BodyTooLarge(max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks if an automatically added header has been removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the header must not be added automatically, false otherwise.
+    pub(crate) fn is_default_header_removed(&self, key: &str) -> bool {
+        self.removed_default_headers
+            .contains(&key.to_ascii_lowercase())
+    }
+
+    /// Validates the Host header override.
+    ///
+    /// The value must be a non-empty host with an optional port, made of visible
+    /// ASCII characters other than those delimiting URL components, so it cannot
+    /// inject further header lines or alter the request target.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if no override is set or it is valid, Err otherwise.
+    pub(crate) fn check_host_header(&self) -> Result<(), RequestError> {
+        let Some(host_header) = &self.host_header else {
+            return Ok(());
+        };
+        let valid: bool = !host_header.is_empty()
+            && host_header.bytes().all(|byte: u8| {
+                byte.is_ascii_graphic() && !matches!(byte, b'/' | b'?' | b'#' | b'@' | b'\\')
+            });
+        if valid {
+            return Ok(());
+        }
+        Err(RequestError::Request(format!(
+            "Invalid Host header override: {host_header:?}"
+        )))
+    }
+
+    /// Gets the addresses to connect to instead of resolving the host.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<SocketAddr>` - The configured addresses with the port.
+    pub(crate) fn get_resolved_addrs(&self, port: u16) -> Vec<SocketAddr> {
+        self.resolved_addrs
+            .iter()
+            .map(|ip: &IpAddr| SocketAddr::new(*ip, port))
+            .collect()
+    }
+
+    /// Substitutes the path parameters for the `{name}` placeholders of a URL.
+    ///
+    /// Values are percent-encoded, so a value containing `/` or `?` stays within its
+    /// path segment.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL template.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The URL with every known placeholder replaced.
+    pub(crate) fn expand_path_params(&self, url: &str) -> String {
+        self.path_params
+            .iter()
+            .fold(url.to_owned(), |url: String, (name, value)| {
+                url.replace(&format!("{{{name}}}"), &percent_encode(value))
+            })
+    }
+
+    /// Gets the addresses overriding the resolution of a host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<SocketAddr>` - The override addresses in the order they were added.
+    pub(crate) fn get_dns_overrides(&self, host: &str) -> Vec<SocketAddr> {
+        self.dns_overrides
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(host))
+            .map(|(_, addr)| *addr)
+            .collect()
+    }
+
+    /// Checks if the request is sent to an HTTP proxy in absolute form.
+    ///
+    /// Only plain HTTP targets are forwarded this way; HTTPS targets are always
+    /// tunneled through CONNECT.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the request line carries the absolute URL and no tunnel is opened.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn is_proxy_absolute_form(&self) -> bool {
+        self.proxy_absolute_form
+            && self.url_obj.protocol.to_lowercase() == HTTP_LOWERCASE
+            && self.proxy.as_ref().is_some_and(|proxy: &ProxyConfig| {
+                matches!(proxy.proxy_type, ProxyType::Http | ProxyType::Https)
+            })
+    }
+
+    /// Checks if the request is sent to an HTTP proxy in absolute form.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Always false, as proxies are not compiled without the `proxy` feature.
+    #[cfg(not(feature = "proxy"))]
+    pub(crate) fn is_proxy_absolute_form(&self) -> bool {
+        false
+    }
+
+    /// Describes the settings as a JSON object.
+    ///
+    /// Proxy passwords are redacted.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    pub(crate) fn to_value(&self) -> Value {
+        let mut removed_default_headers: Vec<&String> =
+            self.removed_default_headers.iter().collect();
+        removed_default_headers.sort();
+        let request_target: &str = match self.request_target {
+            RequestTarget::Origin => "origin",
+            RequestTarget::Absolute => "absolute",
+            RequestTarget::Asterisk => "asterisk",
+        };
+        let settings: Value = serde_json::json!({
+            "timeout_ms": self.timeout,
+            "http_version": self.http_version.to_string(),
+            "buffer_size": self.buffer,
+            "decode": self.decode,
+            "request_target": request_target,
+            "redirect": {
+                "follow": self.redirect,
+                "max_redirects": self.max_redirect_times,
+                "rewrite_method": self.redirect_policy.rewrite_method,
+                "strip_sensitive_headers": self.redirect_policy.strip_sensitive_headers,
+                "allow_https_downgrade": self.redirect_policy.allow_https_downgrade,
+            },
+            "tls": {
+                "insecure_skip_tls_verify": self.insecure_skip_tls_verify,
+                "server_name": self.tls_server_name,
+            },
+            "limits": {
+                "strict_parsing": self.strict_parsing,
+                "max_response_headers": self.max_response_headers,
+                "max_response_header_size": self.max_response_header_size,
+                "max_response_header_line": self.max_response_header_line,
+                "max_response_body": self.max_response_body,
+            },
+            "dns": {
+                "resolved_addrs": self
+                    .resolved_addrs
+                    .iter()
+                    .map(|addr: &IpAddr| addr.to_string())
+                    .collect::<Vec<String>>(),
+                "overrides": self
+                    .dns_overrides
+                    .iter()
+                    .map(|(host, addr): &(String, SocketAddr)| (host.clone(), addr.to_string()))
+                    .collect::<Vec<(String, String)>>(),
+            },
+            "host_header": self.host_header,
+            "removed_default_headers": removed_default_headers,
+            "request_compression": self
+                .request_compression
+                .as_ref()
+                .map(|compress: &Compress| compress.to_string()),
+            "spool_threshold": self.spool_threshold,
+            "query": self.query,
+            "path_params": self.path_params,
+            "single_flight": self.single_flight,
+            "encode_url": self.encode_url,
+            "expect_continue_ms": self.expect_continue,
+            "trailers": self
+                .trailers
+                .iter()
+                .map(|(name, _): &(String, String)| name.clone())
+                .collect::<Vec<String>>(),
+        });
+        #[cfg(feature = "proxy")]
+        let settings: Value = self.with_proxy_value(settings);
+        settings
+    }
+
+    /// Adds the proxy settings to the JSON description of the settings.
+    ///
+    /// # Arguments
+    ///
+    /// - `Value` - The settings described by `to_value`.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings with the proxy, its tunnel options, and the tunnel pool limits.
+    #[cfg(all(feature = "json", feature = "proxy"))]
+    fn with_proxy_value(&self, mut settings: Value) -> Value {
+        if let Value::Object(map) = &mut settings {
+            map.insert(
+                "proxy".to_string(),
+                serde_json::json!(self.proxy.as_ref().map(ProxyConfig::to_value)),
+            );
+            map.insert(
+                "proxy_keep_alive".to_string(),
+                Value::from(self.proxy_keep_alive),
+            );
+            map.insert(
+                "proxy_absolute_form".to_string(),
+                Value::from(self.proxy_absolute_form),
+            );
+            map.insert(
+                "tunnel_pool".to_string(),
+                serde_json::json!({
+                    "max_idle_per_key": MAX_IDLE_TUNNELS_PER_KEY,
+                    "idle_timeout_ms": DEFAULT_TUNNEL_IDLE_TIMEOUT_MS,
+                }),
+            );
+        }
+        settings
+    }
+}
+
+#[cfg(feature = "proxy")]
+impl ProxyConfig {
+    /// Gets the credentials answering the challenges of the proxy.
+    ///
+    /// Only the Basic scheme is supported, and only if a username and password
+    /// are configured.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[AuthChallenge]` - The challenges sent by the proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The `Proxy-Authorization` header value, or None if no challenge can be answered.
+    pub(crate) fn get_authorization(&self, challenges: &[AuthChallenge]) -> Option<String> {
+        if !challenges
+            .iter()
+            .any(|challenge: &AuthChallenge| challenge.is_scheme(BASIC_AUTH_SCHEME))
+        {
+            return None;
+        }
+        self.get_basic_authorization()
+    }
+
+    /// Gets the Basic credentials for the proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The `Proxy-Authorization` header value, or None if no username and password are configured.
+    pub(crate) fn get_basic_authorization(&self) -> Option<String> {
+        let (Some(username), Some(password)) = (&self.username, &self.password) else {
+            return None;
+        };
+        let credentials: String = format!("{username}:{password}");
+        Some(format!(
+            "{BASIC_AUTH_SCHEME} {}",
+            base64_encode(credentials.as_bytes())
+        ))
+    }
+
+    /// Describes the proxy as a JSON object, with the password redacted.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The proxy type, address, and username.
+    #[cfg(feature = "json")]
+    pub(crate) fn to_value(&self) -> Value {
+        let proxy_type: &str = match self.proxy_type {
+            ProxyType::Http => "http",
+            ProxyType::Https => "https",
+            ProxyType::Socks5 => "socks5",
+        };
+        serde_json::json!({
+            "type": proxy_type,
+            "host": self.host,
+            "port": self.port,
+            "username": self.username,
+            "password": self.password.as_ref().map(|_| "<redacted>"),
+        })
+    }
+}
@@ -1,6 +1,9 @@
 mod r#impl;
 mod r#struct;
 
+#[cfg(feature = "proxy")]
+use http_type::HTTP_LOWERCASE;
+
 pub(crate) use r#struct::*;
 
 use super::*;
@@ -15,6 +15,8 @@ pub(crate) struct Config {
     pub(crate) max_redirect_times: usize,
     /// Current number of redirects followed.
     pub(crate) redirect_times: usize,
+    /// How redirects are followed.
+    pub(crate) redirect_policy: RedirectPolicy,
     /// HTTP version to use (1.1 or 2).
     pub(crate) http_version: HttpVersion,
     /// Buffer size for reading responses.
@@ -22,13 +24,65 @@ pub(crate) struct Config {
     /// Whether to decode response bodies automatically.
     pub(crate) decode: bool,
     /// Optional proxy configuration.
+    #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<ProxyConfig>,
+    /// Whether proxy tunnels are kept alive and reused across requests.
+    #[cfg(feature = "proxy")]
+    pub(crate) proxy_keep_alive: bool,
+    /// Whether plain HTTP requests are sent to an HTTP proxy in absolute form instead of through CONNECT.
+    #[cfg(feature = "proxy")]
+    pub(crate) proxy_absolute_form: bool,
+    /// Form of the request target sent in the request line.
+    pub(crate) request_target: RequestTarget,
+    /// Whether ambiguous response framing is rejected instead of parsed leniently.
+    pub(crate) strict_parsing: bool,
+    /// Maximum number of header lines accepted in a response.
+    pub(crate) max_response_headers: usize,
+    /// Maximum size in bytes of a response header section.
+    pub(crate) max_response_header_size: usize,
+    /// Maximum length in bytes of a response status or header line.
+    pub(crate) max_response_header_line: usize,
+    /// Maximum number of body bytes received for a response, unlimited if None.
+    pub(crate) max_response_body: Option<usize>,
+    /// Lowercase names of automatically added headers that are not sent.
+    pub(crate) removed_default_headers: HashSet<String>,
+    /// Host header value sent instead of the host of the URL.
+    pub(crate) host_header: Option<String>,
+    /// Addresses connected to instead of resolving the host of the URL.
+    pub(crate) resolved_addrs: Vec<IpAddr>,
+    /// Addresses connected to instead of resolving a host, keyed by lowercase hostname.
+    pub(crate) dns_overrides: Vec<(String, SocketAddr)>,
+    /// Content coding applied to outgoing request bodies.
+    pub(crate) request_compression: Option<Compress>,
+    /// Body size above which buffered responses are written to a temporary file.
+    pub(crate) spool_threshold: Option<usize>,
+    /// Query parameters appended to the query string of the URL, not yet encoded.
+    pub(crate) query: Vec<(String, String)>,
+    /// Values substituted for the `{name}` placeholders of the URL, not yet encoded.
+    pub(crate) path_params: Vec<(String, String)>,
+    /// Whether server certificates are accepted without verifying their chain.
+    pub(crate) insecure_skip_tls_verify: bool,
+    /// Whether identical concurrent GET requests share one call.
+    pub(crate) single_flight: bool,
+    /// Trailer fields sent after a chunked request body.
+    pub(crate) trailers: Vec<(String, String)>,
+    /// Lowercase names of the headers set on the request, in the order they were first set.
+    pub(crate) header_order: Vec<String>,
+    /// Whether the path and query of the URL are percent-encoded before sending.
+    pub(crate) encode_url: bool,
+    /// Time in milliseconds to wait for a `100 Continue` response before sending the body,
+    /// or None to send the body with the headers.
+    pub(crate) expect_continue: Option<u64>,
+    /// Name sent in the TLS SNI extension and verified against the server certificate,
+    /// or None to use the host being connected to.
+    pub(crate) tls_server_name: Option<String>,
 }
 
 /// Proxy server configuration.
 ///
 /// Contains proxy type, host/port, and optional authentication.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg(feature = "proxy")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct ProxyConfig {
     /// Type of proxy (HTTP/HTTPS/SOCKS5).
     pub(crate) proxy_type: ProxyType,
@@ -43,7 +97,8 @@ pub(crate) struct ProxyConfig {
 }
 
 /// Supported proxy types.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg(feature = "proxy")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) enum ProxyType {
     /// HTTP proxy.
     Http,
@@ -12,3 +12,23 @@ pub(crate) const TRANSFER_ENCODING_PATTERN: &[u8] = b"transfer-encoding:";
 ///
 /// Used for case-sensitive matching of the chunked transfer encoding value.
 pub(crate) const CHUNKED_PATTERN: &[u8] = b"chunked";
+
+/// Byte pattern for matching 'connection' header in HTTP responses.
+///
+/// Used for case-insensitive matching of the connection header.
+pub(crate) const CONNECTION_PATTERN: &[u8] = b"connection:";
+
+/// Byte pattern for matching 'close' value in HTTP headers.
+///
+/// Used for case-insensitive matching of the connection close value.
+pub(crate) const CLOSE_PATTERN: &[u8] = b"close";
+
+/// Request target addressing the server as a whole, used by `OPTIONS *`.
+pub(crate) const ASTERISK_FORM_TARGET: &str = "*";
+
+/// Default time in milliseconds to wait for a `100 Continue` response before sending the body.
+pub(crate) const DEFAULT_EXPECT_CONTINUE_TIMEOUT_MS: u64 = 1000;
+
+/// Error message for an HTTPS connection attempted without the `tls` feature.
+#[cfg(not(feature = "tls"))]
+pub(crate) const TLS_FEATURE_DISABLED: &str = "HTTPS requires the `tls` feature";
@@ -0,0 +1,13 @@
+/// The form of the request target sent in the request line.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RequestTarget {
+    /// The path and query of the URL, such as `/items?page=2`.
+    ///
+    /// Requests forwarded by an HTTP proxy in absolute form still carry the full URL.
+    #[default]
+    Origin,
+    /// The full URL, such as `http://example.com/items?page=2`, as sent to proxies.
+    Absolute,
+    /// A single `*`, addressing the server as a whole. Only valid for OPTIONS requests.
+    Asterisk,
+}
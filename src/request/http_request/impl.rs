@@ -5,14 +5,54 @@ use super::*;
 /// # Generic Parameters
 ///
 /// - `T` - Type implementing AsyncRead + AsyncWrite + Unpin + Send
+#[cfg(feature = "async")]
 impl<T: AsyncRead + AsyncWrite + Unpin + Send> AsyncReadWrite for T {}
 
-/// Blanket implementation for ReadWrite trait.
-///
-/// # Generic Parameters
-///
-/// - `T` - Type implementing Read + Write
-impl<T: Read + Write> ReadWrite for T {}
+impl ReadWrite for TcpStream {
+    /// Sets the read timeout of the socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The timeout, or None to block indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - An error if the timeout could not be set.
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        TcpStream::set_read_timeout(self, timeout)
+    }
+}
+
+#[cfg(feature = "tls")]
+impl<S: ReadWrite> ReadWrite for StreamOwned<ClientConnection, S> {
+    /// Sets the read timeout of the socket carrying the TLS session.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The timeout, or None to block indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - An error if the timeout could not be set.
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.sock.set_read_timeout(timeout)
+    }
+}
+
+impl ReadWrite for BoxReadWrite {
+    /// Sets the read timeout of the boxed stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The timeout, or None to block indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - An error if the timeout could not be set.
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.as_ref().set_read_timeout(timeout)
+    }
+}
 
 /// Async request trait implementation for HttpRequest.
 ///
@@ -23,6 +63,9 @@ impl<T: Read + Write> ReadWrite for T {}
 /// # Returns
 ///
 /// - `Pin<Box<dyn Future<Output = RequestResult> + Send + '_>>` - Future representing the async request.
+impl Sealed for HttpRequest {}
+
+#[cfg(feature = "async")]
 impl AsyncRequestTrait for HttpRequest {
     type RequestResult = RequestResult;
 
@@ -32,7 +75,61 @@ impl AsyncRequestTrait for HttpRequest {
     ///
     /// - `Pin<Box<dyn Future<Output = RequestResult> + Send + '_>>` - Future representing the async request.
     fn send(&mut self) -> Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>> {
-        Box::pin(self.send_async())
+        Box::pin(async move {
+            let _in_flight: InFlightGuard = self.track_in_flight();
+            self.start_metrics();
+            let cancellation: Option<CancellationToken> = self.cancellation.clone();
+            let result: RequestResult = CancellationToken::run(cancellation, async {
+                match self.cache.clone() {
+                    Some(policy) => self.send_cached_async(&policy).await,
+                    None => self.send_uncached_async().await,
+                }
+            })
+            .await
+            .map_err(|error: RequestError| self.with_error_phase(error));
+            self.finish_metrics(&result);
+            result
+        })
+    }
+
+    /// Sends an asynchronous HTTP request and streams the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>` - Future resolving to the streaming response.
+    fn send_stream(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let _in_flight: InFlightGuard = self.track_in_flight();
+            self.start_metrics();
+            let cancellation: Option<CancellationToken> = self.cancellation.clone();
+            let result: Result<AsyncHttpResponseStream, RequestError> =
+                CancellationToken::run(cancellation, self.send_stream_async())
+                    .await
+                    .map_err(|error: RequestError| self.with_error_phase(error));
+            self.finish_metrics(&result);
+            result
+        })
+    }
+    /// Describes the settings the request is sent with as a JSON object.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    fn effective_config(&self) -> Value {
+        self.get_effective_config()
+    }
+
+    /// Serializes the request exactly as it would be written to the connection, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The raw request bytes, or an error if the request is invalid.
+    fn to_raw_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        self.get_raw_bytes()
     }
 }
 
@@ -54,7 +151,48 @@ impl RequestTrait for HttpRequest {
     ///
     /// - `RequestResult` - Result of the sync request.
     fn send(&mut self) -> Self::RequestResult {
-        self.send_sync()
+        let _in_flight: InFlightGuard = self.track_in_flight();
+        self.start_metrics();
+        let result: RequestResult = match self.cache.clone() {
+            Some(policy) => self.send_cached(&policy),
+            None => self.send_uncached(),
+        }
+        .map_err(|error: RequestError| self.with_error_phase(error));
+        self.finish_metrics(&result);
+        result
+    }
+
+    /// Sends a synchronous HTTP request and streams the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseStream, RequestError>` - The streaming response.
+    fn send_stream(&mut self) -> Result<HttpResponseStream, RequestError> {
+        let _in_flight: InFlightGuard = self.track_in_flight();
+        self.start_metrics();
+        let result: Result<HttpResponseStream, RequestError> = self
+            .send_stream_sync()
+            .map_err(|error: RequestError| self.with_error_phase(error));
+        self.finish_metrics(&result);
+        result
+    }
+    /// Describes the settings the request is sent with as a JSON object.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    fn effective_config(&self) -> Value {
+        self.get_effective_config()
+    }
+
+    /// Serializes the request exactly as it would be written to the connection, without sending it.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The raw request bytes, or an error if the request is invalid.
+    fn to_raw_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        self.get_raw_bytes()
     }
 }
 
@@ -81,6 +219,20 @@ impl Default for HttpRequest {
             config: Arc::new(RwLock::new(Config::default())),
             tmp: Arc::new(RwLock::new(Tmp::default())),
             response: Arc::new(RwLock::new(HttpResponseBinary::default())),
+            on_attempt: None,
+            on_redirect: None,
+            auth_handler: None,
+            on_upload_progress: None,
+            on_download_progress: None,
+            on_metrics: None,
+            resolver: None,
+            signer: None,
+            cache: None,
+            #[cfg(feature = "async")]
+            cancellation: None,
+            middleware: MiddlewareStack::default(),
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
@@ -116,14 +268,16 @@ impl HttpRequest {
         self.methods.as_ref().clone()
     }
 
-    /// Gets the request URL.
+    /// Gets the request URL, with the path parameters substituted for its placeholders.
     ///
     /// # Returns
     ///
     /// - `String` - The request URL.
-    #[inline(always)]
-    fn get_url(&self) -> String {
-        self.url.as_ref().clone()
+    pub(crate) fn get_url(&self) -> String {
+        match self.config.read() {
+            Ok(config) if !config.path_params.is_empty() => config.expand_path_params(&self.url),
+            _ => self.url.as_ref().clone(),
+        }
     }
 
     /// Gets the request headers.
@@ -161,15 +315,39 @@ impl HttpRequest {
     /// Returns `Ok(HttpUrlComponents)` if the parsing succeeds, or `Err(RequestError::Request(String))` otherwise.
     /// Parses the current URL into a `HttpUrlComponents` object.
     ///
+    /// Internationalized host names are converted to their Punycode ASCII form.
+    ///
     /// # Returns
     ///
     /// - `Ok(HttpUrlComponents)` if parsing succeeds
     /// - `Err(RequestError::Request(String))` if parsing fails
     pub(crate) fn parse_url(&self) -> Result<HttpUrlComponents, RequestError> {
-        match HttpUrlComponents::parse(self.get_url()) {
-            Ok(parse_res) => Ok(parse_res),
-            Err(error) => Err(RequestError::Request(error.to_string())),
+        let url: String = self.get_url();
+        let templated: bool = self
+            .config
+            .read()
+            .is_ok_and(|config| !config.path_params.is_empty());
+        if templated
+            && let Some(start) = url.find('{')
+            && let Some(end) = url[start..].find('}')
+        {
+            return Err(RequestError::Request(format!(
+                "Missing path parameter {}",
+                &url[start..=start + end]
+            )));
+        }
+        let mut url_obj: HttpUrlComponents = HttpUrlComponents::parse(url.clone())
+            .map_err(|error| RequestError::Request(error.to_string()))?;
+        if let Some((host, port)) = SharedRequestBuilder::parse_ipv6_authority(&url) {
+            url_obj.host = Some(host);
+            url_obj.port = port;
         }
+        if let Some(host) = url_obj.host.as_deref() {
+            let ascii_host: String = host_to_ascii(host)
+                .ok_or_else(|| RequestError::Request(format!("Invalid host name {host}")))?;
+            url_obj.host = Some(ascii_host);
+        }
+        Ok(url_obj)
     }
 
     /// Converts the HTTP headers into a formatted HTTP header string and returns it as a byte vector.
@@ -185,11 +363,13 @@ impl HttpRequest {
     ///
     /// # Notes
     ///
-    /// - The `Host` header is derived from the URL's host in the configuration.
+    /// - The `Host` header is derived from the URL's host in the configuration, or taken
+    ///   from the Host header override if one is set.
     /// - The `Content-Length` header is calculated based on the request method:
-    ///   - For `GET` requests, it is set to `0`.
+    ///   - For `GET`, `HEAD`, and `OPTIONS` requests, and `DELETE` requests without a body, it is omitted.
     ///   - For other methods, it is determined by the length of the body.
-    /// - If any required header is missing, it is automatically added with its default value.
+    /// - If any required header is missing, it is automatically added with its default value,
+    ///   unless it was removed with `remove_default_header`.
     /// - Headers are concatenated into a string with each header ending in a line break specified by `HTTP_BR`.
     ///
     /// # Behavior
@@ -202,63 +382,230 @@ impl HttpRequest {
             .any(|key| key.eq_ignore_ascii_case(target_key))
     }
 
-    /// Converts HTTP headers into formatted HTTP header bytes.
+    /// Converts HTTP headers into formatted HTTP header bytes after the request signer ran.
+    ///
+    /// The signer sees the final headers and body and may add or replace headers
+    /// before they are serialized.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The origin-form path with its query string.
+    /// - `Option<&[u8]>` - The body bytes, or None if there is none or it is streamed.
+    /// - `Option<usize>` - The length of the body sent with the headers, or None if there is none.
+    /// - `bool` - Whether the response body is decoded, so supported codings may be advertised.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The formatted HTTP headers as bytes, or the signer's error.
+    pub(crate) fn get_signed_header_bytes(
+        &self,
+        path: &str,
+        body: Option<&[u8]>,
+        body_length: Option<usize>,
+        accept_encoding: bool,
+    ) -> Result<Vec<u8>, RequestError> {
+        let mut header: RequestHeaders = self.get_request_headers(body_length, accept_encoding);
+        if let Some(signer) = self.signer.as_ref() {
+            let (path, query): (&str, &str) = path.split_once(QUERY).unwrap_or((path, ""));
+            let method: String = self.get_methods().to_string();
+            let mut request: SignableRequest<'_> = SignableRequest {
+                method: &method,
+                path,
+                query,
+                headers: &mut header,
+                body,
+            };
+            signer.0.sign(&mut request)?;
+        }
+        Ok(self.serialize_headers(&header))
+    }
+
+    /// Gets the headers sent with the request, including the required defaults.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The length of the body sent with the headers, or None if there is none.
+    /// - `bool` - Whether the response body is decoded, so supported codings may be advertised.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The formatted HTTP headers as bytes.
-    pub(crate) fn get_header_bytes(&self) -> Vec<u8> {
+    /// - `RequestHeaders` - The user headers merged with the required defaults.
+    fn get_request_headers(
+        &self,
+        body_length: Option<usize>,
+        accept_encoding: bool,
+    ) -> RequestHeaders {
         let mut header: RequestHeaders = self.get_header();
-        let body_length: usize = if self.get_methods().is_get() {
-            0usize
-        } else {
-            self.get_body_bytes().len()
-        };
+        let request_compression: Option<Compress> = self.get_request_compression();
         if let Ok(config) = self.config.read() {
-            let host_value: String = config.url_obj.host.clone().unwrap_or_default();
-            let content_length_value: String = body_length.to_string();
-            if !Self::header_contains_key_case_insensitive(&header, HOST) {
+            let host_value: String = config.host_header.clone().unwrap_or_else(|| {
+                let port: u16 = self.get_port(config.url_obj.port.unwrap_or_default(), &config);
+                let default_port: u16 = Protocol::get_port(&Self::get_protocol(&config));
+                SharedRequestBuilder::format_authority(
+                    &config.url_obj.host.clone().unwrap_or_default(),
+                    (port != default_port).then_some(port),
+                )
+            });
+            if (config.host_header.is_some() || !config.is_default_header_removed(HOST))
+                && !Self::header_contains_key_case_insensitive(&header, HOST)
+            {
                 let mut host_deque: VecDeque<String> = VecDeque::new();
                 host_deque.push_front(host_value);
                 header.insert(HOST.to_owned(), host_deque);
             }
-            if !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH) {
+            if self
+                .get_expect_continue_timeout(&config, body_length)
+                .is_some()
+                && !Self::header_contains_key_case_insensitive(&header, EXPECT)
+            {
+                let mut expect_deque: VecDeque<String> = VecDeque::new();
+                expect_deque.push_front(EXPECT_100_CONTINUE.to_owned());
+                header.insert(EXPECT.to_owned(), expect_deque);
+            }
+            if self.has_streamed_body()
+                && !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH)
+                && !Self::header_contains_key_case_insensitive(&header, TRANSFER_ENCODING)
+            {
+                let mut transfer_encoding_deque: VecDeque<String> = VecDeque::new();
+                transfer_encoding_deque.push_front(CHUNKED.to_owned());
+                header.insert(TRANSFER_ENCODING.to_owned(), transfer_encoding_deque);
+            }
+            if self.has_streamed_body()
+                && !config.trailers.is_empty()
+                && !Self::header_contains_key_case_insensitive(&header, TRAILER)
+            {
+                let mut names: Vec<&str> = Vec::new();
+                for (name, _) in config.trailers.iter() {
+                    if !names
+                        .iter()
+                        .any(|seen: &&str| seen.eq_ignore_ascii_case(name))
+                    {
+                        names.push(name);
+                    }
+                }
+                let mut trailer_deque: VecDeque<String> = VecDeque::new();
+                trailer_deque.push_front(names.join(", "));
+                header.insert(TRAILER.to_owned(), trailer_deque);
+            }
+            if let Some(body_length) = body_length
+                && !config.is_default_header_removed(CONTENT_LENGTH)
+                && !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH)
+            {
                 let mut content_length_deque: VecDeque<String> = VecDeque::new();
-                content_length_deque.push_front(content_length_value);
+                content_length_deque.push_front(body_length.to_string());
                 header.insert(CONTENT_LENGTH.to_owned(), content_length_deque);
             }
-            if !Self::header_contains_key_case_insensitive(&header, ACCEPT) {
+            if let Some(compress) = request_compression
+                && body_length.is_some_and(|body_length: usize| body_length > 0)
+            {
+                let mut content_encoding_deque: VecDeque<String> = VecDeque::new();
+                content_encoding_deque.push_front(compress.to_string());
+                header.insert(CONTENT_ENCODING.to_owned(), content_encoding_deque);
+            }
+            if !config.is_default_header_removed(ACCEPT)
+                && !Self::header_contains_key_case_insensitive(&header, ACCEPT)
+            {
                 let mut accept_deque: VecDeque<String> = VecDeque::new();
                 accept_deque.push_front(ACCEPT_ANY.to_owned());
                 header.insert(ACCEPT.to_owned(), accept_deque);
             }
-            if !Self::header_contains_key_case_insensitive(&header, USER_AGENT) {
+            if accept_encoding
+                && config.decode
+                && !config.is_default_header_removed(ACCEPT_ENCODING)
+                && !Self::header_contains_key_case_insensitive(&header, RANGE)
+                && !Self::header_contains_key_case_insensitive(&header, ACCEPT_ENCODING)
+            {
+                let mut accept_encoding_deque: VecDeque<String> = VecDeque::new();
+                accept_encoding_deque.push_front(CodecRegistry::get_accept_encoding());
+                header.insert(ACCEPT_ENCODING.to_owned(), accept_encoding_deque);
+            }
+            if !config.is_default_header_removed(USER_AGENT)
+                && !Self::header_contains_key_case_insensitive(&header, USER_AGENT)
+            {
                 let mut user_agent_deque: VecDeque<String> = VecDeque::new();
                 user_agent_deque.push_front(APP_NAME.to_owned());
                 header.insert(USER_AGENT.to_owned(), user_agent_deque);
             }
+            #[cfg(feature = "proxy")]
+            if config.is_proxy_absolute_form()
+                && !Self::header_contains_key_case_insensitive(&header, PROXY_AUTHORIZATION)
+                && let Some(authorization) = config
+                    .proxy
+                    .as_ref()
+                    .and_then(ProxyConfig::get_basic_authorization)
+            {
+                let mut authorization_deque: VecDeque<String> = VecDeque::new();
+                authorization_deque.push_front(authorization);
+                header.insert(PROXY_AUTHORIZATION.to_owned(), authorization_deque);
+            }
         }
+        header
+    }
+
+    /// Serializes headers in the configured order, with `Host` first.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestHeaders` - The headers to serialize.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The formatted HTTP headers as bytes.
+    fn serialize_headers(&self, header: &RequestHeaders) -> Vec<u8> {
+        let header_order: Vec<String> = self
+            .config
+            .read()
+            .map_or_else(|_| Vec::new(), |config| config.header_order.clone());
         let estimated_size: usize = header
             .iter()
             .map(|(k, v)| {
-                k.len()
-                    + v.front()
-                        .map_or(0, |header_value: &String| header_value.len())
-                    + 4
+                v.iter()
+                    .map(|header_value: &String| k.len() + header_value.len() + 4)
+                    .sum::<usize>()
             })
             .sum();
+        let mut names: Vec<&String> = header.keys().collect();
+        names.sort_by_cached_key(|name: &&String| {
+            let name: String = name.to_ascii_lowercase();
+            let position: usize = header_order
+                .iter()
+                .position(|ordered: &String| *ordered == name)
+                .unwrap_or(usize::MAX);
+            (!name.eq_ignore_ascii_case(HOST), position, name)
+        });
         let mut header_bytes: Vec<u8> = Vec::with_capacity(estimated_size);
-        for (key, value) in &header {
-            header_bytes.extend_from_slice(key.as_bytes());
-            header_bytes.extend_from_slice(b": ");
-            if let Some(header_value) = value.front() {
-                header_bytes.extend_from_slice(header_value.as_bytes());
+        for key in names {
+            let values: &VecDeque<String> = &header[key];
+            if key.eq_ignore_ascii_case(COOKIE) && values.len() > 1 {
+                let cookie: String = values
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>()
+                    .join("; ");
+                Self::push_header_line(&mut header_bytes, key, &cookie);
+                continue;
+            }
+            for header_value in values {
+                Self::push_header_line(&mut header_bytes, key, header_value);
             }
-            header_bytes.extend_from_slice(HTTP_BR_BYTES);
         }
         header_bytes
     }
 
+    /// Appends one header line to the header bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<u8>` - The header bytes.
+    /// - `&str` - The header name.
+    /// - `&str` - The header value.
+    fn push_header_line(header_bytes: &mut Vec<u8>, key: &str, value: &str) {
+        header_bytes.extend_from_slice(key.as_bytes());
+        header_bytes.extend_from_slice(b": ");
+        header_bytes.extend_from_slice(value.as_bytes());
+        header_bytes.extend_from_slice(HTTP_BR_BYTES);
+    }
+
     /// Converts the HTTP body into a URL-encoded byte vector (`Vec<u8>`).
     ///
     /// This method processes the body of the HTTP request based on the `Content-Type` header.
@@ -273,9 +620,11 @@ impl HttpRequest {
     ///
     /// # Notes
     ///
-    /// The `Content-Type` header is matched case-insensitively. If no matching `Content-Type`
-    /// is found or the parsing fails, the method defaults to returning an empty byte vector.
+    /// The `Content-Type` header is matched case-insensitively and parsed as a `Mime`, so
+    /// parameters such as `charset` do not affect the conversion. If no `Content-Type` is
+    /// found, the method defaults to returning an empty byte vector.
     /// The body processing relies on the implementation of the `ContentType` parsing logic.
+    /// Binary bodies bypass this conversion and are sent untouched.
     /// Converts the HTTP body into URL-encoded bytes.
     ///
     /// # Returns
@@ -284,29 +633,30 @@ impl HttpRequest {
     pub(crate) fn get_body_bytes(&self) -> Vec<u8> {
         let header: RequestHeaders = self.get_header();
         let body: Body = self.get_body();
-        if let Some(content_type_value) = header.get(CONTENT_TYPE)
-            && let Some(first_value) = content_type_value.front()
-        {
-            let res: String = first_value
-                .to_lowercase()
-                .parse::<ContentType>()
-                .unwrap_or_default()
-                .get_body_string(&body);
-            return res.into_bytes();
-        }
-        for (key, value) in &header {
-            if key.eq_ignore_ascii_case(CONTENT_TYPE)
-                && let Some(first_value) = value.front()
-            {
-                let res: String = first_value
-                    .to_lowercase()
-                    .parse::<ContentType>()
-                    .unwrap_or_default()
-                    .get_body_string(&body);
-                return res.into_bytes();
-            }
+        if let Body::Binary(bytes) = body {
+            return bytes;
         }
-        String::new().into_bytes()
+        let content_type_value: Option<&String> = header
+            .get(CONTENT_TYPE)
+            .or_else(|| {
+                header
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(CONTENT_TYPE))
+                    .map(|(_, value)| value)
+            })
+            .and_then(|value: &VecDeque<String>| value.front());
+        let Some(first_value) = content_type_value else {
+            return Vec::new();
+        };
+        let essence: String = first_value
+            .parse::<Mime>()
+            .map(|mime: Mime| mime.essence())
+            .unwrap_or_else(|_| first_value.to_lowercase());
+        essence
+            .parse::<ContentType>()
+            .unwrap_or_default()
+            .get_body_string(&body)
+            .into_bytes()
     }
 
     /// Retrieves the full path of the HTTP request, including the query string if present.
@@ -328,106 +678,263 @@ impl HttpRequest {
     ///
     /// Gets the full request path including query string.
     ///
+    /// Query parameters added on the builder are percent-encoded and appended to any
+    /// query already present in the URL. Redirect targets are used as they are.
+    ///
     /// # Returns
     ///
     /// - `String` - The full path with query string if present.
     pub(crate) fn get_path(&self) -> String {
+        let redirected: bool = self
+            .tmp
+            .read()
+            .is_ok_and(|tmp| !tmp.redirect_chain.is_empty());
         let path: String = self.config.read().map_or(String::new(), |config| {
-            let query: String = config.url_obj.query.clone().unwrap_or_default();
+            let mut query: String = config.url_obj.query.clone().unwrap_or_default();
+            if config.encode_url {
+                query = encode_query(&query);
+            }
+            if !redirected {
+                for (key, value) in &config.query {
+                    if !query.is_empty() {
+                        query.push('&');
+                    }
+                    query.push_str(&percent_encode(key));
+                    query.push('=');
+                    query.push_str(&percent_encode(value));
+                }
+            }
+            let path: String = config
+                .url_obj
+                .path
+                .clone()
+                .filter(|path: &String| !path.is_empty())
+                .unwrap_or(DEFAULT_HTTP_PATH.to_string());
+            let path: String = if config.encode_url {
+                encode_path(&path)
+            } else {
+                path
+            };
             if query.is_empty() {
-                config
-                    .url_obj
-                    .path
-                    .clone()
-                    .unwrap_or(DEFAULT_HTTP_PATH.to_string())
+                path
             } else {
-                format!(
-                    "{}{}{}",
-                    config.url_obj.path.clone().unwrap_or_default(),
-                    QUERY,
-                    query
-                )
+                format!("{path}{QUERY}{query}")
             }
         });
         path
     }
 
-    /// Sends a GET request over the provided stream and returns the HTTP response.
+    /// Gets the request target sent in the request line.
     ///
-    /// This method constructs and sends an HTTP GET request to the server. It formats the URL path
-    /// and query parameters based on the current configuration and sends the request to the server
-    /// via the provided `stream`. After sending the request, it waits for the response and reads
-    /// the result.
+    /// The configured target form decides between the path, the absolute URL, and
+    /// `*`. Requests forwarded by an HTTP proxy in absolute form always carry the
+    /// absolute URL.
     ///
-    /// # Arguments
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for sending and receiving data.
+    /// # Returns
+    ///
+    /// - `Result<String, RequestError>` - The request target, or an error if the
+    ///   asterisk form is used with a method other than OPTIONS.
+    fn get_request_target(&self) -> Result<String, RequestError> {
+        let Ok(config) = self.config.read() else {
+            return Ok(self.get_path());
+        };
+        match config.request_target {
+            RequestTarget::Asterisk if !self.get_methods().is_options() => {
+                Err(RequestError::Request(format!(
+                    "The asterisk request target requires OPTIONS, not {}",
+                    self.get_methods()
+                )))
+            }
+            RequestTarget::Asterisk => Ok(ASTERISK_FORM_TARGET.to_string()),
+            RequestTarget::Origin if !config.is_proxy_absolute_form() => Ok(self.get_path()),
+            RequestTarget::Origin | RequestTarget::Absolute => {
+                let authority: String = SharedRequestBuilder::format_authority(
+                    &config.url_obj.host.clone().unwrap_or_default(),
+                    config.url_obj.port.filter(|port: &u16| *port != 0),
+                );
+                let protocol: String = config.url_obj.protocol.to_lowercase();
+                Ok(format!("{protocol}://{authority}{}", self.get_path()))
+            }
+        }
+    }
+
+    /// Gets the HTTP version token used in the request line.
+    ///
+    /// Any version token is passed through verbatim, but combinations that cannot be
+    /// expressed as an HTTP/1.x request line are rejected: HTTP/0.9 (no version token
+    /// or headers), HTTP/3 (requires QUIC), and tokens that are empty or contain
+    /// whitespace or control characters. HTTP/2 falls back to HTTP/1.1, since its
+    /// binary framing is not spoken and `HTTP/2` is not valid in a request line.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` -
-    /// - `Ok(BoxResponseTrait)` contains the HTTP response received from the server.
-    /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_get_request(
-        &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
+    /// - `Result<String, RequestError>` - The version token, or an error if it is unsupported.
+    pub(crate) fn get_http_version_str(&self) -> Result<String, RequestError> {
+        let http_version_str: String = self
+            .config
+            .read()
+            .map_or(HttpVersion::default().to_string(), |config| {
                 config.http_version.to_string()
             });
-
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-
-        stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        self.read_response(stream)
+        if http_version_str.is_empty()
+            || http_version_str
+                .bytes()
+                .any(|byte: u8| byte.is_ascii_whitespace() || byte.is_ascii_control())
+        {
+            return Err(RequestError::Request(format!(
+                "Invalid HTTP version token: {http_version_str:?}"
+            )));
+        }
+        match http_version_str.to_ascii_uppercase().as_str() {
+            "HTTP/0.9" => Err(RequestError::Request(
+                "HTTP/0.9 is not supported".to_string(),
+            )),
+            "HTTP/2" | "HTTP/2.0" => Ok(HttpVersion::Http1_1.to_string()),
+            "HTTP/3" | "HTTP/3.0" => Err(RequestError::Request(
+                "HTTP/3 requires QUIC and is not supported".to_string(),
+            )),
+            _ => Ok(http_version_str),
+        }
     }
 
-    /// Sends a POST request over the provided stream and returns the HTTP response.
+    /// Sends the request over the provided stream and returns the HTTP response.
     ///
-    /// This method constructs and sends an HTTP POST request to the server. It formats the URL path
-    /// and sends the body content along with the headers to the server via the provided `stream`. After
-    /// sending the request, it waits for the response and reads the result.
+    /// The request line, headers, and body are serialized for the configured method
+    /// and written to the stream, then the response is read.
     ///
     /// # Arguments
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for sending and receiving data.
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The stream used for sending and receiving data.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` -
-    /// - `Ok(BoxResponseTrait)` contains the HTTP response received from the server.
-    /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_post_request(
+    /// - `Result<BoxResponseTrait, RequestError>` - The response, or an error if sending or reading failed.
+    fn send_request(
         &mut self,
         stream: &mut Box<dyn ReadWrite>,
     ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
+        let request: Vec<u8> = self.get_request_bytes(true)?;
+        let (head, body): (&[u8], &[u8]) = self.split_request_body(&request);
+        let expect_continue: Option<Duration> = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| self.get_expect_continue_timeout(&config, Some(body.len())));
+        let Some(wait) = expect_continue else {
+            self.write_request(stream, &request)?;
+            self.write_body_reader(stream)?;
+            stream.flush().map_err(RequestError::from)?;
+            return self.read_response(stream);
+        };
+        self.write_request(stream, head)?;
+        stream.flush().map_err(RequestError::from)?;
+        let (send_body, received): (bool, Vec<u8>) = self.await_continue(stream, wait)?;
+        if !send_body {
+            let response: RequestResult =
+                self.read_response(&mut Read::chain(Cursor::new(received), stream));
+            self.discard_connection();
+            return response;
+        }
+        self.write_request_body(stream, body)?;
+        self.write_body_reader(stream)?;
+        stream.flush().map_err(RequestError::from)?;
+        self.read_response(&mut Read::chain(Cursor::new(received), stream))
+    }
 
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
-            path,
-            header_bytes,
-            body_bytes,
-            http_version_str,
+    /// Waits for the interim `100 Continue` response of a request sent with `Expect: 100-continue`.
+    ///
+    /// Other interim responses are skipped. The body is sent once `100 Continue`
+    /// arrives or the wait times out, but not if the server answers with a final
+    /// response first, such as `417 Expectation Failed` or `413 Content Too Large`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The stream the request headers were written to.
+    /// - `Duration` - The longest time to wait.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(bool, Vec<u8>), RequestError>` - Whether to send the body, and the
+    ///   response bytes already read past the interim responses.
+    fn await_continue(
+        &self,
+        stream: &mut Box<dyn ReadWrite>,
+        wait: Duration,
+    ) -> Result<(bool, Vec<u8>), RequestError> {
+        let read_timeout: Duration = Duration::from_millis(
+            self.config
+                .read()
+                .map_or(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS, |config| {
+                    config.timeout
+                }),
         );
-
+        let deadline: Instant = Instant::now() + wait.min(read_timeout);
+        let mut buffer: Vec<u8> = vec![0; self.get_progress_block_size()];
+        let mut response_bytes: Vec<u8> = Vec::new();
+        self.set_in_flight_state(InFlightState::Waiting);
+        let result: Result<(bool, Vec<u8>), RequestError> = loop {
+            if let Some(is_continue) = Self::take_interim_response(&mut response_bytes) {
+                if is_continue {
+                    break Ok((true, response_bytes));
+                }
+                continue;
+            }
+            if SharedResponseHandler::find_double_crlf(&response_bytes, 0).is_some() {
+                break Ok((false, response_bytes));
+            }
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break Ok((true, response_bytes));
+            }
+            if let Err(error) = stream.set_read_timeout(Some(remaining)) {
+                break Err(RequestError::from(error));
+            }
+            match stream.read(&mut buffer) {
+                Ok(0) => break Ok((false, response_bytes)),
+                Ok(n) => response_bytes.extend_from_slice(&buffer[..n]),
+                Err(error)
+                    if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    break Ok((true, response_bytes));
+                }
+                Err(error) => break Err(RequestError::from(error)),
+            }
+        };
         stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        self.read_response(stream)
+            .set_read_timeout(Some(read_timeout))
+            .map_err(RequestError::from)?;
+        result
+    }
+
+    /// Removes a complete interim response from the start of the bytes read.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<u8>` - The response bytes read so far, left with the bytes after
+    ///   the interim response.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - Whether the interim response was `100 Continue`, or None if
+    ///   the bytes do not start with a complete interim response.
+    fn take_interim_response(response_bytes: &mut Vec<u8>) -> Option<bool> {
+        let pos: usize = SharedResponseHandler::find_double_crlf(response_bytes, 0)?;
+        let status_code: usize = SharedResponseHandler::get_status_code(response_bytes);
+        if !(100..=199).contains(&status_code) || status_code == 101 {
+            return None;
+        }
+        *response_bytes = response_bytes.split_off(pos + 4);
+        Some(status_code == 100)
+    }
+
+    /// Keeps the connection from being reused after the response was read.
+    ///
+    /// Used when the request body was not sent, which leaves the connection in
+    /// a state the server may not expect.
+    fn discard_connection(&self) {
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.keep_alive = false;
+        }
     }
 
     /// Reads the HTTP response from the provided stream.
@@ -438,7 +945,7 @@ impl HttpRequest {
     /// response is read before returning.
     ///
     /// # Arguments
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
+    /// - `stream`: A mutable reference to a `dyn Read`, representing the stream used
     ///   for receiving the response.
     ///
     /// # Returns
@@ -446,14 +953,13 @@ impl HttpRequest {
     /// - `Result<BoxResponseTrait, RequestError>` -
     /// - `Ok(BoxResponseTrait)` contains the complete HTTP response after processing headers and body.
     /// - `Err(RequestError)` indicates that an error occurred while reading the response.
-    fn read_response(
-        &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
+    fn read_response(&mut self, stream: &mut dyn Read) -> Result<BoxResponseTrait, RequestError> {
         let buffer_size: usize = self
             .config
             .read()
             .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let strict_parsing: bool = self.config.read().is_ok_and(|config| config.strict_parsing);
+        let is_head: bool = self.get_methods().is_head();
         let mut buffer: Vec<u8> = vec![0; buffer_size];
         let initial_capacity: usize = buffer_size.max(8192);
         let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
@@ -462,18 +968,25 @@ impl HttpRequest {
         let mut redirect_url: Option<Vec<u8>> = None;
         let mut headers_end_pos: usize = 0;
         let mut is_chunked: bool = false;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
+        let mut read_until_close: bool = false;
         let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
-        'read_loop: while let Ok(n) = stream.read(&mut buffer) {
+        let mut keep_alive: bool = false;
+        self.set_in_flight_state(InFlightState::Waiting);
+        let started_at: Instant = Instant::now();
+        let mut first_byte_at: Option<Instant> = None;
+        'read_loop: loop {
+            let n: usize = match stream.read(&mut buffer) {
+                Ok(n) => n,
+                Err(error) if response_bytes.is_empty() => return Err(RequestError::from(error)),
+                Err(_) => break,
+            };
             if n == 0 {
                 break;
             }
+            if first_byte_at.is_none() {
+                first_byte_at = Some(Instant::now());
+                self.set_in_flight_state(InFlightState::Reading);
+            }
             let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
                 &response_bytes,
                 n,
@@ -485,45 +998,111 @@ impl HttpRequest {
             let old_len: usize = response_bytes.len();
             response_bytes.extend_from_slice(&buffer[..n]);
             if !headers_done {
+                self.config
+                    .read()
+                    .map_or(Ok(()), |config| config.check_header_limits(&response_bytes))?;
                 let search_start: usize = old_len.saturating_sub(3);
                 if let Some(pos) =
                     SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
                 {
                     headers_done = true;
                     headers_end_pos = pos + 4;
+                    if strict_parsing {
+                        SharedResponseHandler::check_strict_headers(
+                            &response_bytes[..headers_end_pos],
+                        )?;
+                    }
                     SharedResponseHandler::parse_response_headers(
                         &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
                         &location_sign_key,
                         &mut content_length,
                         &mut redirect_url,
                         &mut is_chunked,
                     )?;
+                    if SharedResponseHandler::is_bodyless_response(
+                        &response_bytes[..headers_end_pos],
+                        is_head,
+                    ) {
+                        content_length = 0;
+                        is_chunked = false;
+                    }
+                    read_until_close = SharedResponseHandler::is_close_delimited(
+                        &response_bytes[..headers_end_pos],
+                        is_head,
+                    );
+                    if !is_chunked {
+                        self.config
+                            .read()
+                            .map_or(Ok(()), |config| config.check_content_length(content_length))?;
+                    }
                 }
             }
             if headers_done {
-                if is_chunked {
-                    if Self::is_chunked_response_complete(&response_bytes[headers_end_pos..]) {
+                self.report_download_progress(
+                    response_bytes.len() - headers_end_pos,
+                    content_length,
+                    is_chunked || read_until_close,
+                );
+                if is_chunked || read_until_close {
+                    self.config.read().map_or(Ok(()), |config| {
+                        config.check_body_size(response_bytes.len() - headers_end_pos)
+                    })?;
+                    if is_chunked
+                        && let Some(body_len) =
+                            Self::get_chunked_message_len(&response_bytes[headers_end_pos..])
+                    {
+                        keep_alive = SharedResponseHandler::is_reusable_response(&response_bytes);
+                        response_bytes.truncate(headers_end_pos + body_len);
                         break 'read_loop;
                     }
                 } else {
-                    let total_expected_length: usize = headers_end_pos + content_length;
+                    let total_expected_length: usize =
+                        headers_end_pos.saturating_add(content_length);
                     if response_bytes.len() >= total_expected_length {
+                        keep_alive = SharedResponseHandler::is_reusable_response(&response_bytes);
                         response_bytes.truncate(total_expected_length);
                         break 'read_loop;
                     }
                 }
             }
         }
+        if response_bytes.is_empty() {
+            return Err(RequestError::ConnectionReset(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "Connection closed before the response headers were received",
+            )));
+        }
+        if headers_done && !is_chunked && !read_until_close {
+            SharedResponseHandler::check_body_complete(
+                &response_bytes[headers_end_pos..],
+                content_length,
+            )?;
+        }
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.keep_alive = keep_alive;
+        }
+        self.record_read_metrics(started_at, first_byte_at, response_bytes.len());
+        let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
         if is_chunked {
             let body_bytes: Vec<u8> = response_bytes[headers_end_pos..].to_vec();
-            let decoded_body: Vec<u8> = SharedResponseHandler::parse_chunked_body(&body_bytes);
+            let (decoded_body, chunked_trailers): (Vec<u8>, ResponseHeaders) =
+                SharedResponseHandler::parse_chunked_message(&body_bytes);
+            trailers = chunked_trailers;
             response_bytes.truncate(headers_end_pos);
             response_bytes.extend_from_slice(&decoded_body);
         }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
+        let mut response: HttpResponseBinary =
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes);
+        response.trailers = Arc::new(RwLock::new(trailers));
+        response.metrics = self.get_request_metrics();
+        self.response = Arc::new(RwLock::new(response));
+        if let Ok(response) = self.response.read() {
+            self.run_interceptors_after(&response);
+            self.record_response_metrics(&response);
+        }
+        if self.is_auth_retry(&response_bytes[..headers_end_pos]) {
+            return self.send_sync();
+        }
         if let Ok(config) = self.config.read()
             && (!config.redirect || redirect_url.is_none())
         {
@@ -539,6 +1118,7 @@ impl HttpRequest {
             return Ok(response);
         }
         let url: String = String::from_utf8(redirect_url.unwrap())
+            .map(|url: String| url.trim().to_string())
             .map_err(|error: FromUtf8Error| RequestError::Request(error.to_string()))?;
         self.handle_redirect(url)
     }
@@ -551,72 +1131,1418 @@ impl HttpRequest {
     ///
     /// Returns `Ok(BoxResponseTrait)` if the redirection is successful, or `Err(RequestError)` otherwise.
     fn handle_redirect(&mut self, url: String) -> Result<BoxResponseTrait, RequestError> {
-        if let Ok(mut config) = self.config.write() {
-            if !config.redirect {
-                return Err(RequestError::Request("Redirect Not Enabled".to_string()));
-            }
-            if let Ok(mut tmp) = self.tmp.clone().write() {
-                if tmp.visit_url.contains(&url) {
-                    return Err(RequestError::Request("Redirect URL Dead Loop".to_string()));
-                }
-                tmp.visit_url.insert(url.clone());
-                if config.redirect_times >= config.max_redirect_times {
-                    return Err(RequestError::Request(
-                        "Max Redirect Times Exceeded".to_string(),
-                    ));
-                }
-                config.redirect_times += 1;
-            }
-        }
-        self.url(url.clone());
+        let status_code: ResponseStatusCode = self
+            .response
+            .read()
+            .map_or(0, |response| response.get_status_code());
+        self.prepare_redirect(url, status_code)?;
         self.send_sync()
     }
 
-    /// Checks if a chunked response is complete.
+    /// Records a redirect and points the request at the redirection URL.
     ///
-    /// A chunked response is complete when it contains a chunk with size 0
-    /// (the terminating chunk).
+    /// Enforces the redirect settings: redirects must be enabled, a URL may only be
+    /// visited once, the maximum number of redirects must not be exceeded, the
+    /// redirect policy must allow a downgrade from HTTPS, and the redirect hook, if
+    /// any, must approve the hop. A rejected redirect reports the chain of URLs
+    /// requested so far. The method, body, and headers are then adjusted as the
+    /// redirect policy prescribes.
     ///
     /// # Arguments
     ///
-    /// - `&[u8]` - The raw bytes of the chunked body.
+    /// - `String` - The redirection URL.
+    /// - `ResponseStatusCode` - The status code of the redirect response.
     ///
     /// # Returns
     ///
-    /// - `bool` - True if the chunked response is complete, false otherwise.
-    fn is_chunked_response_complete(body_bytes: &[u8]) -> bool {
-        let mut pos: usize = 0;
-        while pos < body_bytes.len() {
-            let chunk_size_end: usize = match body_bytes[pos..]
-                .windows(2)
-                .position(|window: &[u8]| window == b"\r\n")
-            {
-                Some(p) => pos + p,
-                None => return false,
-            };
-            let chunk_size_str: &[u8] = &body_bytes[pos..chunk_size_end];
-            let chunk_size_str: &[u8] = match chunk_size_str.iter().position(|&b| b == b';') {
-                Some(p) => &chunk_size_str[..p],
-                None => chunk_size_str,
-            };
-            let chunk_size: usize = match std::str::from_utf8(chunk_size_str) {
-                Ok(s) => match usize::from_str_radix(s.trim(), 16) {
-                    Ok(n) => n,
-                    Err(_) => return false,
-                },
-                Err(_) => return false,
+    /// - `Result<(), RequestError>` - Ok if the redirect may be followed, or an error otherwise.
+    fn prepare_redirect(
+        &mut self,
+        url: String,
+        status_code: ResponseStatusCode,
+    ) -> Result<(), RequestError> {
+        let policy: RedirectPolicy = self
+            .config
+            .read()
+            .map_or(RedirectPolicy::default(), |config| config.redirect_policy);
+        let from: String = self.get_url();
+        let from_origin: Option<(String, String, u16)> = Self::get_url_origin(&from);
+        let to_origin: Option<(String, String, u16)> = Self::get_url_origin(&url);
+        let https_downgrade: bool = matches!(
+            (&from_origin, &to_origin),
+            (Some((from_protocol, _, _)), Some((to_protocol, _, _)))
+                if from_protocol == "https" && to_protocol == "http"
+        );
+        let rewritten_method: Option<Method> =
+            policy.get_rewritten_method(status_code, &self.get_methods());
+        let hop: RedirectHop = RedirectHop {
+            from,
+            to: url.clone(),
+            status_code,
+            method: rewritten_method.clone().unwrap_or(self.get_methods()),
+            cross_origin: from_origin.is_some() && to_origin.is_some() && from_origin != to_origin,
+        };
+        if let Ok(mut config) = self.config.write()
+            && let Ok(mut tmp) = self.tmp.clone().write()
+        {
+            if tmp.redirect_chain.is_empty() {
+                tmp.redirect_chain.push(hop.from.clone());
+            }
+            let kind: Option<RedirectErrorKind> = if !config.redirect {
+                Some(RedirectErrorKind::NotEnabled)
+            } else if tmp.visit_url.contains(&url) {
+                Some(RedirectErrorKind::DeadLoop)
+            } else if config.redirect_times >= config.max_redirect_times {
+                Some(RedirectErrorKind::MaxRedirectTimes)
+            } else if https_downgrade && !policy.allow_https_downgrade {
+                Some(RedirectErrorKind::HttpsDowngrade)
+            } else if !self.is_redirect_approved(&hop) {
+                Some(RedirectErrorKind::Denied)
+            } else {
+                None
+            };
+            if let Some(kind) = kind {
+                return Err(RequestError::Redirect(RedirectError {
+                    kind,
+                    visited: tmp.redirect_chain.clone(),
+                    location: url,
+                    count: config.redirect_times,
+                }));
+            }
+            tmp.visit_url.insert(url.clone());
+            tmp.redirect_chain.push(url.clone());
+            tmp.metrics.redirects += 1;
+            config.redirect_times += 1;
+        }
+        let mut attempt: RequestAttempt<'_> = RequestAttempt { request: self };
+        if let Some(method) = rewritten_method {
+            attempt.request.methods = Arc::new(method);
+            attempt.request.body = Arc::new(Body::Binary(Vec::new()));
+            for key in REDIRECT_BODY_HEADERS {
+                attempt.remove_header(key);
+            }
+        }
+        if hop.cross_origin && policy.strip_sensitive_headers {
+            for key in REDIRECT_SENSITIVE_HEADERS {
+                attempt.remove_header(key);
+            }
+        }
+        self.url(url);
+        Ok(())
+    }
+
+    /// Asks the redirect hook, if any, whether a redirect is followed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RedirectHop` - The redirect about to be followed.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if there is no hook or it approved the hop, false otherwise.
+    fn is_redirect_approved(&self, hop: &RedirectHop) -> bool {
+        self.on_redirect
+            .as_ref()
+            .is_none_or(|hook: &RedirectHook| (hook.0)(hop))
+    }
+
+    /// Gets the scheme, host, and port identifying the origin of a URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(String, String, u16)>` - The lowercase scheme and host with the
+    ///   effective port, or None if the URL cannot be parsed.
+    fn get_url_origin(url: &str) -> Option<(String, String, u16)> {
+        let url_obj: HttpUrlComponents = HttpUrlComponents::parse(url.to_string()).ok()?;
+        let protocol: String = url_obj.protocol.to_lowercase();
+        let host: String = url_obj.host.unwrap_or_default().to_ascii_lowercase();
+        let port: u16 = url_obj
+            .port
+            .filter(|port: &u16| *port != 0)
+            .unwrap_or_else(|| Protocol::get_port(&protocol));
+        Some((protocol, host, port))
+    }
+
+    /// Invokes the interceptors and the attempt hook, if any, before a network attempt.
+    ///
+    /// Attempts are numbered from 1 and counted across pooled tunnel retries and
+    /// redirect hops of the request. Trace context headers are set before the
+    /// interceptors and the hook run, so they see them.
+    fn run_attempt_hook(&mut self) {
+        #[cfg(feature = "trace-context")]
+        self.apply_trace_context();
+        for interceptor in self.middleware.interceptors.clone() {
+            interceptor.before(&mut RequestAttempt { request: self });
+        }
+        let Some(hook) = self.on_attempt.clone() else {
+            return;
+        };
+        let attempt: usize = self
+            .tmp
+            .write()
+            .map(|mut tmp: RwLockWriteGuard<'_, Tmp>| {
+                tmp.attempts += 1;
+                tmp.attempts
+            })
+            .unwrap_or(1);
+        (hook.0)(attempt, &mut RequestAttempt { request: self });
+    }
+
+    /// Invokes the interceptors after the response of an attempt is parsed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The response, or its head for streaming sends.
+    fn run_interceptors_after(&self, response: &HttpResponseBinary) {
+        for interceptor in &self.middleware.interceptors {
+            interceptor.after(response);
+        }
+    }
+
+    /// Counts the response of an attempt in the metrics of its host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The response, or its head for streaming sends.
+    fn record_response_metrics(&self, response: &HttpResponseBinary) {
+        let Ok(config) = self.config.read() else {
+            return;
+        };
+        let host: String = config.url_obj.host.clone().unwrap_or_default();
+        let port: u16 = self.get_port(config.url_obj.port.unwrap_or_default(), &config);
+        HostMetrics::record_status(&format!("{host}:{port}"), response.get_status_code());
+    }
+
+    /// Updates the metrics of the request in progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - A closure modifying the metrics.
+    fn update_metrics<F: FnOnce(&mut RequestMetrics)>(&self, update: F) {
+        if let Ok(mut tmp) = self.tmp.write() {
+            update(&mut tmp.metrics);
+        }
+    }
+
+    /// Registers the request as in flight until the returned guard is dropped.
+    ///
+    /// # Returns
+    ///
+    /// - `InFlightGuard` - The registration.
+    fn track_in_flight(&self) -> InFlightGuard {
+        let guard: InFlightGuard =
+            InFlightRequest::register(self.get_methods().to_string(), self.get_url());
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.in_flight = Some(guard.0);
+            tmp.state = InFlightState::Connecting;
+        }
+        guard
+    }
+
+    /// Moves the request to another phase in the in-flight registry.
+    ///
+    /// The URL is refreshed when connecting, so followed redirects show up.
+    ///
+    /// # Arguments
+    ///
+    /// - `InFlightState` - The new phase.
+    fn set_in_flight_state(&self, state: InFlightState) {
+        let id: Option<u64> = self.tmp.write().ok().and_then(|mut tmp| {
+            tmp.state = state;
+            tmp.in_flight
+        });
+        if let Some(id) = id {
+            let url: Option<String> = (state == InFlightState::Connecting).then(|| self.get_url());
+            InFlightRequest::update(id, state, url);
+        }
+    }
+
+    /// Sets the phase the request was in on an I/O error that does not know it yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestError` - The error the request failed with.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The error with its phase set.
+    fn with_error_phase(&self, error: RequestError) -> RequestError {
+        match self.tmp.read() {
+            Ok(tmp) => error.with_phase(ErrorPhase::from(tmp.state)),
+            Err(_) => error,
+        }
+    }
+
+    /// Resets the metrics and starts the total timer before a request is sent.
+    fn start_metrics(&self) {
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.metrics = RequestMetrics::default();
+            tmp.started_at = Some(Instant::now());
+        }
+    }
+
+    /// Gets the metrics of the request so far.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestMetrics` - The metrics, with the total time measured up to now.
+    fn get_request_metrics(&self) -> RequestMetrics {
+        self.tmp.read().map_or(RequestMetrics::default(), |tmp| {
+            let mut metrics: RequestMetrics = tmp.metrics;
+            if let Some(started_at) = tmp.started_at {
+                metrics.total = started_at.elapsed();
+            }
+            metrics
+        })
+    }
+
+    /// Records the timings and size of a response read from the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `Instant` - When reading started, right after the request was written.
+    /// - `Option<Instant>` - When the first response byte arrived, if any did.
+    /// - `usize` - The number of bytes read.
+    fn record_read_metrics(
+        &self,
+        started_at: Instant,
+        first_byte_at: Option<Instant>,
+        bytes_received: usize,
+    ) {
+        self.update_metrics(|metrics: &mut RequestMetrics| {
+            let first_byte_at: Instant = first_byte_at.unwrap_or_else(Instant::now);
+            metrics.ttfb += first_byte_at.duration_since(started_at);
+            metrics.transfer += first_byte_at.elapsed();
+            metrics.bytes_received += bytes_received;
+        });
+    }
+
+    /// Reports the metrics of a successful request to the metrics hook, if any.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Result<T, RequestError>` - The result of the request.
+    fn finish_metrics<T>(&self, result: &Result<T, RequestError>) {
+        if let Some(hook) = &self.on_metrics
+            && result.is_ok()
+        {
+            hook.report(&self.get_request_metrics());
+        }
+    }
+
+    /// Describes the resolved settings of the request as a JSON object.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The configuration snapshot, with the method, URL, header names,
+    ///   and installed hooks added.
+    #[cfg(feature = "json")]
+    pub(crate) fn get_effective_config(&self) -> Value {
+        let mut settings: Map<String, Value> = match self.config.read() {
+            Ok(config) => match config.to_value() {
+                Value::Object(settings) => settings,
+                _ => Map::new(),
+            },
+            Err(_) => Map::new(),
+        };
+        let mut header_names: Vec<String> = self.header.keys().cloned().collect();
+        header_names.sort();
+        settings.insert(
+            "method".to_string(),
+            Value::from(self.get_methods().to_string()),
+        );
+        settings.insert("url".to_string(), Value::from(self.get_url()));
+        settings.insert("headers".to_string(), Value::from(header_names));
+        settings.insert(
+            "hooks".to_string(),
+            serde_json::json!({
+                "on_attempt": self.on_attempt.is_some(),
+                "on_redirect": self.on_redirect.is_some(),
+                "auth_handler": self.auth_handler.is_some(),
+                "on_upload_progress": self.on_upload_progress.is_some(),
+                "on_download_progress": self.on_download_progress.is_some(),
+                "on_metrics": self.on_metrics.is_some(),
+                "resolver": self.resolver.is_some(),
+                "signer": self.signer.is_some(),
+                "cache": self.cache.is_some(),
+                "middleware": self.middleware.sync.len(),
+                "interceptors": self.middleware.interceptors.len(),
+            }),
+        );
+        #[cfg(feature = "async")]
+        if let Some(Value::Object(hooks)) = settings.get_mut("hooks") {
+            hooks.insert(
+                "cancellation_token".to_string(),
+                Value::from(self.cancellation.is_some()),
+            );
+            hooks.insert(
+                "async_middleware".to_string(),
+                Value::from(self.middleware.r#async.len()),
+            );
+        }
+        Value::Object(settings)
+    }
+
+    /// Serializes the request as the next attempt would write it, without sending it.
+    ///
+    /// The attempt hooks run on a copy with its own configuration and state, so the
+    /// request itself is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The raw request bytes, or an error if the request is invalid.
+    pub(crate) fn get_raw_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        let mut preview: HttpRequest = self.clone();
+        preview.config = Arc::new(RwLock::new(config));
+        preview.tmp = Arc::new(RwLock::new(Tmp::default()));
+        preview.response = Arc::new(RwLock::new(HttpResponseBinary::default()));
+        preview.get_request_body()?;
+        preview.get_http_version_str()?;
+        preview.prepare_target()?;
+        preview.run_attempt_hook();
+        preview.get_request_bytes(true)
+    }
+
+    /// Computes the key under which identical concurrent requests share one call.
+    ///
+    /// Only GET requests without a body share calls, and only when single-flight is
+    /// enabled. The key covers the URL, the query parameters, the headers, and
+    /// whether the response is decoded.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The request hash, or None if the request is sent on its own.
+    fn get_single_flight_key(&self) -> Option<u64> {
+        if !matches!(self.get_methods(), Method::Get)
+            || self.is_body_reader()
+            || !self.get_body_bytes().is_empty()
+        {
+            return None;
+        }
+        let url: String = self.get_url();
+        let mut headers: Vec<(String, VecDeque<String>)> = self
+            .get_header()
+            .into_iter()
+            .map(|(key, values): (String, VecDeque<String>)| (key.to_ascii_lowercase(), values))
+            .collect();
+        headers.sort();
+        let config: RwLockReadGuard<'_, Config> = self.config.read().ok()?;
+        if !config.single_flight {
+            return None;
+        }
+        let mut hasher: DefaultHasher = DefaultHasher::new();
+        hasher.write(url.as_bytes());
+        for (key, value) in &config.query {
+            hasher.write(key.as_bytes());
+            hasher.write_u8(b'=');
+            hasher.write(value.as_bytes());
+            hasher.write_u8(b'&');
+        }
+        for (key, values) in &headers {
+            hasher.write(key.as_bytes());
+            for value in values {
+                hasher.write_u8(b':');
+                hasher.write(value.as_bytes());
+            }
+            hasher.write_u8(b'\n');
+        }
+        hasher.write_u8(u8::from(config.decode));
+        Some(hasher.finish())
+    }
+
+    /// Returns a response shared by the request that sent an identical call.
+    ///
+    /// # Arguments
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The shared result.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - A copy of the shared response, or the shared error.
+    fn finish_shared_response(
+        &mut self,
+        result: Result<HttpResponseBinary, RequestError>,
+    ) -> RequestResult {
+        let response: HttpResponseBinary = result?;
+        self.response = Arc::new(RwLock::new(response.clone()));
+        Ok(Box::new(response))
+    }
+
+    /// Sends the request, or waits for an identical request already in flight.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The single-flight key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response, shared with every identical concurrent request.
+    fn send_single_flight(&mut self, key: u64) -> RequestResult {
+        match SingleFlightCall::join(key) {
+            SingleFlightRole::Leader(guard) => {
+                let result: RequestResult = self.send_with_middleware();
+                guard.complete(Self::get_shared_result(&result));
+                result
+            }
+            SingleFlightRole::Follower(call) => self.finish_shared_response(call.wait()),
+        }
+    }
+
+    /// Sends the request, sharing the call with identical concurrent requests if
+    /// single-flight is enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    fn send_uncached(&mut self) -> RequestResult {
+        match self.get_single_flight_key() {
+            Some(key) => self.send_single_flight(key),
+            None => self.send_with_middleware(),
+        }
+    }
+
+    /// Sends the request through the response cache.
+    ///
+    /// A fresh stored response is returned without touching the network. A stale one
+    /// is revalidated with its validators, and a 304 answer returns it with refreshed
+    /// headers. Successful unsafe requests remove the response stored for their URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CachePolicy` - The cache policy.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The stored or received response, or an error.
+    fn send_cached(&mut self, policy: &CachePolicy) -> RequestResult {
+        let Some(key) = self.get_readable_cache_key() else {
+            let result: RequestResult = self.send_uncached();
+            self.invalidate_cached_response(policy, &result);
+            return result;
+        };
+        let cached: Option<CachedResponse> = policy.lookup(&key, &self.get_header());
+        if let Some(cached) = &cached
+            && self.is_cache_hit(cached)
+        {
+            return self.finish_shared_response(Ok(cached.get_response().clone()));
+        }
+        let header: Arc<RequestHeaders> = self.add_cache_validators(cached.as_ref());
+        let result: RequestResult = self.send_uncached();
+        self.header = header;
+        self.store_cached_response(policy, &key, cached, result)
+    }
+
+    /// Computes the key under which the response to the request is cached.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the response body is decoded.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The URL with the builder query parameters, prefixed by the decoding mode.
+    fn get_cache_key(&self, decode: bool) -> String {
+        let mut key: String = format!(
+            "{} {}",
+            if decode { "decoded" } else { "raw" },
+            self.get_url()
+        );
+        if let Ok(config) = self.config.read() {
+            for (name, value) in &config.query {
+                key.push(if key.contains(QUERY) { '&' } else { '?' });
+                key.push_str(&percent_encode(name));
+                key.push('=');
+                key.push_str(&percent_encode(value));
+            }
+        }
+        key
+    }
+
+    /// Gets the cache key of a request that may be answered from the cache.
+    ///
+    /// Only GET requests without a body are looked up, and not when they are marked
+    /// `no-store` or carry their own conditional headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The cache key, or None if the request bypasses the cache.
+    fn get_readable_cache_key(&self) -> Option<String> {
+        if !matches!(self.get_methods(), Method::Get)
+            || self.is_body_reader()
+            || !self.get_body_bytes().is_empty()
+            || self.get_request_cache_control().no_store
+        {
+            return None;
+        }
+        let header: RequestHeaders = self.get_header();
+        if Self::header_contains_key_case_insensitive(&header, IF_NONE_MATCH)
+            || Self::header_contains_key_case_insensitive(&header, IF_MODIFIED_SINCE)
+        {
+            return None;
+        }
+        let decode: bool = self.config.read().is_ok_and(|config| config.decode);
+        Some(self.get_cache_key(decode))
+    }
+
+    /// Parses the `Cache-Control` header of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `CacheControl` - The caching directives of the request.
+    fn get_request_cache_control(&self) -> CacheControl {
+        let values: Vec<String> = self
+            .get_header()
+            .into_iter()
+            .filter(|(key, _): &(String, VecDeque<String>)| key.eq_ignore_ascii_case(CACHE_CONTROL))
+            .flat_map(|(_, values): (String, VecDeque<String>)| values)
+            .collect();
+        CacheControl::parse(&values)
+    }
+
+    /// Checks whether a stored response answers the request without revalidation.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CachedResponse` - The stored response.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the response is fresh and the request accepts its age.
+    fn is_cache_hit(&self, cached: &CachedResponse) -> bool {
+        let now: i64 = get_unix_seconds();
+        let control: CacheControl = self.get_request_cache_control();
+        cached.is_fresh(now)
+            && !control.no_cache
+            && control
+                .max_age
+                .is_none_or(|max_age: i64| cached.get_age(now) <= max_age)
+    }
+
+    /// Adds the validators of a stored response to the request headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<&CachedResponse>` - The stored response to revalidate, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<RequestHeaders>` - The headers before the validators were added, to restore afterwards.
+    fn add_cache_validators(&mut self, cached: Option<&CachedResponse>) -> Arc<RequestHeaders> {
+        let header: Arc<RequestHeaders> = Arc::clone(&self.header);
+        if let Some(cached) = cached {
+            cached.add_validators(Arc::make_mut(&mut self.header));
+        }
+        header
+    }
+
+    /// Stores the response to a request sent through the cache.
+    ///
+    /// A 304 response to a revalidation returns the stored response with refreshed
+    /// headers. Other responses replace the stored one if they may be cached and
+    /// remove it otherwise. Responses reached through redirects are not stored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CachePolicy` - The cache policy.
+    /// - `&str` - The cache key.
+    /// - `Option<CachedResponse>` - The stored response that was revalidated, if any.
+    /// - `RequestResult` - The result of the network request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response to return, or the error of the network request.
+    fn store_cached_response(
+        &mut self,
+        policy: &CachePolicy,
+        key: &str,
+        cached: Option<CachedResponse>,
+        result: RequestResult,
+    ) -> RequestResult {
+        let response: BoxResponseTrait = result?;
+        let binary: HttpResponseBinary = response.binary();
+        let now: i64 = get_unix_seconds();
+        if binary.get_status_code() == NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            let revalidated: CachedResponse = cached.revalidate(&binary, now);
+            let stored: HttpResponseBinary = revalidated.get_response().clone();
+            policy.storage.put(key, revalidated);
+            return self.finish_shared_response(Ok(stored));
+        }
+        let decode: bool = self.config.read().is_ok_and(|config| config.decode);
+        if self.get_cache_key(decode) != key {
+            return Ok(response);
+        }
+        let header: RequestHeaders = self.get_header();
+        let authorized: bool = self.signer.is_some()
+            || Self::header_contains_key_case_insensitive(&header, AUTHORIZATION_HEADER);
+        match CachedResponse::new(binary, &header, authorized, now) {
+            Some(stored) => policy.storage.put(key, stored),
+            None => policy.storage.remove(key),
+        }
+        Ok(response)
+    }
+
+    /// Removes the stored responses for the URL of a successful unsafe request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CachePolicy` - The cache policy.
+    /// - `&RequestResult` - The result of the request.
+    fn invalidate_cached_response(&self, policy: &CachePolicy, result: &RequestResult) {
+        let method: Method = self.get_methods();
+        let unsafe_method: bool =
+            method.is_post() || method.is_put() || method.is_patch() || method.is_delete();
+        if unsafe_method
+            && result
+                .as_ref()
+                .is_ok_and(|response: &BoxResponseTrait| response.binary().get_status_code() < 400)
+        {
+            policy.storage.remove(&self.get_cache_key(true));
+            policy.storage.remove(&self.get_cache_key(false));
+        }
+    }
+
+    /// Copies a result so it can be shared with the requests waiting for it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestResult` - The result of the leading request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The copied response or error.
+    fn get_shared_result(result: &RequestResult) -> Result<HttpResponseBinary, RequestError> {
+        result
+            .as_ref()
+            .map(|response: &BoxResponseTrait| response.binary())
+            .map_err(|error: &RequestError| error.clone())
+    }
+
+    /// Lets the auth handler, if any, answer the challenges of a 401 response.
+    ///
+    /// The request is retried at most once: the response to a retry is returned
+    /// even if it is another 401.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the handler set credentials and the request must be sent again.
+    fn is_auth_retry(&mut self, headers_bytes: &[u8]) -> bool {
+        let Some(handler) = self.auth_handler.clone() else {
+            return false;
+        };
+        let retried: bool = self
+            .tmp
+            .write()
+            .map(|mut tmp: RwLockWriteGuard<'_, Tmp>| std::mem::take(&mut tmp.auth_retried))
+            .unwrap_or(true);
+        if retried || SharedResponseHandler::get_status_code(headers_bytes) != UNAUTHORIZED {
+            return false;
+        }
+        let challenges: Vec<AuthChallenge> =
+            AuthChallenge::from_headers(headers_bytes, WWW_AUTHENTICATE);
+        if !handler
+            .0
+            .authorize(&challenges, &mut RequestAttempt { request: self })
+        {
+            return false;
+        }
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.auth_retried = true;
+        }
+        true
+    }
+
+    /// Sets the trace context headers for a new client span of the configured context.
+    #[cfg(feature = "trace-context")]
+    fn apply_trace_context(&mut self) {
+        let Some(span) = self.trace_context.as_ref().map(TraceContext::child) else {
+            return;
+        };
+        let mut attempt: RequestAttempt<'_> = RequestAttempt { request: self };
+        attempt.set_header(TRACEPARENT, &span.to_string());
+        if let Some(trace_state) = span.get_trace_state() {
+            attempt.set_header(TRACESTATE, trace_state);
+        }
+    }
+
+    /// Gets the pool key for the proxy tunnel carrying this request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TunnelKey>` - The key, or None if no proxy is used or tunnels are not kept alive.
+    #[cfg(feature = "proxy")]
+    fn get_tunnel_key(&self, host: &str, port: u16) -> Option<TunnelKey> {
+        let config: RwLockReadGuard<'_, Config> = self.config.read().ok()?;
+        if !config.proxy_keep_alive {
+            return None;
+        }
+        Some(TunnelKey {
+            proxy: config.proxy.clone()?,
+            target_host: host.to_string(),
+            target_port: port,
+            https: Self::get_protocol(&config) == HTTPS_LOWERCASE,
+            insecure_skip_tls_verify: config.insecure_skip_tls_verify,
+            tls_server_name: config.get_tls_server_name(host),
+        })
+    }
+
+    /// Takes and resets the flag telling whether the last response left its connection reusable.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the connection may carry another request, false otherwise.
+    #[cfg(feature = "proxy")]
+    fn take_keep_alive(&self) -> bool {
+        self.tmp
+            .write()
+            .map(|mut tmp: RwLockWriteGuard<'_, Tmp>| std::mem::take(&mut tmp.keep_alive))
+            .unwrap_or(false)
+    }
+
+    /// Checks if a request failed on a stale pooled tunnel and may be sent again.
+    ///
+    /// These errors occur before any response byte was received. The server may still
+    /// have read the whole request, so only idempotent requests are repeated, unless
+    /// the tunnel failed while the request was being written.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestError` - The error of the request on the pooled tunnel.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the request can be repeated on a fresh tunnel, false otherwise.
+    #[cfg(feature = "proxy")]
+    fn is_stale_tunnel_retry(&self, error: &RequestError) -> bool {
+        if !matches!(
+            error,
+            RequestError::ConnectionReset(_) | RequestError::BrokenPipe(_)
+        ) {
+            return false;
+        }
+        let idempotent: bool = matches!(
+            self.get_methods(),
+            Method::Get
+                | Method::Head
+                | Method::Options
+                | Method::Put
+                | Method::Delete
+                | Method::Trace
+        );
+        idempotent || self.with_error_phase(error.clone()).get_phase() == Some(ErrorPhase::Write)
+    }
+
+    /// Updates the parsed URL in the configuration and resolves the connection target.
+    ///
+    /// The Host header override is validated here, before any connection is made.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(String, u16), RequestError>` - The host and port to connect to.
+    fn prepare_target(&mut self) -> Result<(String, u16), RequestError> {
+        let url_obj: HttpUrlComponents = self.parse_url()?;
+        let mut config: RwLockWriteGuard<'_, Config> = self
+            .config
+            .write()
+            .map_err(|error| RequestError::Request(error.to_string()))?;
+        config.check_host_header()?;
+        config.url_obj = url_obj;
+        let host: String = config.url_obj.host.clone().unwrap_or_default();
+        let port: u16 = self.get_port(config.url_obj.port.unwrap_or_default(), &config);
+        Ok((host, port))
+    }
+
+    /// Checks if the body is streamed from a reader.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the body is a blocking or asynchronous reader, false otherwise.
+    fn is_body_reader(&self) -> bool {
+        match self.body.as_ref() {
+            Body::Reader(_) => true,
+            #[cfg(feature = "async")]
+            Body::AsyncReader(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks if a reader body is streamed after the request headers.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the body is a reader and the method carries a body.
+    fn has_streamed_body(&self) -> bool {
+        let methods: Method = self.get_methods();
+        self.is_body_reader()
+            && (methods.is_post() || methods.is_put() || methods.is_patch() || methods.is_delete())
+    }
+
+    /// Gets the time to wait for a `100 Continue` response before sending the body.
+    ///
+    /// The handshake is used only for HTTP/1.1 requests with a non-empty body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Config` - The request configuration.
+    /// - `Option<usize>` - The length of the buffered body, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The time to wait, or None if the body is sent with the headers.
+    fn get_expect_continue_timeout(
+        &self,
+        config: &Config,
+        body_length: Option<usize>,
+    ) -> Option<Duration> {
+        let timeout: u64 = config.expect_continue?;
+        let has_body: bool =
+            body_length.is_some_and(|length: usize| length > 0) || self.has_streamed_body();
+        (has_body
+            && matches!(
+                config.http_version,
+                HttpVersion::Http1_1 | HttpVersion::Http2
+            ))
+        .then(|| Duration::from_millis(timeout))
+    }
+
+    /// Gets the announced length of a streamed body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The `Content-Length` header value, or None if the body is sent chunked.
+    fn get_streamed_body_length(&self) -> Option<usize> {
+        self.get_header()
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(CONTENT_LENGTH))
+            .and_then(|(_, value)| value.front())
+            .and_then(|value: &String| value.trim().parse::<usize>().ok())
+    }
+
+    /// Frames a block of a streamed body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes read from the body reader.
+    /// - `bool` - Whether the body is sent with chunked encoding.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The bytes to write, wrapped in a chunk if the body is chunked.
+    fn frame_body_block(data: &[u8], chunked: bool) -> Vec<u8> {
+        if !chunked {
+            return data.to_vec();
+        }
+        let mut frame: Vec<u8> = format!("{:x}\r\n", data.len()).into_bytes();
+        frame.extend_from_slice(data);
+        frame.extend_from_slice(HTTP_BR_BYTES);
+        frame
+    }
+
+    /// Gets the last chunk and trailer section ending a chunked request body.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The bytes ending the body, including any configured trailers.
+    fn get_chunked_body_end(&self) -> Vec<u8> {
+        let trailers: Vec<(String, String)> = self
+            .config
+            .read()
+            .map_or(Vec::new(), |config| config.trailers.clone());
+        if trailers.is_empty() {
+            return CHUNKED_BODY_END.to_vec();
+        }
+        let mut end: Vec<u8> = b"0\r\n".to_vec();
+        for (name, value) in trailers.iter() {
+            end.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
+        }
+        end.extend_from_slice(HTTP_BR_BYTES);
+        end
+    }
+
+    /// Checks that a streamed body matched its announced length.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes streamed.
+    /// - `Option<usize>` - The announced `Content-Length`, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the lengths match or the body was chunked.
+    fn check_streamed_body_length(
+        written: usize,
+        content_length: Option<usize>,
+    ) -> Result<(), RequestError> {
+        match content_length {
+            Some(content_length) if content_length != written => {
+                Err(RequestError::Request(format!(
+                    "Request body reader produced {written} bytes, but Content-Length is {content_length}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Writes a serialized request to the connection.
+    ///
+    /// With an upload progress hook, the body following the headers is written in
+    /// blocks of the configured buffer size, reporting progress after each block.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The connection stream.
+    /// - `&[u8]` - The request line, headers, and buffered body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once the request is written, or an error.
+    fn write_request(
+        &self,
+        stream: &mut Box<dyn ReadWrite>,
+        request: &[u8],
+    ) -> Result<(), RequestError> {
+        self.set_in_flight_state(InFlightState::Sending);
+        if self.on_upload_progress.is_none() {
+            self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += request.len());
+            return stream.write_all(request).map_err(RequestError::from);
+        }
+        let (head, body): (&[u8], &[u8]) = self.split_request_body(request);
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += head.len());
+        stream.write_all(head).map_err(RequestError::from)?;
+        self.write_request_body(stream, body)
+    }
+
+    /// Writes a buffered request body, reporting the upload progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The stream to write to.
+    /// - `&[u8]` - The body bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the body was written.
+    fn write_request_body(
+        &self,
+        stream: &mut Box<dyn ReadWrite>,
+        body: &[u8],
+    ) -> Result<(), RequestError> {
+        self.set_in_flight_state(InFlightState::Sending);
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += body.len());
+        let mut sent: usize = 0;
+        for block in body.chunks(self.get_progress_block_size()) {
+            stream.write_all(block).map_err(RequestError::from)?;
+            sent += block.len();
+            self.report_upload_progress(sent, Some(body.len()));
+        }
+        Ok(())
+    }
+
+    /// Splits a serialized request into its head and its buffered body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The serialized request.
+    ///
+    /// # Returns
+    ///
+    /// - `(&[u8], &[u8])` - The request line and headers, and the body after them.
+    fn split_request_body<'a>(&self, request: &'a [u8]) -> (&'a [u8], &'a [u8]) {
+        let body_start: usize = SharedResponseHandler::find_double_crlf(request, 0)
+            .map_or(request.len(), |pos: usize| pos + 4);
+        request.split_at(body_start)
+    }
+
+    /// Gets the size of the blocks a body is written in while reporting progress.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The configured buffer size, at least 1.
+    fn get_progress_block_size(&self) -> usize {
+        self.config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer)
+            .max(1)
+    }
+
+    /// Reports the request body bytes written to the upload progress hook, if any.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of body bytes written.
+    /// - `Option<usize>` - The body size, or None if it is unknown.
+    fn report_upload_progress(&self, sent: usize, total: Option<usize>) {
+        if let Some(hook) = &self.on_upload_progress {
+            hook.report(sent, total);
+        }
+    }
+
+    /// Reports the response body bytes read to the download progress hook, if any.
+    ///
+    /// The total is the `Content-Length` of the response, and is unknown for a
+    /// chunked body, whose received count includes the chunk framing, and for a
+    /// body read until the connection closes.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of body bytes read.
+    /// - `usize` - The `Content-Length` of the response.
+    /// - `bool` - Whether the length of the body is unknown in advance.
+    fn report_download_progress(&self, received: usize, content_length: usize, is_chunked: bool) {
+        if let Some(hook) = &self.on_download_progress {
+            let total: Option<usize> = (!is_chunked).then_some(content_length);
+            hook.report(
+                total.map_or(received, |total: usize| received.min(total)),
+                total,
+            );
+        }
+    }
+
+    /// Streams a reader body to the connection after the request headers.
+    ///
+    /// The body is sent with the `Content-Length` set on the request, or with
+    /// chunked encoding otherwise. Asynchronous readers can only be sent by
+    /// asynchronous requests.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The connection stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once the body is written, or an error.
+    fn write_body_reader(&self, stream: &mut Box<dyn ReadWrite>) -> Result<(), RequestError> {
+        if !self.has_streamed_body() {
+            return Ok(());
+        }
+        let Body::Reader(source) = self.body.as_ref() else {
+            return Err(RequestError::Request(
+                "An async body reader requires an async request".to_string(),
+            ));
+        };
+        let mut reader: BoxBodyRead = source.take()?;
+        let content_length: Option<usize> = self.get_streamed_body_length();
+        let buffer_size: usize = self
+            .config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let mut buffer: Vec<u8> = vec![0; buffer_size.max(1)];
+        let mut written: usize = 0;
+        loop {
+            let n: usize = reader.read(&mut buffer).map_err(RequestError::from)?;
+            if n == 0 {
+                break;
+            }
+            written += n;
+            stream
+                .write_all(&Self::frame_body_block(
+                    &buffer[..n],
+                    content_length.is_none(),
+                ))
+                .map_err(RequestError::from)?;
+            self.report_upload_progress(written, content_length);
+        }
+        Self::check_streamed_body_length(written, content_length)?;
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += written);
+        if content_length.is_none() {
+            stream
+                .write_all(&self.get_chunked_body_end())
+                .map_err(RequestError::from)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the body to send for the configured method.
+    ///
+    /// GET, HEAD, and OPTIONS requests carry no body. POST, PUT, and PATCH requests
+    /// always carry one, even if empty, while a DELETE request only carries a body
+    /// that is not empty. Reader bodies are streamed separately and yield None.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Vec<u8>>, RequestError>` - The body bytes, None for a bodyless request, or an error if the method is not supported or the body could not be encoded.
+    pub(crate) fn get_request_body(&self) -> Result<Option<Vec<u8>>, RequestError> {
+        if let Body::Invalid(message) = self.body.as_ref() {
+            return Err(RequestError::Request(message.clone()));
+        }
+        match self.get_methods() {
+            m if m.is_get() || m.is_head() || m.is_options() => Ok(None),
+            _ if self.is_body_reader() => Ok(None),
+            m if m.is_post() || m.is_put() || m.is_patch() => {
+                Ok(Some(self.compress_request_body(self.get_body_bytes())))
+            }
+            m if m.is_delete() => {
+                let body_bytes: Vec<u8> = self.get_body_bytes();
+                Ok((!body_bytes.is_empty()).then(|| self.compress_request_body(body_bytes)))
+            }
+            _err => Err(RequestError::Request("Method Not Allowed".to_string())),
+        }
+    }
+
+    /// Checks if the request is sent to an HTTP proxy in absolute form.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if no CONNECT tunnel is opened for the request, false otherwise.
+    #[cfg(feature = "proxy")]
+    fn is_proxy_absolute_form(&self) -> bool {
+        self.config
+            .read()
+            .is_ok_and(|config| config.is_proxy_absolute_form())
+    }
+
+    /// Checks if server certificates are accepted without verification.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if certificate verification is skipped, false otherwise.
+    #[cfg(feature = "tls")]
+    fn is_insecure_skip_tls_verify(&self) -> bool {
+        self.config
+            .read()
+            .is_ok_and(|config| config.insecure_skip_tls_verify)
+    }
+
+    /// Gets the body size above which responses are written to a temporary file.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The threshold, or None if responses are always kept in memory.
+    fn get_spool_threshold(&self) -> Option<usize> {
+        self.config.read().ok()?.spool_threshold
+    }
+
+    /// Gets the settings used to read a response body that may be spooled.
+    ///
+    /// # Returns
+    ///
+    /// - `(bool, usize)` - Whether to decode bodies kept in memory, and the buffer size.
+    fn get_spool_read_config(&self) -> (bool, usize) {
+        self.config
+            .read()
+            .map_or((false, DEFAULT_BUFFER_SIZE), |config| {
+                (config.decode, config.buffer)
+            })
+    }
+
+    /// Gets the content coding applied to the request body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Compress>` - The configured coding, or None if bodies are sent as they are
+    ///   or the request already sets `Content-Encoding`.
+    fn get_request_compression(&self) -> Option<Compress> {
+        let compress: Compress = self.config.read().ok()?.request_compression.clone()?;
+        (compress != Compress::Unknown
+            && !Self::header_contains_key_case_insensitive(&self.header, CONTENT_ENCODING))
+        .then_some(compress)
+    }
+
+    /// Compresses a request body with the configured content coding.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The serialized body.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The compressed body, or the body itself if it is empty or no coding is set.
+    fn compress_request_body(&self, body: Vec<u8>) -> Vec<u8> {
+        match self.get_request_compression() {
+            Some(compress) if !body.is_empty() => {
+                let buffer_size: usize = self
+                    .config
+                    .read()
+                    .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+                compress.encode(&body, buffer_size).into_owned()
+            }
+            _ => body,
+        }
+    }
+
+    /// Serializes the request line, headers, and body for the configured method.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the response body is decoded, so supported codings may be advertised.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The request bytes, or an error if the method is not supported.
+    fn get_request_bytes(&self, accept_encoding: bool) -> Result<Vec<u8>, RequestError> {
+        let body_bytes: Option<Vec<u8>> = self.get_request_body()?;
+        let path: String = self.get_request_target()?;
+        let header_bytes: Vec<u8> = self.get_signed_header_bytes(
+            &self.get_path(),
+            body_bytes.as_deref(),
+            body_bytes.as_ref().map(Vec::len),
+            accept_encoding,
+        )?;
+        let http_version_str: String = self.get_http_version_str()?;
+        Ok(SharedRequestBuilder::build_http_request(
+            &self.get_methods().to_string(),
+            path,
+            header_bytes,
+            body_bytes,
+            http_version_str,
+        ))
+    }
+
+    /// Gets the redirection URL of a response if redirects are enabled.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<String>, RequestError>` - The redirection URL, or None if the response should be returned.
+    fn get_redirect_url(&self, headers_bytes: &[u8]) -> Result<Option<String>, RequestError> {
+        let redirect: bool = self.config.read().is_ok_and(|config| config.redirect);
+        if !redirect {
+            return Ok(None);
+        }
+        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        let mut content_length: usize = 0;
+        let mut redirect_url: Option<Vec<u8>> = None;
+        let mut is_chunked: bool = false;
+        SharedResponseHandler::parse_response_headers(
+            headers_bytes,
+            &location_sign_key,
+            &mut content_length,
+            &mut redirect_url,
+            &mut is_chunked,
+        )?;
+        redirect_url
+            .map(|url: Vec<u8>| {
+                String::from_utf8(url)
+                    .map(|url: String| url.trim().to_string())
+                    .map_err(|error: FromUtf8Error| RequestError::Request(error.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Reads the status line and headers of a response, skipping interim 1xx responses.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxReadWrite` - The stream to read from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Vec<u8>), RequestError>` - The header bytes and any body bytes read past them.
+    fn read_response_head(
+        &self,
+        stream: &mut BoxReadWrite,
+    ) -> Result<(Vec<u8>, Vec<u8>), RequestError> {
+        let buffer_size: usize = self
+            .config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let strict_parsing: bool = self.config.read().is_ok_and(|config| config.strict_parsing);
+        let mut buffer: Vec<u8> = vec![0; buffer_size];
+        let mut response_bytes: Vec<u8> = Vec::with_capacity(buffer_size.max(8192));
+        self.set_in_flight_state(InFlightState::Waiting);
+        let started_at: Instant = Instant::now();
+        let mut first_byte_at: Option<Instant> = None;
+        let mut bytes_received: usize = 0;
+        loop {
+            self.config
+                .read()
+                .map_or(Ok(()), |config| config.check_header_limits(&response_bytes))?;
+            if let Some(pos) = SharedResponseHandler::find_double_crlf(&response_bytes, 0) {
+                let leftover: Vec<u8> = response_bytes.split_off(pos + 4);
+                let status_code: usize = SharedResponseHandler::get_status_code(&response_bytes);
+                if (100..=199).contains(&status_code) && status_code != 101 {
+                    response_bytes = leftover;
+                    continue;
+                }
+                if strict_parsing {
+                    SharedResponseHandler::check_strict_headers(&response_bytes)?;
+                }
+                self.record_read_metrics(started_at, first_byte_at, bytes_received);
+                return Ok((response_bytes, leftover));
+            }
+            let n: usize = stream.read(&mut buffer).map_err(RequestError::from)?;
+            if n == 0 {
+                return Err(RequestError::ConnectionReset(IoError::new(
+                    ErrorKind::UnexpectedEof,
+                    "Connection closed before the response headers were received",
+                )));
+            }
+            if first_byte_at.is_none() {
+                first_byte_at = Some(Instant::now());
+                self.set_in_flight_state(InFlightState::Reading);
+            }
+            bytes_received += n;
+            response_bytes.extend_from_slice(&buffer[..n]);
+        }
+    }
+
+    /// Gets the length of a chunked body once it has been fully received.
+    ///
+    /// A chunked body is complete once the chunk with size 0 (the terminating
+    /// chunk) and the trailer section after it, up to its closing empty line, have
+    /// been received. Only then may the connection carry another response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the chunked body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The length of the chunked body including its trailer
+    ///   section, or None if the body is not complete yet.
+    fn get_chunked_message_len(body_bytes: &[u8]) -> Option<usize> {
+        let mut pos: usize = 0;
+        while pos < body_bytes.len() {
+            let chunk_size_end: usize = pos
+                + body_bytes[pos..]
+                    .windows(2)
+                    .position(|window: &[u8]| window == b"\r\n")?;
+            let chunk_size_str: &[u8] = &body_bytes[pos..chunk_size_end];
+            let chunk_size_str: &[u8] = match chunk_size_str.iter().position(|&b| b == b';') {
+                Some(p) => &chunk_size_str[..p],
+                None => chunk_size_str,
             };
+            let chunk_size: usize = std::str::from_utf8(chunk_size_str)
+                .ok()
+                .and_then(|s: &str| usize::from_str_radix(s.trim(), 16).ok())?;
             if chunk_size == 0 {
-                return true;
+                let trailer_start: usize = chunk_size_end + 2;
+                let trailer_bytes: &[u8] = &body_bytes[trailer_start..];
+                if trailer_bytes.starts_with(HTTP_BR_BYTES) {
+                    return Some(trailer_start + HTTP_BR_BYTES.len());
+                }
+                return SharedResponseHandler::find_double_crlf(trailer_bytes, 0)
+                    .map(|end: usize| trailer_start + end + 4);
             }
             let chunk_data_start: usize = chunk_size_end + 2;
             let chunk_data_end: usize = chunk_data_start + chunk_size;
             if chunk_data_end + 2 > body_bytes.len() {
-                return false;
+                return None;
             }
             pos = chunk_data_end + 2;
         }
-        false
+        None
     }
 
     /// Determines the appropriate port for the HTTP request.
@@ -650,10 +2576,114 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// - `Ok(Box<dyn ReadWrite>)`: A boxed stream that implements the `ReadWrite` trait,
-    ///   representing the established connection.
-    /// - `Err(RequestError)`: An error indicating what went wrong during the connection process.
-    fn get_connection_stream(
+    /// - `Ok(Box<dyn ReadWrite>)`: A boxed stream that implements the `ReadWrite` trait,
+    ///   representing the established connection.
+    /// - `Err(RequestError)`: An error indicating what went wrong during the connection process.
+    pub(crate) fn get_connection_stream(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        self.set_in_flight_state(InFlightState::Connecting);
+        let metrics_host: String = format!("{host}:{port}");
+        self.open_connection_stream(host, port)
+            .inspect_err(|_| HostMetrics::record_connection_error(&metrics_host))
+    }
+
+    /// Resolves a host through the DNS overrides, the custom resolver, or the system resolver.
+    ///
+    /// Lookups that may block run on a helper thread bounded by the timeout, so a
+    /// stalled resolver cannot hold the request past its deadline.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    /// - `Duration` - The time left for the lookup.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<SocketAddr>, RequestError>` - The resolved addresses, or an error if
+    ///   resolution fails, times out, or yields no address.
+    fn resolve_host(
+        &self,
+        host: &str,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<Vec<SocketAddr>, RequestError> {
+        let overrides: Vec<SocketAddr> = self
+            .config
+            .read()
+            .map(|config: RwLockReadGuard<'_, Config>| config.get_dns_overrides(host))
+            .unwrap_or_default();
+        if !overrides.is_empty() {
+            return Ok(overrides);
+        }
+        let lookup_host: String = host.to_string();
+        let Some(resolver) = &self.resolver else {
+            if let Ok(ip) = host.parse::<IpAddr>() {
+                return Ok(vec![SocketAddr::new(ip, port)]);
+            }
+            if let Some(addrs) = SharedConnector::get_cached_addrs(host, port) {
+                return Ok(addrs);
+            }
+            return SharedConnector::resolve_with_timeout(
+                move || SharedConnector::resolve(&lookup_host, port),
+                timeout,
+            );
+        };
+        let resolver: ArcResolver = Arc::clone(&resolver.0);
+        SharedConnector::resolve_with_timeout(
+            move || {
+                let addrs: Vec<SocketAddr> =
+                    resolver
+                        .resolve(&lookup_host, port)
+                        .map_err(|error: std::io::Error| {
+                            RequestError::from(error).with_phase(ErrorPhase::Resolve)
+                        })?;
+                if addrs.is_empty() {
+                    return Err(RequestError::unresolved(format!("{lookup_host}:{port}")));
+                }
+                Ok(addrs)
+            },
+            timeout,
+        )
+    }
+
+    /// Connects to the first reachable address resolved for a host by `resolve_host`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    /// - `Duration` - The timeout for resolution and all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TcpStream, RequestError>` - The connected stream, or an error.
+    #[cfg(feature = "proxy")]
+    fn connect_host(
+        &self,
+        host: &str,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<TcpStream, RequestError> {
+        let deadline: Instant = Instant::now() + timeout;
+        let addrs: Vec<SocketAddr> = self.resolve_host(host, port, timeout)?;
+        SharedConnector::connect_to(addrs, deadline)
+    }
+
+    /// Opens the connection stream for `get_connection_stream`.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Box<dyn ReadWrite>, RequestError>` - The stream, or an error if the connection failed.
+    fn open_connection_stream(
         &self,
         host: String,
         port: u16,
@@ -662,48 +2692,134 @@ impl HttpRequest {
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
+        #[cfg(feature = "proxy")]
         if let Some(proxy_config) = &config.proxy {
-            return self.get_proxy_connection_stream(host, port, proxy_config);
+            let started_at: Instant = Instant::now();
+            let stream: Box<dyn ReadWrite> =
+                self.get_proxy_connection_stream(host, port, proxy_config)?;
+            self.update_metrics(|metrics: &mut RequestMetrics| {
+                metrics.connect += started_at.elapsed()
+            });
+            return Ok(stream);
         }
-        let host_port: (String, u16) = (host.clone(), port);
         let timeout: Duration = Duration::from_millis(config.timeout);
-        let tcp_stream: TcpStream = TcpStream::connect(host_port.clone())
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+        let deadline: Instant = Instant::now() + timeout;
+        let addrs: Vec<SocketAddr> = if config.resolved_addrs.is_empty() {
+            let started_at: Instant = Instant::now();
+            let addrs: Vec<SocketAddr> = self.resolve_host(&host, port, timeout)?;
+            self.update_metrics(|metrics: &mut RequestMetrics| metrics.dns += started_at.elapsed());
+            addrs
+        } else {
+            config.get_resolved_addrs(port)
+        };
+        let started_at: Instant = Instant::now();
+        let tcp_stream: TcpStream = SharedConnector::connect_to(addrs, deadline)?;
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.connect += started_at.elapsed());
         tcp_stream
             .set_read_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(RequestError::from)?;
         tcp_stream
             .set_write_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let stream: Result<Box<dyn ReadWrite>, RequestError> =
-            if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => {
-                        let roots: RootCertStore = tmp.root_cert.clone();
-                        let tls_config: ClientConfig = ClientConfig::builder()
-                            .with_root_certificates(roots)
-                            .with_no_client_auth();
-                        let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                        let dns_name: ServerName<'_> = ServerName::try_from(host.clone()).map_err(
-                            |error: InvalidDnsNameError| RequestError::Request(error.to_string()),
-                        )?;
-                        let session: ClientConnection =
-                            ClientConnection::new(Arc::clone(&client_config), dns_name).map_err(
-                                |error: rustls::Error| RequestError::Request(error.to_string()),
-                            )?;
-                        let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                            StreamOwned::new(session, tcp_stream);
-                        return Ok(Box::new(tls_stream));
-                    }
-                    Err(error) => Err(RequestError::Request(error.to_string())),
-                }
-            } else {
-                Ok(Box::new(tcp_stream))
-            };
-        stream
+            .map_err(RequestError::from)?;
+        if Self::get_protocol(&config) != HTTPS_LOWERCASE {
+            return Ok(Box::new(tcp_stream));
+        }
+        self.set_in_flight_state(InFlightState::Tls);
+        let started_at: Instant = Instant::now();
+        let tls_stream: Box<dyn ReadWrite> = self
+            .connect_tls(tcp_stream, config.get_tls_server_name(&host))
+            .map_err(|error: RequestError| error.with_phase(ErrorPhase::Tls))?;
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.tls += started_at.elapsed());
+        Ok(tls_stream)
+    }
+
+    /// Builds the TLS client configuration from the trusted roots of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Arc<ClientConfig>, RequestError>` - The shared TLS client configuration.
+    #[cfg(feature = "tls")]
+    pub(crate) fn get_client_config(&self) -> Result<Arc<ClientConfig>, RequestError> {
+        let roots: RootCertStore = match self.tmp.read() {
+            Ok(tmp) => tmp.root_cert.clone(),
+            Err(error) => return Err(RequestError::Request(error.to_string())),
+        };
+        let tls_config: ClientConfig =
+            SharedConnector::get_tls_config(roots, self.is_insecure_skip_tls_verify());
+        Ok(Arc::new(tls_config))
+    }
+
+    /// Starts a TLS session over an established stream and completes its handshake.
+    ///
+    /// # Arguments
+    ///
+    /// - `S` - The stream carrying the session.
+    /// - `String` - The server name used for SNI and certificate verification.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Box<dyn ReadWrite>, RequestError>` - The TLS stream, or the error that
+    ///   prevented the session.
+    #[cfg(feature = "tls")]
+    fn connect_tls<S: ReadWrite + 'static>(
+        &self,
+        mut stream: S,
+        server_name: String,
+    ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        let dns_name: ServerName<'static> =
+            ServerName::try_from(server_name).map_err(RequestError::from)?;
+        let mut session: ClientConnection =
+            ClientConnection::new(self.get_client_config()?, dns_name)
+                .map_err(RequestError::from)?;
+        Self::complete_tls_handshake(&mut session, &mut stream).map_err(RequestError::from)?;
+        let tls_stream: StreamOwned<ClientConnection, S> = StreamOwned::new(session, stream);
+        Ok(Box::new(tls_stream))
+    }
+
+    /// Rejects a TLS session because the crate was built without the `tls` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `S` - The stream that would carry the session.
+    /// - `String` - The server name of the session.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Box<dyn ReadWrite>, RequestError>` - Always a TLS error.
+    #[cfg(not(feature = "tls"))]
+    fn connect_tls<S: ReadWrite + 'static>(
+        &self,
+        _stream: S,
+        _server_name: String,
+    ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        Err(RequestError::tls(TLS_FEATURE_DISABLED))
+    }
+
+    /// Completes a TLS handshake on a stream before any application data is sent.
+    ///
+    /// Handshake failures are reported here instead of on the first write.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut ClientConnection` - The TLS session.
+    /// - `&mut T` - The stream carrying the session.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok once the handshake completed, or the handshake error.
+    #[cfg(feature = "tls")]
+    fn complete_tls_handshake<T: Read + Write>(
+        session: &mut ClientConnection,
+        stream: &mut T,
+    ) -> std::io::Result<()> {
+        while session.is_handshaking() {
+            session.complete_io(stream)?;
+        }
+        Ok(())
     }
 
     /// Establishes a proxy connection stream to the specified host and port.
+    #[cfg(feature = "proxy")]
     fn get_proxy_connection_stream(
         &self,
         target_host: String,
@@ -715,6 +2831,9 @@ impl HttpRequest {
             |config: RwLockReadGuard<'_, Config>| config.timeout,
         ));
         match proxy_config.proxy_type {
+            ProxyType::Http | ProxyType::Https if self.is_proxy_absolute_form() => {
+                self.open_http_proxy_stream(proxy_config, timeout)
+            }
             ProxyType::Http | ProxyType::Https => {
                 self.get_http_proxy_connection(target_host, target_port, proxy_config, timeout)
             }
@@ -724,119 +2843,89 @@ impl HttpRequest {
         }
     }
 
-    /// Establishes an HTTP/HTTPS proxy connection.
-    fn get_http_proxy_connection(
+    /// Opens a connection to an HTTP/HTTPS proxy, using TLS for HTTPS proxies.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ProxyConfig` - The proxy configuration.
+    /// - `Duration` - The connect, read, and write timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Box<dyn ReadWrite>, RequestError>` - The stream to the proxy.
+    #[cfg(feature = "proxy")]
+    fn open_http_proxy_stream(
         &self,
-        target_host: String,
-        target_port: u16,
         proxy_config: &ProxyConfig,
         timeout: Duration,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+        let tcp_stream: TcpStream = self
+            .connect_host(&proxy_config.host, proxy_config.port, timeout)
+            .map_err(|error: RequestError| RequestError::proxy(ProxyErrorPhase::Connect, error))?;
         tcp_stream
             .set_read_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(RequestError::from)?;
         tcp_stream
             .set_write_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let mut proxy_stream: Box<dyn ReadWrite> = if proxy_config.proxy_type == ProxyType::Https {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                        .map_err(|error: InvalidDnsNameError| {
-                            RequestError::Request(error.to_string())
-                        })?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name).map_err(
-                            |error: rustls::Error| RequestError::Request(error.to_string()),
-                        )?;
-                    let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                        StreamOwned::new(session, tcp_stream);
-                    Box::new(tls_stream)
-                }
-                Err(error) => {
-                    return Err(RequestError::Request(error.to_string()));
-                }
-            }
+            .map_err(RequestError::from)?;
+        let proxy_stream: Box<dyn ReadWrite> = if proxy_config.proxy_type == ProxyType::Https {
+            self.connect_tls(tcp_stream, proxy_config.host.clone())
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::Connect, error.get_detail())
+                })?
         } else {
             Box::new(tcp_stream)
         };
-        let connect_request: String = if let (Some(username), Some(password)) =
-            (&proxy_config.username, &proxy_config.password)
-        {
-            let auth: String = format!("{username}:{password}");
-            let auth_encoded: String = base64_encode(auth.as_bytes());
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\nProxy-Authorization: Basic {auth_encoded}\r\n\r\n"
-            )
-        } else {
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\n\r\n"
-            )
-        };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        proxy_stream
-            .flush()
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let mut response_buffer = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let response_str: &str = std::str::from_utf8(&response_buffer[..bytes_read]).unwrap_or("");
-        let headers_end_pos: Option<usize> = response_str.find("\r\n\r\n");
-        let pre_read_data: Vec<u8> = if let Some(pos) = headers_end_pos {
-            let header_part: &str = &response_str[..pos];
-            if !header_part.starts_with("HTTP/1.1 200") && !header_part.starts_with("HTTP/1.0 200")
-            {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
-            }
-            response_buffer[pos + 4..bytes_read].to_vec()
-        } else {
-            if !response_str.starts_with("HTTP/1.1 200")
-                && !response_str.starts_with("HTTP/1.0 200")
-            {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+        Ok(proxy_stream)
+    }
+
+    /// Establishes an HTTP/HTTPS proxy connection.
+    ///
+    /// The CONNECT request is first sent without credentials. If the proxy answers
+    /// 407 with a challenge the configured credentials can answer, the CONNECT is
+    /// retried once on a new connection with a `Proxy-Authorization` header.
+    #[cfg(feature = "proxy")]
+    fn get_http_proxy_connection(
+        &self,
+        target_host: String,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+        timeout: Duration,
+    ) -> Result<Box<dyn ReadWrite>, RequestError> {
+        let mut proxy_stream: Box<dyn ReadWrite> =
+            self.open_http_proxy_stream(proxy_config, timeout)?;
+        let pre_read_data: Vec<u8> = match SharedConnector::proxy_connect(
+            &mut proxy_stream,
+            &target_host,
+            target_port,
+            None,
+        ) {
+            Err(RequestError::ProxyAuthenticationRequired(challenges)) => {
+                let Some(authorization) = proxy_config.get_authorization(&challenges) else {
+                    return Err(RequestError::ProxyAuthenticationRequired(challenges));
+                };
+                proxy_stream = self.open_http_proxy_stream(proxy_config, timeout)?;
+                SharedConnector::proxy_connect(
+                    &mut proxy_stream,
+                    &target_host,
+                    target_port,
+                    Some(&authorization),
+                )?
             }
-            vec![]
+            result => result?,
         };
         let config: Config = self
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|error: InvalidDnsNameError| {
-                            RequestError::Request(error.to_string())
-                        })?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name).map_err(
-                            |error: rustls::Error| RequestError::Request(error.to_string()),
-                        )?;
-                    let tunnel_stream = SyncProxyTunnelStream::new(proxy_stream, pre_read_data);
-                    let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> =
-                        StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(error) => {
-                    return Err(RequestError::Request(error.to_string()));
-                }
-            }
+            let tunnel_stream: SyncProxyTunnelStream =
+                SyncProxyTunnelStream::new(proxy_stream, pre_read_data);
+            return self
+                .connect_tls(tunnel_stream, config.get_tls_server_name(&target_host))
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::TlsToTarget, error.get_detail())
+                });
         }
         let tunnel_stream: SyncProxyTunnelStream =
             SyncProxyTunnelStream::new(proxy_stream, pre_read_data);
@@ -844,6 +2933,7 @@ impl HttpRequest {
     }
 
     /// Establishes a SOCKS5 proxy connection.
+    #[cfg(feature = "proxy")]
     fn get_socks5_proxy_connection(
         &self,
         target_host: String,
@@ -851,15 +2941,15 @@ impl HttpRequest {
         proxy_config: &ProxyConfig,
         timeout: Duration,
     ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+        let mut tcp_stream: TcpStream = self
+            .connect_host(&proxy_config.host, proxy_config.port, timeout)
+            .map_err(|error: RequestError| RequestError::proxy(ProxyErrorPhase::Connect, error))?;
         tcp_stream
             .set_read_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(RequestError::from)?;
         tcp_stream
             .set_write_timeout(Some(timeout))
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(RequestError::from)?;
         let auth_methods: Vec<u8> =
             if proxy_config.username.is_some() && proxy_config.password.is_some() {
                 vec![0x05, 0x02, 0x00, 0x02]
@@ -868,13 +2958,20 @@ impl HttpRequest {
             };
         tcp_stream
             .write_all(&auth_methods)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::Greeting, error)
+            })?;
         let mut response = [0u8; 2];
         tcp_stream
             .read_exact(&mut response)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::Greeting, error)
+            })?;
         if response[0] != 0x05 {
-            return Err(RequestError::Request("Internal Server Error".to_string()));
+            return Err(RequestError::proxy(
+                ProxyErrorPhase::Greeting,
+                format!("Unexpected SOCKS version {:#04x}", response[0]),
+            ));
         }
         match response[1] {
             0x00 => {}
@@ -890,24 +2987,36 @@ impl HttpRequest {
                     tcp_stream
                         .write_all(&auth_request)
                         .map_err(|error: std::io::Error| {
-                            RequestError::Request(error.to_string())
+                            RequestError::proxy(ProxyErrorPhase::Auth, error)
                         })?;
                     let mut auth_response: [u8; 2] = [0u8; 2];
                     tcp_stream.read_exact(&mut auth_response).map_err(
-                        |error: std::io::Error| RequestError::Request(error.to_string()),
+                        |error: std::io::Error| RequestError::proxy(ProxyErrorPhase::Auth, error),
                     )?;
                     if auth_response[1] != 0x00 {
-                        return Err(RequestError::Request("Internal Server Error".to_string()));
+                        return Err(RequestError::proxy(
+                            ProxyErrorPhase::Auth,
+                            "Proxy rejected the username and password",
+                        ));
                     }
                 } else {
-                    return Err(RequestError::Request("Internal Server Error".to_string()));
+                    return Err(RequestError::proxy(
+                        ProxyErrorPhase::Auth,
+                        "Proxy requires username and password authentication",
+                    ));
                 }
             }
             0xFF => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::Greeting,
+                    "Proxy accepted none of the offered authentication methods",
+                ));
             }
-            _ => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+            method => {
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::Greeting,
+                    format!("Unsupported authentication method {method:#04x}"),
+                ));
             }
         }
         let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
@@ -927,39 +3036,60 @@ impl HttpRequest {
         connect_request.extend_from_slice(&target_port.to_be_bytes());
         tcp_stream
             .write_all(&connect_request)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
         let mut connect_response: [u8; 4] = [0u8; 4];
         tcp_stream
             .read_exact(&mut connect_response)
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
-            return Err(RequestError::Request("Internal Server Error".to_string()));
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
+        if connect_response[0] != 0x05 {
+            return Err(RequestError::proxy(
+                ProxyErrorPhase::ConnectCommand,
+                format!("Unexpected SOCKS version {:#04x}", connect_response[0]),
+            ));
+        }
+        if connect_response[1] != 0x00 {
+            return Err(ProxyError::socks5_reply(connect_response[1]));
         }
         match connect_response[3] {
             0x01 => {
                 let mut skip = [0u8; 6];
                 tcp_stream
                     .read_exact(&mut skip)
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
             0x03 => {
                 let mut len = [0u8; 1];
                 tcp_stream
                     .read_exact(&mut len)
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
                 let mut skip = vec![0u8; len[0] as usize + 2];
                 tcp_stream
                     .read_exact(&mut skip)
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
             0x04 => {
                 let mut skip = [0u8; 18];
                 tcp_stream
                     .read_exact(&mut skip)
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
-            _ => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+            address_type => {
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::ConnectCommand,
+                    format!("Unsupported address type {address_type:#04x}"),
+                ));
             }
         }
         let proxy_stream: Box<dyn ReadWrite> = Box::new(tcp_stream);
@@ -968,94 +3098,342 @@ impl HttpRequest {
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|error: InvalidDnsNameError| {
-                            RequestError::Request(error.to_string())
-                        })?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name).map_err(
-                            |error: rustls::Error| RequestError::Request(error.to_string()),
-                        )?;
-                    let tunnel_stream: SyncProxyTunnelStream =
-                        SyncProxyTunnelStream::new(proxy_stream, vec![]);
-                    let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> =
-                        StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(error) => {
-                    return Err(RequestError::Request(error.to_string()));
-                }
-            }
+            let tunnel_stream: SyncProxyTunnelStream =
+                SyncProxyTunnelStream::new(proxy_stream, vec![]);
+            return self
+                .connect_tls(tunnel_stream, config.get_tls_server_name(&target_host))
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::TlsToTarget, error.get_detail())
+                });
         }
         Ok(proxy_stream)
     }
 }
 
 impl HttpRequest {
+    /// Sends the HTTP request synchronously through its middleware chain.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response of the outermost middleware, or of the
+    ///   network request if no middleware is registered.
+    fn send_with_middleware(&mut self) -> RequestResult {
+        if self.middleware.sync.is_empty() {
+            return self.send_sync();
+        }
+        let middleware: Vec<ArcMiddleware> = self.middleware.sync.clone();
+        Next {
+            middleware: &middleware,
+        }
+        .run(&mut RequestAttempt { request: self })
+    }
+
     /// Sends the HTTP request synchronously.
     pub(crate) fn send_sync(&mut self) -> RequestResult {
-        let methods: Method = self.get_methods();
-        let mut host: String = String::new();
-        let mut port: u16 = u16::default();
-        if let Ok(mut config) = self.config.write() {
-            config.url_obj = self
-                .parse_url()
-                .map_err(|error: RequestError| RequestError::Request(error.to_string()))?;
-            host = config.url_obj.host.clone().unwrap_or_default();
-            port = self.get_port(config.url_obj.port.unwrap_or_default(), &config);
+        if let Some(threshold) = self.get_spool_threshold() {
+            return self.send_spooled_sync(threshold);
+        }
+        self.get_request_body()?;
+        self.get_http_version_str()?;
+        let (host, port) = self.prepare_target()?;
+        #[cfg(feature = "proxy")]
+        let tunnel_key: Option<TunnelKey> = self.get_tunnel_key(&host, port);
+        #[cfg(feature = "proxy")]
+        if let Some(key) = &tunnel_key
+            && let Some(mut stream) = TunnelPool::take_sync(key)
+        {
+            match self.send_on_stream(&mut stream) {
+                Err(error) if self.is_stale_tunnel_retry(&error) => {}
+                res => {
+                    self.release_tunnel_sync(tunnel_key, stream, &res);
+                    return res;
+                }
+            }
         }
         let mut stream: BoxReadWrite = self.get_connection_stream(host, port)?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request(&mut stream),
-            m if m.is_post() => self.send_post_request(&mut stream),
-            _err => Err(RequestError::Request("Method Not Allowed".to_string())),
-        };
+        let res: Result<BoxResponseTrait, RequestError> = self.send_on_stream(&mut stream);
+        #[cfg(feature = "proxy")]
+        self.release_tunnel_sync(tunnel_key, stream, &res);
         res
     }
+
+    /// Sends the request and reads the response body into memory or a temporary file.
+    ///
+    /// Bodies kept in memory are decoded if decoding is enabled; spooled bodies are
+    /// stored as they were received.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The largest body kept in memory.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    fn send_spooled_sync(&mut self, threshold: usize) -> RequestResult {
+        let (decode, buffer_size): (bool, usize) = self.get_spool_read_config();
+        let mut response: HttpResponseBinary = self
+            .send_stream_sync()?
+            .into_spooled(threshold, buffer_size)?;
+        if decode && !response.is_spooled() {
+            response = response.decode(buffer_size);
+        }
+        response.metrics = self.get_request_metrics();
+        self.response = Arc::new(RwLock::new(response.clone()));
+        Ok(Box::new(response))
+    }
+
+    /// Sends the request over an established stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxReadWrite` - The connection stream.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    fn send_on_stream(&mut self, stream: &mut BoxReadWrite) -> RequestResult {
+        self.run_attempt_hook();
+        self.send_request(stream)
+    }
+
+    /// Returns a proxy tunnel to the pool if the response left it reusable.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<TunnelKey>` - The pool key, or None if tunnels are not kept alive.
+    /// - `BoxReadWrite` - The tunnel stream.
+    /// - `&RequestResult` - The result of the request sent over the tunnel.
+    #[cfg(feature = "proxy")]
+    fn release_tunnel_sync(
+        &self,
+        tunnel_key: Option<TunnelKey>,
+        stream: BoxReadWrite,
+        res: &RequestResult,
+    ) {
+        if self.take_keep_alive()
+            && res.is_ok()
+            && let Some(key) = tunnel_key
+        {
+            TunnelPool::release_sync(key, stream);
+        }
+    }
+
+    /// Sends the HTTP request synchronously and returns once the response headers arrive.
+    ///
+    /// The body is left on the connection and read through the returned stream.
+    /// Redirects are followed according to the redirect settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseStream, RequestError>` - The streaming response or an error.
+    pub(crate) fn send_stream_sync(&mut self) -> Result<HttpResponseStream, RequestError> {
+        let (host, port) = self.prepare_target()?;
+        self.run_attempt_hook();
+        let request: Vec<u8> = self.get_request_bytes(false)?;
+        let mut stream: BoxReadWrite = self.get_connection_stream(host, port)?;
+        self.write_request(&mut stream, &request)?;
+        self.write_body_reader(&mut stream)?;
+        stream.flush().map_err(RequestError::from)?;
+        let (headers_bytes, leftover) = self.read_response_head(&mut stream)?;
+        let mut head: HttpResponseBinary =
+            <HttpResponseBinary as ResponseTrait>::from(&headers_bytes);
+        self.run_interceptors_after(&head);
+        self.record_response_metrics(&head);
+        if self.is_auth_retry(&headers_bytes) {
+            return self.send_stream_sync();
+        }
+        if let Some(url) = self.get_redirect_url(&headers_bytes)? {
+            self.prepare_redirect(url, head.get_status_code())?;
+            return self.send_stream_sync();
+        }
+        let buffer_size: usize = self
+            .config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let max_body: Option<usize> = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| config.max_response_body);
+        let decoder: BodyDecoder = BodyDecoder::new(&headers_bytes, self.get_methods().is_head())
+            .with_max_body(max_body)?;
+        head.metrics = self.get_request_metrics();
+        Ok(HttpResponseStream {
+            head,
+            body: BodyReader::new(stream, decoder, &leftover, buffer_size)?,
+        })
+    }
 }
 
 /// Async implementation for HttpRequest
+#[cfg(feature = "async")]
 impl HttpRequest {
-    /// Sends an async GET request.
+    /// Starts a TLS session over an established async stream and completes its handshake.
     ///
     /// # Arguments
     ///
-    /// - `&mut BoxAsyncReadWrite` - The async stream to write to.
+    /// - `S` - The stream carrying the session.
+    /// - `String` - The server name used for SNI and certificate verification.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
-    async fn send_get_request_async(
-        &mut self,
-        stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        stream
-            .flush()
+    /// - `Result<BoxAsyncReadWrite, RequestError>` - The TLS stream, or the error that
+    ///   prevented the session.
+    #[cfg(feature = "tls")]
+    async fn connect_tls_async<S: AsyncReadWrite + 'static>(
+        &self,
+        stream: S,
+        server_name: String,
+    ) -> Result<BoxAsyncReadWrite, RequestError> {
+        let connector: TlsConnector = TlsConnector::from(self.get_client_config()?);
+        let dns_name: ServerName<'static> =
+            ServerName::try_from(server_name).map_err(RequestError::from)?;
+        let tls_stream: TlsStream<S> = connector
+            .connect(dns_name, stream)
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        self.read_response_async(stream).await
+            .map_err(RequestError::from)?;
+        Ok(Box::new(tls_stream))
+    }
+
+    /// Rejects a TLS session because the crate was built without the `tls` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `S` - The stream that would carry the session.
+    /// - `String` - The server name of the session.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxAsyncReadWrite, RequestError>` - Always a TLS error.
+    #[cfg(not(feature = "tls"))]
+    async fn connect_tls_async<S: AsyncReadWrite + 'static>(
+        &self,
+        _stream: S,
+        _server_name: String,
+    ) -> Result<BoxAsyncReadWrite, RequestError> {
+        Err(RequestError::tls(TLS_FEATURE_DISABLED))
+    }
+
+    /// Writes a serialized request to the connection asynchronously.
+    ///
+    /// With an upload progress hook, the body following the headers is written in
+    /// blocks of the configured buffer size, reporting progress after each block.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The connection stream.
+    /// - `&[u8]` - The request line, headers, and buffered body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once the request is written, or an error.
+    async fn write_request_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+        request: &[u8],
+    ) -> Result<(), RequestError> {
+        self.set_in_flight_state(InFlightState::Sending);
+        if self.on_upload_progress.is_none() {
+            self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += request.len());
+            return stream.write_all(request).await.map_err(RequestError::from);
+        }
+        let (head, body): (&[u8], &[u8]) = self.split_request_body(request);
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += head.len());
+        stream.write_all(head).await.map_err(RequestError::from)?;
+        self.write_request_body_async(stream, body).await
+    }
+
+    /// Writes a buffered request body asynchronously, reporting the upload progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The stream to write to.
+    /// - `&[u8]` - The body bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the body was written.
+    async fn write_request_body_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+        body: &[u8],
+    ) -> Result<(), RequestError> {
+        self.set_in_flight_state(InFlightState::Sending);
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += body.len());
+        let mut sent: usize = 0;
+        for block in body.chunks(self.get_progress_block_size()) {
+            stream.write_all(block).await.map_err(RequestError::from)?;
+            sent += block.len();
+            self.report_upload_progress(sent, Some(body.len()));
+        }
+        Ok(())
+    }
+
+    /// Streams a reader body to the connection after the request headers.
+    ///
+    /// Both blocking and asynchronous readers are supported; a blocking reader is
+    /// read on the current task.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The connection stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once the body is written, or an error.
+    async fn write_body_reader_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+    ) -> Result<(), RequestError> {
+        if !self.has_streamed_body() {
+            return Ok(());
+        }
+        let content_length: Option<usize> = self.get_streamed_body_length();
+        let buffer_size: usize = self
+            .config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let mut buffer: Vec<u8> = vec![0; buffer_size.max(1)];
+        let mut written: usize = 0;
+        let mut blocking_reader: Option<BoxBodyRead> = None;
+        let mut async_reader: Option<BoxBodyAsyncRead> = None;
+        match self.body.as_ref() {
+            Body::Reader(source) => blocking_reader = Some(source.take()?),
+            Body::AsyncReader(source) => async_reader = Some(source.take()?),
+            _ => return Ok(()),
+        }
+        loop {
+            let n: usize = match (&mut blocking_reader, &mut async_reader) {
+                (Some(reader), _) => reader.read(&mut buffer),
+                (_, Some(reader)) => reader.read(&mut buffer).await,
+                _ => Ok(0),
+            }
+            .map_err(RequestError::from)?;
+            if n == 0 {
+                break;
+            }
+            written += n;
+            stream
+                .write_all(&Self::frame_body_block(
+                    &buffer[..n],
+                    content_length.is_none(),
+                ))
+                .await
+                .map_err(RequestError::from)?;
+            self.report_upload_progress(written, content_length);
+        }
+        Self::check_streamed_body_length(written, content_length)?;
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.bytes_sent += written);
+        if content_length.is_none() {
+            stream
+                .write_all(&self.get_chunked_body_end())
+                .await
+                .map_err(RequestError::from)?;
+        }
+        Ok(())
     }
 
-    /// Sends an async POST request.
+    /// Sends an async request for the configured method.
     ///
     /// # Arguments
     ///
@@ -1064,51 +3442,105 @@ impl HttpRequest {
     /// # Returns
     ///
     /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
-    async fn send_post_request_async(
+    async fn send_request_async(
         &mut self,
         stream: &mut BoxAsyncReadWrite,
     ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
-            path,
-            header_bytes,
-            body_bytes,
-            http_version_str,
-        );
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        stream
-            .flush()
+        let request: Vec<u8> = self.get_request_bytes(true)?;
+        let (head, body): (&[u8], &[u8]) = self.split_request_body(&request);
+        let expect_continue: Option<Duration> = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| self.get_expect_continue_timeout(&config, Some(body.len())));
+        let Some(wait) = expect_continue else {
+            self.write_request_async(stream, &request).await?;
+            self.write_body_reader_async(stream).await?;
+            stream.flush().await.map_err(RequestError::from)?;
+            return self.read_response_async(stream).await;
+        };
+        self.write_request_async(stream, head).await?;
+        stream.flush().await.map_err(RequestError::from)?;
+        let (send_body, received): (bool, Vec<u8>) =
+            self.await_continue_async(stream, wait).await?;
+        if !send_body {
+            let response: RequestResult = self
+                .read_response_async(&mut AsyncReadExt::chain(Cursor::new(received), stream))
+                .await;
+            self.discard_connection();
+            return response;
+        }
+        self.write_request_body_async(stream, body).await?;
+        self.write_body_reader_async(stream).await?;
+        stream.flush().await.map_err(RequestError::from)?;
+        self.read_response_async(&mut AsyncReadExt::chain(Cursor::new(received), stream))
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        self.read_response_async(stream).await
+    }
+
+    /// Waits asynchronously for the interim `100 Continue` response of a request sent
+    /// with `Expect: 100-continue`.
+    ///
+    /// Other interim responses are skipped. The body is sent once `100 Continue`
+    /// arrives or the wait times out, but not if the server answers with a final
+    /// response first.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The stream the request headers were written to.
+    /// - `Duration` - The longest time to wait.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(bool, Vec<u8>), RequestError>` - Whether to send the body, and the
+    ///   response bytes already read past the interim responses.
+    async fn await_continue_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+        wait: Duration,
+    ) -> Result<(bool, Vec<u8>), RequestError> {
+        let deadline: Instant = Instant::now() + wait;
+        let mut buffer: Vec<u8> = vec![0; self.get_progress_block_size()];
+        let mut response_bytes: Vec<u8> = Vec::new();
+        self.set_in_flight_state(InFlightState::Waiting);
+        loop {
+            if let Some(is_continue) = Self::take_interim_response(&mut response_bytes) {
+                if is_continue {
+                    return Ok((true, response_bytes));
+                }
+                continue;
+            }
+            if SharedResponseHandler::find_double_crlf(&response_bytes, 0).is_some() {
+                return Ok((false, response_bytes));
+            }
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            match timeout(remaining, stream.read(&mut buffer)).await {
+                Err(_) => return Ok((true, response_bytes)),
+                Ok(Ok(0)) => return Ok((false, response_bytes)),
+                Ok(Ok(n)) => response_bytes.extend_from_slice(&buffer[..n]),
+                Ok(Err(error)) => return Err(RequestError::from(error)),
+            }
+        }
     }
 
     /// Reads an async HTTP response.
     ///
     /// # Arguments
     ///
-    /// - `&mut BoxAsyncReadWrite` - The async stream to read from.
+    /// - `&mut (dyn AsyncRead + Unpin + Send)` - The async stream to read from.
     ///
     /// # Returns
     ///
     /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
     async fn read_response_async(
         &mut self,
-        stream: &mut BoxAsyncReadWrite,
+        stream: &mut (dyn AsyncRead + Unpin + Send),
     ) -> Result<BoxResponseTrait, RequestError> {
         let buffer_size: usize = self
             .config
             .read()
             .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let strict_parsing: bool = self.config.read().is_ok_and(|config| config.strict_parsing);
+        let is_head: bool = self.get_methods().is_head();
         let mut buffer: Vec<u8> = vec![0; buffer_size];
         let initial_capacity: usize = buffer_size.max(8192);
         let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
@@ -1117,22 +3549,25 @@ impl HttpRequest {
         let mut redirect_url: Option<Vec<u8>> = None;
         let mut headers_end_pos: usize = 0;
         let mut is_chunked: bool = false;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
+        let mut read_until_close: bool = false;
         let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
+        let mut keep_alive: bool = false;
+        self.set_in_flight_state(InFlightState::Waiting);
+        let started_at: Instant = Instant::now();
+        let mut first_byte_at: Option<Instant> = None;
         'read_loop: loop {
-            let bytes_read: usize = stream
-                .read(&mut buffer)
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            let bytes_read: usize = match stream.read(&mut buffer).await {
+                Ok(bytes_read) => bytes_read,
+                Err(_) if read_until_close => break,
+                Err(error) => return Err(RequestError::from(error)),
+            };
             if bytes_read == 0 {
                 break;
             }
+            if first_byte_at.is_none() {
+                first_byte_at = Some(Instant::now());
+                self.set_in_flight_state(InFlightState::Reading);
+            }
             let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
                 &response_bytes,
                 bytes_read,
@@ -1144,45 +3579,111 @@ impl HttpRequest {
             let old_len: usize = response_bytes.len();
             response_bytes.extend_from_slice(&buffer[..bytes_read]);
             if !headers_done {
+                self.config
+                    .read()
+                    .map_or(Ok(()), |config| config.check_header_limits(&response_bytes))?;
                 let search_start: usize = old_len.saturating_sub(3);
                 if let Some(pos) =
                     SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
                 {
                     headers_done = true;
                     headers_end_pos = pos + 4;
+                    if strict_parsing {
+                        SharedResponseHandler::check_strict_headers(
+                            &response_bytes[..headers_end_pos],
+                        )?;
+                    }
                     SharedResponseHandler::parse_response_headers(
                         &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
                         &location_sign_key,
                         &mut content_length,
                         &mut redirect_url,
                         &mut is_chunked,
                     )?;
+                    if SharedResponseHandler::is_bodyless_response(
+                        &response_bytes[..headers_end_pos],
+                        is_head,
+                    ) {
+                        content_length = 0;
+                        is_chunked = false;
+                    }
+                    read_until_close = SharedResponseHandler::is_close_delimited(
+                        &response_bytes[..headers_end_pos],
+                        is_head,
+                    );
+                    if !is_chunked {
+                        self.config
+                            .read()
+                            .map_or(Ok(()), |config| config.check_content_length(content_length))?;
+                    }
                 }
             }
             if headers_done {
-                if is_chunked {
-                    if Self::is_chunked_response_complete(&response_bytes[headers_end_pos..]) {
+                self.report_download_progress(
+                    response_bytes.len() - headers_end_pos,
+                    content_length,
+                    is_chunked || read_until_close,
+                );
+                if is_chunked || read_until_close {
+                    self.config.read().map_or(Ok(()), |config| {
+                        config.check_body_size(response_bytes.len() - headers_end_pos)
+                    })?;
+                    if is_chunked
+                        && let Some(body_len) =
+                            Self::get_chunked_message_len(&response_bytes[headers_end_pos..])
+                    {
+                        keep_alive = SharedResponseHandler::is_reusable_response(&response_bytes);
+                        response_bytes.truncate(headers_end_pos + body_len);
                         break 'read_loop;
                     }
                 } else {
-                    let total_expected_length: usize = headers_end_pos + content_length;
+                    let total_expected_length: usize =
+                        headers_end_pos.saturating_add(content_length);
                     if response_bytes.len() >= total_expected_length {
+                        keep_alive = SharedResponseHandler::is_reusable_response(&response_bytes);
                         response_bytes.truncate(total_expected_length);
                         break 'read_loop;
                     }
                 }
             }
         }
+        if response_bytes.is_empty() {
+            return Err(RequestError::ConnectionReset(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "Connection closed before the response headers were received",
+            )));
+        }
+        if headers_done && !is_chunked && !read_until_close {
+            SharedResponseHandler::check_body_complete(
+                &response_bytes[headers_end_pos..],
+                content_length,
+            )?;
+        }
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.keep_alive = keep_alive;
+        }
+        self.record_read_metrics(started_at, first_byte_at, response_bytes.len());
+        let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
         if is_chunked {
             let body_bytes: Vec<u8> = response_bytes[headers_end_pos..].to_vec();
-            let decoded_body: Vec<u8> = SharedResponseHandler::parse_chunked_body(&body_bytes);
+            let (decoded_body, chunked_trailers): (Vec<u8>, ResponseHeaders) =
+                SharedResponseHandler::parse_chunked_message(&body_bytes);
+            trailers = chunked_trailers;
             response_bytes.truncate(headers_end_pos);
             response_bytes.extend_from_slice(&decoded_body);
         }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
+        let mut response: HttpResponseBinary =
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes);
+        response.trailers = Arc::new(RwLock::new(trailers));
+        response.metrics = self.get_request_metrics();
+        self.response = Arc::new(RwLock::new(response));
+        if let Ok(response) = self.response.read() {
+            self.run_interceptors_after(&response);
+            self.record_response_metrics(&response);
+        }
+        if self.is_auth_retry(&response_bytes[..headers_end_pos]) {
+            return Box::pin(self.send_async()).await;
+        }
         let (should_redirect, should_decode, buffer_size) = {
             if let Ok(config) = self.config.read() {
                 (config.redirect, config.decode, config.buffer)
@@ -1201,6 +3702,7 @@ impl HttpRequest {
             return Ok(response);
         }
         let url: String = String::from_utf8(redirect_url.unwrap())
+            .map(|url: String| url.trim().to_string())
             .map_err(|error: FromUtf8Error| RequestError::Request(error.to_string()))?;
         self.handle_redirect_async(url).await
     }
@@ -1219,28 +3721,11 @@ impl HttpRequest {
         url: String,
     ) -> Pin<Box<dyn Future<Output = Result<BoxResponseTrait, RequestError>> + Send + '_>> {
         Box::pin(async move {
-            {
-                if let Ok(mut config) = self.config.write() {
-                    if !config.redirect {
-                        return Err(RequestError::Request("Redirect Not Enabled".to_string()));
-                    }
-                    if let Ok(mut tmp) = self.tmp.clone().write() {
-                        if tmp.visit_url.contains(&url) {
-                            return Err(RequestError::Request(
-                                "Redirect URL Dead Loop".to_string(),
-                            ));
-                        }
-                        tmp.visit_url.insert(url.clone());
-                        if config.redirect_times >= config.max_redirect_times {
-                            return Err(RequestError::Request(
-                                "Max Redirect Times Exceeded".to_string(),
-                            ));
-                        }
-                        config.redirect_times += 1;
-                    }
-                }
-            }
-            self.url(url.clone());
+            let status_code: ResponseStatusCode = self
+                .response
+                .read()
+                .map_or(0, |response| response.get_status_code());
+            self.prepare_redirect(url, status_code)?;
             self.send_async().await
         })
     }
@@ -1255,7 +3740,104 @@ impl HttpRequest {
     /// # Returns
     ///
     /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
-    async fn get_connection_stream_async(
+    pub(crate) async fn get_connection_stream_async(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<BoxAsyncReadWrite, RequestError> {
+        self.set_in_flight_state(InFlightState::Connecting);
+        let metrics_host: String = format!("{host}:{port}");
+        self.open_connection_stream_async(host, port)
+            .await
+            .inspect_err(|_| HostMetrics::record_connection_error(&metrics_host))
+    }
+
+    /// Resolves a host asynchronously through the DNS overrides, the custom resolver,
+    /// or the system resolver.
+    ///
+    /// Lookups never block the runtime threads: a custom resolver that is not
+    /// non-blocking runs on the blocking thread pool, as does the system resolver.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<SocketAddr>, RequestError>` - The resolved addresses, or an error if
+    ///   resolution fails or yields no address.
+    async fn resolve_host_async(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<Vec<SocketAddr>, RequestError> {
+        let overrides: Vec<SocketAddr> = self
+            .config
+            .read()
+            .map(|config: RwLockReadGuard<'_, Config>| config.get_dns_overrides(host))
+            .unwrap_or_default();
+        if !overrides.is_empty() {
+            return Ok(overrides);
+        }
+        let Some(resolver) = &self.resolver else {
+            return SharedConnector::resolve_async(host, port).await;
+        };
+        let addrs: Vec<SocketAddr> = if resolver.0.is_nonblocking() {
+            resolver.0.resolve_async(host, port).await
+        } else {
+            let resolver: ArcResolver = Arc::clone(&resolver.0);
+            let host: String = host.to_owned();
+            spawn_blocking(move || resolver.resolve(&host, port))
+                .await
+                .map_err(|error: JoinError| RequestError::Request(error.to_string()))?
+        }
+        .map_err(|error: std::io::Error| {
+            RequestError::from(error).with_phase(ErrorPhase::Resolve)
+        })?;
+        if addrs.is_empty() {
+            return Err(RequestError::unresolved(format!("{host}:{port}")));
+        }
+        Ok(addrs)
+    }
+
+    /// Connects asynchronously to the first reachable address resolved for a host by
+    /// `resolve_host_async`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    /// - `Duration` - The timeout for resolution and all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<AsyncTcpStream, RequestError>` - The connected stream, or an error.
+    #[cfg(feature = "proxy")]
+    async fn connect_host_async(
+        &self,
+        host: &str,
+        port: u16,
+        timeout_duration: Duration,
+    ) -> Result<AsyncTcpStream, RequestError> {
+        let deadline: Instant = Instant::now() + timeout_duration;
+        let addrs: Vec<SocketAddr> = timeout(timeout_duration, self.resolve_host_async(host, port))
+            .await
+            .map_err(|_| RequestError::timeout(ErrorPhase::Resolve, "DNS resolution timeout"))??;
+        SharedConnector::connect_to_async(addrs, deadline).await
+    }
+
+    /// Opens the connection stream for `get_connection_stream_async`.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxAsyncReadWrite, RequestError>` - The stream, or an error if the connection failed.
+    async fn open_connection_stream_async(
         &self,
         host: String,
         port: u16,
@@ -1264,36 +3846,43 @@ impl HttpRequest {
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
+        #[cfg(feature = "proxy")]
         if let Some(proxy_config) = &config.proxy {
-            return self
+            let started_at: Instant = Instant::now();
+            let stream: BoxAsyncReadWrite = self
                 .get_proxy_connection_stream_async(host, port, proxy_config)
-                .await;
+                .await?;
+            self.update_metrics(|metrics: &mut RequestMetrics| {
+                metrics.connect += started_at.elapsed()
+            });
+            return Ok(stream);
         }
-        let host_port: (String, u16) = (host.clone(), port);
-        let tcp_stream: http_type::tokio::net::TcpStream =
-            http_type::tokio::net::TcpStream::connect(host_port.clone())
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+        let timeout_duration: Duration = Duration::from_millis(config.timeout);
+        let deadline: Instant = Instant::now() + timeout_duration;
+        let addrs: Vec<SocketAddr> = if config.resolved_addrs.is_empty() {
+            let started_at: Instant = Instant::now();
+            let addrs: Vec<SocketAddr> =
+                timeout(timeout_duration, self.resolve_host_async(&host, port))
+                    .await
+                    .map_err(|_| {
+                        RequestError::timeout(ErrorPhase::Resolve, "DNS resolution timeout")
+                    })??;
+            self.update_metrics(|metrics: &mut RequestMetrics| metrics.dns += started_at.elapsed());
+            addrs
+        } else {
+            config.get_resolved_addrs(port)
+        };
+        let started_at: Instant = Instant::now();
+        let tcp_stream: AsyncTcpStream = SharedConnector::connect_to_async(addrs, deadline).await?;
+        self.update_metrics(|metrics: &mut RequestMetrics| metrics.connect += started_at.elapsed());
         if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(error) => {
-                        return Err(RequestError::Request(error.to_string()));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(host.clone())
-                .map_err(|error: InvalidDnsNameError| RequestError::Request(error.to_string()))?;
-            let tls_stream: TlsStream<http_type::tokio::net::TcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-            Ok(Box::new(tls_stream))
+            self.set_in_flight_state(InFlightState::Tls);
+            let started_at: Instant = Instant::now();
+            let tls_stream: BoxAsyncReadWrite = self
+                .connect_tls_async(tcp_stream, config.get_tls_server_name(&host))
+                .await?;
+            self.update_metrics(|metrics: &mut RequestMetrics| metrics.tls += started_at.elapsed());
+            Ok(tls_stream)
         } else {
             Ok(Box::new(tcp_stream))
         }
@@ -1310,6 +3899,7 @@ impl HttpRequest {
     /// # Returns
     ///
     /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    #[cfg(feature = "proxy")]
     async fn get_proxy_connection_stream_async(
         &self,
         target_host: String,
@@ -1317,6 +3907,14 @@ impl HttpRequest {
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
         match proxy_config.proxy_type {
+            ProxyType::Http | ProxyType::Https if self.is_proxy_absolute_form() => {
+                let timeout: Duration = Duration::from_millis(self.config.read().map_or(
+                    DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+                    |config: RwLockReadGuard<'_, Config>| config.timeout,
+                ));
+                self.open_http_proxy_stream_async(proxy_config, timeout)
+                    .await
+            }
             ProxyType::Http | ProxyType::Https => {
                 self.get_http_proxy_connection_async(target_host, target_port, proxy_config)
                     .await
@@ -1328,8 +3926,44 @@ impl HttpRequest {
         }
     }
 
+    /// Opens an async connection to an HTTP/HTTPS proxy, using TLS for HTTPS proxies.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ProxyConfig` - The proxy configuration.
+    /// - `Duration` - The connect timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxAsyncReadWrite, RequestError>` - The stream to the proxy.
+    #[cfg(feature = "proxy")]
+    async fn open_http_proxy_stream_async(
+        &self,
+        proxy_config: &ProxyConfig,
+        timeout: Duration,
+    ) -> Result<BoxAsyncReadWrite, RequestError> {
+        let tcp_stream: AsyncTcpStream = self
+            .connect_host_async(&proxy_config.host, proxy_config.port, timeout)
+            .await
+            .map_err(|error: RequestError| RequestError::proxy(ProxyErrorPhase::Connect, error))?;
+        let proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
+            self.connect_tls_async(tcp_stream, proxy_config.host.clone())
+                .await
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::Connect, error.get_detail())
+                })?
+        } else {
+            Box::new(tcp_stream)
+        };
+        Ok(proxy_stream)
+    }
+
     /// Establishes an async HTTP/HTTPS proxy connection.
     ///
+    /// The CONNECT request is first sent without credentials. If the proxy answers
+    /// 407 with a challenge the configured credentials can answer, the CONNECT is
+    /// retried once on a new connection with a `Proxy-Authorization` header.
+    ///
     /// # Arguments
     ///
     /// - `String` - The target host.
@@ -1339,109 +3973,58 @@ impl HttpRequest {
     /// # Returns
     ///
     /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    #[cfg(feature = "proxy")]
     async fn get_http_proxy_connection_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: http_type::tokio::net::TcpStream =
-            http_type::tokio::net::TcpStream::connect(proxy_host_port)
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(error) => {
-                        return Err(RequestError::Request(error.to_string()));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                .map_err(|error: InvalidDnsNameError| RequestError::Request(error.to_string()))?;
-            let tls_stream: TlsStream<http_type::tokio::net::TcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-            Box::new(tls_stream)
-        } else {
-            Box::new(tcp_stream)
-        };
-        let connect_request: String = if let (Some(username), Some(password)) =
-            (&proxy_config.username, &proxy_config.password)
+        let timeout: Duration = Duration::from_millis(self.config.read().map_or(
+            DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+            |config: RwLockReadGuard<'_, Config>| config.timeout,
+        ));
+        let mut proxy_stream: BoxAsyncReadWrite = self
+            .open_http_proxy_stream_async(proxy_config, timeout)
+            .await?;
+        let pre_read_data: Vec<u8> = match SharedConnector::proxy_connect_async(
+            &mut proxy_stream,
+            &target_host,
+            target_port,
+            None,
+        )
+        .await
         {
-            let auth: String = format!("{username}:{password}");
-            let auth_encoded: String = base64_encode(auth.as_bytes());
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\nProxy-Authorization: Basic {auth_encoded}\r\n\r\n"
-            )
-        } else {
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\n\r\n"
-            )
-        };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        proxy_stream
-            .flush()
-            .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let mut response_buffer: [u8; 1024] = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-        let response_str: &str = std::str::from_utf8(&response_buffer[..bytes_read]).unwrap_or("");
-        let headers_end_pos: Option<usize> = response_str.find("\r\n\r\n");
-        let pre_read_data: Vec<u8> = if let Some(pos) = headers_end_pos {
-            let header_part: &str = &response_str[..pos];
-            if !header_part.starts_with("HTTP/1.1 200") && !header_part.starts_with("HTTP/1.0 200")
-            {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
-            }
-            response_buffer[pos + 4..bytes_read].to_vec()
-        } else {
-            if !response_str.starts_with("HTTP/1.1 200")
-                && !response_str.starts_with("HTTP/1.0 200")
-            {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+            Err(RequestError::ProxyAuthenticationRequired(challenges)) => {
+                let Some(authorization) = proxy_config.get_authorization(&challenges) else {
+                    return Err(RequestError::ProxyAuthenticationRequired(challenges));
+                };
+                proxy_stream = self
+                    .open_http_proxy_stream_async(proxy_config, timeout)
+                    .await?;
+                SharedConnector::proxy_connect_async(
+                    &mut proxy_stream,
+                    &target_host,
+                    target_port,
+                    Some(&authorization),
+                )
+                .await?
             }
-            vec![]
+            result => result?,
         };
         let config: Config = self
             .config
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(error) => {
-                        return Err(RequestError::Request(error.to_string()));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|error: InvalidDnsNameError| RequestError::Request(error.to_string()))?;
             let tunnel_stream: ProxyTunnelStream =
                 ProxyTunnelStream::new(proxy_stream, pre_read_data);
-            let tls_stream: TlsStream<ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
+            return self
+                .connect_tls_async(tunnel_stream, config.get_tls_server_name(&target_host))
                 .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-            return Ok(Box::new(tls_stream) as BoxAsyncReadWrite);
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::TlsToTarget, error.get_detail())
+                });
         }
         let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream, pre_read_data);
         Ok(Box::new(tunnel_stream) as BoxAsyncReadWrite)
@@ -1458,17 +4041,21 @@ impl HttpRequest {
     /// # Returns
     ///
     /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    #[cfg(feature = "proxy")]
     async fn get_socks5_proxy_connection_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: http_type::tokio::net::TcpStream =
-            http_type::tokio::net::TcpStream::connect(proxy_host_port)
-                .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+        let timeout: Duration = Duration::from_millis(self.config.read().map_or(
+            DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+            |config: RwLockReadGuard<'_, Config>| config.timeout,
+        ));
+        let mut tcp_stream: AsyncTcpStream = self
+            .connect_host_async(&proxy_config.host, proxy_config.port, timeout)
+            .await
+            .map_err(|error: RequestError| RequestError::proxy(ProxyErrorPhase::Connect, error))?;
         let auth_methods: Vec<u8> =
             if proxy_config.username.is_some() && proxy_config.password.is_some() {
                 vec![0x05, 0x02, 0x00, 0x02]
@@ -1478,14 +4065,21 @@ impl HttpRequest {
         tcp_stream
             .write_all(&auth_methods)
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::Greeting, error)
+            })?;
         let mut response: [u8; 2] = [0u8; 2];
         tcp_stream
             .read_exact(&mut response)
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::Greeting, error)
+            })?;
         if response[0] != 0x05 {
-            return Err(RequestError::Request("Internal Server Error".to_string()));
+            return Err(RequestError::proxy(
+                ProxyErrorPhase::Greeting,
+                format!("Unexpected SOCKS version {:#04x}", response[0]),
+            ));
         }
         match response[1] {
             0x00 => {}
@@ -1500,26 +4094,38 @@ impl HttpRequest {
                     auth_request.extend_from_slice(password.as_bytes());
 
                     tcp_stream.write_all(&auth_request).await.map_err(
-                        |error: std::io::Error| RequestError::Request(error.to_string()),
+                        |error: std::io::Error| RequestError::proxy(ProxyErrorPhase::Auth, error),
                     )?;
 
                     let mut auth_response = [0u8; 2];
                     tcp_stream.read_exact(&mut auth_response).await.map_err(
-                        |error: std::io::Error| RequestError::Request(error.to_string()),
+                        |error: std::io::Error| RequestError::proxy(ProxyErrorPhase::Auth, error),
                     )?;
 
                     if auth_response[1] != 0x00 {
-                        return Err(RequestError::Request("Internal Server Error".to_string()));
+                        return Err(RequestError::proxy(
+                            ProxyErrorPhase::Auth,
+                            "Proxy rejected the username and password",
+                        ));
                     }
                 } else {
-                    return Err(RequestError::Request("Internal Server Error".to_string()));
+                    return Err(RequestError::proxy(
+                        ProxyErrorPhase::Auth,
+                        "Proxy requires username and password authentication",
+                    ));
                 }
             }
             0xFF => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::Greeting,
+                    "Proxy accepted none of the offered authentication methods",
+                ));
             }
-            _ => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+            method => {
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::Greeting,
+                    format!("Unsupported authentication method {method:#04x}"),
+                ));
             }
         }
         let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
@@ -1540,16 +4146,26 @@ impl HttpRequest {
         tcp_stream
             .write_all(&connect_request)
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
 
         let mut connect_response: [u8; 4] = [0u8; 4];
         tcp_stream
             .read_exact(&mut connect_response)
             .await
-            .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
 
-        if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
-            return Err(RequestError::Request("Internal Server Error".to_string()));
+        if connect_response[0] != 0x05 {
+            return Err(RequestError::proxy(
+                ProxyErrorPhase::ConnectCommand,
+                format!("Unexpected SOCKS version {:#04x}", connect_response[0]),
+            ));
+        }
+        if connect_response[1] != 0x00 {
+            return Err(ProxyError::socks5_reply(connect_response[1]));
         }
         match connect_response[3] {
             0x01 => {
@@ -1557,29 +4173,40 @@ impl HttpRequest {
                 tcp_stream
                     .read_exact(&mut skip)
                     .await
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
             0x03 => {
                 let mut len: [u8; 1] = [0u8; 1];
                 tcp_stream
                     .read_exact(&mut len)
                     .await
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
                 let mut skip: Vec<u8> = vec![0u8; len[0] as usize + 2];
                 tcp_stream
                     .read_exact(&mut skip)
                     .await
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
             0x04 => {
                 let mut skip: [u8; 18] = [0u8; 18];
                 tcp_stream
                     .read_exact(&mut skip)
                     .await
-                    .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
             }
-            _ => {
-                return Err(RequestError::Request("Internal Server Error".to_string()));
+            address_type => {
+                return Err(RequestError::proxy(
+                    ProxyErrorPhase::ConnectCommand,
+                    format!("Unsupported address type {address_type:#04x}"),
+                ));
             }
         }
         let proxy_stream: BoxAsyncReadWrite = Box::new(tcp_stream);
@@ -1588,55 +4215,308 @@ impl HttpRequest {
             .read()
             .map_or(Config::default(), |config| config.clone());
         if Self::get_protocol(&config) == HTTPS_LOWERCASE {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(error) => {
-                        return Err(RequestError::Request(error.to_string()));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|error: InvalidDnsNameError| RequestError::Request(error.to_string()))?;
             let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream, Vec::new());
-            let tls_stream: TlsStream<ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
+            return self
+                .connect_tls_async(tunnel_stream, config.get_tls_server_name(&target_host))
                 .await
-                .map_err(|error: std::io::Error| RequestError::Request(error.to_string()))?;
-            return Ok(Box::new(tls_stream) as BoxAsyncReadWrite);
+                .map_err(|error: RequestError| {
+                    RequestError::proxy(ProxyErrorPhase::TlsToTarget, error.get_detail())
+                });
         }
         Ok(proxy_stream)
     }
 
+    /// Sends the HTTP request asynchronously through its middleware chain.
+    ///
+    /// # Returns
+    ///
+    /// - `MiddlewareFuture<'_>` - A future resolving to the response of the outermost
+    ///   middleware, or of the network request if no middleware is registered.
+    fn send_with_middleware_async(&mut self) -> MiddlewareFuture<'_> {
+        if self.middleware.r#async.is_empty() {
+            return Box::pin(self.send_async());
+        }
+        let middleware: Vec<ArcAsyncMiddleware> = self.middleware.r#async.clone();
+        Box::pin(async move {
+            AsyncNext {
+                middleware: &middleware,
+            }
+            .run(&mut RequestAttempt { request: self })
+            .await
+        })
+    }
+
+    /// Sends the request asynchronously, or waits for an identical request already in
+    /// flight.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The single-flight key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response, shared with every identical concurrent request.
+    async fn send_single_flight_async(&mut self, key: u64) -> RequestResult {
+        match SingleFlightCall::join(key) {
+            SingleFlightRole::Leader(guard) => {
+                let result: RequestResult = self.send_with_middleware_async().await;
+                guard.complete(Self::get_shared_result(&result));
+                result
+            }
+            SingleFlightRole::Follower(call) => {
+                let result: Result<HttpResponseBinary, RequestError> = call.wait_async().await;
+                self.finish_shared_response(result)
+            }
+        }
+    }
+
+    /// Sends the request asynchronously, sharing the call with identical concurrent
+    /// requests if single-flight is enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    async fn send_uncached_async(&mut self) -> RequestResult {
+        match self.get_single_flight_key() {
+            Some(key) => self.send_single_flight_async(key).await,
+            None => self.send_with_middleware_async().await,
+        }
+    }
+
+    /// Sends the request asynchronously through the response cache.
+    ///
+    /// Behaves like `send_cached`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CachePolicy` - The cache policy.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The stored or received response, or an error.
+    async fn send_cached_async(&mut self, policy: &CachePolicy) -> RequestResult {
+        let Some(key) = self.get_readable_cache_key() else {
+            let result: RequestResult = self.send_uncached_async().await;
+            self.invalidate_cached_response(policy, &result);
+            return result;
+        };
+        let cached: Option<CachedResponse> = policy.lookup(&key, &self.get_header());
+        if let Some(cached) = &cached
+            && self.is_cache_hit(cached)
+        {
+            return self.finish_shared_response(Ok(cached.get_response().clone()));
+        }
+        let header: Arc<RequestHeaders> = self.add_cache_validators(cached.as_ref());
+        let result: RequestResult = self.send_uncached_async().await;
+        self.header = header;
+        self.store_cached_response(policy, &key, cached, result)
+    }
+
     /// Sends the HTTP request asynchronously.
     ///
     /// # Returns
     ///
     /// - `RequestResult` - Result of the async request.
     pub(crate) async fn send_async(&mut self) -> RequestResult {
-        let methods: Method = self.get_methods();
-        let (host, port) = {
-            if let Ok(mut config) = self.config.write() {
-                config.url_obj = self
-                    .parse_url()
-                    .map_err(|error: RequestError| RequestError::Request(error.to_string()))?;
-                let host: String = config.url_obj.host.clone().unwrap_or_default();
-                let port = self.get_port(config.url_obj.port.unwrap_or_default(), &config);
-                (host, port)
-            } else {
-                (String::new(), 0u16)
+        if let Some(threshold) = self.get_spool_threshold() {
+            return self.send_spooled_async(threshold).await;
+        }
+        self.get_request_body()?;
+        self.get_http_version_str()?;
+        let (host, port) = self.prepare_target()?;
+        #[cfg(feature = "proxy")]
+        let tunnel_key: Option<TunnelKey> = self.get_tunnel_key(&host, port);
+        #[cfg(feature = "proxy")]
+        if let Some(key) = &tunnel_key
+            && let Some(mut stream) = TunnelPool::take_async(key)
+        {
+            match self.send_on_stream_async(&mut stream).await {
+                Err(error) if self.is_stale_tunnel_retry(&error) => {}
+                res => {
+                    self.release_tunnel_async(tunnel_key, stream, &res);
+                    return res;
+                }
             }
-        };
+        }
         let mut stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request_async(&mut stream).await,
-            m if m.is_post() => self.send_post_request_async(&mut stream).await,
-            _err => Err(RequestError::Request("Method Not Allowed".to_string())),
-        };
+        let res: Result<BoxResponseTrait, RequestError> =
+            self.send_on_stream_async(&mut stream).await;
+        #[cfg(feature = "proxy")]
+        self.release_tunnel_async(tunnel_key, stream, &res);
         res
     }
+
+    /// Sends the request asynchronously and reads the response body into memory or a
+    /// temporary file.
+    ///
+    /// Bodies kept in memory are decoded if decoding is enabled; spooled bodies are
+    /// stored as they were received.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The largest body kept in memory.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    async fn send_spooled_async(&mut self, threshold: usize) -> RequestResult {
+        let (decode, buffer_size): (bool, usize) = self.get_spool_read_config();
+        let mut response: HttpResponseBinary = self
+            .send_stream_async()
+            .await?
+            .into_spooled(threshold, buffer_size)
+            .await?;
+        if decode && !response.is_spooled() {
+            response = response.decode(buffer_size);
+        }
+        response.metrics = self.get_request_metrics();
+        self.response = Arc::new(RwLock::new(response.clone()));
+        Ok(Box::new(response))
+    }
+
+    /// Sends the request over an established async stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The connection stream.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    async fn send_on_stream_async(&mut self, stream: &mut BoxAsyncReadWrite) -> RequestResult {
+        self.run_attempt_hook();
+        self.send_request_async(stream).await
+    }
+
+    /// Returns an async proxy tunnel to the pool if the response left it reusable.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<TunnelKey>` - The pool key, or None if tunnels are not kept alive.
+    /// - `BoxAsyncReadWrite` - The tunnel stream.
+    /// - `&RequestResult` - The result of the request sent over the tunnel.
+    #[cfg(feature = "proxy")]
+    fn release_tunnel_async(
+        &self,
+        tunnel_key: Option<TunnelKey>,
+        stream: BoxAsyncReadWrite,
+        res: &RequestResult,
+    ) {
+        if self.take_keep_alive()
+            && res.is_ok()
+            && let Some(key) = tunnel_key
+        {
+            TunnelPool::release_async(key, stream);
+        }
+    }
+
+    /// Reads the status line and headers of an async response, skipping interim 1xx responses.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The async stream to read from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Vec<u8>), RequestError>` - The header bytes and any body bytes read past them.
+    async fn read_response_head_async(
+        &self,
+        stream: &mut BoxAsyncReadWrite,
+    ) -> Result<(Vec<u8>, Vec<u8>), RequestError> {
+        let buffer_size: usize = self
+            .config
+            .read()
+            .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+        let strict_parsing: bool = self.config.read().is_ok_and(|config| config.strict_parsing);
+        let mut buffer: Vec<u8> = vec![0; buffer_size];
+        let mut response_bytes: Vec<u8> = Vec::with_capacity(buffer_size.max(8192));
+        self.set_in_flight_state(InFlightState::Waiting);
+        let started_at: Instant = Instant::now();
+        let mut first_byte_at: Option<Instant> = None;
+        let mut bytes_received: usize = 0;
+        loop {
+            self.config
+                .read()
+                .map_or(Ok(()), |config| config.check_header_limits(&response_bytes))?;
+            if let Some(pos) = SharedResponseHandler::find_double_crlf(&response_bytes, 0) {
+                let leftover: Vec<u8> = response_bytes.split_off(pos + 4);
+                let status_code: usize = SharedResponseHandler::get_status_code(&response_bytes);
+                if (100..=199).contains(&status_code) && status_code != 101 {
+                    response_bytes = leftover;
+                    continue;
+                }
+                if strict_parsing {
+                    SharedResponseHandler::check_strict_headers(&response_bytes)?;
+                }
+                self.record_read_metrics(started_at, first_byte_at, bytes_received);
+                return Ok((response_bytes, leftover));
+            }
+            let bytes_read: usize = stream.read(&mut buffer).await.map_err(RequestError::from)?;
+            if bytes_read == 0 {
+                return Err(RequestError::ConnectionReset(IoError::new(
+                    ErrorKind::UnexpectedEof,
+                    "Connection closed before the response headers were received",
+                )));
+            }
+            if first_byte_at.is_none() {
+                first_byte_at = Some(Instant::now());
+                self.set_in_flight_state(InFlightState::Reading);
+            }
+            bytes_received += bytes_read;
+            response_bytes.extend_from_slice(&buffer[..bytes_read]);
+        }
+    }
+
+    /// Sends the HTTP request asynchronously and returns once the response headers arrive.
+    ///
+    /// The body is left on the connection and read through the returned stream.
+    /// Redirects are followed according to the redirect settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>` - Future resolving to the streaming response or an error.
+    pub(crate) fn send_stream_async(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let (host, port) = self.prepare_target()?;
+            self.run_attempt_hook();
+            let request: Vec<u8> = self.get_request_bytes(false)?;
+            let mut stream: BoxAsyncReadWrite =
+                self.get_connection_stream_async(host, port).await?;
+            self.write_request_async(&mut stream, &request).await?;
+            self.write_body_reader_async(&mut stream).await?;
+            stream.flush().await.map_err(RequestError::from)?;
+            let (headers_bytes, leftover) = self.read_response_head_async(&mut stream).await?;
+            let mut head: HttpResponseBinary =
+                <HttpResponseBinary as ResponseTrait>::from(&headers_bytes);
+            self.run_interceptors_after(&head);
+            self.record_response_metrics(&head);
+            if self.is_auth_retry(&headers_bytes) {
+                return self.send_stream_async().await;
+            }
+            if let Some(url) = self.get_redirect_url(&headers_bytes)? {
+                self.prepare_redirect(url, head.get_status_code())?;
+                return self.send_stream_async().await;
+            }
+            let buffer_size: usize = self
+                .config
+                .read()
+                .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
+            let max_body: Option<usize> = self
+                .config
+                .read()
+                .ok()
+                .and_then(|config| config.max_response_body);
+            let decoder: BodyDecoder =
+                BodyDecoder::new(&headers_bytes, self.get_methods().is_head())
+                    .with_max_body(max_body)?;
+            head.metrics = self.get_request_metrics();
+            Ok(AsyncHttpResponseStream {
+                head,
+                body: AsyncBodyReader::new(stream, decoder, &leftover, buffer_size)?,
+            })
+        })
+    }
 }
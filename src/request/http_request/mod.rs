@@ -1,4 +1,5 @@
 mod r#const;
+mod r#enum;
 mod r#impl;
 mod r#struct;
 mod r#trait;
@@ -6,7 +7,7 @@ mod r#type;
 
 use http_type::HTTPS_LOWERCASE;
 
-pub use {r#trait::*, r#type::*};
+pub use {r#enum::*, r#trait::*, r#type::*};
 
 pub(crate) use {r#const::*, r#struct::*};
 
@@ -18,4 +18,30 @@ pub(crate) struct HttpRequest {
     pub(crate) tmp: ArcRwLock<Tmp>,
     /// Response storage for the request.
     pub(crate) response: ArcRwLock<HttpResponseBinary>,
+    /// Hook invoked before every network attempt.
+    pub(crate) on_attempt: Option<AttemptHook>,
+    /// Hook deciding whether each redirect is followed.
+    pub(crate) on_redirect: Option<RedirectHook>,
+    /// Handler answering the challenges of 401 responses.
+    pub(crate) auth_handler: Option<AuthHandlerHook>,
+    /// Hook reporting the request body bytes written.
+    pub(crate) on_upload_progress: Option<ProgressHook>,
+    /// Hook reporting the response body bytes read.
+    pub(crate) on_download_progress: Option<ProgressHook>,
+    /// Hook receiving the metrics of each completed request.
+    pub(crate) on_metrics: Option<MetricsHook>,
+    /// Resolver used instead of the system resolver.
+    pub(crate) resolver: Option<ResolverHook>,
+    /// Signer adding credentials once the request bytes are final.
+    pub(crate) signer: Option<SignerHook>,
+    /// Cache answering and storing the responses of the request.
+    pub(crate) cache: Option<CachePolicy>,
+    /// Token aborting asynchronous sends of the request.
+    #[cfg(feature = "async")]
+    pub(crate) cancellation: Option<CancellationToken>,
+    /// Middleware wrapping the request.
+    pub(crate) middleware: MiddlewareStack,
+    /// Trace context whose child spans are propagated with every attempt.
+    #[cfg(feature = "trace-context")]
+    pub(crate) trace_context: Option<TraceContext>,
 }
@@ -3,17 +3,30 @@ use super::*;
 /// Combines AsyncRead and AsyncWrite traits with Unpin and Send bounds.
 ///
 /// Provides a unified trait for asynchronous read/write operations.
+#[cfg(feature = "async")]
 pub(crate) trait AsyncReadWrite: AsyncRead + AsyncWrite + Unpin + Send {}
 
 /// Combines Read and Write traits.
 ///
 /// Provides a unified trait for synchronous read/write operations.
-pub(crate) trait ReadWrite: Read + Write {}
+pub(crate) trait ReadWrite: Read + Write {
+    /// Sets the read timeout of the underlying socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The timeout, or None to block indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - An error if the timeout could not be set.
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()>;
+}
 
 /// Asynchronous HTTP request trait.
 ///
 /// Defines the interface for sending asynchronous HTTP requests.
-pub trait AsyncRequestTrait: Send + Debug {
+#[cfg(feature = "async")]
+pub trait AsyncRequestTrait: Sealed + Send + Debug {
     /// The result type of the asynchronous request.
     type RequestResult: Sized;
 
@@ -24,12 +37,51 @@ pub trait AsyncRequestTrait: Send + Debug {
     /// - `Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>>` -
     ///   A pinned boxed future representing the asynchronous operation.
     fn send(&mut self) -> Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>>;
+
+    /// Sends the HTTP request asynchronously and returns once the response headers arrive.
+    ///
+    /// The body is not buffered; it is read incrementally through the returned stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>` -
+    ///   A pinned boxed future resolving to the streaming response.
+    fn send_stream(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<AsyncHttpResponseStream, RequestError>> + Send + '_>>;
+
+    /// Describes the settings the request is sent with as a JSON object.
+    ///
+    /// The snapshot covers the method, URL, and header names, the timeouts, proxy,
+    /// TLS, redirect, and limit settings, and which hooks are installed. Header
+    /// values are left out and proxy passwords are redacted, so the snapshot can be
+    /// logged when diagnosing configuration problems.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    fn effective_config(&self) -> Value;
+
+    /// Serializes the request exactly as it would be written to the connection, without sending it.
+    ///
+    /// The request line, headers, and buffered body are built the way a send builds
+    /// them, after interceptors and the attempt hook ran on a copy of the request,
+    /// so signatures and authentication headers can be checked against the wire
+    /// format. Middleware does not run, and a body streamed from a reader is left out.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The raw request bytes, or an error if the request is invalid.
+    fn to_raw_bytes(&self) -> Result<Vec<u8>, RequestError>;
 }
 
 /// Synchronous HTTP request trait.
 ///
-/// Defines the interface for sending synchronous HTTP requests.
-pub trait RequestTrait: Send + Debug {
+/// Defines the interface for sending synchronous HTTP requests. Requests are sent
+/// on blocking sockets without a Tokio runtime, so the trait is also available in
+/// blocking builds without the `async` feature.
+pub trait RequestTrait: Sealed + Send + Debug {
     /// The result type of the synchronous request.
     type RequestResult: Sized;
 
@@ -39,4 +91,38 @@ pub trait RequestTrait: Send + Debug {
     ///
     /// - `Self::RequestResult` - The result of the synchronous request.
     fn send(&mut self) -> Self::RequestResult;
+
+    /// Sends the HTTP request synchronously and returns once the response headers arrive.
+    ///
+    /// The body is not buffered; it is read incrementally through the returned stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseStream, RequestError>` - The streaming response.
+    fn send_stream(&mut self) -> Result<HttpResponseStream, RequestError>;
+
+    /// Describes the settings the request is sent with as a JSON object.
+    ///
+    /// The snapshot covers the method, URL, and header names, the timeouts, proxy,
+    /// TLS, redirect, and limit settings, and which hooks are installed. Header
+    /// values are left out and proxy passwords are redacted, so the snapshot can be
+    /// logged when diagnosing configuration problems.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    fn effective_config(&self) -> Value;
+
+    /// Serializes the request exactly as it would be written to the connection, without sending it.
+    ///
+    /// The request line, headers, and buffered body are built the way a send builds
+    /// them, after interceptors and the attempt hook ran on a copy of the request,
+    /// so signatures and authentication headers can be checked against the wire
+    /// format. Middleware does not run, and a body streamed from a reader is left out.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The raw request bytes, or an error if the request is invalid.
+    fn to_raw_bytes(&self) -> Result<Vec<u8>, RequestError>;
 }
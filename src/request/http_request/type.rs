@@ -4,12 +4,14 @@ use super::*;
 pub type RequestResult = Result<BoxResponseTrait, RequestError>;
 
 /// Boxed trait object for asynchronous HTTP requests.
+#[cfg(feature = "async")]
 pub type BoxAsyncRequestTrait = Box<dyn AsyncRequestTrait<RequestResult = RequestResult>>;
 
 /// Boxed trait object for synchronous HTTP requests.
 pub type BoxRequestTrait = Box<dyn RequestTrait<RequestResult = RequestResult>>;
 
 /// Boxed trait object for asynchronous read/write streams.
+#[cfg(feature = "async")]
 pub(crate) type BoxAsyncReadWrite = Box<dyn AsyncReadWrite>;
 
 /// Boxed trait object for synchronous read/write streams.
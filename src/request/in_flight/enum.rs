@@ -0,0 +1,14 @@
+/// The phase a request in flight is in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InFlightState {
+    /// Resolving the host and establishing the connection.
+    Connecting,
+    /// Performing the TLS handshake.
+    Tls,
+    /// Writing the request line, headers, and body.
+    Sending,
+    /// Waiting for the first byte of the response.
+    Waiting,
+    /// Reading the response.
+    Reading,
+}
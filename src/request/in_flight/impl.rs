@@ -0,0 +1,157 @@
+use super::*;
+
+impl Display for InFlightState {
+    /// Formats the state as a lowercase name, such as `waiting`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - Result of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            Self::Connecting => "connecting",
+            Self::Tls => "tls",
+            Self::Sending => "sending",
+            Self::Waiting => "waiting",
+            Self::Reading => "reading",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl InFlightRequest {
+    /// Adds a request to the registry in the connecting state.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The request method.
+    /// - `String` - The URL being requested.
+    ///
+    /// # Returns
+    ///
+    /// - `InFlightGuard` - The registration, removing the request when dropped.
+    pub(crate) fn register(method: String, url: String) -> InFlightGuard {
+        let id: u64 = IN_FLIGHT_ID.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut requests) = IN_FLIGHT.lock() {
+            requests.insert(
+                id,
+                Self {
+                    id,
+                    method,
+                    url,
+                    started_at: Instant::now(),
+                    state: InFlightState::Connecting,
+                },
+            );
+        }
+        InFlightGuard(id)
+    }
+
+    /// Moves a registered request to another phase.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The registration id.
+    /// - `InFlightState` - The new phase.
+    /// - `Option<String>` - The URL now being requested, if it changed.
+    pub(crate) fn update(id: u64, state: InFlightState, url: Option<String>) {
+        if let Ok(mut requests) = IN_FLIGHT.lock()
+            && let Some(request) = requests.get_mut(&id)
+        {
+            request.state = state;
+            if let Some(url) = url {
+                request.url = url;
+            }
+        }
+    }
+
+    /// Copies every request currently in flight.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<InFlightRequest>` - The requests, oldest first.
+    pub(crate) fn capture() -> Vec<Self> {
+        let mut requests: Vec<Self> = IN_FLIGHT
+            .lock()
+            .map_or(Vec::new(), |requests| requests.values().cloned().collect());
+        requests.sort_by_key(|request: &Self| request.id);
+        requests
+    }
+
+    /// Gets the registration id of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The id, increasing in the order requests were started.
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gets the request method.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The method, such as `GET`.
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+
+    /// Gets the URL being requested.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The URL, the latest one if redirects were followed.
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// Gets the time since the request was started.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The elapsed time, measured when called.
+    pub fn get_elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Gets the phase the request was in when the snapshot was taken.
+    ///
+    /// # Returns
+    ///
+    /// - `InFlightState` - The phase.
+    pub fn get_state(&self) -> InFlightState {
+        self.state
+    }
+}
+
+impl Drop for InFlightGuard {
+    /// Removes the request from the registry.
+    fn drop(&mut self) {
+        if let Ok(mut requests) = IN_FLIGHT.lock() {
+            requests.remove(&self.0);
+        }
+    }
+}
+
+/// Maps the phase of a request in flight to the phase reported on its errors.
+///
+/// # Arguments
+///
+/// - `InFlightState` - The phase of the request.
+///
+/// # Returns
+///
+/// - `ErrorPhase` - The matching error phase.
+impl From<InFlightState> for ErrorPhase {
+    fn from(state: InFlightState) -> Self {
+        match state {
+            InFlightState::Connecting => Self::Connect,
+            InFlightState::Tls => Self::Tls,
+            InFlightState::Sending => Self::Write,
+            InFlightState::Waiting | InFlightState::Reading => Self::Read,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod r#enum;
+mod r#impl;
+mod r#static;
+mod r#struct;
+
+pub use {r#enum::*, r#struct::*};
+
+pub(crate) use r#static::*;
+
+use super::*;
@@ -0,0 +1,8 @@
+use super::*;
+
+/// Requests currently being sent, keyed by their registration id.
+pub(crate) static IN_FLIGHT: LazyLock<StdMutex<HashMapXxHash3_64<u64, InFlightRequest>>> =
+    LazyLock::new(|| StdMutex::new(hash_map_xx_hash3_64()));
+
+/// Source of the ids given to requests in flight.
+pub(crate) static IN_FLIGHT_ID: AtomicU64 = AtomicU64::new(0);
@@ -0,0 +1,20 @@
+use super::*;
+
+/// A request being sent, as seen at the time of a snapshot.
+#[derive(Clone, Debug)]
+pub struct InFlightRequest {
+    /// Registration id, increasing in the order requests were started.
+    pub(crate) id: u64,
+    /// The request method.
+    pub(crate) method: String,
+    /// The URL being requested, updated when a redirect is followed.
+    pub(crate) url: String,
+    /// When the request was started.
+    pub(crate) started_at: Instant,
+    /// The phase the request is in.
+    pub(crate) state: InFlightState,
+}
+
+/// Registration of a request in flight, removed from the registry on drop.
+#[derive(Debug)]
+pub(crate) struct InFlightGuard(pub(crate) u64);
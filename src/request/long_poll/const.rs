@@ -0,0 +1,2 @@
+/// Default delay in milliseconds before polling again after a transient error.
+pub(crate) const DEFAULT_LONG_POLL_RETRY_DELAY_MS: u64 = 1000;
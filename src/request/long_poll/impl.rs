@@ -0,0 +1,219 @@
+use super::*;
+
+impl LongPoll {
+    /// Creates a long poll sending the template request.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpRequest` - The template request.
+    /// - `&str` - The name of the query parameter carrying the resume token.
+    /// - `BoxResumeToken` - The closure extracting the resume token from an event response.
+    ///
+    /// # Returns
+    ///
+    /// - `LongPoll` - A long poll that has not sent any request yet.
+    pub(crate) fn new(
+        http_request: HttpRequest,
+        token_param: &str,
+        resume_token: BoxResumeToken,
+    ) -> Self {
+        Self {
+            http_request,
+            resume_token,
+            token_param: token_param.to_owned(),
+            token: None,
+            retry_delay: Duration::from_millis(DEFAULT_LONG_POLL_RETRY_DELAY_MS),
+            retrying: false,
+            finished: false,
+            polls: 0,
+            empty_polls: 0,
+        }
+    }
+
+    /// Sets the resume token sent with the first poll.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The resume token, typically saved from an earlier long poll.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut LongPoll` - The long poll for method chaining.
+    pub fn resume_from(&mut self, token: &str) -> &mut Self {
+        self.token = Some(token.to_owned());
+        self
+    }
+
+    /// Sets the delay before polling again after a transient error.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The retry delay in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut LongPoll` - The long poll for method chaining.
+    pub fn retry_delay(&mut self, retry_delay: u64) -> &mut Self {
+        self.retry_delay = Duration::from_millis(retry_delay);
+        self
+    }
+
+    /// Gets the resume token sent with the next poll.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The resume token, or None if no event has carried one yet.
+    pub fn get_token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Gets the number of polls sent so far.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of polls, including empty and failed ones.
+    pub fn get_polls(&self) -> usize {
+        self.polls
+    }
+
+    /// Gets the number of polls that were empty.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of polls that timed out or were answered with 204 No Content.
+    pub fn get_empty_polls(&self) -> usize {
+        self.empty_polls
+    }
+
+    /// Builds the request for a poll.
+    ///
+    /// The request uses the template with its own copy of the configuration and
+    /// processing state, and carries the current resume token as a query parameter.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpRequest` - The request for the poll.
+    fn build_request(&self) -> HttpRequest {
+        let mut config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if let Some(token) = &self.token {
+            config
+                .query
+                .retain(|(key, _): &(String, String)| *key != self.token_param);
+            config.query.push((self.token_param.clone(), token.clone()));
+        }
+        let mut http_request: HttpRequest = self.http_request.clone();
+        http_request.config = Arc::new(RwLock::new(config));
+        http_request.tmp = Arc::new(RwLock::new(Tmp::default()));
+        http_request.response = Arc::new(RwLock::new(HttpResponseBinary::default()));
+        http_request
+    }
+
+    /// Records the outcome of a poll.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestResult` - The result of the poll.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<HttpResponseBinary, RequestError>>` - The event response or the
+    ///   error, or None if the poll was empty.
+    fn finish_poll(
+        &mut self,
+        res: RequestResult,
+    ) -> Option<Result<HttpResponseBinary, RequestError>> {
+        self.polls += 1;
+        let response: HttpResponseBinary = match res {
+            Ok(response) => response.binary(),
+            Err(RequestError::Timeout(_)) => {
+                self.empty_polls += 1;
+                return None;
+            }
+            Err(error) => {
+                self.retrying = error.is_transient();
+                self.finished = !self.retrying;
+                return Some(Err(error));
+            }
+        };
+        if response.get_status_code() == 204 {
+            self.empty_polls += 1;
+            return None;
+        }
+        if let Some(token) = (self.resume_token)(&response) {
+            self.token = Some(token);
+        }
+        Some(Ok(response))
+    }
+
+    /// Turns the long poll into a stream polling asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<HttpResponseBinary, RequestError>>` - A stream of the
+    ///   event responses and errors, ending after the first permanent error.
+    #[cfg(feature = "async")]
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<HttpResponseBinary, RequestError>> + Send {
+        unfold(self, |mut long_poll: LongPoll| async move {
+            loop {
+                if long_poll.finished {
+                    return None;
+                }
+                if long_poll.retrying {
+                    long_poll.retrying = false;
+                    sleep(long_poll.retry_delay).await;
+                }
+                let mut http_request: HttpRequest = long_poll.build_request();
+                let res: RequestResult = AsyncRequestTrait::send(&mut http_request).await;
+                if let Some(item) = long_poll.finish_poll(res) {
+                    return Some((item, long_poll));
+                }
+            }
+        })
+    }
+}
+
+impl Iterator for LongPoll {
+    type Item = Result<HttpResponseBinary, RequestError>;
+
+    /// Polls until an event response or an error arrives.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<HttpResponseBinary, RequestError>>` - The event response or error,
+    ///   or None once polling has ended.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.finished {
+                return None;
+            }
+            if self.retrying {
+                self.retrying = false;
+                thread::sleep(self.retry_delay);
+            }
+            let mut http_request: HttpRequest = self.build_request();
+            let res: RequestResult = RequestTrait::send(&mut http_request);
+            if let Some(item) = self.finish_poll(res) {
+                return Some(item);
+            }
+        }
+    }
+}
+
+impl Debug for LongPoll {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LongPoll")
+            .field("http_request", &self.http_request)
+            .field("token_param", &self.token_param)
+            .field("token", &self.token)
+            .field("retry_delay", &self.retry_delay)
+            .field("polls", &self.polls)
+            .field("empty_polls", &self.empty_polls)
+            .finish_non_exhaustive()
+    }
+}
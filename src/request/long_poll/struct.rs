@@ -0,0 +1,30 @@
+use super::*;
+
+/// Repeatedly sends a long-polling request, yielding the responses carrying events.
+///
+/// A poll that times out or is answered with 204 No Content is empty and is sent
+/// again right away. After every event response a closure extracts the resume
+/// token, which later polls send as a query parameter so the server continues
+/// where the previous poll ended. Transient errors are yielded and the poll
+/// resumes after the retry delay; other errors end it. Iterate it for blocking
+/// sends, or turn it into a stream with `into_stream`.
+pub struct LongPoll {
+    /// Template request providing the URL, headers, and configuration.
+    pub(crate) http_request: HttpRequest,
+    /// Closure extracting the resume token from an event response.
+    pub(crate) resume_token: BoxResumeToken,
+    /// Name of the query parameter carrying the resume token.
+    pub(crate) token_param: String,
+    /// Resume token sent with the next poll, if any.
+    pub(crate) token: Option<String>,
+    /// Delay before polling again after a transient error.
+    pub(crate) retry_delay: Duration,
+    /// Whether the next poll waits for the retry delay.
+    pub(crate) retrying: bool,
+    /// Whether polling has ended after a permanent error.
+    pub(crate) finished: bool,
+    /// Number of polls sent so far.
+    pub(crate) polls: usize,
+    /// Number of polls that were empty.
+    pub(crate) empty_polls: usize,
+}
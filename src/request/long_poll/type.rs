@@ -0,0 +1,4 @@
+use super::*;
+
+/// Closure extracting the resume token from an event response.
+pub(crate) type BoxResumeToken = Box<dyn FnMut(&HttpResponseBinary) -> Option<String> + Send>;
@@ -0,0 +1,5 @@
+/// Name of the Prometheus counter of responses per host and status class.
+pub(crate) const METRICS_RESPONSES_NAME: &str = "http_request_responses_total";
+
+/// Name of the Prometheus counter of failed connections per host.
+pub(crate) const METRICS_CONNECTION_ERRORS_NAME: &str = "http_request_connection_errors_total";
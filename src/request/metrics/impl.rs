@@ -0,0 +1,288 @@
+use super::*;
+
+impl HostMetrics {
+    /// Counts a response of a host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host, as `host:port`.
+    /// - `ResponseStatusCode` - The status code of the response.
+    pub(crate) fn record_status(host: &str, status_code: ResponseStatusCode) {
+        Self::update(host, |metrics: &mut Self| match status_code {
+            100..=199 => metrics.informational += 1,
+            200..=299 => metrics.success += 1,
+            300..=399 => metrics.redirection += 1,
+            400..=499 => metrics.client_error += 1,
+            500..=599 => metrics.server_error += 1,
+            _ => {}
+        });
+    }
+
+    /// Counts a connection to a host that could not be established.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host, as `host:port`.
+    pub(crate) fn record_connection_error(host: &str) {
+        Self::update(host, |metrics: &mut Self| metrics.connection_errors += 1);
+    }
+
+    /// Updates the counters of a host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host, as `host:port`.
+    /// - `F` - A closure modifying the counters.
+    fn update<F: FnOnce(&mut Self)>(host: &str, update: F) {
+        if let Ok(mut metrics) = HOST_METRICS.lock() {
+            update(metrics.entry(host.to_ascii_lowercase()).or_default());
+        }
+    }
+
+    /// Gets the number of 1xx responses returned as final responses.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of informational responses.
+    pub fn get_informational(&self) -> u64 {
+        self.informational
+    }
+
+    /// Gets the number of 2xx responses.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of successful responses.
+    pub fn get_success(&self) -> u64 {
+        self.success
+    }
+
+    /// Gets the number of 3xx responses, including followed redirects.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of redirection responses.
+    pub fn get_redirection(&self) -> u64 {
+        self.redirection
+    }
+
+    /// Gets the number of 4xx responses.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of client error responses.
+    pub fn get_client_error(&self) -> u64 {
+        self.client_error
+    }
+
+    /// Gets the number of 5xx responses.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of server error responses.
+    pub fn get_server_error(&self) -> u64 {
+        self.server_error
+    }
+
+    /// Gets the number of connections that could not be established.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of connection errors.
+    pub fn get_connection_errors(&self) -> u64 {
+        self.connection_errors
+    }
+
+    /// Gets the response counters labeled with their status class.
+    ///
+    /// # Returns
+    ///
+    /// - `[(&str, u64); 5]` - The `1xx` to `5xx` counters.
+    fn get_status_classes(&self) -> [(&'static str, u64); 5] {
+        [
+            ("1xx", self.informational),
+            ("2xx", self.success),
+            ("3xx", self.redirection),
+            ("4xx", self.client_error),
+            ("5xx", self.server_error),
+        ]
+    }
+}
+
+impl MetricsSnapshot {
+    /// Copies the current counters of every host.
+    ///
+    /// # Returns
+    ///
+    /// - `MetricsSnapshot` - The snapshot, with hosts sorted by name.
+    pub(crate) fn capture() -> Self {
+        let mut hosts: Vec<(String, HostMetrics)> =
+            HOST_METRICS.lock().map_or(Vec::new(), |metrics| {
+                metrics
+                    .iter()
+                    .map(|(host, host_metrics)| (host.clone(), *host_metrics))
+                    .collect()
+            });
+        hosts.sort_by(|(left, _), (right, _)| left.cmp(right));
+        Self { hosts }
+    }
+
+    /// Gets the counters of every host.
+    ///
+    /// # Returns
+    ///
+    /// - `&[(String, HostMetrics)]` - The hosts, as `host:port`, with their counters.
+    pub fn get_hosts(&self) -> &[(String, HostMetrics)] {
+        &self.hosts
+    }
+
+    /// Gets the counters of a host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host, as `host:port`, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<HostMetrics>` - The counters, or None if the host was not contacted.
+    pub fn get_host(&self, host: &str) -> Option<HostMetrics> {
+        self.hosts
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(host))
+            .map(|(_, metrics)| *metrics)
+    }
+
+    /// Renders the counters in the Prometheus text exposition format.
+    ///
+    /// Responses are exported as `http_request_responses_total` labeled by `host`
+    /// and `class`, and connection errors as `http_request_connection_errors_total`
+    /// labeled by `host`.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The exposition text.
+    pub fn to_prometheus(&self) -> String {
+        let mut output: String = format!(
+            "# HELP {METRICS_RESPONSES_NAME} Responses received, by host and status class.\n# TYPE {METRICS_RESPONSES_NAME} counter\n"
+        );
+        for (host, metrics) in &self.hosts {
+            let host: String = host.replace('\\', "\\\\").replace('"', "\\\"");
+            for (class, count) in metrics.get_status_classes() {
+                output.push_str(&format!(
+                    "{METRICS_RESPONSES_NAME}{{host=\"{host}\",class=\"{class}\"}} {count}\n"
+                ));
+            }
+        }
+        output.push_str(&format!(
+            "# HELP {METRICS_CONNECTION_ERRORS_NAME} Connections that could not be established, by host.\n# TYPE {METRICS_CONNECTION_ERRORS_NAME} counter\n"
+        ));
+        for (host, metrics) in &self.hosts {
+            let host: String = host.replace('\\', "\\\\").replace('"', "\\\"");
+            output.push_str(&format!(
+                "{METRICS_CONNECTION_ERRORS_NAME}{{host=\"{host}\"}} {}\n",
+                metrics.connection_errors
+            ));
+        }
+        output
+    }
+}
+
+impl RequestMetrics {
+    /// Gets the time spent resolving host names.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The DNS resolution time, zero for IP addresses and cached or pinned hosts.
+    pub fn get_dns(&self) -> Duration {
+        self.dns
+    }
+
+    /// Gets the time spent establishing TCP connections.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The connect time.
+    pub fn get_connect(&self) -> Duration {
+        self.connect
+    }
+
+    /// Gets the time spent in TLS handshakes.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The TLS handshake time, zero for plain HTTP connections.
+    pub fn get_tls(&self) -> Duration {
+        self.tls
+    }
+
+    /// Gets the time from the request being written to the first response byte.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The time to first byte.
+    pub fn get_ttfb(&self) -> Duration {
+        self.ttfb
+    }
+
+    /// Gets the time from the first response byte to the end of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The body transfer time, zero for streamed responses.
+    pub fn get_transfer(&self) -> Duration {
+        self.transfer
+    }
+
+    /// Gets the time from the start of the request to the end of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The total time.
+    pub fn get_total(&self) -> Duration {
+        self.total
+    }
+
+    /// Gets the number of bytes written to the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The bytes sent, including request lines and headers.
+    pub fn get_bytes_sent(&self) -> usize {
+        self.bytes_sent
+    }
+
+    /// Gets the number of bytes read from the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The bytes received, including status lines and headers.
+    pub fn get_bytes_received(&self) -> usize {
+        self.bytes_received
+    }
+
+    /// Gets the number of redirects followed.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The redirect count.
+    pub fn get_redirects(&self) -> usize {
+        self.redirects
+    }
+}
+
+impl MetricsHook {
+    /// Reports the metrics of a completed request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestMetrics` - The metrics of the request.
+    pub(crate) fn report(&self, metrics: &RequestMetrics) {
+        (self.0)(metrics);
+    }
+}
+
+impl Debug for MetricsHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsHook")
+    }
+}
@@ -0,0 +1,11 @@
+mod r#const;
+mod r#impl;
+mod r#static;
+mod r#struct;
+mod r#type;
+
+pub use r#struct::*;
+
+pub(crate) use {r#const::*, r#static::*, r#type::*};
+
+use super::*;
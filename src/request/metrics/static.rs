@@ -0,0 +1,5 @@
+use super::*;
+
+/// Response and connection error counters, keyed by `host:port`.
+pub(crate) static HOST_METRICS: LazyLock<StdMutex<HashMapXxHash3_64<String, HostMetrics>>> =
+    LazyLock::new(|| StdMutex::new(hash_map_xx_hash3_64()));
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Response counters of one host, by status class.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HostMetrics {
+    /// Number of 1xx responses returned as final responses.
+    pub(crate) informational: u64,
+    /// Number of 2xx responses.
+    pub(crate) success: u64,
+    /// Number of 3xx responses, including followed redirects.
+    pub(crate) redirection: u64,
+    /// Number of 4xx responses.
+    pub(crate) client_error: u64,
+    /// Number of 5xx responses.
+    pub(crate) server_error: u64,
+    /// Number of connections that could not be established.
+    pub(crate) connection_errors: u64,
+}
+
+/// Point-in-time copy of the response counters of every host contacted.
+///
+/// Counters are process-wide and accumulate from the start of the process.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MetricsSnapshot {
+    /// The counters of each host, sorted by `host:port`.
+    pub(crate) hosts: Vec<(String, HostMetrics)>,
+}
+
+/// Timings and transfer sizes of one request.
+///
+/// Connection phases add up over every connection opened for the request,
+/// including those for redirects. A phase that did not take place, such as TLS
+/// on a plain HTTP connection, stays zero, and a connection through a proxy is
+/// counted as connect time as a whole.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RequestMetrics {
+    /// Time spent resolving host names.
+    pub(crate) dns: Duration,
+    /// Time spent establishing TCP connections.
+    pub(crate) connect: Duration,
+    /// Time spent in TLS handshakes.
+    pub(crate) tls: Duration,
+    /// Time from the request being written to the first response byte.
+    pub(crate) ttfb: Duration,
+    /// Time from the first response byte to the end of the response.
+    pub(crate) transfer: Duration,
+    /// Time from the start of the request to the end of the response.
+    pub(crate) total: Duration,
+    /// Number of bytes written, including request lines and headers.
+    pub(crate) bytes_sent: usize,
+    /// Number of bytes read, including status lines and headers.
+    pub(crate) bytes_received: usize,
+    /// Number of redirects followed.
+    pub(crate) redirects: usize,
+}
+
+/// Hook receiving the metrics of each completed request.
+#[derive(Clone)]
+pub(crate) struct MetricsHook(pub(crate) MetricsHookFn);
@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared callback receiving the metrics of a completed request.
+pub(crate) type MetricsHookFn = Arc<dyn Fn(&RequestMetrics) + Send + Sync>;
@@ -0,0 +1,61 @@
+use super::*;
+
+impl Next<'_> {
+    /// Runs the rest of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestAttempt<'_>` - The request to pass on.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response of the next middleware, or of the network
+    ///   request once the chain is exhausted.
+    pub fn run(self, request: &mut RequestAttempt<'_>) -> RequestResult {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Next { middleware: rest }),
+            None => request.request.send_sync(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a> AsyncNext<'a> {
+    /// Runs the rest of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestAttempt<'_>` - The request to pass on.
+    ///
+    /// # Returns
+    ///
+    /// - `MiddlewareFuture<'b>` - A future resolving to the response of the next
+    ///   middleware, or of the network request once the chain is exhausted.
+    pub fn run<'b>(self, request: &'b mut RequestAttempt<'_>) -> MiddlewareFuture<'b>
+    where
+        'a: 'b,
+    {
+        Box::pin(async move {
+            match self.middleware.split_first() {
+                Some((middleware, rest)) => {
+                    middleware
+                        .handle(request, AsyncNext { middleware: rest })
+                        .await
+                }
+                None => request.request.send_async().await,
+            }
+        })
+    }
+}
+
+impl Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug: fmt::DebugStruct<'_, '_> = f.debug_struct("MiddlewareStack");
+        debug.field("sync", &self.sync.len());
+        #[cfg(feature = "async")]
+        debug.field("async", &self.r#async.len());
+        debug
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
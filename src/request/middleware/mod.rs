@@ -0,0 +1,13 @@
+mod r#impl;
+mod r#struct;
+mod r#trait;
+mod r#type;
+
+pub use {r#struct::*, r#trait::*};
+
+pub(crate) use r#type::*;
+
+#[cfg(feature = "async")]
+pub use r#type::MiddlewareFuture;
+
+use super::*;
@@ -0,0 +1,26 @@
+use super::*;
+
+/// The rest of a synchronous middleware chain.
+pub struct Next<'a> {
+    /// The middleware still to run.
+    pub(crate) middleware: &'a [ArcMiddleware],
+}
+
+/// The rest of an asynchronous middleware chain.
+#[cfg(feature = "async")]
+pub struct AsyncNext<'a> {
+    /// The middleware still to run.
+    pub(crate) middleware: &'a [ArcAsyncMiddleware],
+}
+
+/// Middleware registered on a request.
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack {
+    /// Middleware wrapping synchronous requests.
+    pub(crate) sync: Vec<ArcMiddleware>,
+    /// Middleware wrapping asynchronous requests.
+    #[cfg(feature = "async")]
+    pub(crate) r#async: Vec<ArcAsyncMiddleware>,
+    /// Interceptors run around every network attempt.
+    pub(crate) interceptors: Vec<ArcRequestInterceptor>,
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+/// Middleware wrapping synchronous requests.
+///
+/// Middleware run in the order they were registered, each wrapping the ones
+/// after it. A middleware either passes the request on by calling `Next::run`,
+/// optionally inspecting or replacing the response, or returns a response or
+/// error of its own without touching the network.
+pub trait Middleware: Send + Sync {
+    /// Handles a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestAttempt<'_>` - The request, which may be modified before it is passed on.
+    /// - `Next<'_>` - The rest of the chain, ending with the network request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - The response or an error.
+    fn handle(&self, request: &mut RequestAttempt<'_>, next: Next<'_>) -> RequestResult;
+}
+
+/// Middleware wrapping asynchronous requests.
+///
+/// Behaves like `Middleware`, with the rest of the chain run through `AsyncNext::run`.
+#[cfg(feature = "async")]
+pub trait AsyncMiddleware: Send + Sync {
+    /// Handles a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestAttempt<'_>` - The request, which may be modified before it is passed on.
+    /// - `AsyncNext<'_>` - The rest of the chain, ending with the network request.
+    ///
+    /// # Returns
+    ///
+    /// - `MiddlewareFuture<'a>` - A future resolving to the response or an error.
+    fn handle<'a>(
+        &'a self,
+        request: &'a mut RequestAttempt<'_>,
+        next: AsyncNext<'a>,
+    ) -> MiddlewareFuture<'a>;
+}
+
+/// Interceptor observing every network attempt of a request.
+///
+/// Unlike middleware, which wraps a whole send, interceptors run once per attempt,
+/// including each redirect hop and streaming sends. Both methods do nothing by
+/// default, so an interceptor implements only the ones it needs.
+pub trait RequestInterceptor: Send + Sync {
+    /// Runs before the request of an attempt is serialized.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestAttempt<'_>` - The request, which may be modified before it is sent.
+    fn before(&self, _request: &mut RequestAttempt<'_>) {}
+
+    /// Runs after the response of an attempt is parsed.
+    ///
+    /// The body is not decoded yet, and streaming sends pass the response head only.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The response.
+    fn after(&self, _response: &HttpResponseBinary) {}
+}
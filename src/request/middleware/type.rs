@@ -0,0 +1,15 @@
+use super::*;
+
+/// Shared synchronous middleware in a request's chain.
+pub(crate) type ArcMiddleware = Arc<dyn Middleware>;
+
+/// Shared asynchronous middleware in a request's chain.
+#[cfg(feature = "async")]
+pub(crate) type ArcAsyncMiddleware = Arc<dyn AsyncMiddleware>;
+
+/// Shared interceptor observing the attempts of a request.
+pub(crate) type ArcRequestInterceptor = Arc<dyn RequestInterceptor>;
+
+/// Boxed future resolving to the result of an asynchronous middleware.
+#[cfg(feature = "async")]
+pub type MiddlewareFuture<'a> = Pin<Box<dyn Future<Output = RequestResult> + Send + 'a>>;
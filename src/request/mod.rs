@@ -1,13 +1,60 @@
+mod attempt;
+mod auth;
+mod beacon;
+mod cache;
+#[cfg(feature = "async")]
+mod cancellation;
 mod config;
 mod http_request;
+mod in_flight;
+mod long_poll;
+mod metrics;
+mod middleware;
+mod multipart;
+mod paginator;
+mod pipeline;
+mod progress;
+#[cfg(feature = "proxy")]
 mod proxy;
+mod redirect;
 mod request_builder;
+mod resolver;
+#[cfg(feature = "async")]
+mod segmented_download;
 mod shared;
+mod signer;
+mod single_flight;
+#[cfg(feature = "websocket")]
 mod socket;
+#[cfg(feature = "tls")]
+mod tls_info;
 mod tmp;
+#[cfg(feature = "trace-context")]
+mod trace_context;
+#[cfg(feature = "proxy")]
+mod tunnel_pool;
 
-pub use {http_request::*, request_builder::*, socket::*};
+pub use {
+    attempt::*, auth::*, beacon::*, cache::*, http_request::*, in_flight::*, long_poll::*,
+    metrics::*, middleware::*, multipart::*, paginator::*, pipeline::*, redirect::*,
+    request_builder::*, resolver::*, signer::*,
+};
 
-pub(crate) use {config::*, proxy::*, shared::*, tmp::*};
+pub(crate) use {config::*, progress::*, shared::*, single_flight::*, tmp::*};
+
+#[cfg(feature = "async")]
+pub use {cancellation::*, segmented_download::*};
+
+#[cfg(feature = "proxy")]
+pub(crate) use {proxy::*, tunnel_pool::*};
+
+#[cfg(feature = "tls")]
+pub use tls_info::*;
+
+#[cfg(feature = "websocket")]
+pub use socket::*;
+
+#[cfg(feature = "trace-context")]
+pub use trace_context::*;
 
 use super::*;
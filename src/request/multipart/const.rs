@@ -0,0 +1,17 @@
+/// Prefix of generated multipart boundaries.
+pub(crate) const MULTIPART_BOUNDARY_PREFIX: &str = "----http-request-";
+
+/// Top-level media type of a multipart body.
+pub(crate) const MULTIPART: &str = "multipart";
+
+/// Media subtype of a multipart form body.
+pub(crate) const FORM_DATA: &str = "form-data";
+
+/// Media type parameter naming the multipart boundary.
+pub(crate) const BOUNDARY: &str = "boundary";
+
+/// Media type of file fields without an explicit content type.
+pub(crate) const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
+
+/// Delimiter preceding a boundary line.
+pub(crate) const MULTIPART_DASHES: &str = "--";
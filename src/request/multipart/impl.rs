@@ -0,0 +1,235 @@
+use super::*;
+
+impl MultipartForm {
+    /// Creates an empty form with a random boundary.
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartForm` - A form without fields.
+    pub fn new() -> Self {
+        Self {
+            boundary: format!(
+                "{MULTIPART_BOUNDARY_PREFIX}{:016x}{:016x}",
+                random_u64(),
+                random_u64()
+            ),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Adds a text field.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `T` - The field value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartForm` - The form with the field added.
+    pub fn text_field<T: ToString>(mut self, name: &str, value: T) -> Self {
+        self.parts.push(MultipartPart {
+            name: name.to_owned(),
+            filename: None,
+            content_type: None,
+            data: value.to_string().into_bytes(),
+        });
+        self
+    }
+
+    /// Adds a file field with in-memory content.
+    ///
+    /// The content type defaults to `application/octet-stream` and can be changed
+    /// with `with_content_type`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `&str` - The file name sent to the server.
+    /// - `T` - The file content (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartForm` - The form with the field added.
+    pub fn file_field<T: Into<Vec<u8>>>(mut self, name: &str, filename: &str, data: T) -> Self {
+        self.parts.push(MultipartPart {
+            name: name.to_owned(),
+            filename: Some(filename.to_owned()),
+            content_type: Some(APPLICATION_OCTET_STREAM.to_owned()),
+            data: data.into(),
+        });
+        self
+    }
+
+    /// Adds a file field read from disk.
+    ///
+    /// The file name sent to the server is the last component of the path.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `P` - The path of the file (must implement AsRef<Path>).
+    ///
+    /// # Returns
+    ///
+    /// - `Result<MultipartForm, RequestError>` - The form with the field added, or an
+    ///   error if the file cannot be read.
+    pub fn file_path_field<P: AsRef<Path>>(
+        self,
+        name: &str,
+        path: P,
+    ) -> Result<Self, RequestError> {
+        let path: &Path = path.as_ref();
+        let data: Vec<u8> = fs::read(path).map_err(RequestError::from)?;
+        let filename: String = path
+            .file_name()
+            .map(|filename| filename.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(self.file_field(name, &filename, data))
+    }
+
+    /// Sets the content type of the most recently added field.
+    ///
+    /// # Arguments
+    ///
+    /// - `Mime` - The media type of the field content.
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartForm` - The form with the content type set.
+    pub fn with_content_type(mut self, mime: Mime) -> Self {
+        if let Some(part) = self.parts.last_mut() {
+            part.content_type = Some(mime.to_string());
+        }
+        self
+    }
+
+    /// Gets the boundary separating the parts.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The boundary.
+    pub fn get_boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Gets the media type of the encoded form.
+    ///
+    /// # Returns
+    ///
+    /// - `Mime` - `multipart/form-data` with the boundary parameter.
+    pub fn get_content_type(&self) -> Mime {
+        Mime::new(MULTIPART, FORM_DATA).with_parameter(BOUNDARY, &self.boundary)
+    }
+
+    /// Escapes a name for a quoted `Content-Disposition` parameter.
+    ///
+    /// Quotes and line breaks are percent-encoded, as browsers do.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field or file name.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The escaped name.
+    fn escape_name(name: &str) -> String {
+        name.replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    /// Encodes the boundary line and headers preceding the content of a part.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `Option<&str>` - The file name, for file fields.
+    /// - `Option<&str>` - The media type of the content.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The encoded part head, ending with an empty line.
+    pub(crate) fn encode_part_head(
+        &self,
+        name: &str,
+        filename: Option<&str>,
+        content_type: Option<&str>,
+    ) -> String {
+        let mut head: String = format!(
+            "{MULTIPART_DASHES}{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            Self::escape_name(name)
+        );
+        if let Some(filename) = filename {
+            head.push_str(&format!("; filename=\"{}\"", Self::escape_name(filename)));
+        }
+        head.push_str("\r\n");
+        if let Some(content_type) = content_type {
+            head.push_str(&format!("{CONTENT_TYPE}: {content_type}\r\n"));
+        }
+        head.push_str("\r\n");
+        head
+    }
+
+    /// Encodes the closing boundary line ending the body.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The encoded closing boundary.
+    pub(crate) fn encode_end(&self) -> String {
+        format!("{MULTIPART_DASHES}{}{MULTIPART_DASHES}\r\n", self.boundary)
+    }
+
+    /// Encodes the form as a `multipart/form-data` body.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The encoded body.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut body: Vec<u8> = Vec::new();
+        for part in &self.parts {
+            let head: String = self.encode_part_head(
+                &part.name,
+                part.filename.as_deref(),
+                part.content_type.as_deref(),
+            );
+            body.extend_from_slice(head.as_bytes());
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(HTTP_BR_BYTES);
+        }
+        body.extend_from_slice(self.encode_end().as_bytes());
+        body
+    }
+}
+
+/// Default implementation for MultipartForm.
+///
+/// # Returns
+///
+/// - `MultipartForm` - An empty form with a random boundary.
+impl Default for MultipartForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads the file, opening it on the first read.
+///
+/// # Arguments
+///
+/// - `&mut [u8]` - The buffer to read into.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of bytes read, or an error if the file
+///   cannot be opened or read.
+impl Read for MultipartFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let file: &mut File = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(File::open(&self.path)?),
+        };
+        file.read(buf)
+    }
+}
@@ -0,0 +1,10 @@
+mod r#const;
+mod r#impl;
+mod r#struct;
+mod r#type;
+
+pub use r#struct::*;
+
+pub(crate) use {r#const::*, r#type::*};
+
+use super::*;
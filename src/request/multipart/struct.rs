@@ -0,0 +1,35 @@
+use super::*;
+
+/// A `multipart/form-data` request body made of text and file fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultipartForm {
+    /// Boundary separating the parts.
+    pub(crate) boundary: String,
+    /// Fields in the order they were added.
+    pub(crate) parts: Vec<MultipartPart>,
+}
+
+/// A field of a multipart form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MultipartPart {
+    /// Field name.
+    pub(crate) name: String,
+    /// File name, set for file fields.
+    pub(crate) filename: Option<String>,
+    /// Media type of the field content.
+    pub(crate) content_type: Option<String>,
+    /// Field content.
+    pub(crate) data: Vec<u8>,
+}
+
+/// A file read as the content of a multipart file field.
+///
+/// The file is opened on the first read, so it is not held open before the
+/// request is sent.
+#[derive(Debug)]
+pub(crate) struct MultipartFileReader {
+    /// Path of the file.
+    pub(crate) path: PathBuf,
+    /// The opened file, set after the first read.
+    pub(crate) file: Option<File>,
+}
@@ -0,0 +1,5 @@
+use super::*;
+
+/// Body of a single-file upload: the part head, the file, and the closing boundary.
+pub(crate) type MultipartFileBody =
+    Chain<Chain<Cursor<Vec<u8>>, MultipartFileReader>, Cursor<Vec<u8>>>;
@@ -0,0 +1,131 @@
+use super::*;
+
+impl Paginator {
+    /// Creates a paginator starting at the URL of the template request.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpRequest` - The template request.
+    /// - `BoxNextPage` - The closure returning the URL of the next page.
+    ///
+    /// # Returns
+    ///
+    /// - `Paginator` - A paginator that has not sent any request yet.
+    pub(crate) fn new(http_request: HttpRequest, next_page: BoxNextPage) -> Self {
+        let next_url: Option<String> = Some(http_request.get_url());
+        Self {
+            http_request,
+            next_page,
+            next_url,
+            pages: 0,
+        }
+    }
+
+    /// Builds the request for a page.
+    ///
+    /// The request uses the template with its own copy of the configuration and
+    /// processing state, so redirect counts and responses do not carry over between pages.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The URL of the page.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpRequest` - The request for the page.
+    fn build_request(&self, url: String) -> HttpRequest {
+        let config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        let mut http_request: HttpRequest = self.http_request.clone();
+        http_request.url(url);
+        http_request.config = Arc::new(RwLock::new(config));
+        http_request.tmp = Arc::new(RwLock::new(Tmp::default()));
+        http_request.response = Arc::new(RwLock::new(HttpResponseBinary::default()));
+        http_request
+    }
+
+    /// Records the outcome of a page request and determines the next page.
+    ///
+    /// Pagination ends after an error or when the closure returns None.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestResult` - The result of the page request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The page response or the error.
+    fn finish_page(&mut self, res: RequestResult) -> Result<HttpResponseBinary, RequestError> {
+        self.pages += 1;
+        let response: HttpResponseBinary = res?.binary();
+        self.next_url = (self.next_page)(&response);
+        Ok(response)
+    }
+
+    /// Gets the number of pages requested so far.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of page requests sent, including failed ones.
+    pub fn get_pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Gets the URL of the next page.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The URL requested next, or None once pagination has ended.
+    pub fn get_next_url(&self) -> Option<&str> {
+        self.next_url.as_deref()
+    }
+
+    /// Turns the paginator into a stream sending each page asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<HttpResponseBinary, RequestError>>` - A stream of the
+    ///   page responses, ending after the last page or the first error.
+    #[cfg(feature = "async")]
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<HttpResponseBinary, RequestError>> + Send {
+        unfold(self, |mut paginator: Paginator| async move {
+            let url: String = paginator.next_url.take()?;
+            let mut http_request: HttpRequest = paginator.build_request(url);
+            let res: RequestResult = AsyncRequestTrait::send(&mut http_request).await;
+            let page: Result<HttpResponseBinary, RequestError> = paginator.finish_page(res);
+            Some((page, paginator))
+        })
+    }
+}
+
+impl Iterator for Paginator {
+    type Item = Result<HttpResponseBinary, RequestError>;
+
+    /// Sends the request for the next page.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<HttpResponseBinary, RequestError>>` - The page response or error,
+    ///   or None once pagination has ended.
+    fn next(&mut self) -> Option<Self::Item> {
+        let url: String = self.next_url.take()?;
+        let mut http_request: HttpRequest = self.build_request(url);
+        let res: RequestResult = RequestTrait::send(&mut http_request);
+        Some(self.finish_page(res))
+    }
+}
+
+impl Debug for Paginator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("http_request", &self.http_request)
+            .field("next_url", &self.next_url)
+            .field("pages", &self.pages)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,9 @@
+mod r#impl;
+mod r#struct;
+mod r#type;
+
+pub use r#struct::*;
+
+pub(crate) use r#type::*;
+
+use super::*;
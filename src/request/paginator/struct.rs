@@ -0,0 +1,18 @@
+use super::*;
+
+/// Sends a sequence of page requests, each derived from the previous response.
+///
+/// The first request is the template request; after every successful response a
+/// closure returns the URL of the next page, or None to stop. This suits cursor-based
+/// APIs where the next URL or cursor is in the response body rather than a `Link`
+/// header. Iterate it for blocking sends, or turn it into a stream with `into_stream`.
+pub struct Paginator {
+    /// Template request providing the first URL, headers, and configuration.
+    pub(crate) http_request: HttpRequest,
+    /// Closure returning the URL of the page after a response.
+    pub(crate) next_page: BoxNextPage,
+    /// URL of the next page to request, or None once pagination has ended.
+    pub(crate) next_url: Option<String>,
+    /// Number of pages requested so far.
+    pub(crate) pages: usize,
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+/// Closure deriving the URL of the next page from a response.
+pub(crate) type BoxNextPage = Box<dyn FnMut(&HttpResponseBinary) -> Option<String> + Send>;
@@ -0,0 +1,2 @@
+/// Default maximum number of requests written back-to-back before reading responses.
+pub(crate) const DEFAULT_PIPELINE_MAX_DEPTH: usize = 16;
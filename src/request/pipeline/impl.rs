@@ -0,0 +1,574 @@
+use super::*;
+
+impl PipelineEntry {
+    /// Checks if the entry is a HEAD request, whose response carries no body.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the entry is a HEAD request, false otherwise.
+    fn is_head(&self) -> bool {
+        self.method.to_string().eq_ignore_ascii_case("HEAD")
+    }
+}
+
+impl HttpPipeline {
+    /// Creates a new pipeline from a template request.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpRequest` - The template request providing origin, headers, and configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpPipeline` - A new pipeline with an empty queue.
+    pub(crate) fn new(http_request: HttpRequest) -> Self {
+        Self {
+            http_request,
+            queue: Vec::new(),
+            max_depth: DEFAULT_PIPELINE_MAX_DEPTH,
+        }
+    }
+
+    /// Queues a GET request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - An origin-relative path (starting with `/`) or an absolute URL on the same origin.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut HttpPipeline` - The pipeline for method chaining.
+    pub fn get(&mut self, target: &str) -> &mut Self {
+        self.queue.push(PipelineEntry {
+            method: Method::Get,
+            target: target.to_owned(),
+        });
+        self
+    }
+
+    /// Queues a HEAD request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - An origin-relative path (starting with `/`) or an absolute URL on the same origin.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut HttpPipeline` - The pipeline for method chaining.
+    pub fn head(&mut self, target: &str) -> &mut Self {
+        self.queue.push(PipelineEntry {
+            method: Method::Head,
+            target: target.to_owned(),
+        });
+        self
+    }
+
+    /// Sets the maximum number of requests in flight on the connection.
+    ///
+    /// A depth of 0 is treated as 1, which disables pipelining while keeping the
+    /// connection reuse.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum pipeline depth.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut HttpPipeline` - The pipeline for method chaining.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = max_depth.max(1);
+        self
+    }
+
+    /// Gets the number of queued requests.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of requests waiting to be sent.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Checks if the queue is empty.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if no requests are queued, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Validates the template request and resolves the connection target.
+    ///
+    /// Pipelining is only attempted over HTTP/1.1.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Config, String, u16), RequestError>` - The template configuration, host, and port.
+    fn prepare(&self) -> Result<(Config, String, u16), RequestError> {
+        let http_version_str: String = self.http_request.get_http_version_str()?;
+        if !http_version_str.eq_ignore_ascii_case("HTTP/1.1") {
+            return Err(RequestError::Request(format!(
+                "Pipelining requires HTTP/1.1, got {http_version_str}"
+            )));
+        }
+        let url_obj: HttpUrlComponents = self.http_request.parse_url()?;
+        let mut config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        config.check_host_header()?;
+        config.url_obj = url_obj;
+        let host: String = config.url_obj.host.clone().unwrap_or_default();
+        let port: u16 = self
+            .http_request
+            .get_port(config.url_obj.port.unwrap_or_default(), &config);
+        Ok((config, host, port))
+    }
+
+    /// Serializes a queued request into its wire format.
+    ///
+    /// Absolute targets must share the protocol, host, and port of the template request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PipelineEntry` - The queued request.
+    /// - `&Config` - The template configuration.
+    /// - `u16` - The resolved port of the template origin.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The serialized request, or an error if the target is rejected.
+    fn serialize_entry(
+        &self,
+        entry: &PipelineEntry,
+        config: &Config,
+        port: u16,
+    ) -> Result<Vec<u8>, RequestError> {
+        let mut entry_config: Config = config.clone();
+        if entry.target.starts_with('/') {
+            let (path, query) = match entry.target.split_once(QUERY) {
+                Some((path, query)) => (path.to_owned(), Some(query.to_owned())),
+                None => (entry.target.clone(), None),
+            };
+            entry_config.url_obj.path = Some(path);
+            entry_config.url_obj.query = query;
+        } else {
+            let url_obj: HttpUrlComponents = HttpUrlComponents::parse(entry.target.clone())
+                .map_err(|error| RequestError::Request(error.to_string()))?;
+            let entry_port: u16 = match url_obj.port {
+                Some(entry_port) if entry_port != 0 => entry_port,
+                _ => Protocol::get_port(&url_obj.protocol.to_lowercase()),
+            };
+            let entry_host: String = url_obj
+                .host
+                .as_deref()
+                .and_then(host_to_ascii)
+                .unwrap_or_default();
+            if !url_obj
+                .protocol
+                .eq_ignore_ascii_case(&config.url_obj.protocol)
+                || !entry_host
+                    .eq_ignore_ascii_case(&config.url_obj.host.clone().unwrap_or_default())
+                || entry_port != port
+            {
+                return Err(RequestError::Request(format!(
+                    "Pipelined request target is not on the same origin: {}",
+                    entry.target
+                )));
+            }
+            entry_config.url_obj.path = url_obj.path;
+            entry_config.url_obj.query = url_obj.query;
+        }
+        let mut entry_request: HttpRequest = self.http_request.clone();
+        entry_request.methods = Arc::new(entry.method.clone());
+        entry_request.body = Arc::new(Body::default());
+        entry_request.config = Arc::new(RwLock::new(entry_config));
+        let http_version_str: String = entry_request.get_http_version_str()?;
+        let body_bytes: Option<Vec<u8>> = entry_request.get_request_body()?;
+        let path: String = entry_request.get_path();
+        let header_bytes: Vec<u8> = entry_request.get_signed_header_bytes(
+            &path,
+            body_bytes.as_deref(),
+            body_bytes.as_ref().map(Vec::len),
+            true,
+        )?;
+        Ok(SharedRequestBuilder::build_http_request(
+            &entry.method.to_string(),
+            path,
+            header_bytes,
+            None,
+            http_version_str,
+        ))
+    }
+
+    /// Converts the raw bytes of one pipelined response into a response object.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The raw response bytes.
+    /// - `&Config` - The template configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `BoxResponseTrait` - The parsed response.
+    fn build_response(mut response_bytes: Vec<u8>, config: &Config) -> BoxResponseTrait {
+        let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
+        if let Some(pos) = SharedResponseHandler::find_double_crlf(&response_bytes, 0) {
+            let headers_end_pos: usize = pos + 4;
+            if SharedResponseHandler::is_chunked_encoding(&response_bytes[..headers_end_pos]) {
+                let (decoded_body, chunked_trailers): (Vec<u8>, ResponseHeaders) =
+                    SharedResponseHandler::parse_chunked_message(
+                        &response_bytes[headers_end_pos..],
+                    );
+                trailers = chunked_trailers;
+                response_bytes.truncate(headers_end_pos);
+                response_bytes.extend_from_slice(&decoded_body);
+            }
+        }
+        let mut response: HttpResponseBinary =
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes);
+        response.trailers = Arc::new(RwLock::new(trailers));
+        if config.decode {
+            response = response.decode(config.buffer);
+        }
+        Box::new(response)
+    }
+
+    /// Checks whether a response frame ends the usability of the connection.
+    ///
+    /// Responses older than HTTP/1.1 are treated as closing, since persistent
+    /// connections are not the default there.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw response bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the server announced it will close the connection.
+    fn closes_connection(response_bytes: &[u8]) -> bool {
+        let headers_end_pos: usize = SharedResponseHandler::find_double_crlf(response_bytes, 0)
+            .map_or(response_bytes.len(), |pos| pos + 4);
+        !response_bytes.starts_with(b"HTTP/1.1")
+            || SharedResponseHandler::is_connection_close(&response_bytes[..headers_end_pos])
+    }
+
+    /// Checks whether a read failed because the server closed the connection after
+    /// answering an earlier request of the window.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The position of the unanswered request in the window.
+    /// - `&[u8]` - Bytes read from the connection but not consumed yet.
+    /// - `&RequestError` - The read error.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the rest of the window can be retried on a fresh connection.
+    fn is_closed_after_response(position: usize, data: &[u8], error: &RequestError) -> bool {
+        position > 0 && data.is_empty() && matches!(error, RequestError::ConnectionReset(_))
+    }
+
+    /// Reads one complete response from the stream, skipping interim 1xx responses.
+    ///
+    /// Bytes belonging to later responses are left in `data`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxReadWrite` - The connection stream.
+    /// - `&mut Vec<u8>` - Bytes read from the connection but not consumed yet.
+    /// - `bool` - Whether the response answers a HEAD request.
+    /// - `&Config` - The template configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, bool), RequestError>` - The response bytes and whether the connection reached EOF.
+    fn read_frame(
+        stream: &mut BoxReadWrite,
+        data: &mut Vec<u8>,
+        is_head: bool,
+        config: &Config,
+    ) -> Result<(Vec<u8>, bool), RequestError> {
+        let mut buffer: Vec<u8> = vec![0; config.buffer];
+        loop {
+            config.check_header_limits(data)?;
+            if let Some(frame_length) =
+                SharedResponseHandler::get_response_frame_length(data, is_head)
+            {
+                let frame: Vec<u8> = data.drain(..frame_length).collect();
+                let status_code: usize = SharedResponseHandler::get_status_code(&frame);
+                if (100..=199).contains(&status_code) && status_code != 101 {
+                    continue;
+                }
+                return Ok((frame, false));
+            }
+            let n: usize = stream.read(&mut buffer).map_err(RequestError::from)?;
+            if n == 0 {
+                if SharedResponseHandler::find_double_crlf(data, 0).is_some() {
+                    return Ok((std::mem::take(data), true));
+                }
+                return Err(RequestError::ConnectionReset(IoError::new(
+                    ErrorKind::UnexpectedEof,
+                    "Connection closed before the pipelined response was received",
+                )));
+            }
+            data.extend_from_slice(&buffer[..n]);
+        }
+    }
+
+    /// Sends all queued requests synchronously.
+    ///
+    /// The queue is drained. Results are returned in queue order, one per request.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<RequestResult>` - The result of each queued request.
+    pub fn send_sync(&mut self) -> Vec<RequestResult> {
+        let entries: Vec<PipelineEntry> = std::mem::take(&mut self.queue);
+        let mut results: Vec<Option<RequestResult>> = (0..entries.len()).map(|_| None).collect();
+        let (config, host, port) = match self.prepare() {
+            Ok(prepared) => prepared,
+            Err(error) => return entries.iter().map(|_| Err(error.clone())).collect(),
+        };
+        let mut pending: VecDeque<(usize, Vec<u8>, bool)> = VecDeque::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            match self.serialize_entry(entry, &config, port) {
+                Ok(request) => pending.push_back((index, request, entry.is_head())),
+                Err(error) => results[index] = Some(Err(error)),
+            }
+        }
+        let mut stream: Option<BoxReadWrite> = None;
+        let mut max_depth: usize = self.max_depth;
+        while !pending.is_empty() {
+            let window_size: usize = max_depth.min(pending.len());
+            let window: Vec<(usize, Vec<u8>, bool)> = pending.drain(..window_size).collect();
+            let mut current: BoxReadWrite = match stream.take() {
+                Some(current) => current,
+                None => match self.http_request.get_connection_stream(host.clone(), port) {
+                    Ok(current) => current,
+                    Err(error) => {
+                        for (index, _, _) in &window {
+                            results[*index] = Some(Err(error.clone()));
+                        }
+                        continue;
+                    }
+                },
+            };
+            let request_bytes: Vec<u8> = window
+                .iter()
+                .flat_map(|(_, request, _)| request.iter().copied())
+                .collect();
+            if let Err(error) = current
+                .write_all(&request_bytes)
+                .and_then(|_| current.flush())
+                .map_err(RequestError::from)
+            {
+                for (index, _, _) in &window {
+                    results[*index] = Some(Err(error.clone()));
+                }
+                continue;
+            }
+            let mut data: Vec<u8> = Vec::with_capacity(config.buffer.max(8192));
+            let mut reusable: bool = true;
+            for (position, (index, _, is_head)) in window.iter().enumerate() {
+                match Self::read_frame(&mut current, &mut data, *is_head, &config) {
+                    Ok((frame, eof)) => {
+                        let closed: bool = eof || Self::closes_connection(&frame);
+                        results[*index] = Some(Ok(Self::build_response(frame, &config)));
+                        if closed {
+                            reusable = false;
+                            if position + 1 < window.len() {
+                                max_depth = 1;
+                            }
+                            for unanswered in window[position + 1..].iter().rev() {
+                                pending.push_front(unanswered.clone());
+                            }
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        reusable = false;
+                        if Self::is_closed_after_response(position, &data, &error) {
+                            max_depth = 1;
+                            for unanswered in window[position..].iter().rev() {
+                                pending.push_front(unanswered.clone());
+                            }
+                        } else {
+                            for (unanswered, _, _) in &window[position..] {
+                                results[*unanswered] = Some(Err(error.clone()));
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+            if reusable {
+                stream = Some(current);
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or(Err(RequestError::Request(
+                    "Pipelined request was not sent".to_string(),
+                )))
+            })
+            .collect()
+    }
+
+    /// Reads one complete response from the async stream, skipping interim 1xx responses.
+    ///
+    /// Bytes belonging to later responses are left in `data`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The connection stream.
+    /// - `&mut Vec<u8>` - Bytes read from the connection but not consumed yet.
+    /// - `bool` - Whether the response answers a HEAD request.
+    /// - `&Config` - The template configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, bool), RequestError>` - The response bytes and whether the connection reached EOF.
+    #[cfg(feature = "async")]
+    async fn read_frame_async(
+        stream: &mut BoxAsyncReadWrite,
+        data: &mut Vec<u8>,
+        is_head: bool,
+        config: &Config,
+    ) -> Result<(Vec<u8>, bool), RequestError> {
+        let mut buffer: Vec<u8> = vec![0; config.buffer];
+        loop {
+            config.check_header_limits(data)?;
+            if let Some(frame_length) =
+                SharedResponseHandler::get_response_frame_length(data, is_head)
+            {
+                let frame: Vec<u8> = data.drain(..frame_length).collect();
+                let status_code: usize = SharedResponseHandler::get_status_code(&frame);
+                if (100..=199).contains(&status_code) && status_code != 101 {
+                    continue;
+                }
+                return Ok((frame, false));
+            }
+            let bytes_read: usize = stream.read(&mut buffer).await.map_err(RequestError::from)?;
+            if bytes_read == 0 {
+                if SharedResponseHandler::find_double_crlf(data, 0).is_some() {
+                    return Ok((std::mem::take(data), true));
+                }
+                return Err(RequestError::ConnectionReset(IoError::new(
+                    ErrorKind::UnexpectedEof,
+                    "Connection closed before the pipelined response was received",
+                )));
+            }
+            data.extend_from_slice(&buffer[..bytes_read]);
+        }
+    }
+
+    /// Sends all queued requests asynchronously.
+    ///
+    /// The queue is drained. Results are returned in queue order, one per request.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<RequestResult>` - The result of each queued request.
+    #[cfg(feature = "async")]
+    pub async fn send_async(&mut self) -> Vec<RequestResult> {
+        let entries: Vec<PipelineEntry> = std::mem::take(&mut self.queue);
+        let mut results: Vec<Option<RequestResult>> = (0..entries.len()).map(|_| None).collect();
+        let (config, host, port) = match self.prepare() {
+            Ok(prepared) => prepared,
+            Err(error) => return entries.iter().map(|_| Err(error.clone())).collect(),
+        };
+        let mut pending: VecDeque<(usize, Vec<u8>, bool)> = VecDeque::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            match self.serialize_entry(entry, &config, port) {
+                Ok(request) => pending.push_back((index, request, entry.is_head())),
+                Err(error) => results[index] = Some(Err(error)),
+            }
+        }
+        let mut stream: Option<BoxAsyncReadWrite> = None;
+        let mut max_depth: usize = self.max_depth;
+        while !pending.is_empty() {
+            let window_size: usize = max_depth.min(pending.len());
+            let window: Vec<(usize, Vec<u8>, bool)> = pending.drain(..window_size).collect();
+            let mut current: BoxAsyncReadWrite = match stream.take() {
+                Some(current) => current,
+                None => match self
+                    .http_request
+                    .get_connection_stream_async(host.clone(), port)
+                    .await
+                {
+                    Ok(current) => current,
+                    Err(error) => {
+                        for (index, _, _) in &window {
+                            results[*index] = Some(Err(error.clone()));
+                        }
+                        continue;
+                    }
+                },
+            };
+            let request_bytes: Vec<u8> = window
+                .iter()
+                .flat_map(|(_, request, _)| request.iter().copied())
+                .collect();
+            let write_result: Result<(), RequestError> =
+                match current.write_all(&request_bytes).await {
+                    Ok(_) => current.flush().await.map_err(RequestError::from),
+                    Err(error) => Err(RequestError::from(error)),
+                };
+            if let Err(error) = write_result {
+                for (index, _, _) in &window {
+                    results[*index] = Some(Err(error.clone()));
+                }
+                continue;
+            }
+            let mut data: Vec<u8> = Vec::with_capacity(config.buffer.max(8192));
+            let mut reusable: bool = true;
+            for (position, (index, _, is_head)) in window.iter().enumerate() {
+                match Self::read_frame_async(&mut current, &mut data, *is_head, &config).await {
+                    Ok((frame, eof)) => {
+                        let closed: bool = eof || Self::closes_connection(&frame);
+                        results[*index] = Some(Ok(Self::build_response(frame, &config)));
+                        if closed {
+                            reusable = false;
+                            if position + 1 < window.len() {
+                                max_depth = 1;
+                            }
+                            for unanswered in window[position + 1..].iter().rev() {
+                                pending.push_front(unanswered.clone());
+                            }
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        reusable = false;
+                        if Self::is_closed_after_response(position, &data, &error) {
+                            max_depth = 1;
+                            for unanswered in window[position..].iter().rev() {
+                                pending.push_front(unanswered.clone());
+                            }
+                        } else {
+                            for (unanswered, _, _) in &window[position..] {
+                                results[*unanswered] = Some(Err(error.clone()));
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+            if reusable {
+                stream = Some(current);
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or(Err(RequestError::Request(
+                    "Pipelined request was not sent".to_string(),
+                )))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+/// Experimental HTTP/1.1 pipeline sending several idempotent requests over one connection.
+///
+/// Requests are queued, written back-to-back in windows of at most `max_depth`
+/// requests, and their responses are read in order. Only GET and HEAD requests to
+/// the origin of the template request are accepted, and redirects are not followed.
+/// Requests left unanswered when the server closes the connection, by sending
+/// `Connection: close`, answering with HTTP/1.0, or closing after a complete
+/// response, are retried on a fresh connection one at a time, since the server
+/// evidently does not keep pipelined requests.
+#[derive(Clone, Debug)]
+pub struct HttpPipeline {
+    /// Template request providing the origin, headers, and configuration.
+    pub(crate) http_request: HttpRequest,
+    /// Queued requests waiting to be sent.
+    pub(crate) queue: Vec<PipelineEntry>,
+    /// Maximum number of in-flight requests on the connection.
+    pub(crate) max_depth: usize,
+}
+
+/// A single queued pipeline request.
+#[derive(Clone, Debug)]
+pub(crate) struct PipelineEntry {
+    /// HTTP method of the request.
+    pub(crate) method: Method,
+    /// Origin-relative path or absolute URL of the request.
+    pub(crate) target: String,
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+impl ProgressHook {
+    /// Reports the bytes transferred so far.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of body bytes transferred.
+    /// - `Option<usize>` - The expected body size, or None if it is unknown.
+    pub(crate) fn report(&self, transferred: usize, total: Option<usize>) {
+        (self.0)(transferred, total);
+    }
+}
+
+impl Debug for ProgressHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook")
+    }
+}
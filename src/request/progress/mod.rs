@@ -0,0 +1,7 @@
+mod r#impl;
+mod r#struct;
+mod r#type;
+
+pub(crate) use {r#struct::*, r#type::*};
+
+use super::*;
@@ -0,0 +1,5 @@
+use super::*;
+
+/// Hook reporting the progress of an upload or a download.
+#[derive(Clone)]
+pub(crate) struct ProgressHook(pub(crate) ProgressHookFn);
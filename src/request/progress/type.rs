@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared callback receiving the bytes transferred so far and the expected total.
+pub(crate) type ProgressHookFn = Arc<dyn Fn(usize, Option<usize>) + Send + Sync>;
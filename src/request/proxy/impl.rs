@@ -1,6 +1,7 @@
 use super::*;
 
 /// Implementation of ProxyTunnelStream methods.
+#[cfg(feature = "async")]
 impl ProxyTunnelStream {
     /// Creates a new ProxyTunnelStream from an async read/write stream.
     ///
@@ -22,6 +23,7 @@ impl ProxyTunnelStream {
 /// AsyncRead implementation for ProxyTunnelStream.
 ///
 /// Delegates all operations to the underlying stream.
+#[cfg(feature = "async")]
 impl AsyncRead for ProxyTunnelStream {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -41,6 +43,7 @@ impl AsyncRead for ProxyTunnelStream {
 /// AsyncWrite implementation for ProxyTunnelStream.
 ///
 /// Delegates all operations to the underlying stream.
+#[cfg(feature = "async")]
 impl AsyncWrite for ProxyTunnelStream {
     fn poll_write(
         mut self: Pin<&mut Self>,
@@ -65,6 +68,7 @@ impl AsyncWrite for ProxyTunnelStream {
     }
 }
 
+#[cfg(feature = "async")]
 impl Unpin for ProxyTunnelStream {}
 
 /// Implementation of SyncProxyTunnelStream methods.
@@ -113,3 +117,12 @@ impl Write for SyncProxyTunnelStream {
         self.inner.flush()
     }
 }
+
+/// ReadWrite implementation for SyncProxyTunnelStream.
+///
+/// Delegates the read timeout to the underlying stream.
+impl ReadWrite for SyncProxyTunnelStream {
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.inner.set_read_timeout(timeout)
+    }
+}
@@ -3,6 +3,7 @@ use super::*;
 /// Asynchronous proxy tunnel stream wrapper.
 ///
 /// Provides unified interface for different proxy implementations.
+#[cfg(feature = "async")]
 pub struct ProxyTunnelStream {
     /// The underlying asynchronous read/write stream.
     pub(super) inner: BoxAsyncReadWrite,
@@ -0,0 +1,13 @@
+use super::*;
+
+/// Credential headers removed when a redirect leaves the origin of the request.
+pub(crate) const REDIRECT_SENSITIVE_HEADERS: [&str; 3] =
+    ["Authorization", "Cookie", PROXY_AUTHORIZATION];
+
+/// Entity headers that describe a body dropped when a redirect switches to GET.
+pub(crate) const REDIRECT_BODY_HEADERS: [&str; 4] = [
+    CONTENT_TYPE,
+    CONTENT_LENGTH,
+    CONTENT_ENCODING,
+    TRANSFER_ENCODING,
+];
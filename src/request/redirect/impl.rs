@@ -0,0 +1,186 @@
+use super::*;
+
+/// Provides the default redirect policy.
+///
+/// # Returns
+///
+/// - `RedirectPolicy` - A policy rewriting methods like browsers, stripping credentials
+///   across origins, and allowing HTTPS to HTTP redirects.
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            rewrite_method: true,
+            strip_sensitive_headers: true,
+            allow_https_downgrade: true,
+        }
+    }
+}
+
+impl RedirectPolicy {
+    /// Creates the default redirect policy.
+    ///
+    /// # Returns
+    ///
+    /// - `RedirectPolicy` - The default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the method is switched to GET on 301, 302, and 303 redirects.
+    ///
+    /// When enabled, POST becomes GET on 301 and 302, and every method but HEAD
+    /// becomes GET on 303. The body and the headers describing it are dropped.
+    /// 307 and 308 redirects always keep the method and body.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to rewrite the method.
+    ///
+    /// # Returns
+    ///
+    /// - `RedirectPolicy` - The updated policy.
+    pub fn rewrite_method(mut self, rewrite_method: bool) -> Self {
+        self.rewrite_method = rewrite_method;
+        self
+    }
+
+    /// Sets whether credential headers are removed on hops to another origin.
+    ///
+    /// The `Authorization`, `Cookie`, and `Proxy-Authorization` headers are removed
+    /// when the redirection URL has another scheme, host, or port, and are not sent
+    /// on any later hop.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to strip the headers.
+    ///
+    /// # Returns
+    ///
+    /// - `RedirectPolicy` - The updated policy.
+    pub fn strip_sensitive_headers(mut self, strip_sensitive_headers: bool) -> Self {
+        self.strip_sensitive_headers = strip_sensitive_headers;
+        self
+    }
+
+    /// Sets whether a redirect from HTTPS to HTTP is followed.
+    ///
+    /// A refused downgrade fails with `RedirectErrorKind::HttpsDowngrade`.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to allow the downgrade.
+    ///
+    /// # Returns
+    ///
+    /// - `RedirectPolicy` - The updated policy.
+    pub fn allow_https_downgrade(mut self, allow_https_downgrade: bool) -> Self {
+        self.allow_https_downgrade = allow_https_downgrade;
+        self
+    }
+
+    /// Checks if the method is switched to GET on 301, 302, and 303 redirects.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the method is rewritten, false otherwise.
+    pub fn is_rewrite_method(&self) -> bool {
+        self.rewrite_method
+    }
+
+    /// Checks if credential headers are removed on hops to another origin.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the headers are stripped, false otherwise.
+    pub fn is_strip_sensitive_headers(&self) -> bool {
+        self.strip_sensitive_headers
+    }
+
+    /// Checks if a redirect from HTTPS to HTTP is followed.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the downgrade is allowed, false otherwise.
+    pub fn is_allow_https_downgrade(&self) -> bool {
+        self.allow_https_downgrade
+    }
+
+    /// Gets the method the redirection URL is requested with.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The status code of the redirect response.
+    /// - `Method` - The method of the request that was redirected.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Method>` - GET if the method is rewritten, or None if it is kept.
+    pub(crate) fn get_rewritten_method(
+        &self,
+        status_code: ResponseStatusCode,
+        method: &Method,
+    ) -> Option<Method> {
+        if !self.rewrite_method || method.is_get() {
+            return None;
+        }
+        let rewrite: bool = match status_code {
+            301 | 302 => method.is_post(),
+            303 => !method.is_head(),
+            _ => false,
+        };
+        rewrite.then_some(Method::Get)
+    }
+}
+
+impl RedirectHop {
+    /// Gets the URL that answered with the redirect.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The redirected URL.
+    pub fn get_from(&self) -> &str {
+        &self.from
+    }
+
+    /// Gets the redirection URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The URL requested next.
+    pub fn get_to(&self) -> &str {
+        &self.to
+    }
+
+    /// Gets the status code of the redirect response.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The redirect status code.
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.status_code
+    }
+
+    /// Gets the method the redirection URL is requested with.
+    ///
+    /// # Returns
+    ///
+    /// - `Method` - The method after any rewrite by the redirect policy.
+    pub fn get_method(&self) -> Method {
+        self.method.clone()
+    }
+
+    /// Checks if the redirection URL has another scheme, host, or port.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the hop leaves the origin, false otherwise.
+    pub fn is_cross_origin(&self) -> bool {
+        self.cross_origin
+    }
+}
+
+impl Debug for RedirectHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("RedirectHook")
+    }
+}
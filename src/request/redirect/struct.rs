@@ -0,0 +1,36 @@
+use super::*;
+
+/// How redirects are followed.
+///
+/// Applies to every hop once redirects are enabled with `RequestBuilder::redirect`.
+/// By default POST is switched to GET on 301 and 302, every method but HEAD is
+/// switched to GET on 303, credential headers are dropped when a hop leaves the
+/// origin, and redirects from HTTPS to HTTP are followed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RedirectPolicy {
+    /// Whether the method is switched to GET, dropping the body, as browsers do.
+    pub(crate) rewrite_method: bool,
+    /// Whether credential headers are removed on hops to another origin.
+    pub(crate) strip_sensitive_headers: bool,
+    /// Whether a redirect from HTTPS to HTTP is followed.
+    pub(crate) allow_https_downgrade: bool,
+}
+
+/// A redirect about to be followed, passed to `RequestBuilder::on_redirect` hooks.
+#[derive(Clone, Debug)]
+pub struct RedirectHop {
+    /// The URL that answered with the redirect.
+    pub(crate) from: String,
+    /// The redirection URL.
+    pub(crate) to: String,
+    /// The status code of the redirect response.
+    pub(crate) status_code: ResponseStatusCode,
+    /// The method the redirection URL is requested with.
+    pub(crate) method: Method,
+    /// Whether the redirection URL has another scheme, host, or port.
+    pub(crate) cross_origin: bool,
+}
+
+/// Hook deciding whether a redirect is followed.
+#[derive(Clone)]
+pub(crate) struct RedirectHook(pub(crate) RedirectHookFn);
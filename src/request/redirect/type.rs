@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared callback approving or denying a redirect hop.
+pub(crate) type RedirectHookFn = Arc<dyn Fn(&RedirectHop) -> bool + Send + Sync>;
@@ -40,6 +40,81 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the HTTP method to PUT and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn put(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::Put);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to DELETE and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn delete(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::Delete);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to PATCH and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn patch(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::Patch);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to HEAD and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn head(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::Head);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to OPTIONS and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn options(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::Options);
+        self.url(url);
+        self
+    }
+
     /// Sets the request URL.
     ///
     /// # Arguments
@@ -54,26 +129,154 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the HTTP version token sent in the request line.
+    ///
+    /// Any `HttpVersion` is accepted, including `HttpVersion::Unknown` for nonstandard
+    /// tokens, which are passed through verbatim. HTTP/2 is sent as HTTP/1.1. Versions
+    /// that cannot be sent as an HTTP/1.x request line (HTTP/0.9, HTTP/3, or malformed
+    /// tokens) are rejected with an error when the request is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpVersion` - The HTTP version to use.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn http_version(&mut self, http_version: HttpVersion) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.http_version = http_version;
+        }
+        self
+    }
+
     /// Forces HTTP/1.1 protocol version.
     ///
+    /// Shorthand for `http_version(HttpVersion::Http1_1)`.
+    ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
     pub fn http1_1_only(&mut self) -> &mut Self {
+        self.http_version(HttpVersion::Http1_1)
+    }
+
+    /// Requests HTTP/2, falling back to HTTP/1.1.
+    ///
+    /// Shorthand for `http_version(HttpVersion::Http2)`. HTTP/2 framing is not
+    /// implemented, so TLS connections negotiate `http/1.1` through ALPN and requests
+    /// are sent as valid HTTP/1.1 messages.
+    /// Use `inspect_tls` to check whether a server offers HTTP/2.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn http2_only(&mut self) -> &mut Self {
+        self.http_version(HttpVersion::Http2)
+    }
+
+    /// Adds query parameters to the request URL.
+    ///
+    /// Names and values are percent-encoded and appended, in order, to any query
+    /// already present in the URL. Parameters accumulate across calls.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(K, V)]` - The query parameter names and values.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn query<K, V>(&mut self, params: &[(K, V)]) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.http_version = HttpVersion::Http1_1;
+            config.query.extend(
+                params
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string())),
+            );
         }
         self
     }
 
-    /// Forces HTTP/2 protocol version.
+    /// Adds query parameters sent with every request built by this builder.
+    ///
+    /// Unlike `query`, these parameters are kept after a request is built, so a builder
+    /// reused for several requests adds them to each one, for example an API key. They
+    /// come before the request's own parameters, and a parameter the request sets with
+    /// `query` replaces the default of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(K, V)]` - The parameter names and values.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn http2_only(&mut self) -> &mut Self {
+    pub fn default_query<K, V>(&mut self, params: &[(K, V)]) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.default_query.extend(
+            params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        self
+    }
+
+    /// Sets the value of a `{name}` placeholder in the request URL.
+    ///
+    /// The value is percent-encoded, so it always stays within its path segment. A
+    /// placeholder left without a value fails the request with an error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The placeholder name, without braces.
+    /// - `V` - The value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn path_param<V: ToString>(&mut self, name: &str, value: V) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config
+                .path_params
+                .retain(|(key, _): &(String, String)| key != name);
+            config
+                .path_params
+                .push((name.to_owned(), value.to_string()));
+        }
+        self
+    }
+
+    /// Adds query parameters from a map to the request URL.
+    ///
+    /// Names and values are percent-encoded and appended to any query already
+    /// present in the URL. The order of the parameters follows the map iteration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `HashMapXxHash3_64<K, V>` - The query parameters.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn query_map<K, V>(&mut self, params: HashMapXxHash3_64<K, V>) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.http_version = HttpVersion::Http2;
+            config.query.extend(
+                params
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string())),
+            );
         }
         self
     }
@@ -108,6 +311,9 @@ impl RequestBuilder {
                 if found_existing && let Some(existing_key) = existing_key {
                     tmp_header.remove(&existing_key);
                 }
+                if let Ok(mut config) = self.http_request.config.write() {
+                    config.push_header_order(&key_str);
+                }
                 let mut value_deque: VecDeque<String> = VecDeque::new();
                 value_deque.push_front(value_str);
                 tmp_header.insert(key_str, value_deque);
@@ -116,194 +322,906 @@ impl RequestBuilder {
         self
     }
 
-    /// Sets JSON request body.
+    /// Adds a request header value, keeping the values already set for the name.
+    ///
+    /// Unlike `headers`, which replaces a header, every value is sent on its own
+    /// header line, in the order it was added. `Cookie` values are joined into one
+    /// line separated by `; `, as servers expect a single `Cookie` header.
     ///
     /// # Arguments
     ///
-    /// - `Value` - The JSON body data.
+    /// - `K` - The header name (must implement ToString).
+    /// - `V` - The header value (must implement ToString).
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn json(&mut self, body: serde_json::Value) -> &mut Self {
-        if let serde_json::Value::Object(map) = body {
-            let mut res_body: HashMapXxHash3_64<String, serde_json::Value> = hash_map_xx_hash3_64();
-            for (k, v) in map.iter() {
-                res_body.insert(k.to_string(), v.clone());
+    pub fn append_header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        let key_str: String = key.to_string();
+        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
+            let existing_key: String = tmp_header
+                .keys()
+                .find(|existing_key: &&String| existing_key.eq_ignore_ascii_case(&key_str))
+                .cloned()
+                .unwrap_or_else(|| key_str.clone());
+            tmp_header
+                .entry(existing_key)
+                .or_default()
+                .push_back(value.to_string());
+            if let Ok(mut config) = self.http_request.config.write() {
+                config.push_header_order(&key_str);
             }
-            self.http_request.body = Arc::new(Body::Json(res_body));
         }
         self
     }
 
-    /// Sets plain text request body.
+    /// Sets the `Accept` header to a media type.
     ///
     /// # Arguments
     ///
-    /// - `T` - The text body data (must implement ToString).
+    /// - `Mime` - The accepted media type.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn text<T: ToString>(&mut self, body: T) -> &mut Self {
-        self.http_request.body = Arc::new(Body::Text(body.to_string()));
-        self
+    pub fn accept(&mut self, mime: Mime) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(ACCEPT, mime.to_string());
+        self.headers(header)
     }
 
-    /// Sets binary request body.
+    /// Sets the `Accept` header to weighted media ranges for content negotiation.
     ///
     /// # Arguments
     ///
-    /// - `T` - The binary body data (must implement Into<Vec<u8>>).
+    /// - `&[(&str, f32)]` - The media ranges and their quality values, such as `("text/html", 0.5)`.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
-        self.http_request.body = Arc::new(Body::Binary(body.into()));
-        self
+    pub fn accepts(&mut self, accepts: &[(&str, f32)]) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(ACCEPT, Mime::format_accept(accepts));
+        self.headers(header)
     }
 
-    /// Sets the timeout value for the current connection.
+    /// Requests the bytes from `start` to `end` inclusive with a `Range` header.
     ///
-    /// This method sets the timeout duration for the connection, which is used to determine
-    /// how long the system should wait for a response before considering the connection attempt
-    /// as failed. The timeout value is stored in an `Arc` to allow it to be shared safely across
-    /// multiple threads if needed.
+    /// Range requests do not advertise `Accept-Encoding`, so the offsets refer to the
+    /// bytes of the representation rather than of a compressed transfer. Check
+    /// `HttpResponseBinary::is_partial_content` on the response: a server ignoring
+    /// the range answers `200` with the full body.
     ///
     /// # Arguments
     ///
-    /// - `u64` - The timeout duration in milliseconds. This value will be used to configure the
-    ///   connection timeout.
+    /// - `u64` - The offset of the first byte.
+    /// - `u64` - The offset of the last byte, inclusive.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.timeout = timeout;
-        }
-        self
+    pub fn range(&mut self, start: u64, end: u64) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(RANGE, format!("{BYTES_UNIT}={start}-{end}"));
+        self.headers(header)
     }
 
-    /// Enables HTTP redirection for the request.
+    /// Requests the bytes from `start` to the end of the representation with a `Range` header.
     ///
-    /// This method sets the `redirect` property of the `http_request` to `true`.
+    /// Behaves like `range`, for resuming a download at an offset.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The offset of the first byte.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn redirect(&mut self) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.redirect = true;
-        }
-        self
+    pub fn range_from(&mut self, start: u64) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(RANGE, format!("{BYTES_UNIT}={start}-"));
+        self.headers(header)
     }
 
-    /// Unenables HTTP redirection for the request.
+    /// Sets the `Content-Type` header to a media type.
     ///
-    /// This method sets the `redirect` property of the `http_request` to `false`.
+    /// # Arguments
+    ///
+    /// - `Mime` - The media type of the request body.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn unredirect(&mut self) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.redirect = false;
-        };
-        self
+    pub fn content_type(&mut self, mime: Mime) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+        header.insert(CONTENT_TYPE, mime.to_string());
+        self.headers(header)
     }
 
-    /// Sets the maximum number of allowed redirections for the HTTP request.
-    ///
-    /// This method updates the `max_redirect_times` field in the configuration and returns a mutable
-    /// reference to `self` to enable method chaining.
+    /// Sets JSON request body.
     ///
     /// # Arguments
     ///
-    /// - `usize` - The maximum number of redirections allowed. A value of `0` disables redirection.
+    /// - `Value` - The JSON body data.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance for method chaining.
-    ///
-    /// # Notes
-    ///
-    /// Ensure that the value provided to `num` is within a valid range. Excessively high values
-    /// may lead to performance issues or unintended behavior.
-    pub fn max_redirect_times(&mut self, num: usize) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.max_redirect_times = num;
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    #[cfg(feature = "json")]
+    pub fn json(&mut self, body: serde_json::Value) -> &mut Self {
+        if let serde_json::Value::Object(map) = body {
+            let mut res_body: HashMapXxHash3_64<String, serde_json::Value> = hash_map_xx_hash3_64();
+            for (k, v) in map.iter() {
+                res_body.insert(k.to_string(), v.clone());
+            }
+            self.http_request.body = Arc::new(Body::Json(res_body));
         }
         self
     }
 
-    /// Sets the buffer size for the HTTP request configuration.
-    ///
-    /// This method allows you to set the size of the buffer used for reading
-    /// the HTTP response. It modifies the `buffer` field of the HTTP request's
-    /// configuration, which will be used when processing the response data.
+    /// Sets plain text request body.
     ///
     /// # Arguments
     ///
-    /// - `usize` - The size of the buffer to be used, in bytes.
+    /// - `T` - The text body data (must implement ToString).
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - Returns a mutable reference to `self`, allowing for method chaining.
-    pub fn buffer(&mut self, buffer: usize) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.buffer = buffer;
-        }
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn text<T: ToString>(&mut self, body: T) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Text(body.to_string()));
         self
     }
 
-    /// Enables automatic response decoding.
+    /// Sets binary request body.
+    ///
+    /// # Arguments
     ///
-    /// When enabled, the response body will be automatically decompressed if it is encoded
-    /// using a supported compression format.
+    /// - `T` - The binary body data (must implement Into<Vec<u8>>).
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn decode(&mut self) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.decode = true;
-        }
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Binary(body.into()));
         self
     }
 
-    /// Disables automatic response decoding.
+    /// Compresses outgoing request bodies with a content coding.
+    ///
+    /// Non-empty bodies are compressed before sending and the `Content-Encoding`
+    /// header is set to the coding. Bodies are sent as they are if the request
+    /// already sets `Content-Encoding`, and bodies streamed from a reader are never
+    /// compressed. `Compress::Unknown` turns compression off.
+    ///
+    /// # Arguments
     ///
-    /// When disabled, the response body will not be automatically decompressed,
-    /// and the raw encoded data will be returned as-is.
+    /// - `Compress` - The content coding, such as `Compress::Gzip`.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn undecode(&mut self) -> &mut Self {
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn compress_request_body(&mut self, compress: Compress) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.decode = false;
+            config.request_compression = Some(compress);
         }
         self
     }
 
-    /// Sets an HTTP proxy for the request.
+    /// Writes large response bodies to a temporary file instead of memory.
     ///
-    /// This method configures the request to use an HTTP proxy server.
+    /// Buffered sends read the body into memory until it grows past the threshold,
+    /// then move it to a file in the system temporary directory. A spooled response
+    /// has an empty in-memory body and is not decoded; read it with
+    /// `HttpResponseBinary::open_body` or `get_spool_path`. The file is removed when
+    /// the response is dropped.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `usize` - The largest body in bytes kept in memory.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn spool_to_disk(&mut self, threshold_bytes: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.spool_threshold = Some(threshold_bytes);
+        }
+        self
+    }
+
+    /// Sets a URL-encoded form request body from a serializable value.
+    ///
+    /// The value is serialized as `application/x-www-form-urlencoded`, so it must be
+    /// a struct, map, or sequence of pairs with scalar values, and the `Content-Type`
+    /// header is set accordingly, replacing any existing value. A value that cannot
+    /// be serialized this way fails the send with the serialization error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The form fields (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn form<T: Serialize + ?Sized>(&mut self, form: &T) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+        header.insert(CONTENT_TYPE, APPLICATION_X_WWW_FORM_URLENCODED);
+        self.headers(header);
+        self.http_request.body = Arc::new(match serde_urlencoded::to_string(form) {
+            Ok(encoded) => Body::Binary(encoded.into_bytes()),
+            Err(error) => Body::Invalid(format!("Invalid form body: {error}")),
+        });
+        self
+    }
+
+    /// Sets a URL-encoded form request body from a map of fields.
+    ///
+    /// Fields are encoded in order of their names, so the body does not depend on
+    /// the iteration order of the map. Otherwise behaves like `form`.
+    ///
+    /// # Arguments
+    ///
+    /// - `HashMapXxHash3_64<K, V>` - The form fields (keys and values must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn form_map<K, V>(&mut self, form: HashMapXxHash3_64<K, V>) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        let mut fields: Vec<(String, String)> = form
+            .iter()
+            .map(|(key, value): (&K, &V)| (key.to_string(), value.to_string()))
+            .collect();
+        fields.sort();
+        self.form(&fields)
+    }
+
+    /// Sets a `multipart/form-data` request body.
+    ///
+    /// The form is encoded with its boundary, and the `Content-Type` header is set
+    /// to `multipart/form-data` with that boundary, replacing any existing value.
+    ///
+    /// # Arguments
+    ///
+    /// - `MultipartForm` - The form to send.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn multipart(&mut self, form: MultipartForm) -> &mut Self {
+        self.content_type(form.get_content_type());
+        self.body(form.encode())
+    }
+
+    /// Uploads a file as the only field of a `multipart/form-data` body.
+    ///
+    /// The file name sent to the server is the last component of the path, and the
+    /// content type is inferred from its extension, falling back to
+    /// `application/octet-stream`. The file is streamed when the request is sent
+    /// rather than read into memory, so an unreadable file fails the send. The
+    /// `Content-Length` header is set if the file size can be determined.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `P` - The path of the file (must implement AsRef<Path>).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn upload_file<P: AsRef<Path>>(&mut self, field_name: &str, path: P) -> &mut Self {
+        let path: PathBuf = path.as_ref().to_path_buf();
+        let filename: String = path
+            .file_name()
+            .map(|filename| filename.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let content_type: String = path
+            .extension()
+            .and_then(|extension| Mime::from_extension(&extension.to_string_lossy()))
+            .map_or(APPLICATION_OCTET_STREAM.to_owned(), |mime: Mime| {
+                mime.to_string()
+            });
+        let form: MultipartForm = MultipartForm::new();
+        let head: Vec<u8> = form
+            .encode_part_head(field_name, Some(&filename), Some(&content_type))
+            .into_bytes();
+        let mut tail: Vec<u8> = HTTP_BR_BYTES.to_vec();
+        tail.extend_from_slice(form.encode_end().as_bytes());
+        if let Ok(metadata) = fs::metadata(&path) {
+            let length: u64 = head.len() as u64 + metadata.len() + tail.len() as u64;
+            let mut header: HashMapXxHash3_64<&str, String> = hash_map_xx_hash3_64();
+            header.insert(CONTENT_LENGTH, length.to_string());
+            self.headers(header);
+        }
+        self.content_type(form.get_content_type());
+        let file: MultipartFileReader = MultipartFileReader { path, file: None };
+        let body: MultipartFileBody =
+            Read::chain(Read::chain(Cursor::new(head), file), Cursor::new(tail));
+        self.body_reader(body)
+    }
+
+    /// Sets a request body streamed from a blocking reader.
+    ///
+    /// The body is read in blocks of the buffer size and written as it is read,
+    /// so large uploads are not held in memory. It is sent with the `Content-Length`
+    /// header if one is set, and with chunked transfer encoding otherwise. The reader
+    /// can only be sent once, so a request redirected with its body fails.
+    ///
+    /// # Arguments
+    ///
+    /// - `R` - The reader producing the body (must implement Read + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn body_reader<R: Read + Send + 'static>(&mut self, reader: R) -> &mut Self {
+        self.http_request.body = Arc::new(Body::Reader(BodyReaderSource::new(reader)));
+        self
+    }
+
+    /// Sets a request body streamed from an asynchronous reader.
+    ///
+    /// Behaves like `body_reader`, but can only be sent by asynchronous requests.
+    ///
+    /// # Arguments
+    ///
+    /// - `R` - The reader producing the body (must implement AsyncRead + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    #[cfg(feature = "async")]
+    pub fn body_async_reader<R: AsyncRead + Send + 'static>(&mut self, reader: R) -> &mut Self {
+        self.http_request.body = Arc::new(Body::AsyncReader(AsyncBodyReaderSource::new(reader)));
+        self
+    }
+
+    /// Adds trailer fields sent after a streamed request body.
+    ///
+    /// Trailers are only sent when a `body_reader` or `body_async_reader` body goes out
+    /// with chunked transfer encoding; a `Trailer` header announcing their names is added
+    /// unless one is set. They are ignored for bodies sent with a `Content-Length`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(K, V)]` - The trailer field names and values.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn trailers<K, V>(&mut self, trailers: &[(K, V)]) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.trailers.extend(
+                trailers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string())),
+            );
+        }
+        self
+    }
+
+    /// Sets a binary protobuf request body.
+    ///
+    /// The bytes are sent untouched and the `Content-Type` header is set to
+    /// `application/x-protobuf`, replacing any existing value.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The encoded protobuf bytes (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn protobuf<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
+        let mut header: HashMapXxHash3_64<&str, &str> = hash_map_xx_hash3_64();
+        header.insert(CONTENT_TYPE, APPLICATION_X_PROTOBUF);
+        self.headers(header);
+        self.body(body)
+    }
+
+    /// Encodes a protobuf message and sets it as the request body.
+    ///
+    /// Shorthand for `protobuf(message.encode_to_vec())`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&M` - The protobuf message to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    #[cfg(feature = "prost")]
+    pub fn protobuf_message<M: ProtobufMessage>(&mut self, message: &M) -> &mut Self {
+        self.protobuf(message.encode_to_vec())
+    }
+
+    /// Sets the timeout value for the current connection.
+    ///
+    /// This method sets the timeout duration for the connection, which is used to determine
+    /// how long the system should wait for a response before considering the connection attempt
+    /// as failed. The timeout value is stored in an `Arc` to allow it to be shared safely across
+    /// multiple threads if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout duration in milliseconds. This value will be used to configure the
+    ///   connection timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.timeout = timeout;
+        }
+        self
+    }
+
+    /// Enables HTTP redirection for the request.
+    ///
+    /// This method sets the `redirect` property of the `http_request` to `true`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn redirect(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect = true;
+        }
+        self
+    }
+
+    /// Unenables HTTP redirection for the request.
+    ///
+    /// This method sets the `redirect` property of the `http_request` to `false`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn unredirect(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect = false;
+        };
+        self
+    }
+
+    /// Sets the maximum number of allowed redirections for the HTTP request.
+    ///
+    /// This method updates the `max_redirect_times` field in the configuration and returns a mutable
+    /// reference to `self` to enable method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of redirections allowed. A value of `0` disables redirection.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance for method chaining.
+    ///
+    /// # Notes
+    ///
+    /// Ensure that the value provided to `num` is within a valid range. Excessively high values
+    /// may lead to performance issues or unintended behavior.
+    pub fn max_redirect_times(&mut self, num: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_redirect_times = num;
+        }
+        self
+    }
+
+    /// Sets how redirects are followed.
+    ///
+    /// The policy decides whether the method is switched to GET on 301, 302, and
+    /// 303, whether credential headers are dropped on hops to another origin, and
+    /// whether redirects from HTTPS to HTTP are followed.
+    ///
+    /// # Arguments
+    ///
+    /// - `RedirectPolicy` - The redirect policy.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn redirect_policy(&mut self, redirect_policy: RedirectPolicy) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect_policy = redirect_policy;
+        }
+        self
+    }
+
+    /// Registers a hook approving or denying each redirect hop.
+    ///
+    /// The hook runs after the redirect settings and policy accepted a hop, and a
+    /// hop it returns false for fails with `RedirectErrorKind::Denied`. A later call
+    /// replaces an earlier hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The hook, called with the redirect about to be followed.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn on_redirect<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&RedirectHop) -> bool + Send + Sync + 'static,
+    {
+        self.http_request.on_redirect = Some(RedirectHook(Arc::new(hook)));
+        self
+    }
+
+    /// Registers a hook reporting the progress of the request body upload.
+    ///
+    /// The hook is called with the body bytes written so far and the body size,
+    /// which is None for a reader body sent with chunked encoding. Buffered bodies
+    /// are written in blocks of the configured buffer size while a hook is set. A
+    /// later call replaces an earlier hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The hook, called after each block of the body is written.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn on_upload_progress<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(usize, Option<usize>) + Send + Sync + 'static,
+    {
+        self.http_request.on_upload_progress = Some(ProgressHook(Arc::new(hook)));
+        self
+    }
+
+    /// Registers a hook reporting the progress of the response body download.
+    ///
+    /// The hook is called with the body bytes read so far and the `Content-Length`
+    /// of the response, which is None for a chunked body. A later call replaces an
+    /// earlier hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The hook, called after each read from the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn on_download_progress<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(usize, Option<usize>) + Send + Sync + 'static,
+    {
+        self.http_request.on_download_progress = Some(ProgressHook(Arc::new(hook)));
+        self
+    }
+
+    /// Registers a hook receiving the timings and transfer sizes of each request.
+    ///
+    /// The hook is called once a request sent with `send` or `send_stream` has
+    /// succeeded, after redirects were followed. The same metrics are available
+    /// through `get_metrics` on the response. A later call replaces an earlier hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The hook, called with the metrics of the completed request.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn on_metrics<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&RequestMetrics) + Send + Sync + 'static,
+    {
+        self.http_request.on_metrics = Some(MetricsHook(Arc::new(hook)));
+        self
+    }
+
+    /// Sets the TLS server name, sent as SNI and verified against the server certificate.
+    ///
+    /// By default the host of the URL is used. Set it when connecting by IP address,
+    /// or through a DNS override, to a server whose certificate is issued for a DNS
+    /// name. The `Host` header is not affected. Applies to the target server, also
+    /// when it is reached through a proxy tunnel, but not to an HTTPS proxy itself.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The server name, such as `internal.example.com`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn tls_server_name(&mut self, server_name: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls_server_name = Some(server_name.to_string());
+        }
+        self
+    }
+
+    /// Attaches a token that aborts the request when cancelled.
+    ///
+    /// Cancelling the token stops an asynchronous `send` or `send_stream` at its next
+    /// await point, whether it is connecting, performing the TLS handshake, writing
+    /// the request, or reading the response, and the send fails with
+    /// `RequestError::RequestAborted`. The connection is closed rather than reused.
+    /// The body of a streamed response is not covered once `send_stream` returned.
+    /// Blocking sends are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CancellationToken` - The token, shared with the caller that may cancel it.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    #[cfg(feature = "async")]
+    pub fn cancellation_token(&mut self, token: &CancellationToken) -> &mut Self {
+        self.http_request.cancellation = Some(token.clone());
+        self
+    }
+
+    /// Sets the buffer size for the HTTP request configuration.
+    ///
+    /// This method allows you to set the size of the buffer used for reading
+    /// the HTTP response. It modifies the `buffer` field of the HTTP request's
+    /// configuration, which will be used when processing the response data.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The size of the buffer to be used, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - Returns a mutable reference to `self`, allowing for method chaining.
+    pub fn buffer(&mut self, buffer: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.buffer = buffer;
+        }
+        self
+    }
+
+    /// Enables automatic response decoding.
+    ///
+    /// Decoding is enabled by default, so this only undoes an earlier `undecode`.
+    /// While enabled, the response body is decompressed if it is encoded with a
+    /// supported format, and requests whose body is read in full advertise the codings
+    /// listed by `CodecRegistry::get_encodings` in `Accept-Encoding`, unless the header
+    /// is set or removed.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn decode(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.decode = true;
+        }
+        self
+    }
+
+    /// Disables automatic response decoding, which is enabled by default.
+    ///
+    /// When disabled, `get_body` returns the body as sent by the server, while
+    /// converting the response to text still decodes it on demand.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn undecode(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.decode = false;
+        }
+        self
+    }
+
+    /// Registers a hook invoked before every network attempt of the request.
+    ///
+    /// The hook receives the attempt number, starting at 1, and mutable access to
+    /// the request, and runs again for each redirect hop and for a resend after a
+    /// pooled proxy tunnel turned out stale. This lets per-attempt headers such as
+    /// `X-Request-Id` or `traceparent` differ between attempts. A later call
+    /// replaces an earlier hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The hook, called with the attempt number and the request.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn on_attempt<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(usize, &mut RequestAttempt<'_>) + Send + Sync + 'static,
+    {
+        self.http_request.on_attempt = Some(AttemptHook(Arc::new(hook)));
+        self
+    }
+
+    /// Adds middleware wrapping synchronous sends of the request.
+    ///
+    /// Middleware run in the order they are added, so the first one added sees the
+    /// request first and the response last. Middleware can return a response without
+    /// calling the rest of the chain, for example to serve a cache hit, stub a
+    /// response in tests, or deny a request by policy. They apply to `send`, not to
+    /// streaming sends.
+    ///
+    /// # Arguments
+    ///
+    /// - `M` - The middleware (must implement Middleware).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn middleware<M: Middleware + 'static>(&mut self, middleware: M) -> &mut Self {
+        self.http_request.middleware.sync.push(Arc::new(middleware));
+        self
+    }
+
+    /// Adds middleware wrapping asynchronous sends of the request.
+    ///
+    /// Behaves like `middleware`, for requests built with `build_async`.
+    ///
+    /// # Arguments
+    ///
+    /// - `M` - The middleware (must implement AsyncMiddleware).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "async")]
+    pub fn async_middleware<M: AsyncMiddleware + 'static>(&mut self, middleware: M) -> &mut Self {
+        self.http_request
+            .middleware
+            .r#async
+            .push(Arc::new(middleware));
+        self
+    }
+
+    /// Adds an interceptor running around every network attempt of the request.
+    ///
+    /// Interceptors run in the order they are added, before each attempt is
+    /// serialized and after its response is parsed, including redirect hops. They
+    /// apply to synchronous, asynchronous, and streaming sends alike.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn RequestInterceptor>` - The interceptor.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn with_interceptor(&mut self, interceptor: Arc<dyn RequestInterceptor>) -> &mut Self {
+        self.http_request.middleware.interceptors.push(interceptor);
+        self
+    }
+
+    /// Sets the handler answering the authentication challenges of 401 responses.
+    ///
+    /// On a 401 response the handler receives the parsed `WWW-Authenticate`
+    /// challenges and may set credentials on the request, which is then sent once
+    /// more. This applies to every send, including redirect hops and streaming sends.
+    /// A later call replaces an earlier handler.
+    ///
+    /// # Arguments
+    ///
+    /// - `H` - The handler (must implement AuthHandler).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn auth_handler<H: AuthHandler + 'static>(&mut self, handler: H) -> &mut Self {
+        self.http_request.auth_handler = Some(AuthHandlerHook(Arc::new(handler)));
+        self
+    }
+
+    /// Propagates a W3C trace context with the request.
+    ///
+    /// Every attempt sends a `traceparent` header naming a new child span of the
+    /// given context, along with its `tracestate`.
+    ///
+    /// # Arguments
+    ///
+    /// - `TraceContext` - The parent context of the request spans.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "trace-context")]
+    pub fn trace_context(&mut self, trace_context: TraceContext) -> &mut Self {
+        self.http_request.trace_context = Some(trace_context);
+        self
+    }
+
+    /// Propagates the ambient W3C trace context with the request.
+    ///
+    /// Uses the context made current by `TraceContext::scope` on this thread, or
+    /// starts a new trace if there is none.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "trace-context")]
+    pub fn propagate_trace(&mut self) -> &mut Self {
+        self.trace_context(TraceContext::current().unwrap_or_else(TraceContext::new_root))
+    }
+
+    /// Sets an HTTP proxy for the request.
+    ///
+    /// This method configures the request to use an HTTP proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Http,
+                host: host.to_string(),
+                port,
+                username: None,
+                password: None,
+            });
+        }
+        self
+    }
+
+    /// Sets an HTTPS proxy for the request.
+    ///
+    /// This method configures the request to use an HTTPS proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Https,
+                host: host.to_string(),
+                port,
+                username: None,
+                password: None,
+            });
+        }
+        self
+    }
+
+    /// Sets a SOCKS5 proxy for the request.
+    ///
+    /// This method configures the request to use a SOCKS5 proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
             config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Http,
+                proxy_type: ProxyType::Socks5,
                 host: host.to_string(),
                 port,
                 username: None,
@@ -313,155 +1231,537 @@ impl RequestBuilder {
         self
     }
 
-    /// Sets an HTTPS proxy for the request.
+    /// Sets an HTTP proxy with authentication for the request.
+    ///
+    /// This method configures the request to use an HTTP proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `&str` - The username for proxy authentication.
+    /// - `&str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn http_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Http,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Sets an HTTPS proxy with authentication for the request.
+    ///
+    /// This method configures the request to use an HTTPS proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `&str` - The username for proxy authentication.
+    /// - `&str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn https_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Https,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Sets a SOCKS5 proxy with authentication for the request.
+    ///
+    /// This method configures the request to use a SOCKS5 proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `&str` - The username for proxy authentication.
+    /// - `&str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn socks5_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Socks5,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Sets whether responses with ambiguous framing are rejected.
+    ///
+    /// When enabled, a response is rejected with `RequestError::InvalidResponse` if
+    /// its header section uses obsolete line folding, carries conflicting or invalid
+    /// `Content-Length` values, or carries both `Content-Length` and
+    /// `Transfer-Encoding`. Disabled by default, in which case such responses are
+    /// parsed on a best-effort basis.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to enable strict parsing.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn strict_parsing(&mut self, strict: bool) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.strict_parsing = strict;
+        }
+        self
+    }
+
+    /// Sets whether identical concurrent GET requests share one network request.
+    ///
+    /// While a GET request without a body is in flight, requests with the same URL,
+    /// query parameters, and headers that also enable single-flight wait for it
+    /// instead of sending their own, and all of them receive a copy of its response
+    /// or error. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to share identical concurrent requests.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn single_flight(&mut self, enabled: bool) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.single_flight = enabled;
+        }
+        self
+    }
+
+    /// Sets whether the path and query of the URL are percent-encoded before sending.
+    ///
+    /// When enabled, characters that are not allowed in a request target, such as
+    /// spaces and non-ASCII characters, are percent-encoded, while existing `%XX`
+    /// escapes are kept as they are. Disable it to send a pre-encoded URL verbatim.
+    /// Internationalized host names are always converted to Punycode for the `Host`
+    /// header, TLS server name, and DNS lookup. Enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to percent-encode the path and query.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn encode_url(&mut self, enabled: bool) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.encode_url = enabled;
+        }
+        self
+    }
+
+    /// Waits for the server to accept the request headers before sending the body.
+    ///
+    /// Requests with a body are sent with `Expect: 100-continue`, and the body is
+    /// written only once the server answers `100 Continue` or a second passes
+    /// without an answer. If the server rejects the request first, for example with
+    /// `417 Expectation Failed` or `413 Content Too Large`, the body is not sent and
+    /// that response is returned. Applies to requests sent with `send`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn expect_continue(&mut self) -> &mut Self {
+        self.expect_continue_timeout(DEFAULT_EXPECT_CONTINUE_TIMEOUT_MS)
+    }
+
+    /// Waits for the server to accept the request headers before sending the body,
+    /// for at most the given time.
+    ///
+    /// Behaves like `expect_continue`, with the body sent once the timeout elapses
+    /// without an answer from the server.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The longest time to wait for `100 Continue`, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn expect_continue_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.expect_continue = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets the maximum number of header lines accepted in a response.
+    ///
+    /// A response announcing more headers is rejected with
+    /// `RequestError::TooManyHeaders` while its header section is still being read.
+    /// Defaults to 100.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn max_response_headers(&mut self, max_response_headers: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_response_headers = max_response_headers;
+        }
+        self
+    }
+
+    /// Sets the maximum size of a response header section.
+    ///
+    /// A response whose status line and headers exceed this size is rejected with
+    /// `RequestError::HeaderSectionTooLarge` once the limit is crossed, without
+    /// reading the rest of the section. Defaults to 64 KiB.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum header section size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn max_response_header_size(&mut self, max_response_header_size: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_response_header_size = max_response_header_size;
+        }
+        self
+    }
+
+    /// Sets the maximum length of a response status or header line.
+    ///
+    /// A response with a longer line is rejected with `RequestError::HeaderLineTooLong`
+    /// as soon as the line grows past the limit, without waiting for its end.
+    /// Defaults to 8 KiB.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum line length in bytes, excluding the line break.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn max_response_header_line(&mut self, max_response_header_line: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_response_header_line = max_response_header_line;
+        }
+        self
+    }
+
+    /// Sets the maximum size of a response body.
+    ///
+    /// A response announcing a larger `Content-Length` is rejected with
+    /// `RequestError::ContentLengthTooLarge` before its body is read. A body without
+    /// a length, such as a chunked one, is rejected with `RequestError::BodyTooLarge`
+    /// once the bytes received for it, chunk framing included, cross the limit. This
+    /// applies to streamed bodies as they are read. Unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum body size in bytes, before content decoding.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn max_response_body(&mut self, max_response_body: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_response_body = Some(max_response_body);
+        }
+        self
+    }
+
+    /// Sets the Host header value independently of the URL.
+    ///
+    /// The connection, including the TLS server name, still targets the host of the
+    /// URL, while the automatically added `Host` header carries this value instead.
+    /// The value must be a host with an optional port; values that could inject
+    /// header lines or alter the request target make sending fail with an error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The Host header value.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn host_header(&mut self, host: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.host_header = Some(host.to_owned());
+        }
+        self
+    }
+
+    /// Connects to the given addresses instead of resolving the host of the URL.
+    ///
+    /// Lets callers supply addresses from their own resolver and skip the blocking
+    /// resolution done when connecting. The port still comes from the URL, and the
+    /// host still names the server for the `Host` header and TLS. Not used when the
+    /// request goes through a proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<IpAddr>` - The addresses to try in order.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn with_resolved_addrs(&mut self, addrs: Vec<IpAddr>) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.resolved_addrs = addrs;
+        }
+        self
+    }
+
+    /// Connects to an address instead of resolving a host, like curl's `--resolve`.
+    ///
+    /// Applies to every connection to the host on any port, including redirect
+    /// targets and proxy hosts. The host still names the server for the `Host`
+    /// header and TLS. Calling it again for the same host adds another address to try.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname, matched case-insensitively.
+    /// - `SocketAddr` - The address to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn resolve(&mut self, host: &str, addr: SocketAddr) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.dns_overrides.push((host.to_ascii_lowercase(), addr));
+        }
+        self
+    }
+
+    /// Sets the resolver used instead of the system resolver.
     ///
-    /// This method configures the request to use an HTTPS proxy server.
+    /// The resolver is asked for every host the request connects to, including
+    /// proxy hosts, unless `resolve` or `with_resolved_addrs` supplies its addresses.
+    /// A later call replaces an earlier resolver.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `Arc<dyn Resolver>` - The resolver.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
-        if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Https,
-                host: host.to_string(),
-                port,
-                username: None,
-                password: None,
-            });
-        }
+    pub fn dns_resolver(&mut self, resolver: Arc<dyn Resolver>) -> &mut Self {
+        self.http_request.resolver = Some(ResolverHook(resolver));
         self
     }
 
-    /// Sets a SOCKS5 proxy for the request.
+    /// Sets the signer adding credentials to the request.
     ///
-    /// This method configures the request to use a SOCKS5 proxy server.
+    /// The signer runs right before each attempt is written, once the default
+    /// headers were added and the body was encoded, so it signs the exact bytes
+    /// sent. `HmacSha256Signer` is a reference implementation. A later call replaces
+    /// an earlier signer.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `Arc<dyn RequestSigner>` - The signer.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+    pub fn signer(&mut self, signer: Arc<dyn RequestSigner>) -> &mut Self {
+        self.http_request.signer = Some(SignerHook(signer));
+        self
+    }
+
+    /// Enables the response cache.
+    ///
+    /// GET responses are stored according to their `Cache-Control`, `Expires`, and
+    /// `Vary` headers. Fresh responses are returned without a network request; stale
+    /// ones are revalidated with `If-None-Match` and `If-Modified-Since`, and a
+    /// `304 Not Modified` answer returns the stored response. A request sent with
+    /// `Cache-Control: no-cache` or `max-age` limits which stored responses it
+    /// accepts, and `no-store` bypasses the cache. Streaming sends are not cached.
+    /// Responses marked `private` are never stored, nor are responses to requests
+    /// carrying `Authorization` or a signature unless the response allows it.
+    ///
+    /// # Arguments
+    ///
+    /// - `CachePolicy` - The cache policy; clone one policy to share its cache between requests.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn cache(&mut self, policy: CachePolicy) -> &mut Self {
+        self.http_request.cache = Some(policy);
+        self
+    }
+
+    /// Accepts any server certificate without verifying it.
+    ///
+    /// Expired, self-signed, and mismatched certificates are all accepted, for
+    /// direct connections, HTTPS proxies, and tunnels through a proxy alike. This
+    /// removes the protection TLS gives against impersonation and is meant only for
+    /// local development against servers with self-signed certificates.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn insecure_skip_tls_verify(&mut self) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Socks5,
-                host: host.to_string(),
-                port,
-                username: None,
-                password: None,
-            });
+            config.insecure_skip_tls_verify = true;
         }
         self
     }
 
-    /// Sets an HTTP proxy with authentication for the request.
+    /// Stops a header from being added automatically.
     ///
-    /// This method configures the request to use an HTTP proxy server with username and password authentication.
+    /// The `Host`, `Content-Length`, `Accept`, and `User-Agent` headers are added
+    /// to every request that does not set them. Removing one suppresses it for
+    /// services that reject it; a value set explicitly with `headers` is still sent.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `&str` - The username for proxy authentication.
-    /// - `&str` - The password for proxy authentication.
+    /// - `&str` - The header name, matched case-insensitively.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn http_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    pub fn remove_default_header(&mut self, key: &str) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Http,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            config
+                .removed_default_headers
+                .insert(key.to_ascii_lowercase());
         }
         self
     }
 
-    /// Sets an HTTPS proxy with authentication for the request.
+    /// Stops the `User-Agent` header from being added automatically.
     ///
-    /// This method configures the request to use an HTTPS proxy server with username and password authentication.
+    /// # Returns
     ///
-    /// # Arguments
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn no_user_agent(&mut self) -> &mut Self {
+        self.remove_default_header(USER_AGENT)
+    }
+
+    /// Keeps proxy tunnels alive and reuses them for later requests.
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `&str` - The username for proxy authentication.
-    /// - `&str` - The password for proxy authentication.
+    /// Established CONNECT tunnels, including the TLS session with the target, are
+    /// pooled by proxy and target once a response has been fully read, and picked up
+    /// by the next request through the same proxy to the same target. Synchronous
+    /// requests reuse tunnels opened on the same thread.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn https_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    #[cfg(feature = "proxy")]
+    pub fn proxy_keep_alive(&mut self) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Https,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            config.proxy_keep_alive = true;
         }
         self
     }
 
-    /// Sets a SOCKS5 proxy with authentication for the request.
+    /// Sends plain HTTP requests to an HTTP or HTTPS proxy in absolute form.
     ///
-    /// This method configures the request to use a SOCKS5 proxy server with username and password authentication.
+    /// Instead of opening a CONNECT tunnel, the request is written to the proxy with
+    /// the full URL as its request target, as some proxies refuse CONNECT to port 80.
+    /// Configured proxy credentials are sent in a `Proxy-Authorization` header with
+    /// every request. HTTPS targets are still tunneled through CONNECT.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    #[cfg(feature = "proxy")]
+    pub fn proxy_absolute_form(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy_absolute_form = true;
+        }
+        self
+    }
+
+    /// Sets the form of the request target sent in the request line.
+    ///
+    /// `RequestTarget::Absolute` sends the full URL, as proxies expect, and
+    /// `RequestTarget::Asterisk` sends `*` for server-wide OPTIONS requests; sending
+    /// it with another method fails. The default, `RequestTarget::Origin`, sends the
+    /// path and query of the URL.
     ///
     /// # Arguments
     ///
-    /// - `&str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `&str` - The username for proxy authentication.
-    /// - `&str` - The password for proxy authentication.
+    /// - `RequestTarget` - The request target form.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn socks5_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn request_target(&mut self, request_target: RequestTarget) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Socks5,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            config.request_target = request_target;
         }
         self
     }
 
+    /// Moves the current request into `builder` and resets the builder for the next request.
+    ///
+    /// Default query parameters are added to the request first, except those it sets itself.
+    fn finish_request(&mut self) {
+        if !self.default_query.is_empty()
+            && let Ok(mut config) = self.http_request.config.write()
+        {
+            let defaults: Vec<(String, String)> = self
+                .default_query
+                .iter()
+                .filter(|(key, _)| !config.query.iter().any(|(name, _)| name == key))
+                .cloned()
+                .collect();
+            config.query.splice(0..0, defaults);
+        }
+        self.builder = self.http_request.clone();
+        self.http_request = HttpRequest::default();
+    }
+
     /// Finalizes the builder and returns a fully constructed async `HttpRequest` instance.
     ///
     /// This method takes the current configuration stored in `http_request`, creates a new
@@ -471,9 +1771,9 @@ impl RequestBuilder {
     /// # Returns
     ///
     /// - `BoxAsyncRequestTrait` - Returns a fully constructed `BoxAsyncRequestTrait` instance based on the current builder state.
+    #[cfg(feature = "async")]
     pub fn build_async(&mut self) -> BoxAsyncRequestTrait {
-        self.builder = self.http_request.clone();
-        self.http_request = HttpRequest::default();
+        self.finish_request();
         Box::new(self.builder.clone())
     }
 
@@ -487,8 +1787,384 @@ impl RequestBuilder {
     ///
     /// - `BoxRequestTrait` - Returns a fully constructed `BoxRequestTrait` instance based on the current builder state.
     pub fn build_sync(&mut self) -> BoxRequestTrait {
-        self.builder = self.http_request.clone();
-        self.http_request = HttpRequest::default();
+        self.finish_request();
         Box::new(self.builder.clone())
     }
+
+    /// Finalizes the builder into an experimental HTTP/1.1 pipeline.
+    ///
+    /// The current request acts as a template: its URL fixes the origin, and its
+    /// headers and configuration apply to every queued request. Requests are then
+    /// queued on the returned pipeline with `get` or `head` and sent together over
+    /// a single connection.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpPipeline` - A pipeline using the current builder state as its template.
+    pub fn build_pipeline(&mut self) -> HttpPipeline {
+        self.finish_request();
+        HttpPipeline::new(self.builder.clone())
+    }
+
+    /// Finalizes the builder into a beacon for fire-and-forget events.
+    ///
+    /// The current request acts as a template: its headers and configuration apply
+    /// to every batch the beacon posts. Events are then queued on the returned beacon
+    /// with `send` and delivered in the background.
+    ///
+    /// # Returns
+    ///
+    /// - `Beacon` - A beacon using the current builder state as its template.
+    pub fn build_beacon(&mut self) -> Beacon {
+        self.finish_request();
+        Beacon::new(self.builder.clone())
+    }
+
+    /// Finalizes the builder into a paginator following a sequence of pages.
+    ///
+    /// The current request is sent first. After each successful response, the closure
+    /// returns the absolute URL of the next page, typically read from a cursor in the
+    /// response body, or None to stop. Every page request uses the headers and
+    /// configuration of the current request.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure returning the URL of the next page.
+    ///
+    /// # Returns
+    ///
+    /// - `Paginator` - A paginator using the current builder state as its first request.
+    pub fn paginate_with<F>(&mut self, next_page: F) -> Paginator
+    where
+        F: FnMut(&HttpResponseBinary) -> Option<String> + Send + 'static,
+    {
+        self.finish_request();
+        Paginator::new(self.builder.clone(), Box::new(next_page))
+    }
+
+    /// Finalizes the builder into a long poll repeatedly sending the current request.
+    ///
+    /// Polls that time out or are answered with 204 No Content are sent again right
+    /// away. After each event response, the closure returns the resume token read
+    /// from the response, or None to keep the previous one; later polls send it as the
+    /// named query parameter. Set a timeout slightly above the server's hold time so
+    /// an idle poll ends as an empty poll.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The name of the query parameter carrying the resume token.
+    /// - `F` - The closure extracting the resume token from an event response.
+    ///
+    /// # Returns
+    ///
+    /// - `LongPoll` - A long poll using the current builder state as its template.
+    pub fn build_long_poll<F>(&mut self, token_param: &str, resume_token: F) -> LongPoll
+    where
+        F: FnMut(&HttpResponseBinary) -> Option<String> + Send + 'static,
+    {
+        self.finish_request();
+        LongPoll::new(self.builder.clone(), token_param, Box::new(resume_token))
+    }
+
+    /// Finalizes the builder into a downloader fetching the current URL in concurrent
+    /// byte range segments.
+    ///
+    /// The headers and configuration of the request apply to every segment request,
+    /// except that segments are requested with `Accept-Encoding: identity` and not decoded.
+    /// Downloads run on the async client, so they must be awaited inside a Tokio runtime.
+    ///
+    /// # Returns
+    ///
+    /// - `SegmentedDownloader` - A downloader using the current builder state as its template.
+    #[cfg(feature = "async")]
+    pub fn build_segmented_download(&mut self) -> SegmentedDownloader {
+        self.finish_request();
+        SegmentedDownloader::new(self.builder.clone())
+    }
+
+    /// Creates a WebSocket builder upgrading the current request to a WebSocket.
+    ///
+    /// The URL of the request is used with `http` mapped to `ws` and `https` to
+    /// `wss`. The headers, timeout, buffer size, proxy, resolved addresses, and TLS
+    /// verification setting of the request carry over to the WebSocket, so an HTTP
+    /// client and its WebSocket connections are configured in one place. Headers
+    /// belonging to the WebSocket handshake, such as `Host` and `Upgrade`, are not
+    /// copied. `build_sync` and `build_async` on the returned builder yield a
+    /// `BoxWebSocketTrait` and a `BoxAsyncWebSocketTrait`, which perform the upgrade
+    /// handshake when first used. The builder itself is left unchanged and can keep
+    /// building HTTP requests.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketBuilder` - A WebSocket builder using the current URL and configuration.
+    #[cfg(feature = "websocket")]
+    pub fn websocket(&self) -> WebSocketBuilder {
+        let config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        let mut header: RequestHeaders = self.http_request.header.as_ref().clone();
+        header.retain(|key: &String, _| {
+            !WEBSOCKET_HANDSHAKE_HEADERS
+                .iter()
+                .any(|name: &&str| key.eq_ignore_ascii_case(name))
+        });
+        let url: String = self.http_request.get_url();
+        let websocket_url: String = match url.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case(HTTPS_LOWERCASE) => {
+                format!("wss://{rest}")
+            }
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case(HTTP_LOWERCASE) => {
+                format!("ws://{rest}")
+            }
+            _ => url.to_owned(),
+        };
+        let mut websocket_builder: WebSocketBuilder = WebSocketBuilder::new();
+        websocket_builder.connect(&websocket_url);
+        websocket_builder.websocket.header = Arc::new(header);
+        if let Ok(mut websocket_config) = websocket_builder.websocket.config.write() {
+            websocket_config.timeout = config.timeout;
+            websocket_config.buffer = config.buffer;
+            #[cfg(feature = "proxy")]
+            {
+                websocket_config.proxy = config.proxy;
+            }
+            websocket_config.resolved_addrs = config.resolved_addrs;
+            websocket_config.insecure_skip_tls_verify = config.insecure_skip_tls_verify;
+        }
+        websocket_builder
+    }
+
+    /// Gets a snapshot of the response counters of every host contacted.
+    ///
+    /// Responses are counted per `host:port` by status class, along with
+    /// connections that could not be established. The counters are shared by all
+    /// requests of the process, and the snapshot can be rendered for Prometheus
+    /// with `MetricsSnapshot::to_prometheus`.
+    ///
+    /// # Returns
+    ///
+    /// - `MetricsSnapshot` - The current counters.
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot::capture()
+    }
+
+    /// Describes the settings of the request being built as a JSON object.
+    ///
+    /// The snapshot is what `effective_config` returns on the built request, plus the
+    /// default query parameters of the builder, which are merged into the request
+    /// when it is built. Header values are left out and proxy passwords are
+    /// redacted.
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The settings, grouped by area.
+    #[cfg(feature = "json")]
+    pub fn effective_config(&self) -> Value {
+        let mut settings: Value = self.http_request.get_effective_config();
+        if let Value::Object(map) = &mut settings {
+            map.insert(
+                "default_query".to_string(),
+                serde_json::json!(self.default_query),
+            );
+        }
+        settings
+    }
+
+    /// Gets a snapshot of the requests currently being sent.
+    ///
+    /// Every request of the process is listed from the moment it is sent until its
+    /// response has been read, with the phase it is in: connecting, TLS handshake,
+    /// sending, waiting for the response, or reading it. Streamed responses leave
+    /// the list once their headers are read.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<InFlightRequest>` - The requests in flight, oldest first.
+    pub fn in_flight(&self) -> Vec<InFlightRequest> {
+        InFlightRequest::capture()
+    }
+
+    /// Resolves a host ahead of time and caches its addresses.
+    ///
+    /// Later requests to the host reuse the cached addresses for a minute instead of
+    /// resolving it again while connecting.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<IpAddr>, RequestError>` - The resolved addresses, or an error if resolution fails.
+    pub fn prefetch_dns(&self, host: &str) -> Result<Vec<IpAddr>, RequestError> {
+        SharedConnector::prefetch(host)
+    }
+
+    /// Resolves a host ahead of time without blocking and caches its addresses.
+    ///
+    /// The async counterpart of `prefetch_dns`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<IpAddr>, RequestError>` - The resolved addresses, or an error if resolution fails.
+    #[cfg(feature = "async")]
+    pub async fn prefetch_dns_async(&self, host: &str) -> Result<Vec<IpAddr>, RequestError> {
+        SharedConnector::prefetch_async(host).await
+    }
+
+    /// Probes a URL and reports whether it is reachable and healthy.
+    ///
+    /// Sends a GET request with the current builder settings and stops once the
+    /// response headers arrive, without reading the body. For HTTPS targets that
+    /// answered, a separate TLS handshake retrieves the server certificate to report
+    /// the days left before it expires. Failures are reported in the result instead
+    /// of as an error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL to probe.
+    /// - `ResponseStatusCode` - The status code that counts as healthy.
+    /// - `u64` - The timeout in milliseconds for the request and the TLS handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `HealthCheck` - The outcome of the probe.
+    pub fn health_check(
+        &mut self,
+        url: &str,
+        expected_status: ResponseStatusCode,
+        timeout: u64,
+    ) -> HealthCheck {
+        let mut request: BoxRequestTrait = self.get(url).timeout(timeout).build_sync();
+        let start: Instant = Instant::now();
+        let result: Result<HttpResponseStream, RequestError> = request.send_stream();
+        let latency: Duration = start.elapsed();
+        match result {
+            Ok(response) => HealthCheck {
+                reachable: true,
+                status: Some(response.get_status_code()),
+                expected_status,
+                latency,
+                tls_expiry_days: Self::get_tls_expiry_days(url, Duration::from_millis(timeout)),
+                error: None,
+            },
+            Err(error) => HealthCheck {
+                reachable: false,
+                status: None,
+                expected_status,
+                latency,
+                tls_expiry_days: None,
+                error: Some(error),
+            },
+        }
+    }
+
+    /// Retrieves the days left before the certificate of an HTTPS URL expires.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL whose server certificate is checked.
+    /// - `Duration` - The timeout for the TLS handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<i64>` - The whole days left, negative once expired, or `None` for
+    ///   non-HTTPS URLs or when the certificate cannot be retrieved or parsed.
+    #[cfg(feature = "tls")]
+    fn get_tls_expiry_days(url: &str, timeout: Duration) -> Option<i64> {
+        let url_obj: HttpUrlComponents = HttpUrlComponents::parse(url.to_string()).ok()?;
+        let protocol: String = url_obj.protocol.to_lowercase();
+        if protocol != HTTPS_LOWERCASE {
+            return None;
+        }
+        let host: String = url_obj.host?;
+        let port: u16 = url_obj.port.unwrap_or(Protocol::get_port(&protocol));
+        let tls_config: ClientConfig = ClientConfig::builder()
+            .with_root_certificates(RootCertStore {
+                roots: TLS_SERVER_ROOTS.to_vec(),
+            })
+            .with_no_client_auth();
+        let session: ClientConnection =
+            SharedConnector::tls_handshake(&host, port, timeout, tls_config).ok()?;
+        let leaf: &CertificateDer<'_> = session.peer_certificates()?.first()?;
+        let certificate: TlsCertificate = TlsCertificate::from_der(leaf.as_ref().to_vec())?;
+        Some(certificate.get_days_until_expiry())
+    }
+
+    /// Reports no certificate expiry because the crate was built without the `tls` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL whose server certificate would be checked.
+    /// - `Duration` - The timeout for the TLS handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<i64>` - Always `None`.
+    #[cfg(not(feature = "tls"))]
+    fn get_tls_expiry_days(_url: &str, _timeout: Duration) -> Option<i64> {
+        None
+    }
+
+    /// Performs a TLS handshake with a server and reports its certificates.
+    ///
+    /// No HTTP request is sent. The certificate chain is not verified, so expired,
+    /// self-signed or mismatched certificates are still reported; the handshake
+    /// signatures are checked as usual. The builder's timeout bounds the connection
+    /// and each socket operation.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address, also sent as SNI.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TlsInfo, RequestError>` - The negotiated parameters and the parsed
+    ///   certificate chain, or an error if the handshake fails or a certificate is malformed.
+    #[cfg(feature = "tls")]
+    pub fn inspect_tls(&self, host: &str, port: u16) -> Result<TlsInfo, RequestError> {
+        let timeout: u64 = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default().timeout, |config| config.timeout);
+        let verifier: InspectionVerifier = InspectionVerifier {
+            algorithms: default_provider().signature_verification_algorithms,
+        };
+        let mut tls_config: ClientConfig = ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_no_client_auth();
+        tls_config.alpn_protocols = vec![ALPN_H2.to_vec(), ALPN_HTTP_1_1.to_vec()];
+        let session: ClientConnection =
+            SharedConnector::tls_handshake(host, port, Duration::from_millis(timeout), tls_config)?;
+        let certificates: Vec<TlsCertificate> = session
+            .peer_certificates()
+            .unwrap_or_default()
+            .iter()
+            .map(|certificate| {
+                TlsCertificate::from_der(certificate.as_ref().to_vec())
+                    .ok_or_else(|| RequestError::tls("Malformed server certificate"))
+            })
+            .collect::<Result<Vec<TlsCertificate>, RequestError>>()?;
+        Ok(TlsInfo {
+            certificates,
+            protocol_version: session
+                .protocol_version()
+                .map(|version| format!("{version:?}")),
+            cipher_suite: session
+                .negotiated_cipher_suite()
+                .map(|suite| format!("{:?}", suite.suite())),
+            alpn_protocol: session
+                .alpn_protocol()
+                .map(|protocol: &[u8]| String::from_utf8_lossy(protocol).into_owned()),
+        })
+    }
 }
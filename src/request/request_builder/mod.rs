@@ -1,6 +1,11 @@
 mod r#impl;
 mod r#struct;
 
+#[cfg(feature = "websocket")]
+use http_type::HTTP_LOWERCASE;
+#[cfg(feature = "tls")]
+use http_type::HTTPS_LOWERCASE;
+
 pub use r#struct::*;
 
 use super::*;
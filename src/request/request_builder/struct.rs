@@ -11,6 +11,7 @@ use super::*;
 ///   the construction process. It holds the current state of the builder.
 /// - `builder`: A finalized `HttpRequest` instance that holds the result after the
 ///   builder process has been completed. It is returned when the builder is finalized.
+/// - `default_query`: Query parameters added to every request built, kept across builds.
 ///
 /// This builder simplifies the creation of `HttpRequest` objects, ensuring thread-safety
 /// and immutability of shared references, while providing a fluent API for constructing
@@ -19,4 +20,5 @@ use super::*;
 pub struct RequestBuilder {
     pub(crate) http_request: HttpRequest,
     pub(crate) builder: HttpRequest,
+    pub(crate) default_query: Vec<(String, String)>,
 }
@@ -0,0 +1,7 @@
+use super::*;
+
+impl Debug for ResolverHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("ResolverHook")
+    }
+}
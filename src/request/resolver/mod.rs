@@ -0,0 +1,10 @@
+mod r#impl;
+mod r#struct;
+mod r#trait;
+mod r#type;
+
+pub use {r#trait::*, r#type::*};
+
+pub(crate) use r#struct::*;
+
+use super::*;
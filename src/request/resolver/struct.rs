@@ -0,0 +1,5 @@
+use super::*;
+
+/// Resolver replacing the system resolver for a request.
+#[derive(Clone)]
+pub(crate) struct ResolverHook(pub(crate) ArcResolver);
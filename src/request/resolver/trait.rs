@@ -0,0 +1,49 @@
+use super::*;
+
+/// Resolver turning hostnames into socket addresses.
+///
+/// A resolver replaces the system resolver for the hosts a request connects to,
+/// including proxy hosts. Asynchronous requests run `resolve` on Tokio's blocking
+/// thread pool so a slow lookup cannot stall the runtime threads; resolvers with a
+/// non-blocking lookup override `resolve_async` and `is_nonblocking` instead.
+pub trait Resolver: Send + Sync {
+    /// Resolves a host and port.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Vec<SocketAddr>>` - The addresses to try in order, or an error.
+    fn resolve(&self, host: &str, port: u16) -> std::io::Result<Vec<SocketAddr>>;
+
+    /// Resolves a host and port asynchronously.
+    ///
+    /// Only called when `is_nonblocking` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `ResolveFuture<'a>` - A future resolving to the addresses or an error.
+    fn resolve_async<'a>(&'a self, host: &'a str, port: u16) -> ResolveFuture<'a> {
+        Box::pin(async move { self.resolve(host, port) })
+    }
+
+    /// Tells whether `resolve_async` resolves without blocking.
+    ///
+    /// Resolvers overriding `resolve_async` with a lookup that never blocks return
+    /// `true`.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if `resolve_async` never blocks; `false` by default.
+    fn is_nonblocking(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,8 @@
+use super::*;
+
+/// Shared resolver used for the connections of a request.
+pub(crate) type ArcResolver = Arc<dyn Resolver>;
+
+/// Boxed future resolving to the addresses of an asynchronous lookup.
+pub type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = std::io::Result<Vec<SocketAddr>>> + Send + 'a>>;
@@ -0,0 +1,14 @@
+/// Default number of segments downloaded concurrently.
+pub(crate) const DEFAULT_DOWNLOAD_SEGMENTS: usize = 4;
+
+/// Default smallest segment in bytes, below which fewer segments are used.
+pub(crate) const DEFAULT_MIN_SEGMENT_SIZE: u64 = 1024 * 1024;
+
+/// Header announcing the range units a server accepts.
+pub(crate) const ACCEPT_RANGES: &str = "Accept-Ranges";
+
+/// Header making a range request conditional on the representation being unchanged.
+pub(crate) const IF_RANGE: &str = "If-Range";
+
+/// Prefix of a weak entity tag, which `If-Range` does not accept.
+pub(crate) const WEAK_ETAG_PREFIX: &str = "W/";
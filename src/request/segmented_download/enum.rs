@@ -0,0 +1,16 @@
+use super::*;
+
+/// What probing a resource revealed about downloading it.
+pub(crate) enum DownloadProbe {
+    /// The server serves byte ranges of a representation of known size.
+    Ranges {
+        /// The size of the representation in bytes.
+        size: u64,
+        /// The validator sent with `If-Range`, if the server gave a usable one.
+        validator: Option<String>,
+    },
+    /// The probe request already received the whole representation.
+    Complete(HttpResponseBinary),
+    /// The server does not serve byte ranges.
+    Unsupported,
+}
@@ -0,0 +1,414 @@
+use super::*;
+
+impl SegmentedDownloader {
+    /// Creates a downloader fetching the resource of the template request.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpRequest` - The template request.
+    ///
+    /// # Returns
+    ///
+    /// - `SegmentedDownloader` - A downloader with the default segment settings.
+    pub(crate) fn new(http_request: HttpRequest) -> Self {
+        Self {
+            http_request,
+            segments: DEFAULT_DOWNLOAD_SEGMENTS,
+            min_segment_size: DEFAULT_MIN_SEGMENT_SIZE,
+        }
+    }
+
+    /// Sets the largest number of segments downloaded concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of segments, at least one.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut SegmentedDownloader` - The downloader for method chaining.
+    pub fn segments(&mut self, segments: usize) -> &mut Self {
+        self.segments = segments.max(1);
+        self
+    }
+
+    /// Sets the smallest segment, so small resources are split into fewer segments.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The smallest segment in bytes, at least one.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut SegmentedDownloader` - The downloader for method chaining.
+    pub fn min_segment_size(&mut self, min_segment_size: u64) -> &mut Self {
+        self.min_segment_size = min_segment_size.max(1);
+        self
+    }
+
+    /// Gets the largest number of segments downloaded concurrently.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of segments.
+    pub fn get_segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Gets the smallest segment.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The smallest segment in bytes.
+    pub fn get_min_segment_size(&self) -> u64 {
+        self.min_segment_size
+    }
+
+    /// Downloads the resource into memory.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The bytes of the resource, or an error if a
+    ///   request failed or a segment was not served as requested.
+    pub async fn download(&self) -> Result<Vec<u8>, RequestError> {
+        match self.probe().await? {
+            DownloadProbe::Complete(response) => Ok(response.get_body()),
+            DownloadProbe::Unsupported => Ok(self.download_whole().await?.get_body()),
+            DownloadProbe::Ranges { size, validator } => {
+                let segments: Vec<Vec<u8>> =
+                    try_join_all(self.get_segment_ranges(size).into_iter().map(
+                        |(start, end): (u64, u64)| {
+                            self.download_segment(start, end, validator.as_deref())
+                        },
+                    ))
+                    .await?;
+                Ok(segments.concat())
+            }
+        }
+    }
+
+    /// Downloads the resource into a file, replacing any existing file.
+    ///
+    /// Segments are written at their offsets as they arrive, so only the segments in
+    /// flight are held in memory. Without range support the body is streamed to the
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// - `P` - The path of the file (must implement AsRef<Path>).
+    ///
+    /// # Returns
+    ///
+    /// - `Result<u64, RequestError>` - The number of bytes written, or an error if a
+    ///   request failed, a segment was not served as requested, or the file could not
+    ///   be written.
+    pub async fn download_to_file<P: AsRef<Path>>(&self, path: P) -> Result<u64, RequestError> {
+        let mut file: File = File::create(path).map_err(RequestError::from)?;
+        match self.probe().await? {
+            DownloadProbe::Complete(response) => {
+                let body: Vec<u8> = response.get_body();
+                file.write_all(&body).map_err(RequestError::from)?;
+                Ok(body.len() as u64)
+            }
+            DownloadProbe::Unsupported => self.stream_whole(&mut file).await,
+            DownloadProbe::Ranges { size, validator } => {
+                file.set_len(size).map_err(RequestError::from)?;
+                let file: StdMutex<File> = StdMutex::new(file);
+                try_join_all(self.get_segment_ranges(size).into_iter().map(
+                    |(start, end): (u64, u64)| {
+                        let file: &StdMutex<File> = &file;
+                        let validator: Option<&str> = validator.as_deref();
+                        async move {
+                            let segment: Vec<u8> =
+                                self.download_segment(start, end, validator).await?;
+                            let mut file: StdMutexGuard<'_, File> = file.lock().map_err(|_| {
+                                RequestError::Request("Download file lock poisoned".to_string())
+                            })?;
+                            file.seek(SeekFrom::Start(start))
+                                .map_err(RequestError::from)?;
+                            file.write_all(&segment).map_err(RequestError::from)
+                        }
+                    },
+                ))
+                .await?;
+                Ok(size)
+            }
+        }
+    }
+
+    /// Probes the size of the resource and whether the server serves byte ranges.
+    ///
+    /// A HEAD response with a `Content-Encoding` describes an encoded representation
+    /// whose size says nothing about the identity bytes, so the probe falls back to a
+    /// GET request for the first byte.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<DownloadProbe, RequestError>` - What the probe revealed, or an error if
+    ///   the probe GET request failed, was answered with an error status, or was served
+    ///   with a `Content-Encoding` despite asking for `identity`.
+    async fn probe(&self) -> Result<DownloadProbe, RequestError> {
+        let mut head: HttpRequest = self.build_request(Method::Head);
+        if let Ok(response) = AsyncRequestTrait::send(&mut head).await {
+            let response: HttpResponseBinary = response.binary();
+            let accepts_ranges: bool =
+                response
+                    .get_header(ACCEPT_RANGES)
+                    .is_some_and(|units: String| {
+                        units
+                            .split(',')
+                            .any(|unit: &str| unit.trim().eq_ignore_ascii_case(BYTES_UNIT))
+                    });
+            let size: Option<u64> = response
+                .get_header(CONTENT_LENGTH)
+                .and_then(|length: String| length.trim().parse().ok());
+            if Self::is_success(response.get_status_code())
+                && !response.has_content_encoding()
+                && accepts_ranges
+                && let Some(size) = size
+            {
+                return Ok(DownloadProbe::Ranges {
+                    size,
+                    validator: Self::get_validator(&response),
+                });
+            }
+        }
+        let mut probe: HttpRequest = self.build_request(Method::Get);
+        Self::set_header(&mut probe, RANGE, format!("{BYTES_UNIT}=0-0"));
+        let response: HttpResponseBinary = AsyncRequestTrait::send(&mut probe).await?.binary();
+        if response.has_content_encoding() {
+            return Err(RequestError::Request(format!(
+                "Download was served with Content-Encoding {} despite {ACCEPT_ENCODING}: {IDENTITY_ENCODING}",
+                response.get_header(CONTENT_ENCODING).unwrap_or_default()
+            )));
+        }
+        if response.is_partial_content() {
+            return Ok(
+                match response
+                    .get_content_range()
+                    .and_then(|range: ContentRange| range.get_total())
+                {
+                    Some(size) => DownloadProbe::Ranges {
+                        size,
+                        validator: Self::get_validator(&response),
+                    },
+                    None => DownloadProbe::Unsupported,
+                },
+            );
+        }
+        if response.get_unsatisfied_range_total() == Some(0) {
+            return Ok(DownloadProbe::Ranges {
+                size: 0,
+                validator: None,
+            });
+        }
+        if !Self::is_success(response.get_status_code()) {
+            return Err(Self::get_status_error(response.get_status_code()));
+        }
+        Ok(DownloadProbe::Complete(response))
+    }
+
+    /// Downloads the whole resource with a single request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The response, or an error if the request
+    ///   failed or was answered with an error status.
+    async fn download_whole(&self) -> Result<HttpResponseBinary, RequestError> {
+        let mut request: HttpRequest = self.build_request(Method::Get);
+        let response: HttpResponseBinary = AsyncRequestTrait::send(&mut request).await?.binary();
+        if !Self::is_success(response.get_status_code()) {
+            return Err(Self::get_status_error(response.get_status_code()));
+        }
+        Ok(response)
+    }
+
+    /// Streams the whole resource into a file with a single request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut File` - The file to write.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<u64, RequestError>` - The number of bytes written, or an error.
+    async fn stream_whole(&self, file: &mut File) -> Result<u64, RequestError> {
+        let mut request: HttpRequest = self.build_request(Method::Get);
+        let mut response: AsyncHttpResponseStream =
+            AsyncRequestTrait::send_stream(&mut request).await?;
+        if !Self::is_success(response.get_status_code()) {
+            return Err(Self::get_status_error(response.get_status_code()));
+        }
+        let mut buffer: Vec<u8> = vec![0; DEFAULT_BUFFER_SIZE];
+        let mut written: u64 = 0;
+        loop {
+            let n: usize = response
+                .body()
+                .read(&mut buffer)
+                .await
+                .map_err(RequestError::from)?;
+            if n == 0 {
+                return Ok(written);
+            }
+            file.write_all(&buffer[..n]).map_err(RequestError::from)?;
+            written += n as u64;
+        }
+    }
+
+    /// Downloads one segment of the resource.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The offset of the first byte.
+    /// - `u64` - The offset of the last byte, inclusive.
+    /// - `Option<&str>` - The validator sent with `If-Range`, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The bytes of the segment, or an error if the
+    ///   request failed or the server did not answer with exactly the requested range.
+    async fn download_segment(
+        &self,
+        start: u64,
+        end: u64,
+        validator: Option<&str>,
+    ) -> Result<Vec<u8>, RequestError> {
+        let mut request: HttpRequest = self.build_request(Method::Get);
+        Self::set_header(&mut request, RANGE, format!("{BYTES_UNIT}={start}-{end}"));
+        if let Some(validator) = validator {
+            Self::set_header(&mut request, IF_RANGE, validator.to_string());
+        }
+        let response: HttpResponseBinary = AsyncRequestTrait::send(&mut request).await?.binary();
+        let body: Vec<u8> = response.get_body();
+        let served: Option<(u64, u64)> = response
+            .get_content_range()
+            .map(|range: ContentRange| (range.get_start(), range.get_end()));
+        if !response.is_partial_content()
+            || response.has_content_encoding()
+            || served != Some((start, end))
+            || body.len() as u64 != end - start + 1
+        {
+            return Err(RequestError::Request(format!(
+                "Segment {BYTES_UNIT}={start}-{end} was not served as requested (status {})",
+                response.get_status_code()
+            )));
+        }
+        Ok(body)
+    }
+
+    /// Splits the resource into segments.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The size of the resource in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<(u64, u64)>` - The inclusive start and end offsets of each segment in order,
+    ///   empty for an empty resource.
+    fn get_segment_ranges(&self, size: u64) -> Vec<(u64, u64)> {
+        if size == 0 {
+            return Vec::new();
+        }
+        let count: u64 = size
+            .div_ceil(self.min_segment_size)
+            .min(self.segments as u64)
+            .max(1);
+        let segment_size: u64 = size.div_ceil(count);
+        (0..size)
+            .step_by(segment_size as usize)
+            .map(|start: u64| (start, (start + segment_size).min(size) - 1))
+            .collect()
+    }
+
+    /// Builds a request for the resource.
+    ///
+    /// The request uses the template with its own copy of the configuration and
+    /// processing state. Byte ranges address the representation as sent, so the
+    /// request asks for `Accept-Encoding: identity` and leaves the body undecoded.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The request method.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpRequest` - The request.
+    fn build_request(&self, method: Method) -> HttpRequest {
+        let mut config: Config = self
+            .http_request
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        config.decode = false;
+        let mut http_request: HttpRequest = self.http_request.clone();
+        http_request.methods = Arc::new(method);
+        http_request.body = Arc::new(Body::default());
+        http_request.config = Arc::new(RwLock::new(config));
+        http_request.tmp = Arc::new(RwLock::new(Tmp::default()));
+        http_request.response = Arc::new(RwLock::new(HttpResponseBinary::default()));
+        Self::set_header(
+            &mut http_request,
+            ACCEPT_ENCODING,
+            IDENTITY_ENCODING.to_string(),
+        );
+        http_request
+    }
+
+    /// Sets a header of a request, replacing any existing value regardless of case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HttpRequest` - The request.
+    /// - `&str` - The header name.
+    /// - `String` - The header value.
+    fn set_header(http_request: &mut HttpRequest, key: &str, value: String) {
+        let header: &mut RequestHeaders = Arc::make_mut(&mut http_request.header);
+        header.retain(|existing_key: &String, _| !existing_key.eq_ignore_ascii_case(key));
+        let mut value_deque: VecDeque<String> = VecDeque::new();
+        value_deque.push_front(value);
+        header.insert(key.to_string(), value_deque);
+    }
+
+    /// Gets the validator keeping the segments of one representation together.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The probe response.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The strong entity tag, or else the `Last-Modified` date.
+    fn get_validator(response: &HttpResponseBinary) -> Option<String> {
+        response
+            .get_header(ETAG)
+            .filter(|etag: &String| !etag.trim_start().starts_with(WEAK_ETAG_PREFIX))
+            .or_else(|| response.get_header(LAST_MODIFIED))
+    }
+
+    /// Checks whether a status code reports success.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` for a 2xx status code.
+    fn is_success(status_code: ResponseStatusCode) -> bool {
+        (200..300).contains(&status_code)
+    }
+
+    /// Builds the error of a download answered with an error status.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The error.
+    fn get_status_error(status_code: ResponseStatusCode) -> RequestError {
+        RequestError::Request(format!("Download failed with status {status_code}"))
+    }
+}
@@ -0,0 +1,10 @@
+mod r#const;
+mod r#enum;
+mod r#impl;
+mod r#struct;
+
+pub use r#struct::*;
+
+pub(crate) use {r#const::*, r#enum::*};
+
+use super::*;
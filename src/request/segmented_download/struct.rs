@@ -0,0 +1,19 @@
+use super::*;
+
+/// Downloads a resource in concurrent byte range segments.
+///
+/// The size of the resource is probed with a HEAD request, or with a GET request
+/// for its first byte if the HEAD response does not announce range support. The
+/// resource is then split into segments downloaded concurrently with the async
+/// client and reassembled in order, into memory or a file. A server without range
+/// support is downloaded with a single request instead. Every request asks for
+/// `Accept-Encoding: identity`, and a server that still encodes the body is
+/// rejected, since byte ranges of an encoded body cannot be reassembled.
+pub struct SegmentedDownloader {
+    /// Template request providing the URL, headers, and configuration.
+    pub(crate) http_request: HttpRequest,
+    /// The largest number of segments downloaded concurrently.
+    pub(crate) segments: usize,
+    /// The smallest segment in bytes.
+    pub(crate) min_segment_size: u64,
+}
@@ -0,0 +1,22 @@
+/// ALPN protocol identifier of HTTP/1.1.
+#[cfg(feature = "tls")]
+pub(crate) const ALPN_HTTP_1_1: &[u8] = b"http/1.1";
+
+/// ALPN protocol identifier of HTTP/2 over TLS.
+#[cfg(feature = "tls")]
+pub(crate) const ALPN_H2: &[u8] = b"h2";
+
+/// Largest number of blocking DNS lookups running on helper threads at once.
+pub(crate) const MAX_RESOLVE_THREADS: usize = 8;
+
+/// Time in seconds a prefetched DNS resolution stays valid.
+pub(crate) const DNS_CACHE_TTL_SECS: u64 = 60;
+
+/// Default maximum number of header lines accepted in a response.
+pub(crate) const DEFAULT_MAX_RESPONSE_HEADERS: usize = 100;
+
+/// Default maximum size in bytes of a response header section.
+pub(crate) const DEFAULT_MAX_RESPONSE_HEADER_SIZE: usize = 64 * 1024;
+
+/// Default maximum length in bytes of a response status or header line.
+pub(crate) const DEFAULT_MAX_RESPONSE_HEADER_LINE: usize = 8 * 1024;
@@ -39,60 +39,91 @@ impl SharedRequestBuilder {
         request
     }
 
-    /// Constructs an HTTP GET request byte vector.
+    /// Constructs a CONNECT request opening a tunnel through a proxy.
     ///
     /// # Arguments
     ///
-    /// - `String` - The request path.
-    /// - `Vec<u8>` - The raw bytes of the request headers.
-    /// - `String` - The HTTP version string.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `Option<&str>` - The `Proxy-Authorization` header value, if any.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The complete HTTP GET request as a byte vector.
-    pub(crate) fn build_get_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        http_version_str: String,
+    /// - `Vec<u8>` - The complete CONNECT request as a byte vector.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn build_connect_request(
+        target_host: &str,
+        target_port: u16,
+        authorization: Option<&str>,
     ) -> Vec<u8> {
-        Self::build_http_request("GET", path, header_bytes, None, http_version_str)
+        let authorization_line: String = authorization
+            .map(|authorization: &str| format!("{PROXY_AUTHORIZATION}: {authorization}\r\n"))
+            .unwrap_or_default();
+        let authority: String = Self::format_authority(target_host, Some(target_port));
+        format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n{authorization_line}\r\n")
+            .into_bytes()
     }
 
-    /// Constructs an HTTP POST request byte vector.
+    /// Formats a host and optional port as the authority of a request.
+    ///
+    /// IPv6 literals are enclosed in brackets so the port separator stays unambiguous.
     ///
     /// # Arguments
     ///
-    /// - `String` - The request path.
-    /// - `Vec<u8>` - The raw bytes of the request headers.
-    /// - `Vec<u8>` - The raw bytes of the request body.
-    /// - `String` - The HTTP version string.
+    /// - `&str` - The host name or IP address.
+    /// - `Option<u16>` - The port, if it is included.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The complete HTTP POST request as a byte vector.
-    pub(crate) fn build_post_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        body_bytes: Vec<u8>,
-        http_version_str: String,
-    ) -> Vec<u8> {
-        Self::build_http_request(
-            "POST",
-            path,
-            header_bytes,
-            Some(body_bytes),
-            http_version_str,
-        )
+    /// - `String` - The authority, such as `example.com:8080` or `[::1]:8080`.
+    pub(crate) fn format_authority(host: &str, port: Option<u16>) -> String {
+        let host: String = if host.contains(':') && !host.starts_with('[') {
+            format!("[{host}]")
+        } else {
+            host.to_string()
+        };
+        match port {
+            Some(port) => format!("{host}:{port}"),
+            None => host,
+        }
+    }
+
+    /// Extracts the host and port of a URL whose authority is a bracketed IPv6 literal.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL to inspect.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(String, Option<u16>)>` - The IPv6 address without brackets and the port,
+    ///   or `None` if the host is not an IPv6 literal.
+    pub(crate) fn parse_ipv6_authority(url: &str) -> Option<(String, Option<u16>)> {
+        let (_, rest) = url.split_once("://")?;
+        let authority: &str = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        let host_port: &str = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host_port): (&str, &str)| host_port);
+        let (host, after) = host_port.strip_prefix('[')?.split_once(']')?;
+        host.parse::<Ipv6Addr>().ok()?;
+        let port: Option<u16> = match after.strip_prefix(':') {
+            Some(port) => Some(port.parse::<u16>().ok()?),
+            None if after.is_empty() => None,
+            None => return None,
+        };
+        Some((host.to_string(), port))
     }
 }
 
 impl SharedResponseHandler {
     /// Parses response headers to extract status code, content length, redirect URL, and chunked encoding.
     ///
+    /// The status code is read from the status line regardless of the HTTP version
+    /// token the server answered with.
+    ///
     /// # Arguments
     ///
     /// - `&[u8]` - The raw bytes of the response headers.
-    /// - `&[u8]` - The raw bytes of the HTTP version.
     /// - `&[u8]` - The byte pattern to identify the "Location" header.
     /// - `&mut usize` - A mutable reference to store the content length.
     /// - `&mut Option<Vec<u8>>` - A mutable reference to store the redirect URL if present.
@@ -103,39 +134,241 @@ impl SharedResponseHandler {
     /// - `Result<(), RequestError>` - Ok if parsing is successful, Err otherwise.
     pub(crate) fn parse_response_headers(
         headers_bytes: &[u8],
-        http_version_bytes: &[u8],
         location_sign_key: &[u8],
         content_length: &mut usize,
         redirect_url: &mut Option<Vec<u8>>,
         is_chunked: &mut bool,
     ) -> Result<(), RequestError> {
-        if let Some(status_pos) =
-            Self::find_pattern_case_insensitive(headers_bytes, http_version_bytes)
+        let status_code: usize = Self::get_status_code(headers_bytes);
+        if (300..=399).contains(&status_code)
+            && let Some(location_pos) =
+                Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
         {
-            let status_code_start: usize = status_pos + http_version_bytes.len() + 1;
-            let status_code_end: usize = status_code_start + 3;
-            if status_code_end <= headers_bytes.len() {
-                let status_code: usize =
-                    Self::parse_status_code(&headers_bytes[status_code_start..status_code_end]);
-
-                if (300..=399).contains(&status_code)
-                    && let Some(location_pos) =
-                        Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
-                {
-                    let start: usize = location_pos + location_sign_key.len();
-                    if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
-                        let mut url_vec = Vec::with_capacity(end_pos - start);
-                        url_vec.extend_from_slice(&headers_bytes[start..end_pos]);
-                        *redirect_url = Some(url_vec);
-                    }
-                }
+            let start: usize = location_pos + location_sign_key.len();
+            if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
+                let mut url_vec = Vec::with_capacity(end_pos - start);
+                url_vec.extend_from_slice(&headers_bytes[start..end_pos]);
+                *redirect_url = Some(url_vec);
             }
         }
-        *content_length = Self::get_content_length(headers_bytes);
+        *content_length = Self::get_content_length(headers_bytes)
+            .ok_or(RequestError::ContentLengthTooLarge(usize::MAX))?;
         *is_chunked = Self::is_chunked_encoding(headers_bytes);
         Ok(())
     }
 
+    /// Extracts the status code from the status line of the response headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The status code, or 0 if the status line is malformed.
+    pub(crate) fn get_status_code(headers_bytes: &[u8]) -> usize {
+        let status_line_end: usize =
+            Self::find_crlf(headers_bytes, 0).unwrap_or(headers_bytes.len());
+        let status_line: &[u8] = &headers_bytes[..status_line_end];
+        if let Some(status_pos) = status_line.iter().position(|&byte| byte == SPACE_U8) {
+            let status_code_start: usize = status_pos + 1;
+            let status_code_end: usize = status_code_start + 3;
+            if status_code_end <= status_line.len() {
+                return Self::parse_status_code(&status_line[status_code_start..status_code_end]);
+            }
+        }
+        0
+    }
+
+    /// Gets the values of every header with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the HTTP response headers.
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The trimmed header values in the order they were sent.
+    pub(crate) fn get_header_values(headers_bytes: &[u8], name: &str) -> Vec<String> {
+        String::from_utf8_lossy(headers_bytes)
+            .split("\r\n")
+            .skip(1)
+            .filter_map(|line: &str| line.split_once(':'))
+            .filter(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.trim().to_owned())
+            .collect()
+    }
+
+    /// Parses the response of a proxy to a CONNECT request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes read from the proxy, starting with the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The tunnel bytes read past the response
+    ///   headers if the proxy accepted the request, or an error carrying the
+    ///   challenges if it requires authentication.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn parse_connect_response(response_bytes: &[u8]) -> Result<Vec<u8>, RequestError> {
+        let headers_end: usize =
+            Self::find_double_crlf(response_bytes, 0).map_or(response_bytes.len(), |pos| pos + 4);
+        let headers_bytes: &[u8] = &response_bytes[..headers_end];
+        match Self::get_status_code(headers_bytes) {
+            200..=299 => Ok(response_bytes[headers_end..].to_vec()),
+            PROXY_AUTHENTICATION_REQUIRED => Err(RequestError::ProxyAuthenticationRequired(
+                AuthChallenge::from_headers(headers_bytes, PROXY_AUTHENTICATE),
+            )),
+            0 => Err(RequestError::proxy(
+                ProxyErrorPhase::ConnectCommand,
+                "Proxy closed the connection without responding to CONNECT",
+            )),
+            status_code => Err(RequestError::proxy(
+                ProxyErrorPhase::ConnectCommand,
+                format!("Proxy responded to CONNECT with status {status_code}"),
+            )),
+        }
+    }
+
+    /// Checks if the response asks for the connection to be closed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the HTTP response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if a `Connection: close` header is present, false otherwise.
+    pub(crate) fn is_connection_close(headers_bytes: &[u8]) -> bool {
+        if let Some(pos) = Self::find_pattern_case_insensitive(headers_bytes, CONNECTION_PATTERN) {
+            let value_start: usize = pos + CONNECTION_PATTERN.len();
+            if let Some(end_pos) = Self::find_crlf(headers_bytes, value_start) {
+                let value_bytes: &[u8] = &headers_bytes[value_start..end_pos];
+                return Self::find_pattern_case_insensitive(value_bytes, CLOSE_PATTERN).is_some();
+            }
+        }
+        false
+    }
+
+    /// Checks if a connection can carry another request after this response.
+    ///
+    /// The response must be exactly one complete, self-delimited HTTP/1.1 message
+    /// that does not ask for the connection to be closed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - All bytes read from the connection for the response.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the connection may be reused, false otherwise.
+    pub(crate) fn is_reusable_response(data: &[u8]) -> bool {
+        data.starts_with(b"HTTP/1.1")
+            && Self::get_response_frame_length(data, false) == Some(data.len())
+            && !Self::is_connection_close(data)
+    }
+
+    /// Finds the end of a chunked transfer encoded body.
+    ///
+    /// The body ends after the terminating zero-size chunk and the optional trailer
+    /// section, which is closed by an empty line.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the chunked body (starting after headers).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The length of the complete chunked body, or None if incomplete.
+    pub(crate) fn find_chunked_body_end(body_bytes: &[u8]) -> Option<usize> {
+        let mut pos: usize = 0;
+        while pos < body_bytes.len() {
+            let chunk_size_end: usize = Self::find_crlf(body_bytes, pos)?;
+            let chunk_size_str: &[u8] = &body_bytes[pos..chunk_size_end];
+            let chunk_size_str: &[u8] = match chunk_size_str.iter().position(|&b| b == b';') {
+                Some(p) => &chunk_size_str[..p],
+                None => chunk_size_str,
+            };
+            let chunk_size: usize = std::str::from_utf8(chunk_size_str)
+                .ok()
+                .and_then(|s: &str| usize::from_str_radix(s.trim(), 16).ok())?;
+            if chunk_size == 0 {
+                return Self::find_double_crlf(body_bytes, chunk_size_end).map(|end| end + 4);
+            }
+            pos = chunk_size_end + 2 + chunk_size + 2;
+        }
+        None
+    }
+
+    /// Checks if a response has no body regardless of its framing headers.
+    ///
+    /// Responses to HEAD requests and responses with a 1xx, 204, or 304 status
+    /// never carry a body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `bool` - Whether the response answers a HEAD request.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the response has no body, false otherwise.
+    pub(crate) fn is_bodyless_response(headers_bytes: &[u8], is_head: bool) -> bool {
+        let status_code: usize = Self::get_status_code(headers_bytes);
+        is_head || (100..=199).contains(&status_code) || status_code == 204 || status_code == 304
+    }
+
+    /// Checks if the end of a response body is signalled by the connection closing.
+    ///
+    /// A response with a body but neither a chunked transfer encoding nor a
+    /// `Content-Length` header, as sent by HTTP/1.0 servers, ends when the server
+    /// closes the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `bool` - Whether the response answers a HEAD request and therefore has no body.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the body is read until the connection closes.
+    pub(crate) fn is_close_delimited(headers_bytes: &[u8], is_head: bool) -> bool {
+        !Self::is_bodyless_response(headers_bytes, is_head)
+            && !Self::is_chunked_encoding(headers_bytes)
+            && Self::find_pattern_case_insensitive(headers_bytes, CONTENT_LENGTH_PATTERN).is_none()
+    }
+
+    /// Determines the length of the first complete response in a byte buffer.
+    ///
+    /// Used when several responses share one connection, so the bytes of a response
+    /// must be split off without consuming the start of the next one.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The buffered response bytes.
+    /// - `bool` - Whether the response answers a HEAD request and therefore has no body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The length of the complete response, or None if more data is
+    ///   needed or the body is delimited by the connection closing.
+    pub(crate) fn get_response_frame_length(data: &[u8], is_head: bool) -> Option<usize> {
+        let headers_end: usize = Self::find_double_crlf(data, 0)? + 4;
+        let headers_bytes: &[u8] = &data[..headers_end];
+        if Self::is_bodyless_response(headers_bytes, is_head) {
+            return Some(headers_end);
+        }
+        if Self::is_chunked_encoding(headers_bytes) {
+            return Self::find_chunked_body_end(&data[headers_end..]).map(|end| headers_end + end);
+        }
+        Self::find_pattern_case_insensitive(headers_bytes, CONTENT_LENGTH_PATTERN)?;
+        let total_length: usize =
+            headers_end.checked_add(Self::get_content_length(headers_bytes)?)?;
+        (data.len() >= total_length).then_some(total_length)
+    }
+
     /// Finds a pattern within a byte slice, ignoring case.
     ///
     /// # Arguments
@@ -226,8 +459,9 @@ impl SharedResponseHandler {
     ///
     /// # Returns
     ///
-    /// - `usize` - The content length value, or 0 if not found or parsing fails.
-    pub(crate) fn get_content_length(response_bytes: &[u8]) -> usize {
+    /// - `Option<usize>` - The content length value, 0 if not found, or None if the
+    ///   value does not fit in a `usize`.
+    pub(crate) fn get_content_length(response_bytes: &[u8]) -> Option<usize> {
         if let Some(pos) =
             Self::find_pattern_case_insensitive(response_bytes, CONTENT_LENGTH_PATTERN)
         {
@@ -242,7 +476,7 @@ impl SharedResponseHandler {
                 return Self::parse_decimal_bytes(value_bytes);
             }
         }
-        0
+        Some(0)
     }
 
     /// Checks if the response uses chunked transfer encoding.
@@ -274,6 +508,82 @@ impl SharedResponseHandler {
         false
     }
 
+    /// Checks a response header section against the strict parsing rules.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the status line and headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the framing is unambiguous, or
+    ///   `RequestError::InvalidResponse` naming the violation.
+    pub(crate) fn check_strict_headers(headers_bytes: &[u8]) -> Result<(), RequestError> {
+        let mut content_length: Option<&[u8]> = None;
+        let mut has_transfer_encoding: bool = false;
+        for line in split_multi_byte(headers_bytes, BR_BYTES)
+            .into_iter()
+            .skip(1)
+        {
+            if line.is_empty() {
+                continue;
+            }
+            if line[0] == SPACE_U8 || line[0] == TAB_U8 {
+                return Err(RequestError::InvalidResponse(
+                    ResponseViolation::ObsoleteLineFolding,
+                ));
+            }
+            let Some(colon) = line.iter().position(|&byte| byte == COLON_U8) else {
+                continue;
+            };
+            let name: &[u8] = &line[..colon];
+            let value: &[u8] = line[colon + 1..].trim_ascii();
+            if name.eq_ignore_ascii_case(CONTENT_LENGTH.as_bytes()) {
+                for length in value.split(|&byte| byte == b',').map(<[u8]>::trim_ascii) {
+                    let valid: bool = !length.is_empty() && length.iter().all(u8::is_ascii_digit);
+                    if !valid || content_length.is_some_and(|existing| existing != length) {
+                        return Err(RequestError::InvalidResponse(
+                            ResponseViolation::ConflictingContentLength,
+                        ));
+                    }
+                    content_length = Some(length);
+                }
+            } else if name.eq_ignore_ascii_case(TRANSFER_ENCODING.as_bytes()) {
+                has_transfer_encoding = true;
+            }
+        }
+        if content_length.is_some() && has_transfer_encoding {
+            return Err(RequestError::InvalidResponse(
+                ResponseViolation::ContentLengthWithTransferEncoding,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that a body framed by `Content-Length` was read completely.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The body bytes read after the headers.
+    /// - `usize` - The length announced by `Content-Length`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the whole body was read, or
+    ///   `RequestError::IncompleteBody` with the expected and received byte counts.
+    pub(crate) fn check_body_complete(
+        body_bytes: &[u8],
+        content_length: usize,
+    ) -> Result<(), RequestError> {
+        if body_bytes.len() < content_length {
+            return Err(RequestError::IncompleteBody {
+                bytes_expected: content_length,
+                bytes_received: body_bytes.len(),
+            });
+        }
+        Ok(())
+    }
+
     /// Parses a chunked transfer encoded body.
     ///
     /// Decodes the chunked encoding and returns the decoded body bytes.
@@ -286,7 +596,21 @@ impl SharedResponseHandler {
     ///
     /// - `Vec<u8>` - The decoded body bytes.
     pub(crate) fn parse_chunked_body(body_bytes: &[u8]) -> Vec<u8> {
+        Self::parse_chunked_message(body_bytes).0
+    }
+
+    /// Parses a chunked transfer encoded body together with its trailer section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the chunked body (starting after headers).
+    ///
+    /// # Returns
+    ///
+    /// - `(Vec<u8>, ResponseHeaders)` - The decoded body bytes and the trailer fields.
+    pub(crate) fn parse_chunked_message(body_bytes: &[u8]) -> (Vec<u8>, ResponseHeaders) {
         let mut result: Vec<u8> = Vec::new();
+        let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
         let mut pos: usize = 0;
         while pos < body_bytes.len() {
             let chunk_size_end: usize = match body_bytes[pos..]
@@ -309,6 +633,7 @@ impl SharedResponseHandler {
                 Err(_) => break,
             };
             if chunk_size == 0 {
+                trailers = Self::parse_trailer_fields(&body_bytes[chunk_size_end + 2..]);
                 break;
             }
             let chunk_data_start: usize = chunk_size_end + 2;
@@ -319,7 +644,43 @@ impl SharedResponseHandler {
             result.extend_from_slice(&body_bytes[chunk_data_start..chunk_data_end]);
             pos = chunk_data_end + 2;
         }
-        result
+        (result, trailers)
+    }
+
+    /// Parses the trailer section following the last chunk of a chunked body.
+    ///
+    /// Parsing stops at the empty line closing the section. Repeated field names keep
+    /// every value in order.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes after the last chunk size line.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeaders` - The trailer fields.
+    pub(crate) fn parse_trailer_fields(trailer_bytes: &[u8]) -> ResponseHeaders {
+        let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
+        for line in trailer_bytes.split(|&byte: &u8| byte == b'\n') {
+            let line: &[u8] = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                break;
+            }
+            let Some(colon_pos) = line.iter().position(|&byte: &u8| byte == COLON_U8) else {
+                continue;
+            };
+            let name: String = String::from_utf8_lossy(&line[..colon_pos])
+                .trim()
+                .to_string();
+            if name.is_empty() {
+                continue;
+            }
+            let value: String = String::from_utf8_lossy(&line[colon_pos + 1..])
+                .trim()
+                .to_string();
+            trailers.entry(name).or_default().push_back(value);
+        }
+        trailers
     }
 
     /// Parses a byte slice representing a decimal number into a `usize`.
@@ -332,21 +693,23 @@ impl SharedResponseHandler {
     ///
     /// # Returns
     ///
-    /// - `usize` - The parsed decimal value.
-    pub(crate) fn parse_decimal_bytes(bytes: &[u8]) -> usize {
+    /// - `Option<usize>` - The parsed decimal value, or None if it does not fit in a `usize`.
+    pub(crate) fn parse_decimal_bytes(bytes: &[u8]) -> Option<usize> {
         let mut result: usize = 0;
         let mut started: bool = false;
         for &byte in bytes {
             match byte {
                 b'0'..=b'9' => {
                     started = true;
-                    result = result * 10 + (byte - b'0') as usize;
+                    result = result
+                        .checked_mul(10)?
+                        .checked_add((byte - b'0') as usize)?;
                 }
                 b' ' | b'\t' if !started => continue,
                 _ => break,
             }
         }
-        result
+        Some(result)
     }
 
     /// Parses a byte slice representing an HTTP status code into a `usize`.
@@ -408,3 +771,546 @@ impl SharedResponseHandler {
         }
     }
 }
+
+impl SharedConnector {
+    /// Resolves a host and port into the list of candidate socket addresses.
+    ///
+    /// Addresses of a prefetched host are taken from the DNS cache while they are valid.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<SocketAddr>, RequestError>` - The resolved addresses, or an error if
+    ///   resolution fails or yields no address.
+    pub(crate) fn resolve(host: &str, port: u16) -> Result<Vec<SocketAddr>, RequestError> {
+        if let Some(addrs) = Self::get_cached_addrs(host, port) {
+            return Ok(addrs);
+        }
+        let addrs: Vec<SocketAddr> = (host, port)
+            .to_socket_addrs()
+            .map_err(|error: std::io::Error| {
+                RequestError::from(error).with_phase(ErrorPhase::Resolve)
+            })?
+            .collect();
+        if addrs.is_empty() {
+            return Err(RequestError::unresolved(format!("{host}:{port}")));
+        }
+        Ok(addrs)
+    }
+
+    /// Runs a blocking lookup on a helper thread, giving up once the timeout elapses.
+    ///
+    /// At most `MAX_RESOLVE_THREADS` lookups run at once; a lookup waits for a free
+    /// helper thread within its timeout. Blocking resolvers cannot be interrupted, so a
+    /// timed out lookup is not reclaimed: its thread runs, and holds its slot, until the
+    /// resolver returns, but the request no longer waits for it. Stalled resolvers
+    /// therefore delay later lookups rather than piling up threads.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The lookup to run.
+    /// - `Duration` - The time left for the lookup.
+    ///
+    /// # Returns
+    ///
+    /// - `ResolveResult` - The result of the lookup, or a timeout error if it did not
+    ///   finish in time.
+    pub(crate) fn resolve_with_timeout<F>(lookup: F, timeout: Duration) -> ResolveResult
+    where
+        F: FnOnce() -> ResolveResult + Send + 'static,
+    {
+        let deadline: Instant = Instant::now() + timeout;
+        let Some(slot) = ResolveSlot::acquire(deadline) else {
+            return Err(RequestError::timeout(
+                ErrorPhase::Resolve,
+                "DNS resolution timeout",
+            ));
+        };
+        let (sender, receiver): (Sender<ResolveResult>, Receiver<ResolveResult>) = channel();
+        thread::spawn(move || {
+            let _slot: ResolveSlot = slot;
+            let _ = sender.send(lookup());
+        });
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(RequestError::timeout(
+                ErrorPhase::Resolve,
+                "DNS resolution timeout",
+            )),
+            Err(RecvTimeoutError::Disconnected) => Err(RequestError::Request(
+                "DNS resolution failed: the lookup thread stopped".to_string(),
+            )),
+        }
+    }
+
+    /// Resolves a host and port asynchronously into the list of candidate socket addresses.
+    ///
+    /// Addresses of a prefetched host are taken from the DNS cache while they are valid.
+    /// Other hosts are looked up on the blocking thread pool, leaving the runtime
+    /// threads free.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<SocketAddr>, RequestError>` - The resolved addresses, or an error if
+    ///   resolution fails or yields no address.
+    #[cfg(feature = "async")]
+    pub(crate) async fn resolve_async(
+        host: &str,
+        port: u16,
+    ) -> Result<Vec<SocketAddr>, RequestError> {
+        if let Some(addrs) = Self::get_cached_addrs(host, port) {
+            return Ok(addrs);
+        }
+        let addrs: Vec<SocketAddr> = lookup_host((host, port))
+            .await
+            .map_err(|error: std::io::Error| {
+                RequestError::from(error).with_phase(ErrorPhase::Resolve)
+            })?
+            .collect();
+        if addrs.is_empty() {
+            return Err(RequestError::unresolved(format!("{host}:{port}")));
+        }
+        Ok(addrs)
+    }
+
+    /// Gets the cached addresses of a prefetched host.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Vec<SocketAddr>>` - The cached addresses with the port, or None if the
+    ///   host was not prefetched or its entry expired.
+    pub(crate) fn get_cached_addrs(host: &str, port: u16) -> Option<Vec<SocketAddr>> {
+        let cache: RwLockReadGuard<'_, HashMapXxHash3_64<String, DnsCacheEntry>> =
+            DNS_CACHE.read().ok()?;
+        let entry: &DnsCacheEntry = cache.get(&host.to_ascii_lowercase())?;
+        if entry.expires_at <= Instant::now() {
+            return None;
+        }
+        Some(
+            entry
+                .addrs
+                .iter()
+                .map(|ip: &IpAddr| SocketAddr::new(*ip, port))
+                .collect(),
+        )
+    }
+
+    /// Stores the resolved addresses of a host in the DNS cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    /// - `Vec<SocketAddr>` - The resolved addresses.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<IpAddr>, RequestError>` - The cached addresses, or an error if none was resolved.
+    fn store_cached_addrs(host: &str, addrs: Vec<SocketAddr>) -> Result<Vec<IpAddr>, RequestError> {
+        let mut ips: Vec<IpAddr> = Vec::with_capacity(addrs.len());
+        for addr in addrs {
+            if !ips.contains(&addr.ip()) {
+                ips.push(addr.ip());
+            }
+        }
+        if ips.is_empty() {
+            return Err(RequestError::unresolved(host));
+        }
+        if let Ok(mut cache) = DNS_CACHE.write() {
+            let now: Instant = Instant::now();
+            cache.retain(|_, entry: &mut DnsCacheEntry| entry.expires_at > now);
+            cache.insert(
+                host.to_ascii_lowercase(),
+                DnsCacheEntry {
+                    addrs: ips.clone(),
+                    expires_at: now + Duration::from_secs(DNS_CACHE_TTL_SECS),
+                },
+            );
+        }
+        Ok(ips)
+    }
+
+    /// Resolves a host and stores its addresses in the DNS cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<IpAddr>, RequestError>` - The resolved addresses, or an error if resolution fails.
+    pub(crate) fn prefetch(host: &str) -> Result<Vec<IpAddr>, RequestError> {
+        let addrs: Vec<SocketAddr> = (host, 0)
+            .to_socket_addrs()
+            .map_err(|error: std::io::Error| {
+                RequestError::from(error).with_phase(ErrorPhase::Resolve)
+            })?
+            .collect();
+        Self::store_cached_addrs(host, addrs)
+    }
+
+    /// Resolves a host asynchronously and stores its addresses in the DNS cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<IpAddr>, RequestError>` - The resolved addresses, or an error if resolution fails.
+    #[cfg(feature = "async")]
+    pub(crate) async fn prefetch_async(host: &str) -> Result<Vec<IpAddr>, RequestError> {
+        let addrs: Vec<SocketAddr> = lookup_host((host, 0))
+            .await
+            .map_err(|error: std::io::Error| {
+                RequestError::from(error).with_phase(ErrorPhase::Resolve)
+            })?
+            .collect();
+        Self::store_cached_addrs(host, addrs)
+    }
+
+    /// Connects to the first reachable address resolved for the host.
+    ///
+    /// Every resolved address is tried in order until one accepts the connection.
+    /// Each attempt is bounded by the time left before the overall deadline, so a
+    /// fast failure (e.g. connection reset or refused) moves on to the next address
+    /// while a hanging one cannot exceed the configured timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    /// - `Duration` - The overall deadline for all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TcpStream, RequestError>` - The connected stream, or the last connection error.
+    #[cfg(feature = "tls")]
+    pub(crate) fn connect(
+        host: &str,
+        port: u16,
+        timeout_duration: Duration,
+    ) -> Result<TcpStream, RequestError> {
+        let deadline: Instant = Instant::now() + timeout_duration;
+        let lookup_host: String = host.to_string();
+        let addrs: Vec<SocketAddr> = Self::resolve_with_timeout(
+            move || Self::resolve(&lookup_host, port),
+            timeout_duration,
+        )?;
+        Self::connect_to(addrs, deadline)
+    }
+
+    /// Connects to the first reachable address of a list.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<SocketAddr>` - The candidate addresses, tried in order.
+    /// - `Instant` - The deadline for all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TcpStream, RequestError>` - The connected stream, or the last connection error.
+    pub(crate) fn connect_to(
+        addrs: Vec<SocketAddr>,
+        deadline: Instant,
+    ) -> Result<TcpStream, RequestError> {
+        let mut last_error: Option<std::io::Error> = None;
+        for addr in addrs {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            match TcpStream::connect_timeout(&addr, remaining) {
+                Ok(tcp_stream) => return Ok(tcp_stream),
+                Err(error) => last_error = Some(error),
+            }
+        }
+        Err(last_error.map_or(
+            RequestError::timeout(ErrorPhase::Connect, "Connection timeout"),
+            |error: std::io::Error| RequestError::from(error).with_phase(ErrorPhase::Connect),
+        ))
+    }
+
+    /// Connects asynchronously to the first reachable address resolved for the host.
+    ///
+    /// Resolution and every connection attempt share one overall deadline; addresses
+    /// are tried in order until one accepts the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address.
+    /// - `u16` - The port number.
+    /// - `Duration` - The overall deadline for resolution and all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<AsyncTcpStream, RequestError>` - The connected stream, or the last connection error.
+    #[cfg(feature = "websocket")]
+    pub(crate) async fn connect_async(
+        host: &str,
+        port: u16,
+        timeout_duration: Duration,
+    ) -> Result<AsyncTcpStream, RequestError> {
+        let deadline: Instant = Instant::now() + timeout_duration;
+        let addrs: Vec<SocketAddr> = timeout(timeout_duration, Self::resolve_async(host, port))
+            .await
+            .map_err(|_| RequestError::timeout(ErrorPhase::Resolve, "DNS resolution timeout"))??;
+        Self::connect_to_async(addrs, deadline).await
+    }
+
+    /// Connects asynchronously to the first reachable address of a list.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<SocketAddr>` - The candidate addresses, tried in order.
+    /// - `Instant` - The deadline for all connection attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<AsyncTcpStream, RequestError>` - The connected stream, or the last connection error.
+    #[cfg(feature = "async")]
+    pub(crate) async fn connect_to_async(
+        addrs: Vec<SocketAddr>,
+        deadline: Instant,
+    ) -> Result<AsyncTcpStream, RequestError> {
+        let mut last_error: Option<std::io::Error> = None;
+        for addr in addrs {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            match timeout(remaining, AsyncTcpStream::connect(addr)).await {
+                Ok(Ok(tcp_stream)) => return Ok(tcp_stream),
+                Ok(Err(error)) => last_error = Some(error),
+                Err(_) => break,
+            }
+        }
+        Err(last_error.map_or(
+            RequestError::timeout(ErrorPhase::Connect, "Connection timeout"),
+            |error: std::io::Error| RequestError::from(error).with_phase(ErrorPhase::Connect),
+        ))
+    }
+
+    /// Builds the TLS configuration used for HTTP connections.
+    ///
+    /// HTTP/1.1 is the only application protocol offered through ALPN, so servers
+    /// that also support HTTP/2 keep speaking HTTP/1.1 on the connection. When
+    /// verification is skipped, any certificate chain is accepted and only the
+    /// handshake signatures are checked.
+    ///
+    /// # Arguments
+    ///
+    /// - `RootCertStore` - The trusted root certificates.
+    /// - `bool` - Whether to skip verifying the server certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `ClientConfig` - The TLS configuration.
+    #[cfg(feature = "tls")]
+    pub(crate) fn get_tls_config(
+        roots: RootCertStore,
+        insecure_skip_tls_verify: bool,
+    ) -> ClientConfig {
+        let mut tls_config: ClientConfig = if insecure_skip_tls_verify {
+            let verifier: InspectionVerifier = InspectionVerifier {
+                algorithms: default_provider().signature_verification_algorithms,
+            };
+            ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(verifier))
+                .with_no_client_auth()
+        } else {
+            ClientConfig::builder()
+                .with_root_certificates(roots)
+                .with_no_client_auth()
+        };
+        tls_config.alpn_protocols = vec![ALPN_HTTP_1_1.to_vec()];
+        tls_config
+    }
+
+    /// Connects and completes a TLS handshake without sending any application data.
+    ///
+    /// The returned session exposes the negotiated parameters and the certificate
+    /// chain presented by the server; the connection itself is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hostname or IP address, also used for SNI and certificate verification.
+    /// - `u16` - The port number.
+    /// - `Duration` - The deadline for connecting and for each socket operation.
+    /// - `ClientConfig` - The TLS configuration, including how certificates are verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ClientConnection, RequestError>` - The session after the handshake completed.
+    #[cfg(feature = "tls")]
+    pub(crate) fn tls_handshake(
+        host: &str,
+        port: u16,
+        timeout_duration: Duration,
+        tls_config: ClientConfig,
+    ) -> Result<ClientConnection, RequestError> {
+        let mut tcp_stream: TcpStream = Self::connect(host, port, timeout_duration)?;
+        tcp_stream
+            .set_read_timeout(Some(timeout_duration))
+            .map_err(RequestError::from)?;
+        tcp_stream
+            .set_write_timeout(Some(timeout_duration))
+            .map_err(RequestError::from)?;
+        let dns_name: ServerName<'_> =
+            ServerName::try_from(host.to_string()).map_err(RequestError::from)?;
+        let mut session: ClientConnection =
+            ClientConnection::new(Arc::new(tls_config), dns_name).map_err(RequestError::from)?;
+        while session.is_handshaking() {
+            session
+                .complete_io(&mut tcp_stream)
+                .map_err(|error: std::io::Error| {
+                    RequestError::from(error).with_phase(ErrorPhase::Tls)
+                })?;
+        }
+        Ok(session)
+    }
+
+    /// Sends a CONNECT request to a proxy and reads its response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Box<dyn ReadWrite>` - The stream to the proxy.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `Option<&str>` - The `Proxy-Authorization` header value, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The tunnel bytes read past the response headers.
+    #[cfg(feature = "proxy")]
+    pub(crate) fn proxy_connect(
+        proxy_stream: &mut Box<dyn ReadWrite>,
+        target_host: &str,
+        target_port: u16,
+        authorization: Option<&str>,
+    ) -> Result<Vec<u8>, RequestError> {
+        let connect_request: Vec<u8> =
+            SharedRequestBuilder::build_connect_request(target_host, target_port, authorization);
+        proxy_stream
+            .write_all(&connect_request)
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
+        proxy_stream.flush().map_err(|error: std::io::Error| {
+            RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+        })?;
+        let mut response_bytes: Vec<u8> = Vec::new();
+        let mut response_buffer: [u8; 1024] = [0u8; 1024];
+        while SharedResponseHandler::find_double_crlf(&response_bytes, 0).is_none() {
+            let bytes_read: usize =
+                proxy_stream
+                    .read(&mut response_buffer)
+                    .map_err(|error: std::io::Error| {
+                        RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+                    })?;
+            if bytes_read == 0 {
+                break;
+            }
+            response_bytes.extend_from_slice(&response_buffer[..bytes_read]);
+        }
+        SharedResponseHandler::parse_connect_response(&response_bytes)
+    }
+
+    /// Sends a CONNECT request to a proxy and reads its response asynchronously.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The stream to the proxy.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `Option<&str>` - The `Proxy-Authorization` header value, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The tunnel bytes read past the response headers.
+    #[cfg(feature = "async")]
+    #[cfg(feature = "proxy")]
+    pub(crate) async fn proxy_connect_async(
+        proxy_stream: &mut BoxAsyncReadWrite,
+        target_host: &str,
+        target_port: u16,
+        authorization: Option<&str>,
+    ) -> Result<Vec<u8>, RequestError> {
+        let connect_request: Vec<u8> =
+            SharedRequestBuilder::build_connect_request(target_host, target_port, authorization);
+        proxy_stream
+            .write_all(&connect_request)
+            .await
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
+        proxy_stream
+            .flush()
+            .await
+            .map_err(|error: std::io::Error| {
+                RequestError::proxy(ProxyErrorPhase::ConnectCommand, error)
+            })?;
+        let mut response_bytes: Vec<u8> = Vec::new();
+        let mut response_buffer: [u8; 1024] = [0u8; 1024];
+        while SharedResponseHandler::find_double_crlf(&response_bytes, 0).is_none() {
+            let bytes_read: usize = proxy_stream.read(&mut response_buffer).await.map_err(
+                |error: std::io::Error| RequestError::proxy(ProxyErrorPhase::ConnectCommand, error),
+            )?;
+            if bytes_read == 0 {
+                break;
+            }
+            response_bytes.extend_from_slice(&response_buffer[..bytes_read]);
+        }
+        SharedResponseHandler::parse_connect_response(&response_bytes)
+    }
+}
+
+impl ResolveSlot {
+    /// Waits for a free helper thread slot for a blocking DNS lookup.
+    ///
+    /// # Arguments
+    ///
+    /// - `Instant` - The deadline of the wait.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResolveSlot>` - The slot, or `None` if none came free before the deadline.
+    pub(crate) fn acquire(deadline: Instant) -> Option<Self> {
+        let mut in_flight: StdMutexGuard<'_, usize> = RESOLVE_THREADS.lock().ok()?;
+        while *in_flight >= MAX_RESOLVE_THREADS {
+            let remaining: Duration = deadline
+                .checked_duration_since(Instant::now())
+                .filter(|remaining: &Duration| !remaining.is_zero())?;
+            in_flight = RESOLVE_THREAD_RELEASED
+                .wait_timeout(in_flight, remaining)
+                .ok()?
+                .0;
+        }
+        *in_flight += 1;
+        Some(Self)
+    }
+}
+
+impl Drop for ResolveSlot {
+    /// Frees the slot and wakes a lookup waiting for one.
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = RESOLVE_THREADS.lock() {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+        RESOLVE_THREAD_RELEASED.notify_one();
+    }
+}
@@ -1,6 +1,9 @@
+mod r#const;
 mod r#impl;
+mod r#static;
 mod r#struct;
+mod r#type;
 
-pub(crate) use r#struct::*;
+pub(crate) use {r#const::*, r#static::*, r#struct::*, r#type::*};
 
 use super::*;
@@ -0,0 +1,11 @@
+use super::*;
+
+/// Addresses of prefetched hosts, keyed by lowercase host name.
+pub(crate) static DNS_CACHE: LazyLock<RwLock<HashMapXxHash3_64<String, DnsCacheEntry>>> =
+    LazyLock::new(|| RwLock::new(hash_map_xx_hash3_64()));
+
+/// Number of blocking DNS lookups running on helper threads.
+pub(crate) static RESOLVE_THREADS: StdMutex<usize> = StdMutex::new(0);
+
+/// Signalled whenever a DNS lookup helper thread finishes.
+pub(crate) static RESOLVE_THREAD_RELEASED: Condvar = Condvar::new();
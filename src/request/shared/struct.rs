@@ -1,5 +1,23 @@
+use super::*;
+
 /// A shared builder for constructing HTTP requests.
 pub(crate) struct SharedRequestBuilder;
 
 /// A shared handler for processing HTTP responses.
 pub(crate) struct SharedResponseHandler;
+
+/// A shared connector for establishing TCP connections.
+pub(crate) struct SharedConnector;
+
+/// A helper thread slot held by a blocking DNS lookup, released on drop.
+#[derive(Debug)]
+pub(crate) struct ResolveSlot;
+
+/// Addresses of a prefetched host.
+#[derive(Clone, Debug)]
+pub(crate) struct DnsCacheEntry {
+    /// The resolved addresses.
+    pub(crate) addrs: Vec<IpAddr>,
+    /// When the entry stops being used.
+    pub(crate) expires_at: Instant,
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+/// Outcome of a DNS lookup sent back from its helper thread.
+pub(crate) type ResolveResult = Result<Vec<SocketAddr>, RequestError>;
@@ -0,0 +1,18 @@
+/// Header carrying the credentials of a request.
+pub(crate) const AUTHORIZATION_HEADER: &str = "Authorization";
+
+/// Header carrying the signing time of the HMAC-SHA256 signer.
+pub(crate) const X_DATE: &str = "X-Date";
+
+/// Header carrying the hex SHA-256 of the body signed by the HMAC-SHA256 signer.
+pub(crate) const X_CONTENT_SHA256: &str = "X-Content-SHA256";
+
+/// Name of the HMAC-SHA256 signing scheme.
+pub(crate) const HMAC_SHA256_SCHEME: &str = "HMAC-SHA256";
+
+/// Body hash used when a streamed body cannot be signed.
+pub(crate) const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Headers signed by default by the HMAC-SHA256 signer when they are present.
+pub(crate) const DEFAULT_SIGNED_HEADERS: [&str; 4] =
+    ["host", "content-type", "x-date", "x-content-sha256"];
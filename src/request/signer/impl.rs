@@ -0,0 +1,235 @@
+use super::*;
+
+impl SignableRequest<'_> {
+    /// Gets the request method.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The HTTP method.
+    pub fn get_method(&self) -> &str {
+        self.method
+    }
+
+    /// Gets the path as written on the request line.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The percent-encoded origin-form path.
+    pub fn get_path(&self) -> &str {
+        self.path
+    }
+
+    /// Gets the query string as written on the request line.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The percent-encoded query without the leading `?`, empty if there is none.
+    pub fn get_query(&self) -> &str {
+        self.query
+    }
+
+    /// Gets the headers written with the request.
+    ///
+    /// # Returns
+    ///
+    /// - `&RequestHeaders` - The headers, including the defaults added by the client.
+    pub fn get_headers(&self) -> &RequestHeaders {
+        self.headers
+    }
+
+    /// Gets the values of a header, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&VecDeque<String>>` - The header values, or None if the header is absent.
+    pub fn get_header(&self, key: &str) -> Option<&VecDeque<String>> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, values)| values)
+    }
+
+    /// Gets the body written with the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&[u8]>` - The encoded body bytes, or None if there is none or it is streamed.
+    pub fn get_body(&self) -> Option<&[u8]> {
+        self.body
+    }
+
+    /// Sets a header, replacing any existing value regardless of case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The request for method chaining.
+    pub fn set_header(&mut self, key: &str, value: &str) -> &mut Self {
+        self.remove_header(key);
+        let mut value_deque: VecDeque<String> = VecDeque::new();
+        value_deque.push_front(value.to_string());
+        self.headers.insert(key.to_string(), value_deque);
+        self
+    }
+
+    /// Removes a header, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The request for method chaining.
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        self.headers
+            .retain(|existing_key: &String, _| !existing_key.eq_ignore_ascii_case(key));
+        self
+    }
+}
+
+impl HmacSha256Signer {
+    /// Creates a signer signing `Host`, `Content-Type`, `X-Date`, and `X-Content-SHA256`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The identifier of the key.
+    /// - `&[u8]` - The secret key.
+    ///
+    /// # Returns
+    ///
+    /// - `HmacSha256Signer` - The signer.
+    pub fn new(key_id: &str, secret: &[u8]) -> Self {
+        Self {
+            key_id: key_id.to_string(),
+            secret: secret.to_vec(),
+            signed_headers: DEFAULT_SIGNED_HEADERS
+                .iter()
+                .map(|name: &&str| name.to_string())
+                .collect(),
+        }
+    }
+
+    /// Adds a header to sign when it is present on the request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The signer.
+    pub fn sign_header(mut self, name: &str) -> Self {
+        let name: String = name.to_ascii_lowercase();
+        if !self.signed_headers.contains(&name) {
+            self.signed_headers.push(name);
+        }
+        self
+    }
+
+    /// Gets the identifier of the key.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The key identifier.
+    pub fn get_key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Builds the canonical request that is signed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&SignableRequest<'_>` - The request.
+    /// - `&[String]` - The lowercase, sorted names of the signed headers.
+    /// - `&str` - The hex SHA-256 of the body.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The canonical request.
+    fn get_canonical_request(
+        request: &SignableRequest<'_>,
+        signed_headers: &[String],
+        body_hash: &str,
+    ) -> String {
+        let mut canonical: String = format!(
+            "{}\n{}\n{}\n",
+            request.get_method(),
+            request.get_path(),
+            request.get_query()
+        );
+        for name in signed_headers {
+            let values: Vec<&str> = request
+                .get_header(name)
+                .map(|values: &VecDeque<String>| values.iter().map(|value| value.trim()).collect())
+                .unwrap_or_default();
+            canonical.push_str(name);
+            canonical.push(':');
+            canonical.push_str(&values.join(","));
+            canonical.push('\n');
+        }
+        canonical.push('\n');
+        canonical.push_str(&signed_headers.join(";"));
+        canonical.push('\n');
+        canonical.push_str(body_hash);
+        canonical
+    }
+}
+
+impl RequestSigner for HmacSha256Signer {
+    fn sign(&self, request: &mut SignableRequest<'_>) -> Result<(), RequestError> {
+        if request.get_header(X_DATE).is_none() {
+            let now: i64 = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64);
+            request.set_header(X_DATE, &format_basic_timestamp(now));
+        }
+        let body_hash: String = request
+            .get_body()
+            .map_or(UNSIGNED_PAYLOAD.to_string(), |body: &[u8]| {
+                hex_encode(&Sha256::digest(body))
+            });
+        request.set_header(X_CONTENT_SHA256, &body_hash);
+        let mut signed_headers: Vec<String> = self
+            .signed_headers
+            .iter()
+            .filter(|name: &&String| request.get_header(name).is_some())
+            .cloned()
+            .collect();
+        signed_headers.sort();
+        let canonical: String = Self::get_canonical_request(request, &signed_headers, &body_hash);
+        let signature: String = hex_encode(&hmac_sha256(&self.secret, canonical.as_bytes()));
+        request.set_header(
+            AUTHORIZATION_HEADER,
+            &format!(
+                "{HMAC_SHA256_SCHEME} KeyId={}, SignedHeaders={}, Signature={signature}",
+                self.key_id,
+                signed_headers.join(";")
+            ),
+        );
+        Ok(())
+    }
+}
+
+impl Debug for HmacSha256Signer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacSha256Signer")
+            .field("key_id", &self.key_id)
+            .field("signed_headers", &self.signed_headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for SignerHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("SignerHook")
+    }
+}
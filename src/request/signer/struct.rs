@@ -0,0 +1,41 @@
+use super::*;
+
+/// Final view of a request passed to a `RequestSigner`.
+pub struct SignableRequest<'a> {
+    /// The request method.
+    pub(crate) method: &'a str,
+    /// The percent-encoded path.
+    pub(crate) path: &'a str,
+    /// The percent-encoded query string without the leading `?`.
+    pub(crate) query: &'a str,
+    /// The headers written with the request.
+    pub(crate) headers: &'a mut RequestHeaders,
+    /// The body bytes, or None if there is none or it is streamed.
+    pub(crate) body: Option<&'a [u8]>,
+}
+
+/// Reference signer authenticating requests with an HMAC-SHA256 signature.
+///
+/// The signer sets `X-Date` to the signing time unless the request already has one,
+/// sets `X-Content-SHA256` to the hex SHA-256 of the body, or `UNSIGNED-PAYLOAD`
+/// for streamed bodies, and signs the canonical request
+///
+/// ```text
+/// METHOD\npath\nquery\nname:value\n...\n\nsigned-headers\nbody-hash
+/// ```
+///
+/// where the signed headers are lowercase, sorted, and joined with `;`. The result
+/// is sent as `Authorization: HMAC-SHA256 KeyId=<id>, SignedHeaders=<names>, Signature=<hex>`.
+#[derive(Clone)]
+pub struct HmacSha256Signer {
+    /// The identifier of the key, sent in the clear.
+    pub(crate) key_id: String,
+    /// The secret key.
+    pub(crate) secret: Vec<u8>,
+    /// The lowercase names of the headers signed when present.
+    pub(crate) signed_headers: Vec<String>,
+}
+
+/// Signer invoked before a request is written.
+#[derive(Clone)]
+pub(crate) struct SignerHook(pub(crate) ArcRequestSigner);
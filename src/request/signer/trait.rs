@@ -0,0 +1,19 @@
+use super::*;
+
+/// Signer adding credentials to a request once its bytes are final.
+///
+/// The signer runs for every attempt, including redirect hops and retries, after
+/// the default headers such as `Host` and `Content-Length` were added and the body
+/// was encoded and compressed, right before the request is written to the stream.
+pub trait RequestSigner: Send + Sync {
+    /// Signs a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut SignableRequest<'_>` - The request, whose headers may be added or replaced.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once signed, or an error failing the request.
+    fn sign(&self, request: &mut SignableRequest<'_>) -> Result<(), RequestError>;
+}
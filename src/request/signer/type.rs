@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared signer invoked before a request is written.
+pub(crate) type ArcRequestSigner = Arc<dyn RequestSigner>;
@@ -0,0 +1,9 @@
+use super::*;
+
+/// The part a request plays in a shared call.
+pub(crate) enum SingleFlightRole {
+    /// The request sends the call and shares its outcome through the guard.
+    Leader(SingleFlightGuard),
+    /// The request waits for the outcome of a call sent by another request.
+    Follower(Arc<SingleFlightCall>),
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+impl SingleFlightCall {
+    /// Joins the call in progress for a request hash, or starts a new one.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The request hash.
+    ///
+    /// # Returns
+    ///
+    /// - `SingleFlightRole` - Leader if no identical request is in flight, follower otherwise.
+    pub(crate) fn join(key: u64) -> SingleFlightRole {
+        let Ok(mut calls) = SINGLE_FLIGHT_CALLS.lock() else {
+            return SingleFlightRole::Leader(SingleFlightGuard {
+                key,
+                call: Arc::new(Self::default()),
+            });
+        };
+        if let Some(call) = calls.get(&key) {
+            return SingleFlightRole::Follower(Arc::clone(call));
+        }
+        let call: Arc<Self> = Arc::new(Self::default());
+        calls.insert(key, Arc::clone(&call));
+        SingleFlightRole::Leader(SingleFlightGuard { key, call })
+    }
+
+    /// Gets the shared result if it is set.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<HttpResponseBinary, RequestError>>` - A copy of the result, or None
+    ///   while the leading request is in flight.
+    #[cfg(feature = "async")]
+    fn get_result(&self) -> Option<Result<HttpResponseBinary, RequestError>> {
+        self.result.lock().ok()?.clone()
+    }
+
+    /// Blocks until the leading request completes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - A copy of the shared result.
+    pub(crate) fn wait(&self) -> Result<HttpResponseBinary, RequestError> {
+        self.result
+            .lock()
+            .ok()
+            .and_then(|result| {
+                self.ready
+                    .wait_while(
+                        result,
+                        |result: &mut Option<Result<HttpResponseBinary, RequestError>>| {
+                            result.is_none()
+                        },
+                    )
+                    .ok()
+            })
+            .and_then(|result| result.clone())
+            .unwrap_or_else(|| {
+                Err(RequestError::Request(
+                    "Shared request result is unavailable".to_string(),
+                ))
+            })
+    }
+
+    /// Waits asynchronously until the leading request completes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - A copy of the shared result.
+    #[cfg(feature = "async")]
+    pub(crate) async fn wait_async(&self) -> Result<HttpResponseBinary, RequestError> {
+        loop {
+            let mut notified: Pin<&mut Notified<'_>> = pin!(self.notify.notified());
+            notified.as_mut().enable();
+            if let Some(result) = self.get_result() {
+                return result;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl SingleFlightGuard {
+    /// Shares the result of the leading request and unregisters the call.
+    ///
+    /// # Arguments
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The result to share.
+    pub(crate) fn complete(&self, result: Result<HttpResponseBinary, RequestError>) {
+        if let Ok(mut calls) = SINGLE_FLIGHT_CALLS.lock()
+            && calls
+                .get(&self.key)
+                .is_some_and(|call: &Arc<SingleFlightCall>| Arc::ptr_eq(call, &self.call))
+        {
+            calls.remove(&self.key);
+        }
+        if let Ok(mut shared) = self.call.result.lock()
+            && shared.is_none()
+        {
+            *shared = Some(result);
+        }
+        self.call.ready.notify_all();
+        #[cfg(feature = "async")]
+        self.call.notify.notify_waiters();
+    }
+}
+
+impl Drop for SingleFlightGuard {
+    /// Fails the followers if the leading request was dropped before completing.
+    fn drop(&mut self) {
+        self.complete(Err(RequestError::Request(
+            "Shared request was cancelled before completing".to_string(),
+        )));
+    }
+}
@@ -0,0 +1,8 @@
+mod r#enum;
+mod r#impl;
+mod r#static;
+mod r#struct;
+
+pub(crate) use {r#enum::*, r#static::*, r#struct::*};
+
+use super::*;
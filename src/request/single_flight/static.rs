@@ -0,0 +1,6 @@
+use super::*;
+
+/// Calls shared by concurrent identical requests, keyed by request hash.
+pub(crate) static SINGLE_FLIGHT_CALLS: LazyLock<
+    StdMutex<HashMapXxHash3_64<u64, Arc<SingleFlightCall>>>,
+> = LazyLock::new(|| StdMutex::new(hash_map_xx_hash3_64()));
@@ -0,0 +1,22 @@
+use super::*;
+
+/// Outcome of a request shared with the identical requests that joined it.
+#[derive(Debug, Default)]
+pub(crate) struct SingleFlightCall {
+    /// The response or error, once the leading request has completed.
+    pub(crate) result: StdMutex<Option<Result<HttpResponseBinary, RequestError>>>,
+    /// Wakes synchronous followers when the result is set.
+    pub(crate) ready: Condvar,
+    /// Wakes asynchronous followers when the result is set.
+    #[cfg(feature = "async")]
+    pub(crate) notify: Notify,
+}
+
+/// Leadership of a shared call, completing it with an error if dropped unfinished.
+#[derive(Debug)]
+pub(crate) struct SingleFlightGuard {
+    /// The request hash the call is registered under.
+    pub(crate) key: u64,
+    /// The shared call.
+    pub(crate) call: Arc<SingleFlightCall>,
+}
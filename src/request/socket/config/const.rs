@@ -0,0 +1,27 @@
+use super::*;
+
+/// Delay before the first WebSocket connect retry, in milliseconds.
+pub(crate) const DEFAULT_CONNECT_RETRY_BACKOFF_MS: u64 = 200;
+
+/// Upper bound of the delay between WebSocket connect retries, in milliseconds.
+pub(crate) const MAX_CONNECT_RETRY_BACKOFF_MS: u64 = 10_000;
+
+/// Number of attempts made to restore a lost WebSocket connection.
+pub(crate) const DEFAULT_RECONNECT_MAX_ATTEMPTS: usize = 5;
+
+/// Close code reported when a Close frame carries no status code (RFC 6455 section 7.1.5).
+pub(crate) const CLOSE_CODE_NO_STATUS: u16 = 1005;
+
+/// Maximum length in bytes of a Close frame reason, leaving room for the status code
+/// within the 125 byte control frame payload.
+pub(crate) const MAX_CLOSE_REASON_LEN: usize = 123;
+
+/// Headers set by the WebSocket handshake itself, not copied from an HTTP request.
+pub(crate) const WEBSOCKET_HANDSHAKE_HEADERS: [&str; 6] = [
+    HOST,
+    CONNECTION,
+    UPGRADE,
+    SEC_WEBSOCKET_KEY,
+    SEC_WEBSOCKET_VERSION,
+    CONTENT_LENGTH,
+];
@@ -1,14 +1,93 @@
 use super::*;
 
+impl WebSocketConfig {
+    pub(crate) fn get_tungstenite_config(&self) -> TungsteniteWebSocketConfig {
+        let mut config: TungsteniteWebSocketConfig = TungsteniteWebSocketConfig::default();
+        if let Some(write_buffer_size) = self.write_buffer_size {
+            config.write_buffer_size = write_buffer_size;
+        }
+        if let Some(max_write_buffer_size) = self.max_write_buffer_size {
+            config.max_write_buffer_size = max_write_buffer_size;
+        }
+        config.max_write_buffer_size = config
+            .max_write_buffer_size
+            .max(config.write_buffer_size.saturating_add(1));
+        if let Some(max_message_size) = self.max_message_size {
+            config.max_message_size = Some(max_message_size);
+        }
+        if let Some(max_frame_size) = self.max_frame_size {
+            config.max_frame_size = Some(max_frame_size);
+        }
+        config
+    }
+
+    pub(crate) fn get_connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout.unwrap_or(self.timeout))
+    }
+
+    pub(crate) fn get_handshake_timeout(&self) -> Duration {
+        Duration::from_millis(self.handshake_timeout.unwrap_or(self.timeout))
+    }
+
+    pub(crate) fn get_message_timeout(&self) -> Duration {
+        Duration::from_millis(self.message_timeout.unwrap_or(self.timeout))
+    }
+
+    pub(crate) fn get_close_timeout(&self) -> Duration {
+        Duration::from_millis(self.close_timeout.unwrap_or(self.timeout))
+    }
+
+    pub(crate) fn get_resolved_addrs(&self, port: u16) -> Vec<SocketAddr> {
+        self.resolved_addrs
+            .iter()
+            .map(|ip: &IpAddr| SocketAddr::new(*ip, port))
+            .collect()
+    }
+
+    pub(crate) fn get_connect_retry_delay(&self, attempt: u32) -> Duration {
+        Self::get_backoff_delay(self.connect_retry_backoff, attempt)
+    }
+
+    pub(crate) fn get_reconnect_delay(&self, attempt: u32) -> Duration {
+        Self::get_backoff_delay(self.reconnect_backoff, attempt)
+    }
+
+    fn get_backoff_delay(backoff: u64, attempt: u32) -> Duration {
+        let delay: u64 = backoff
+            .saturating_mul(2u64.saturating_pow(attempt))
+            .min(MAX_CONNECT_RETRY_BACKOFF_MS);
+        Duration::from_millis(delay)
+    }
+}
+
 impl Default for WebSocketConfig {
     #[inline(always)]
     fn default() -> Self {
         Self {
             timeout: DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+            connect_timeout: None,
+            handshake_timeout: None,
+            message_timeout: None,
+            close_timeout: None,
             url_obj: HttpUrlComponents::default(),
             buffer: DEFAULT_BUFFER_SIZE,
             protocols: Vec::new(),
+            #[cfg(feature = "proxy")]
             proxy: None,
+            resolved_addrs: Vec::new(),
+            insecure_skip_tls_verify: false,
+            auto_flush: true,
+            write_buffer_size: None,
+            max_write_buffer_size: None,
+            max_message_size: None,
+            max_frame_size: None,
+            send_queue_limit: None,
+            fail_on_backpressure: false,
+            connect_retries: 0,
+            connect_retry_backoff: DEFAULT_CONNECT_RETRY_BACKOFF_MS,
+            reconnect: false,
+            reconnect_max_attempts: DEFAULT_RECONNECT_MAX_ATTEMPTS,
+            reconnect_backoff: DEFAULT_CONNECT_RETRY_BACKOFF_MS,
         }
     }
 }
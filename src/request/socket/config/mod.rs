@@ -1,6 +1,7 @@
+mod r#const;
 mod r#impl;
 mod r#struct;
 
-pub(crate) use r#struct::*;
+pub(crate) use {r#const::*, r#struct::*};
 
 use super::*;
@@ -3,8 +3,27 @@ use super::*;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct WebSocketConfig {
     pub(crate) timeout: u64,
+    pub(crate) connect_timeout: Option<u64>,
+    pub(crate) handshake_timeout: Option<u64>,
+    pub(crate) message_timeout: Option<u64>,
+    pub(crate) close_timeout: Option<u64>,
     pub(crate) url_obj: HttpUrlComponents,
     pub(crate) buffer: usize,
     pub(crate) protocols: Vec<String>,
+    #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<ProxyConfig>,
+    pub(crate) resolved_addrs: Vec<IpAddr>,
+    pub(crate) insecure_skip_tls_verify: bool,
+    pub(crate) auto_flush: bool,
+    pub(crate) write_buffer_size: Option<usize>,
+    pub(crate) max_write_buffer_size: Option<usize>,
+    pub(crate) max_message_size: Option<usize>,
+    pub(crate) max_frame_size: Option<usize>,
+    pub(crate) send_queue_limit: Option<usize>,
+    pub(crate) fail_on_backpressure: bool,
+    pub(crate) connect_retries: usize,
+    pub(crate) connect_retry_backoff: u64,
+    pub(crate) reconnect: bool,
+    pub(crate) reconnect_max_attempts: usize,
+    pub(crate) reconnect_backoff: u64,
 }
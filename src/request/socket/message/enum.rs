@@ -6,5 +6,5 @@ pub enum WebSocketMessage {
     Binary(Vec<u8>),
     Ping(Vec<u8>),
     Pong(Vec<u8>),
-    Close,
+    Close(Option<WebSocketCloseFrame>),
 }
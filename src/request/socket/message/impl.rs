@@ -18,7 +18,18 @@ impl WebSocketMessage {
     }
 
     pub fn close() -> Self {
-        Self::Close
+        Self::Close(None)
+    }
+
+    pub fn close_with<T: ToString>(code: u16, reason: T) -> Self {
+        Self::Close(Some(WebSocketCloseFrame::new(code, reason)))
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Result<Self, WebSocketError> {
+        serde_json::to_string(value)
+            .map(Self::Text)
+            .map_err(|error: serde_json::Error| WebSocketError::json(error.to_string()))
     }
 
     pub fn is_text(&self) -> bool {
@@ -38,7 +49,7 @@ impl WebSocketMessage {
     }
 
     pub fn is_close(&self) -> bool {
-        matches!(self, Self::Close)
+        matches!(self, Self::Close(_))
     }
 
     pub fn as_text(&self) -> Option<&str> {
@@ -55,6 +66,13 @@ impl WebSocketMessage {
         }
     }
 
+    pub fn as_close(&self) -> Option<&WebSocketCloseFrame> {
+        match self {
+            Self::Close(frame) => frame.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn into_text(self) -> Option<String> {
         match self {
             Self::Text(text) => Some(text),
@@ -68,4 +86,23 @@ impl WebSocketMessage {
             _ => None,
         }
     }
+
+    #[cfg(feature = "json")]
+    pub fn parse_json<T: DeserializeOwned>(&self) -> Result<T, WebSocketError> {
+        match self {
+            Self::Text(text) => serde_json::from_str(text)
+                .map_err(|error: serde_json::Error| WebSocketError::json(error.to_string())),
+            _ => Err(WebSocketError::json("Expected a text message")),
+        }
+    }
+
+    pub(crate) fn into_message(self) -> Message {
+        match self {
+            Self::Text(text) => Message::Text(text.into()),
+            Self::Binary(data) => Message::Binary(data.into()),
+            Self::Ping(data) => Message::Ping(data.into()),
+            Self::Pong(data) => Message::Pong(data.into()),
+            Self::Close(frame) => Message::Close(frame.map(WebSocketCloseFrame::into_frame)),
+        }
+    }
 }
@@ -1,5 +1,6 @@
 mod config;
 mod message;
+#[cfg(feature = "proxy")]
 mod proxy;
 mod shared;
 mod websocket;
@@ -7,6 +8,9 @@ mod websocket_builder;
 
 pub use {message::*, shared::*, websocket::*, websocket_builder::*};
 
-pub(crate) use {config::*, proxy::*};
+pub(crate) use config::*;
+
+#[cfg(feature = "proxy")]
+pub(crate) use proxy::*;
 
 use super::*;
@@ -2,13 +2,16 @@ use super::*;
 
 impl std::fmt::Display for WebSocketError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self.kind {
+        match &self.kind {
             WebSocketErrorKind::Connection => write!(f, "Connection error: {}", self.message),
             WebSocketErrorKind::String => write!(f, "String error: {}", self.message),
             WebSocketErrorKind::Timeout => write!(f, "Timeout error: {}", self.message),
             WebSocketErrorKind::InvalidUrl => write!(f, "Invalid URL: {}", self.message),
             WebSocketErrorKind::Io => write!(f, "IO error: {}", self.message),
             WebSocketErrorKind::Tls => write!(f, "TLS error: {}", self.message),
+            WebSocketErrorKind::Backpressure => write!(f, "Backpressure error: {}", self.message),
+            WebSocketErrorKind::Json => write!(f, "JSON error: {}", self.message),
+            WebSocketErrorKind::ProxyAuthentication(_) => f.write_str(&self.message),
         }
     }
 }
@@ -16,6 +19,18 @@ impl std::fmt::Display for WebSocketError {
 impl std::error::Error for WebSocketError {}
 
 impl WebSocketError {
+    pub fn kind(&self) -> &WebSocketErrorKind {
+        &self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn is_backpressure(&self) -> bool {
+        self.kind == WebSocketErrorKind::Backpressure
+    }
+
     pub(crate) fn connection<T: ToString>(message: T) -> Self {
         Self {
             kind: WebSocketErrorKind::Connection,
@@ -57,6 +72,45 @@ impl WebSocketError {
             message: message.to_string(),
         }
     }
+
+    pub(crate) fn backpressure<T: ToString>(message: T) -> Self {
+        Self {
+            kind: WebSocketErrorKind::Backpressure,
+            message: message.to_string(),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub(crate) fn json<T: ToString>(message: T) -> Self {
+        Self {
+            kind: WebSocketErrorKind::Json,
+            message: message.to_string(),
+        }
+    }
+
+    #[cfg(feature = "proxy")]
+    pub(crate) fn from_proxy_error(error: RequestError) -> Self {
+        match error {
+            RequestError::ProxyAuthenticationRequired(challenges) => Self {
+                message: RequestError::ProxyAuthenticationRequired(challenges.clone()).to_string(),
+                kind: WebSocketErrorKind::ProxyAuthentication(challenges),
+            },
+            RequestError::Request(message) => {
+                Self::connection(format!("Proxy connection failed: {message}"))
+            }
+            RequestError::Io(error) => {
+                Self::connection(format!("Proxy connection failed: {error}"))
+            }
+            error => Self::protocol(error.to_string()),
+        }
+    }
+
+    pub(crate) fn from_send_error(error: tungstenite::Error) -> Self {
+        match error {
+            tungstenite::Error::WriteBufferFull(_) => Self::backpressure("Write buffer is full"),
+            error => Self::protocol(error.to_string()),
+        }
+    }
 }
 
 impl SharedWebSocketBuilder {
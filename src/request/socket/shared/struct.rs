@@ -17,4 +17,7 @@ pub enum WebSocketErrorKind {
     InvalidUrl,
     Io,
     Tls,
+    Backpressure,
+    Json,
+    ProxyAuthentication(Vec<AuthChallenge>),
 }
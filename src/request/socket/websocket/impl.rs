@@ -18,6 +18,26 @@ impl WebSocket {
         base64_encode(&key_bytes)
     }
 
+    fn report_error<T>(&self, result: Result<T, WebSocketError>) -> Result<T, WebSocketError> {
+        if let Err(error) = &result {
+            self.callbacks.error(error);
+        }
+        result
+    }
+
+    fn mark_closed(&self) {
+        if self.connected.swap(false, Ordering::Relaxed) {
+            self.callbacks.close();
+        }
+    }
+
+    fn get_message_timeout(&self) -> Duration {
+        self.config
+            .read()
+            .map(|config| config.get_message_timeout())
+            .unwrap_or(Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS))
+    }
+
     fn get_headers(&self) -> Vec<(String, String)> {
         let mut headers: Vec<(String, String)> = Vec::new();
         for (key, value) in self.header.iter() {
@@ -32,90 +52,118 @@ impl WebSocket {
         if self.connected.load(Ordering::Relaxed) {
             return Ok(());
         }
+        let connect_retries: usize = self
+            .config
+            .read()
+            .map(|config| config.connect_retries)
+            .unwrap_or_default();
+        let mut attempt: u32 = 0;
+        loop {
+            match self.connect_once_async().await {
+                Err(error)
+                    if (attempt as usize) < connect_retries
+                        && *error.kind() != WebSocketErrorKind::InvalidUrl =>
+                {
+                    let delay: Duration = self
+                        .config
+                        .read()
+                        .map(|config| config.get_connect_retry_delay(attempt))
+                        .unwrap_or_default();
+                    sleep(delay).await;
+                    attempt += 1;
+                    self.callbacks.reconnect_attempt(attempt as usize, &error);
+                }
+                result => return self.report_error(result),
+            }
+        }
+    }
+
+    async fn connect_once_async(&self) -> Result<(), WebSocketError> {
         let url: String = self.get_url();
         if url.is_empty() {
             return Err(WebSocketError::invalid_url("URL is empty"));
         }
         let url_obj: HttpUrlComponents = SharedWebSocketBuilder::parse_url(&url)?;
         if let Ok(mut config) = self.config.write() {
-            config.url_obj = url_obj;
+            config.url_obj = url_obj.clone();
         }
-        let timeout_duration: Duration = Duration::from_millis(
-            self.config
-                .read()
-                .map(|config| config.timeout)
-                .unwrap_or(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS),
-        );
+        let (connect_timeout, handshake_timeout): (Duration, Duration) = self
+            .config
+            .read()
+            .map(|config| (config.get_connect_timeout(), config.get_handshake_timeout()))
+            .unwrap_or((
+                Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS),
+                Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS),
+            ));
+        let tungstenite_config: TungsteniteWebSocketConfig = self
+            .config
+            .read()
+            .map(|config| config.get_tungstenite_config())
+            .unwrap_or_default();
         let headers: Vec<(String, String)> = self.get_headers();
-        let mut request_builder = Request::builder().uri(&url);
+        let target_host: String = url_obj.host.clone().unwrap_or_default();
+        let target_port: u16 = url_obj.port.unwrap_or_default();
+        let mut request_builder = Request::builder()
+            .uri(&url)
+            .header(HOST, format!("{target_host}:{target_port}"))
+            .header(UPGRADE, "websocket")
+            .header(CONNECTION, "Upgrade")
+            .header(SEC_WEBSOCKET_VERSION, "13")
+            .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
         for (key, value) in &headers {
             request_builder = request_builder.header(key, value);
         }
         let request: Request = request_builder.body(()).map_err(|error| {
             WebSocketError::invalid_url(format!("Failed to build request: {error}"))
         })?;
-        let proxy_config: Option<ProxyConfig> = self
-            .config
-            .read()
-            .ok()
-            .and_then(|config| config.proxy.clone());
-        let ws_stream: WebSocketConnectionType = if let Some(proxy_config) = proxy_config {
-            let url_obj: HttpUrlComponents = self
-                .config
-                .read()
-                .map(|config| config.url_obj.clone())
-                .unwrap_or_default();
-            let target_host: String = url_obj.host.clone().unwrap_or_default();
-            let target_port: u16 = url_obj.port.unwrap_or_default();
-            let proxy_stream: BoxAsyncReadWrite = self
-                .get_proxy_connection_stream_async(target_host.clone(), target_port, &proxy_config)
-                .await?;
-            let proxy_tunnel_stream: WebSocketProxyTunnelStream =
-                WebSocketProxyTunnelStream::new(proxy_stream);
-            let mut proxy_request_builder = Request::builder().uri(&url);
-            proxy_request_builder = proxy_request_builder
-                .header(HOST, format!("{target_host}:{target_port}"))
-                .header(UPGRADE, "websocket")
-                .header(CONNECTION, "Upgrade")
-                .header(SEC_WEBSOCKET_VERSION, "13")
-                .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
-            for (key, value) in &headers {
-                proxy_request_builder = proxy_request_builder.header(key, value);
-            }
-            let protocols: Vec<String> = self
+        let ws_stream: WebSocketConnectionType = if let Some(ws_stream) = self
+            .connect_proxy_async(
+                &url,
+                &headers,
+                &tungstenite_config,
+                connect_timeout,
+                handshake_timeout,
+            )
+            .await?
+        {
+            ws_stream
+        } else if url_obj.protocol == HTTP_LOWERCASE {
+            let resolved_addrs: Vec<SocketAddr> = self
                 .config
                 .read()
-                .map(|config| config.protocols.clone())
+                .map(|config| config.get_resolved_addrs(target_port))
                 .unwrap_or_default();
-            if !protocols.is_empty() {
-                proxy_request_builder =
-                    proxy_request_builder.header("Sec-WebSocket-String", protocols.join(", "));
-            }
-            let proxy_request: Request = proxy_request_builder.body(()).map_err(|e| {
-                WebSocketError::invalid_url(format!("Failed to build proxy request: {e}"))
-            })?;
-            let connect_future = client_async_with_config(proxy_request, proxy_tunnel_stream, None);
-            let (ws_stream, _) = timeout(timeout_duration, connect_future)
+            let connect_future = async {
+                if resolved_addrs.is_empty() {
+                    SharedConnector::connect_async(&target_host, target_port, connect_timeout).await
+                } else {
+                    SharedConnector::connect_to_async(
+                        resolved_addrs,
+                        Instant::now() + connect_timeout,
+                    )
+                    .await
+                }
+            };
+            let tcp_stream: AsyncTcpStream = timeout(connect_timeout, connect_future)
                 .await
                 .map_err(|_| WebSocketError::timeout("Connection timeout"))?
-                .map_err(|e| {
-                    let error_msg: String = e.to_string();
-                    if error_msg.contains("tls")
-                        || error_msg.contains("TLS")
-                        || error_msg.contains("ssl")
-                        || error_msg.contains("SSL")
-                        || error_msg.contains("certificate")
-                        || error_msg.contains("handshake")
-                    {
-                        WebSocketError::tls(error_msg)
-                    } else {
-                        WebSocketError::connection(error_msg)
-                    }
+                .map_err(|error: RequestError| WebSocketError::connection(error.to_string()))?;
+            let connect_future = client_async_with_config(
+                request,
+                MaybeTlsStream::Plain(tcp_stream),
+                Some(tungstenite_config),
+            );
+            let (ws_stream, _) = timeout(handshake_timeout, connect_future)
+                .await
+                .map_err(|_| WebSocketError::timeout("Handshake timeout"))?
+                .map_err(|error: tungstenite::Error| {
+                    WebSocketError::connection(error.to_string())
                 })?;
-            WebSocketConnectionType::Proxy(ws_stream)
+            WebSocketConnectionType::Direct(ws_stream)
         } else {
-            let connect_future = connect_async_with_config(request, None, false);
-            let (ws_stream, _) = timeout(timeout_duration, connect_future)
+            let connect_future =
+                connect_async_with_config(request, Some(tungstenite_config), false);
+            let (ws_stream, _) = timeout(connect_timeout + handshake_timeout, connect_future)
                 .await
                 .map_err(|_| WebSocketError::timeout("Connection timeout"))?
                 .map_err(|e| {
@@ -134,32 +182,252 @@ impl WebSocket {
                 })?;
             WebSocketConnectionType::Direct(ws_stream)
         };
-        let mut connection: http_type::tokio::sync::MutexGuard<
+        let (ws_writer, ws_reader): (
+            SplitSink<WebSocketConnectionType, Message>,
+            SplitStream<WebSocketConnectionType>,
+        ) = ws_stream.split();
+        let mut writer: http_type::tokio::sync::MutexGuard<
             '_,
-            Option<WebSocketConnectionType>,
-        > = self.connection.lock().await;
-        *connection = Some(ws_stream);
+            Option<SplitSink<WebSocketConnectionType, Message>>,
+        > = self.writer.lock().await;
+        *writer = Some(ws_writer);
+        *self.reader.lock().await = Some(ws_reader);
+        self.queued.store(0, Ordering::Relaxed);
         self.connected.store(true, Ordering::Relaxed);
+        drop(writer);
+        self.callbacks.connect();
         Ok(())
     }
 
+    /// Opens the WebSocket connection through the configured proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The WebSocket URL.
+    /// - `&[(String, String)]` - The headers sent with the handshake.
+    /// - `&TungsteniteWebSocketConfig` - The protocol settings.
+    /// - `Duration` - The timeout for connecting to the proxy and the target.
+    /// - `Duration` - The timeout for the WebSocket handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketConnectionType>, WebSocketError>` - The connection, None if
+    ///   no proxy is configured, or an error.
+    #[cfg(feature = "proxy")]
+    async fn connect_proxy_async(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        tungstenite_config: &TungsteniteWebSocketConfig,
+        connect_timeout: Duration,
+        handshake_timeout: Duration,
+    ) -> Result<Option<WebSocketConnectionType>, WebSocketError> {
+        let Some(proxy_config) = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| config.proxy.clone())
+        else {
+            return Ok(None);
+        };
+        let url_obj: HttpUrlComponents = self
+            .config
+            .read()
+            .map(|config| config.url_obj.clone())
+            .unwrap_or_default();
+        let target_host: String = url_obj.host.clone().unwrap_or_default();
+        let target_port: u16 = url_obj.port.unwrap_or_default();
+        let proxy_stream: BoxAsyncReadWrite = timeout(
+            connect_timeout,
+            self.get_proxy_connection_stream_async(target_host.clone(), target_port, &proxy_config),
+        )
+        .await
+        .map_err(|_| WebSocketError::timeout("Connection timeout"))??;
+        let proxy_tunnel_stream: WebSocketProxyTunnelStream =
+            WebSocketProxyTunnelStream::new(proxy_stream);
+        let mut proxy_request_builder = Request::builder().uri(url);
+        proxy_request_builder = proxy_request_builder
+            .header(HOST, format!("{target_host}:{target_port}"))
+            .header(UPGRADE, "websocket")
+            .header(CONNECTION, "Upgrade")
+            .header(SEC_WEBSOCKET_VERSION, "13")
+            .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
+        for (key, value) in headers {
+            proxy_request_builder = proxy_request_builder.header(key, value);
+        }
+        let protocols: Vec<String> = self
+            .config
+            .read()
+            .map(|config| config.protocols.clone())
+            .unwrap_or_default();
+        if !protocols.is_empty() {
+            proxy_request_builder =
+                proxy_request_builder.header("Sec-WebSocket-String", protocols.join(", "));
+        }
+        let proxy_request: Request = proxy_request_builder.body(()).map_err(|e| {
+            WebSocketError::invalid_url(format!("Failed to build proxy request: {e}"))
+        })?;
+        let connect_future = client_async_with_config(
+            proxy_request,
+            proxy_tunnel_stream,
+            Some(*tungstenite_config),
+        );
+        let (ws_stream, _) = timeout(handshake_timeout, connect_future)
+            .await
+            .map_err(|_| WebSocketError::timeout("Handshake timeout"))?
+            .map_err(|e| {
+                let error_msg: String = e.to_string();
+                if error_msg.contains("tls")
+                    || error_msg.contains("TLS")
+                    || error_msg.contains("ssl")
+                    || error_msg.contains("SSL")
+                    || error_msg.contains("certificate")
+                    || error_msg.contains("handshake")
+                {
+                    WebSocketError::tls(error_msg)
+                } else {
+                    WebSocketError::connection(error_msg)
+                }
+            })?;
+        Ok(Some(WebSocketConnectionType::Proxy(ws_stream)))
+    }
+
+    /// Opens the WebSocket connection through the configured proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketConnectionType>, WebSocketError>` - Always None, as proxies
+    ///   are not compiled without the `proxy` feature.
+    #[cfg(not(feature = "proxy"))]
+    async fn connect_proxy_async(
+        &self,
+        _url: &str,
+        _headers: &[(String, String)],
+        _tungstenite_config: &TungsteniteWebSocketConfig,
+        _connect_timeout: Duration,
+        _handshake_timeout: Duration,
+    ) -> Result<Option<WebSocketConnectionType>, WebSocketError> {
+        Ok(None)
+    }
+
     async fn send_message_async(&self, message: Message) -> Result<(), WebSocketError> {
+        let auto_flush: bool = self
+            .config
+            .read()
+            .map(|config| config.auto_flush)
+            .unwrap_or(true);
+        if !auto_flush {
+            return self.feed_message_async(message).await;
+        }
         if !self.connected.load(Ordering::Relaxed) {
             self.connect_async_internal().await?;
         }
-        let mut connection: http_type::tokio::sync::MutexGuard<
-            '_,
-            Option<WebSocketConnectionType>,
-        > = self.connection.lock().await;
-        if let Some(ref mut ws_stream) = *connection {
-            ws_stream
-                .send(message)
-                .await
-                .map_err(|error: tungstenite::Error| WebSocketError::protocol(error.to_string()))?;
-        } else {
-            return Err(WebSocketError::connection("Not connected"));
+        let result: Result<(), WebSocketError> = async {
+            let message_timeout: Duration = self.get_message_timeout();
+            let mut writer: http_type::tokio::sync::MutexGuard<
+                '_,
+                Option<SplitSink<WebSocketConnectionType, Message>>,
+            > = self.writer.lock().await;
+            if let Some(ref mut ws_stream) = *writer {
+                timeout(message_timeout, ws_stream.send(message))
+                    .await
+                    .map_err(|_| WebSocketError::timeout("Send timeout"))?
+                    .map_err(WebSocketError::from_send_error)?;
+                self.queued.store(0, Ordering::Relaxed);
+            } else {
+                return Err(WebSocketError::connection("Not connected"));
+            }
+            Ok(())
         }
-        Ok(())
+        .await;
+        self.report_error(result)
+    }
+
+    async fn feed_message_async(&self, message: Message) -> Result<(), WebSocketError> {
+        if !self.connected.load(Ordering::Relaxed) {
+            self.connect_async_internal().await?;
+        }
+        let result: Result<(), WebSocketError> = async {
+            let (send_queue_limit, fail_on_backpressure) = self
+                .config
+                .read()
+                .map(|config| (config.send_queue_limit, config.fail_on_backpressure))
+                .unwrap_or((None, false));
+            let mut writer: http_type::tokio::sync::MutexGuard<
+                '_,
+                Option<SplitSink<WebSocketConnectionType, Message>>,
+            > = self.writer.lock().await;
+            let Some(ref mut ws_stream) = *writer else {
+                return Err(WebSocketError::connection("Not connected"));
+            };
+            if let Some(limit) = send_queue_limit
+                && self.queued.load(Ordering::Relaxed) >= limit
+            {
+                if fail_on_backpressure {
+                    return Err(WebSocketError::backpressure(format!(
+                        "Send queue limit of {limit} messages reached"
+                    )));
+                }
+                ws_stream
+                    .flush()
+                    .await
+                    .map_err(WebSocketError::from_send_error)?;
+                self.queued.store(0, Ordering::Relaxed);
+            }
+            match ws_stream.feed(message).await {
+                Ok(()) => {}
+                Err(tungstenite::Error::WriteBufferFull(message)) if !fail_on_backpressure => {
+                    ws_stream
+                        .flush()
+                        .await
+                        .map_err(WebSocketError::from_send_error)?;
+                    self.queued.store(0, Ordering::Relaxed);
+                    ws_stream
+                        .feed(*message)
+                        .await
+                        .map_err(WebSocketError::from_send_error)?;
+                }
+                Err(error) => return Err(WebSocketError::from_send_error(error)),
+            }
+            self.queued.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+        .await;
+        self.report_error(result)
+    }
+
+    fn feed_message_sync(&self, message: Message) -> Result<(), WebSocketError> {
+        let rt: Runtime = Runtime::new()
+            .map_err(|error: std::io::Error| WebSocketError::io(error.to_string()))?;
+        rt.block_on(self.feed_message_async(message))
+    }
+
+    async fn flush_async_internal(&self) -> Result<(), WebSocketError> {
+        let result: Result<(), WebSocketError> = async {
+            let message_timeout: Duration = self.get_message_timeout();
+            let mut writer: http_type::tokio::sync::MutexGuard<
+                '_,
+                Option<SplitSink<WebSocketConnectionType, Message>>,
+            > = self.writer.lock().await;
+            if let Some(ref mut ws_stream) = *writer {
+                timeout(message_timeout, ws_stream.flush())
+                    .await
+                    .map_err(|_| WebSocketError::timeout("Flush timeout"))?
+                    .map_err(WebSocketError::from_send_error)?;
+                self.queued.store(0, Ordering::Relaxed);
+            } else {
+                return Err(WebSocketError::connection("Not connected"));
+            }
+            Ok(())
+        }
+        .await;
+        self.report_error(result)
+    }
+
+    fn flush_sync(&self) -> Result<(), WebSocketError> {
+        let rt: Runtime = Runtime::new()
+            .map_err(|error: std::io::Error| WebSocketError::io(error.to_string()))?;
+        rt.block_on(self.flush_async_internal())
     }
 
     fn send_message_sync(&self, message: Message) -> Result<(), WebSocketError> {
@@ -169,32 +437,141 @@ impl WebSocket {
     }
 
     async fn receive_message_async(&self) -> Result<WebSocketMessage, WebSocketError> {
-        if !self.connected.load(Ordering::Relaxed) {
-            return Err(WebSocketError::connection("Not connected"));
+        match self.receive_raw_message_async().await? {
+            Some(message) => Ok(self.convert_message(message)),
+            None => Err(WebSocketError::connection("Connection closed")),
+        }
+    }
+
+    async fn receive_raw_message_async(&self) -> Result<Option<Message>, WebSocketError> {
+        if let Some(message) = self.pop_pending() {
+            return Ok(Some(message));
+        }
+        let result: Result<Option<Message>, WebSocketError> = async {
+            if !self.connected.load(Ordering::Relaxed) {
+                return Err(WebSocketError::connection("Not connected"));
+            }
+            let timeout_duration: Duration = self.get_message_timeout();
+            let mut reader: http_type::tokio::sync::MutexGuard<
+                '_,
+                Option<SplitStream<WebSocketConnectionType>>,
+            > = self.reader.lock().await;
+            if let Some(ref mut ws_stream) = *reader {
+                let receive_future = ws_stream.next();
+                if let Some(msg_result) = timeout(timeout_duration, receive_future)
+                    .await
+                    .map_err(|_| WebSocketError::timeout("Receive timeout"))?
+                {
+                    let message: Message = msg_result.map_err(|error: tungstenite::Error| {
+                        WebSocketError::protocol(error.to_string())
+                    })?;
+                    return Ok(Some(message));
+                }
+            }
+            Ok(None)
+        }
+        .await;
+        if let Ok(Some(Message::Close(frame))) = &result {
+            self.set_peer_close(frame.as_ref());
+        }
+        if matches!(result, Ok(None) | Ok(Some(Message::Close(_)))) {
+            self.mark_closed();
+        }
+        self.report_error(result)
+    }
+
+    async fn receive_reconnecting_async(&self) -> Result<WebSocketMessage, WebSocketError> {
+        let reconnect: bool = self
+            .config
+            .read()
+            .map(|config| config.reconnect)
+            .unwrap_or_default();
+        loop {
+            let was_connected: bool = self.connected.load(Ordering::Relaxed);
+            match self.receive_message_async().await {
+                Err(error)
+                    if reconnect
+                        && was_connected
+                        && *error.kind() != WebSocketErrorKind::Timeout =>
+                {
+                    self.reconnect_async(error).await?;
+                }
+                result => return result,
+            }
         }
-        let timeout_duration: Duration = Duration::from_millis(
-            self.config
+    }
+
+    async fn reconnect_async(&self, error: WebSocketError) -> Result<(), WebSocketError> {
+        *self.writer.lock().await = None;
+        *self.reader.lock().await = None;
+        self.mark_closed();
+        let reconnect_max_attempts: usize = self
+            .config
+            .read()
+            .map(|config| config.reconnect_max_attempts)
+            .unwrap_or_default();
+        let mut last_error: WebSocketError = error;
+        for attempt in 0..reconnect_max_attempts {
+            let delay: Duration = self
+                .config
                 .read()
-                .map(|config| config.timeout)
-                .unwrap_or(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS),
-        );
-        let mut connection: http_type::tokio::sync::MutexGuard<
-            '_,
-            Option<WebSocketConnectionType>,
-        > = self.connection.lock().await;
-        if let Some(ref mut ws_stream) = *connection {
-            let receive_future = ws_stream.next();
-            if let Some(msg_result) = timeout(timeout_duration, receive_future)
-                .await
-                .map_err(|_| WebSocketError::timeout("Receive timeout"))?
-            {
-                let message: Message = msg_result.map_err(|error: tungstenite::Error| {
-                    WebSocketError::protocol(error.to_string())
-                })?;
-                return Ok(self.convert_message(message));
+                .map(|config| config.get_reconnect_delay(attempt as u32))
+                .unwrap_or_default();
+            sleep(delay).await;
+            self.callbacks.reconnect_attempt(attempt + 1, &last_error);
+            match self.connect_once_async().await {
+                Ok(()) => {
+                    if let Ok(mut peer_close) = self.peer_close.lock() {
+                        *peer_close = None;
+                    }
+                    for message in self.callbacks.reconnect() {
+                        self.send_message_async(message.into_message()).await?;
+                    }
+                    return Ok(());
+                }
+                Err(error) if *error.kind() == WebSocketErrorKind::InvalidUrl => {
+                    return self.report_error(Err(error));
+                }
+                Err(error) => last_error = error,
+            }
+        }
+        self.report_error(Err(WebSocketError::connection(format!(
+            "Reconnect failed after {reconnect_max_attempts} attempts: {last_error}"
+        ))))
+    }
+
+    async fn next_text_async(&self) -> Option<Result<String, WebSocketError>> {
+        if !self.connected.load(Ordering::Relaxed)
+            && !self.has_pending()
+            && let Err(error) = self.connect_async_internal().await
+        {
+            return Some(Err(error));
+        }
+        loop {
+            let message: Message = match self.receive_raw_message_async().await {
+                Ok(Some(message)) => message,
+                Ok(None) => return None,
+                Err(error) => return Some(Err(error)),
+            };
+            match message {
+                Message::Text(text) => return Some(Ok(text.to_string())),
+                Message::Ping(data) => {
+                    let mut writer: http_type::tokio::sync::MutexGuard<
+                        '_,
+                        Option<SplitSink<WebSocketConnectionType, Message>>,
+                    > = self.writer.lock().await;
+                    if let Some(ref mut ws_stream) = *writer
+                        && let Err(error) = ws_stream.send(Message::Pong(data)).await
+                    {
+                        return Some(
+                            self.report_error(Err(WebSocketError::from_send_error(error))),
+                        );
+                    }
+                }
+                Message::Close(_) => return None,
+                _ => {}
             }
         }
-        Err(WebSocketError::connection("Connection closed"))
     }
 
     fn receive_message_sync(&self) -> Result<WebSocketMessage, WebSocketError> {
@@ -209,38 +586,123 @@ impl WebSocket {
             Message::Binary(data) => WebSocketMessage::Binary(data.to_vec()),
             Message::Ping(data) => WebSocketMessage::Ping(data.to_vec()),
             Message::Pong(data) => WebSocketMessage::Pong(data.to_vec()),
-            Message::Close(_) => WebSocketMessage::Close,
-            Message::Frame(_) => WebSocketMessage::Close,
+            Message::Close(frame) => {
+                WebSocketMessage::Close(frame.as_ref().map(WebSocketCloseFrame::from_close_frame))
+            }
+            Message::Frame(_) => WebSocketMessage::Close(None),
         }
     }
 
-    async fn close_async_internal(&self) -> Result<(), WebSocketError> {
-        let mut connection: http_type::tokio::sync::MutexGuard<
-            '_,
-            Option<WebSocketConnectionType>,
-        > = self.connection.lock().await;
-        if let Some(ref mut ws_stream) = *connection {
-            ws_stream
-                .send(Message::Close(None))
-                .await
-                .map_err(|error: tungstenite::Error| WebSocketError::protocol(error.to_string()))?;
-            use futures::SinkExt;
-            ws_stream
-                .close()
-                .await
-                .map_err(|error: tungstenite::Error| WebSocketError::protocol(error.to_string()))?;
+    #[cfg(feature = "json")]
+    fn json_from_message<T: DeserializeOwned>(
+        message: WebSocketMessage,
+    ) -> Option<Result<T, WebSocketError>> {
+        match message {
+            WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => None,
+            WebSocketMessage::Close(_) => {
+                Some(Err(WebSocketError::connection("Connection closed by peer")))
+            }
+            message => Some(message.parse_json()),
+        }
+    }
+
+    async fn close_async_internal(&self, frame: Option<CloseFrame>) -> Result<(), WebSocketError> {
+        let result: Result<(), WebSocketError> = async {
+            let mut writer: http_type::tokio::sync::MutexGuard<
+                '_,
+                Option<SplitSink<WebSocketConnectionType, Message>>,
+            > = self.writer.lock().await;
+            if let Some(ref mut ws_stream) = *writer
+                && self.connected.load(Ordering::Relaxed)
+            {
+                ws_stream.send(Message::Close(frame)).await.map_err(
+                    |error: tungstenite::Error| WebSocketError::protocol(error.to_string()),
+                )?;
+                let close_timeout: Duration = self
+                    .config
+                    .read()
+                    .map(|config| config.get_close_timeout())
+                    .unwrap_or(Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS));
+                if let Ok(mut reader) = self.reader.try_lock()
+                    && let Some(ref mut ws_reader) = *reader
+                {
+                    let _ = timeout(close_timeout, self.drain_until_close(ws_reader)).await;
+                    *reader = None;
+                }
+                match ws_stream.close().await {
+                    Ok(())
+                    | Err(tungstenite::Error::ConnectionClosed)
+                    | Err(tungstenite::Error::AlreadyClosed) => {}
+                    Err(error) => return Err(WebSocketError::protocol(error.to_string())),
+                }
+            }
+            *writer = None;
+            drop(writer);
+            self.mark_closed();
+            Ok(())
+        }
+        .await;
+        self.report_error(result)
+    }
+
+    async fn drain_until_close(&self, ws_reader: &mut SplitStream<WebSocketConnectionType>) {
+        while let Some(Ok(message)) = ws_reader.next().await {
+            match message {
+                Message::Close(frame) => {
+                    self.set_peer_close(frame.as_ref());
+                    return;
+                }
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+                message => {
+                    if let Ok(mut pending) = self.pending.lock() {
+                        pending.push_back(message);
+                    }
+                }
+            }
+        }
+    }
+
+    fn share(&self) -> Self {
+        Self {
+            url: self.url.clone(),
+            header: self.header.clone(),
+            config: self.config.clone(),
+            connected: self.connected.clone(),
+            queued: self.queued.clone(),
+            writer: self.writer.clone(),
+            reader: self.reader.clone(),
+            callbacks: self.callbacks.clone(),
+            pending: self.pending.clone(),
+            peer_close: self.peer_close.clone(),
         }
-        *connection = None;
-        self.connected.store(false, Ordering::Relaxed);
-        Ok(())
     }
 
-    fn close_sync(&self) -> Result<(), WebSocketError> {
+    fn pop_pending(&self) -> Option<Message> {
+        self.pending.lock().ok()?.pop_front()
+    }
+
+    fn has_pending(&self) -> bool {
+        self.pending
+            .lock()
+            .is_ok_and(|pending: StdMutexGuard<'_, VecDeque<Message>>| !pending.is_empty())
+    }
+
+    fn set_peer_close(&self, frame: Option<&CloseFrame>) {
+        if let Ok(mut peer_close) = self.peer_close.lock() {
+            *peer_close = Some(frame.map_or(
+                WebSocketCloseFrame::new(CLOSE_CODE_NO_STATUS, ""),
+                WebSocketCloseFrame::from_close_frame,
+            ));
+        }
+    }
+
+    fn close_sync(&self, frame: Option<CloseFrame>) -> Result<(), WebSocketError> {
         let rt: Runtime = Runtime::new()
             .map_err(|error: std::io::Error| WebSocketError::io(error.to_string()))?;
-        rt.block_on(self.close_async_internal())
+        rt.block_on(self.close_async_internal(frame))
     }
 
+    #[cfg(feature = "proxy")]
     async fn get_proxy_connection_stream_async(
         &self,
         target_host: String,
@@ -259,24 +721,26 @@ impl WebSocket {
         }
     }
 
-    async fn get_http_proxy_connection_async(
+    #[cfg(feature = "proxy")]
+    async fn open_http_proxy_stream_async(
         &self,
-        target_host: String,
-        target_port: u16,
         proxy_config: &ProxyConfig,
+        timeout_duration: Duration,
     ) -> Result<BoxAsyncReadWrite, WebSocketError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: http_type::tokio::net::TcpStream =
-            http_type::tokio::net::TcpStream::connect(proxy_host_port)
+        let tcp_stream: AsyncTcpStream =
+            SharedConnector::connect_async(&proxy_config.host, proxy_config.port, timeout_duration)
                 .await
                 .map_err(|err| WebSocketError::connection(err.to_string()))?;
-        let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
+        let proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
             let roots: RootCertStore = RootCertStore {
                 roots: TLS_SERVER_ROOTS.to_vec(),
             };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
+            let insecure_skip_tls_verify: bool = self
+                .config
+                .read()
+                .is_ok_and(|config| config.insecure_skip_tls_verify);
+            let tls_config: ClientConfig =
+                SharedConnector::get_tls_config(roots, insecure_skip_tls_verify);
             let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
             let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
                 .map_err(|err| WebSocketError::tls(err.to_string()))?;
@@ -288,51 +752,72 @@ impl WebSocket {
         } else {
             Box::new(tcp_stream)
         };
-        let connect_request: String = if let (Some(username), Some(password)) =
-            (&proxy_config.username, &proxy_config.password)
+        Ok(proxy_stream)
+    }
+
+    #[cfg(feature = "proxy")]
+    async fn get_http_proxy_connection_async(
+        &self,
+        target_host: String,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<BoxAsyncReadWrite, WebSocketError> {
+        let timeout_duration: Duration = self
+            .config
+            .read()
+            .map(|config| config.get_connect_timeout())
+            .unwrap_or(Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS));
+        let mut proxy_stream: BoxAsyncReadWrite = self
+            .open_http_proxy_stream_async(proxy_config, timeout_duration)
+            .await?;
+        let pre_read_data: Vec<u8> = match SharedConnector::proxy_connect_async(
+            &mut proxy_stream,
+            &target_host,
+            target_port,
+            None,
+        )
+        .await
         {
-            let auth: String = format!("{username}:{password}");
-            let auth_encoded: String = base64_encode(auth.as_bytes());
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\nProxy-Authorization: Basic {auth_encoded}\r\n\r\n"
-            )
-        } else {
-            format!(
-                "CONNECT {target_host}:{target_port} HTTP/1.1\r\nHost: {target_host}:{target_port}\r\n\r\n"
-            )
+            Err(RequestError::ProxyAuthenticationRequired(challenges)) => {
+                let Some(authorization) = proxy_config.get_authorization(&challenges) else {
+                    return Err(WebSocketError::from_proxy_error(
+                        RequestError::ProxyAuthenticationRequired(challenges),
+                    ));
+                };
+                proxy_stream = self
+                    .open_http_proxy_stream_async(proxy_config, timeout_duration)
+                    .await?;
+                SharedConnector::proxy_connect_async(
+                    &mut proxy_stream,
+                    &target_host,
+                    target_port,
+                    Some(&authorization),
+                )
+                .await
+                .map_err(WebSocketError::from_proxy_error)?
+            }
+            result => result.map_err(WebSocketError::from_proxy_error)?,
         };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .await
-            .map_err(|err: std::io::Error| WebSocketError::protocol(err.to_string()))?;
-        proxy_stream
-            .flush()
-            .await
-            .map_err(|err: std::io::Error| WebSocketError::protocol(err.to_string()))?;
-        let mut response_buffer: [u8; 1024] = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .await
-            .map_err(|err: std::io::Error| WebSocketError::protocol(err.to_string()))?;
-        let response: Cow<'_, str> = String::from_utf8_lossy(&response_buffer[..bytes_read]);
-        if !response.starts_with("HTTP/1.1 200") && !response.starts_with("HTTP/1.0 200") {
-            return Err(WebSocketError::connection(format!(
-                "Proxy connection failed: {}",
-                response.lines().next().unwrap_or("Unknown error")
-            )));
-        }
-        Ok(proxy_stream)
+        Ok(Box::new(ProxyTunnelStream::new(
+            proxy_stream,
+            pre_read_data,
+        )))
     }
 
+    #[cfg(feature = "proxy")]
     async fn get_socks5_proxy_connection_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, WebSocketError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: http_type::tokio::net::TcpStream =
-            http_type::tokio::net::TcpStream::connect(proxy_host_port)
+        let timeout_duration: Duration = self
+            .config
+            .read()
+            .map(|config| config.get_connect_timeout())
+            .unwrap_or(Duration::from_millis(DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS));
+        let mut tcp_stream: AsyncTcpStream =
+            SharedConnector::connect_async(&proxy_config.host, proxy_config.port, timeout_duration)
                 .await
                 .map_err(|err| WebSocketError::connection(err.to_string()))?;
         let auth_methods: Vec<u8> =
@@ -519,31 +1004,143 @@ impl WebSocket {
         self.send_message_sync(message)
     }
 
-    /// Receives a message synchronously.
+    /// Queues a message synchronously without flushing it to the socket.
+    ///
+    /// Queued messages are coalesced in the write buffer and written by the next
+    /// `flush`, by a flushing send, or once the write buffer fills up.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketMessage` - The message to queue.
     ///
     /// # Returns
     ///
-    /// - `WebSocketMessageResult` - Result containing the received message or error.
-    pub fn receive(&mut self) -> WebSocketMessageResult {
-        self.receive_message_sync()
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub fn feed(&mut self, message: WebSocketMessage) -> WebSocketResult {
+        self.feed_message_sync(message.into_message())
     }
 
-    /// Closes the WebSocket connection synchronously.
+    /// Flushes all queued messages to the socket synchronously.
     ///
     /// # Returns
     ///
     /// - `WebSocketResult` - Result indicating success or failure.
-    pub fn close(&mut self) -> WebSocketResult {
-        self.close_sync()
+    pub fn flush(&mut self) -> WebSocketResult {
+        self.flush_sync()
     }
 
-    /// Checks if the WebSocket is currently connected.
+    /// Receives a message synchronously.
     ///
     /// # Returns
     ///
-    /// - `bool` - True if connected, false otherwise.
-    pub fn is_connected(&self) -> bool {
-        self.connected.load(Ordering::Relaxed)
+    /// - `WebSocketMessageResult` - Result containing the received message or error.
+    pub fn receive(&mut self) -> WebSocketMessageResult {
+        self.receive_message_sync()
+    }
+
+    /// Serializes a value to JSON and sends it as a text message synchronously.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure, with a `Json` error
+    ///   if the value cannot be serialized.
+    #[cfg(feature = "json")]
+    pub fn send_json<T: Serialize + ?Sized>(&mut self, value: &T) -> WebSocketResult {
+        let message: WebSocketMessage = WebSocketMessage::json(value)?;
+        self.send_message_sync(message.into_message())
+    }
+
+    /// Receives a text message synchronously and deserializes it from JSON.
+    ///
+    /// Ping and pong messages are skipped. A binary message or invalid JSON yields a
+    /// `Json` error, and a Close frame from the peer a `Connection` error.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, WebSocketError>` - The deserialized value or error.
+    #[cfg(feature = "json")]
+    pub fn receive_json<T: DeserializeOwned>(&mut self) -> Result<T, WebSocketError> {
+        loop {
+            if let Some(result) = Self::json_from_message(self.receive()?) {
+                return result;
+            }
+        }
+    }
+
+    /// Closes the WebSocket connection synchronously.
+    ///
+    /// Sends a Close frame and waits, up to the close timeout, for the peer's Close
+    /// reply. Messages arriving meanwhile are kept for later receives, and the peer's
+    /// close code and reason are available from `get_peer_close`.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub fn close(&mut self) -> WebSocketResult {
+        self.close_sync(None)
+    }
+
+    /// Closes the WebSocket connection synchronously with a status code and reason.
+    ///
+    /// Behaves like `close`, but the Close frame carries the given code and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code, e.g. 1000 for a normal closure or 4000-4999
+    ///   for application-defined codes.
+    /// - `&str` - The close reason, at most 123 bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure. The code and reason
+    ///   are rejected without closing if they may not be sent in a Close frame.
+    pub fn close_with(&mut self, code: u16, reason: &str) -> WebSocketResult {
+        let frame: CloseFrame = WebSocketCloseFrame::new(code, reason).try_into_frame()?;
+        self.close_sync(Some(frame))
+    }
+
+    /// Gets the Close frame sent by the peer.
+    ///
+    /// A Close frame is recorded when it is received, or while `close` waits for
+    /// the peer to acknowledge the closing handshake. Messages received during that
+    /// wait are returned by later receives.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketCloseFrame>` - The peer's close code and reason, or None if
+    ///   no Close frame has been received.
+    pub fn get_peer_close(&self) -> Option<WebSocketCloseFrame> {
+        self.peer_close.lock().ok()?.clone()
+    }
+
+    /// Checks if the WebSocket is currently connected.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if connected, false otherwise.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Converts the connection into a blocking iterator over incoming text messages.
+    ///
+    /// Pings are answered automatically, binary and pong frames are skipped, and
+    /// the iterator ends when the server closes the connection. Receive timeouts are
+    /// yielded as errors without ending the iteration; any other error ends it.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketTextIter` - An iterator over the text payloads.
+    pub fn into_text_iter(self) -> WebSocketTextIter {
+        WebSocketTextIter {
+            websocket: self,
+            runtime: None,
+            done: false,
+        }
     }
 
     /// Sends a text message asynchronously.
@@ -602,25 +1199,360 @@ impl WebSocket {
         self.send_message_async(message).await
     }
 
+    /// Queues a message asynchronously without flushing it to the socket.
+    ///
+    /// Queued messages are coalesced in the write buffer and written by the next
+    /// `flush_async`, by a flushing send, or once the write buffer fills up.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketMessage` - The message to queue.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn feed_async(&mut self, message: WebSocketMessage) -> WebSocketResult {
+        self.feed_message_async(message.into_message()).await
+    }
+
+    /// Flushes all queued messages to the socket asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn flush_async(&mut self) -> WebSocketResult {
+        self.flush_async_internal().await
+    }
+
     /// Receives a message asynchronously.
     ///
+    /// With reconnection enabled, a connection lost while receiving is restored with
+    /// backoff, the messages returned by the `on_reconnect` callback are sent on the
+    /// new connection, and receiving continues there. A Close frame from the peer or
+    /// a local close ends the connection without reconnecting.
+    ///
     /// # Returns
     ///
     /// - `WebSocketMessageResult` - Result containing the received message or error.
     pub async fn receive_async(&mut self) -> WebSocketMessageResult {
-        self.receive_message_async().await
+        self.receive_reconnecting_async().await
+    }
+
+    /// Serializes a value to JSON and sends it as a text message asynchronously.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure, with a `Json` error
+    ///   if the value cannot be serialized.
+    #[cfg(feature = "json")]
+    pub async fn send_json_async<T: Serialize + ?Sized>(&mut self, value: &T) -> WebSocketResult {
+        let message: WebSocketMessage = WebSocketMessage::json(value)?;
+        self.send_message_async(message.into_message()).await
+    }
+
+    /// Receives a text message asynchronously and deserializes it from JSON.
+    ///
+    /// Behaves like `receive_json`, receiving through `receive_async`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, WebSocketError>` - The deserialized value or error.
+    #[cfg(feature = "json")]
+    pub async fn receive_json_async<T: DeserializeOwned>(&mut self) -> Result<T, WebSocketError> {
+        loop {
+            if let Some(result) = Self::json_from_message(self.receive_async().await?) {
+                return result;
+            }
+        }
+    }
+
+    /// Streams incoming text messages asynchronously.
+    ///
+    /// Pings are answered automatically, binary and pong frames are skipped, and
+    /// the stream ends when the server closes the connection. Receive timeouts are
+    /// yielded as errors without ending the stream; any other error ends it.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<String, WebSocketError>>` - A stream of the text payloads.
+    pub fn text_stream(
+        &mut self,
+    ) -> impl Stream<Item = Result<String, WebSocketError>> + Send + '_ {
+        unfold((&*self, false), |(websocket, done)| async move {
+            if done {
+                return None;
+            }
+            let item: Result<String, WebSocketError> = websocket.next_text_async().await?;
+            let done: bool =
+                matches!(&item, Err(error) if *error.kind() != WebSocketErrorKind::Timeout);
+            Some((item, (websocket, done)))
+        })
     }
 
     /// Closes the WebSocket connection asynchronously.
     ///
+    /// Sends a Close frame and waits, up to the close timeout, for the peer's Close
+    /// reply. Messages arriving meanwhile are kept for later receives, and the peer's
+    /// close code and reason are available from `get_peer_close`.
+    ///
     /// # Returns
     ///
     /// - `WebSocketResult` - Result indicating success or failure.
     pub async fn close_async_method(&mut self) -> WebSocketResult {
-        self.close_async_internal().await
+        self.close_async_internal(None).await
+    }
+
+    /// Closes the WebSocket connection asynchronously with a status code and reason.
+    ///
+    /// Behaves like `close_async_method`, but the Close frame carries the given code
+    /// and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `&str` - The close reason, at most 123 bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure. The code and reason
+    ///   are rejected without closing if they may not be sent in a Close frame.
+    pub async fn close_with_async(&mut self, code: u16, reason: &str) -> WebSocketResult {
+        let frame: CloseFrame = WebSocketCloseFrame::new(code, reason).try_into_frame()?;
+        self.close_async_internal(Some(frame)).await
+    }
+
+    /// Splits the connection into independent sending and receiving halves.
+    ///
+    /// The halves share the connection, its configuration, and its callbacks. A
+    /// send on the sender proceeds while a receive is waiting on the receiver,
+    /// allowing full-duplex use from separate tasks.
+    ///
+    /// # Returns
+    ///
+    /// - `(WebSocketSender, WebSocketReceiver)` - The sending and receiving halves.
+    pub fn split(self) -> (WebSocketSender, WebSocketReceiver) {
+        let sender: WebSocketSender = WebSocketSender {
+            websocket: self.share(),
+        };
+        (sender, WebSocketReceiver { websocket: self })
     }
 }
 
+impl WebSocketSender {
+    /// Sends a text message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The text message to send.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn send_text_async(&mut self, text: &str) -> WebSocketResult {
+        self.websocket.send_text_async(text).await
+    }
+
+    /// Sends a binary message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The binary data to send.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn send_binary_async(&mut self, data: &[u8]) -> WebSocketResult {
+        self.websocket.send_binary_async(data).await
+    }
+
+    /// Serializes a value to JSON and sends it as a text message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    #[cfg(feature = "json")]
+    pub async fn send_json_async<T: Serialize + ?Sized>(&mut self, value: &T) -> WebSocketResult {
+        self.websocket.send_json_async(value).await
+    }
+
+    /// Sends a ping message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The ping data to send.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn send_ping_async(&mut self, data: &[u8]) -> WebSocketResult {
+        self.websocket.send_ping_async(data).await
+    }
+
+    /// Sends a pong message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The pong data to send.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn send_pong_async(&mut self, data: &[u8]) -> WebSocketResult {
+        self.websocket.send_pong_async(data).await
+    }
+
+    /// Queues a message without flushing it to the socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketMessage` - The message to queue.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn feed_async(&mut self, message: WebSocketMessage) -> WebSocketResult {
+        self.websocket.feed_async(message).await
+    }
+
+    /// Flushes all queued messages to the socket.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn flush_async(&mut self) -> WebSocketResult {
+        self.websocket.flush_async().await
+    }
+
+    /// Closes the connection.
+    ///
+    /// Sends a Close frame. If no receive is pending on the receiving half, waits
+    /// up to the close timeout for the peer's Close reply; otherwise the reply is
+    /// returned by that receive.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn close_async(&mut self) -> WebSocketResult {
+        self.websocket.close_async_method().await
+    }
+
+    /// Closes the connection with a status code and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `&str` - The close reason, at most 123 bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    pub async fn close_with_async(&mut self, code: u16, reason: &str) -> WebSocketResult {
+        self.websocket.close_with_async(code, reason).await
+    }
+
+    /// Checks if the connection is currently open.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if connected, false otherwise.
+    pub fn is_connected(&self) -> bool {
+        self.websocket.is_connected()
+    }
+}
+
+impl WebSocketReceiver {
+    /// Receives a message.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketMessageResult` - Result containing the received message or error.
+    pub async fn receive_async(&mut self) -> WebSocketMessageResult {
+        self.websocket.receive_async().await
+    }
+
+    /// Receives a text message and deserializes it from JSON.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, WebSocketError>` - The deserialized value or error.
+    #[cfg(feature = "json")]
+    pub async fn receive_json_async<T: DeserializeOwned>(&mut self) -> Result<T, WebSocketError> {
+        self.websocket.receive_json_async().await
+    }
+
+    /// Streams incoming text messages.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<String, WebSocketError>>` - A stream of the text payloads.
+    pub fn text_stream(
+        &mut self,
+    ) -> impl Stream<Item = Result<String, WebSocketError>> + Send + '_ {
+        self.websocket.text_stream()
+    }
+
+    /// Gets the Close frame sent by the peer.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketCloseFrame>` - The peer's close code and reason, or None if
+    ///   no Close frame has been received.
+    pub fn get_peer_close(&self) -> Option<WebSocketCloseFrame> {
+        self.websocket.get_peer_close()
+    }
+
+    /// Checks if the connection is currently open.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if connected, false otherwise.
+    pub fn is_connected(&self) -> bool {
+        self.websocket.is_connected()
+    }
+}
+
+impl Iterator for WebSocketTextIter {
+    type Item = Result<String, WebSocketError>;
+
+    /// Blocks until the next text message arrives.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<String, WebSocketError>>` - The next text payload, an error, or None once the connection is closed.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.runtime.is_none() {
+            match Runtime::new() {
+                Ok(runtime) => self.runtime = Some(runtime),
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(WebSocketError::io(error.to_string())));
+                }
+            }
+        }
+        let runtime: &Runtime = self.runtime.as_ref()?;
+        let item: Option<Self::Item> = runtime.block_on(self.websocket.next_text_async());
+        self.done = match &item {
+            None => true,
+            Some(Ok(_)) => false,
+            Some(Err(error)) => *error.kind() != WebSocketErrorKind::Timeout,
+        };
+        item
+    }
+}
+
+impl Sealed for WebSocket {}
+
 /// Synchronous WebSocket trait implementation.
 ///
 /// Provides synchronous methods for WebSocket operations including:
@@ -645,6 +1577,14 @@ impl WebSocketTrait for WebSocket {
         self.send_pong(data)
     }
 
+    fn feed(&mut self, message: WebSocketMessage) -> WebSocketResult {
+        self.feed(message)
+    }
+
+    fn flush(&mut self) -> WebSocketResult {
+        self.flush()
+    }
+
     fn receive(&mut self) -> WebSocketMessageResult {
         self.receive()
     }
@@ -653,6 +1593,10 @@ impl WebSocketTrait for WebSocket {
         self.close()
     }
 
+    fn close_with(&mut self, code: u16, reason: &str) -> WebSocketResult {
+        self.close_with(code, reason)
+    }
+
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
@@ -694,6 +1638,17 @@ impl AsyncWebSocketTrait for WebSocket {
         Box::pin(self.send_pong_async(data))
     }
 
+    fn feed(
+        &mut self,
+        message: WebSocketMessage,
+    ) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + '_>> {
+        Box::pin(self.feed_async(message))
+    }
+
+    fn flush(&mut self) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + '_>> {
+        Box::pin(self.flush_async())
+    }
+
     fn receive(&mut self) -> Pin<Box<dyn Future<Output = WebSocketMessageResult> + Send + '_>> {
         Box::pin(self.receive_async())
     }
@@ -702,7 +1657,191 @@ impl AsyncWebSocketTrait for WebSocket {
         Box::pin(self.close_async_method())
     }
 
+    fn close_with<'a>(
+        &'a mut self,
+        code: u16,
+        reason: &'a str,
+    ) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>> {
+        Box::pin(self.close_with_async(code, reason))
+    }
+
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
 }
+
+/// JSON helpers for boxed synchronous WebSockets.
+impl dyn WebSocketTrait {
+    /// Serializes a value to JSON and sends it as a text message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    #[cfg(feature = "json")]
+    pub fn send_json<T: Serialize + ?Sized>(&mut self, value: &T) -> WebSocketResult {
+        let text: String = WebSocketMessage::json(value)?
+            .into_text()
+            .unwrap_or_default();
+        self.send_text(&text)
+    }
+
+    /// Receives a text message and deserializes it from JSON.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, WebSocketError>` - The deserialized value or error.
+    #[cfg(feature = "json")]
+    pub fn receive_json<T: DeserializeOwned>(&mut self) -> Result<T, WebSocketError> {
+        loop {
+            if let Some(result) = WebSocket::json_from_message(self.receive()?) {
+                return result;
+            }
+        }
+    }
+}
+
+/// JSON helpers for boxed asynchronous WebSockets.
+impl dyn AsyncWebSocketTrait {
+    /// Serializes a value to JSON and sends it as a text message.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    #[cfg(feature = "json")]
+    pub async fn send_json<T: Serialize + ?Sized>(&mut self, value: &T) -> WebSocketResult {
+        let text: String = WebSocketMessage::json(value)?
+            .into_text()
+            .unwrap_or_default();
+        self.send_text(&text).await
+    }
+
+    /// Receives a text message and deserializes it from JSON.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, WebSocketError>` - The deserialized value or error.
+    #[cfg(feature = "json")]
+    pub async fn receive_json<T: DeserializeOwned>(&mut self) -> Result<T, WebSocketError> {
+        loop {
+            if let Some(result) = WebSocket::json_from_message(self.receive().await?) {
+                return result;
+            }
+        }
+    }
+}
+
+impl WebSocketCallbacks {
+    pub(crate) fn connect(&self) {
+        if let Some(on_connect) = &self.on_connect {
+            on_connect();
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        if let Some(on_close) = &self.on_close {
+            on_close();
+        }
+    }
+
+    pub(crate) fn error(&self, error: &WebSocketError) {
+        if let Some(on_error) = &self.on_error {
+            on_error(error);
+        }
+    }
+
+    pub(crate) fn reconnect_attempt(&self, attempt: usize, error: &WebSocketError) {
+        if let Some(on_reconnect_attempt) = &self.on_reconnect_attempt {
+            on_reconnect_attempt(attempt, error);
+        }
+    }
+
+    pub(crate) fn reconnect(&self) -> Vec<WebSocketMessage> {
+        self.on_reconnect
+            .as_ref()
+            .map(|on_reconnect: &WebSocketResubscribeFn| on_reconnect())
+            .unwrap_or_default()
+    }
+}
+
+impl Debug for WebSocketCallbacks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocketCallbacks")
+            .field("on_connect", &self.on_connect.is_some())
+            .field("on_close", &self.on_close.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .field("on_reconnect_attempt", &self.on_reconnect_attempt.is_some())
+            .field("on_reconnect", &self.on_reconnect.is_some())
+            .finish()
+    }
+}
+
+impl WebSocketCloseFrame {
+    /// Creates a Close frame with a status code and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `T` - The close reason.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketCloseFrame` - The Close frame.
+    pub fn new<T: ToString>(code: u16, reason: T) -> Self {
+        Self {
+            code,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub(crate) fn from_close_frame(frame: &CloseFrame) -> Self {
+        Self::new(u16::from(frame.code), frame.reason.as_str())
+    }
+
+    pub(crate) fn into_frame(self) -> CloseFrame {
+        CloseFrame {
+            code: CloseCode::from(self.code),
+            reason: self.reason.into(),
+        }
+    }
+
+    pub(crate) fn try_into_frame(self) -> Result<CloseFrame, WebSocketError> {
+        if !CloseCode::from(self.code).is_allowed() {
+            return Err(WebSocketError::protocol(format!(
+                "Close code {} may not be sent in a Close frame",
+                self.code
+            )));
+        }
+        if self.reason.len() > MAX_CLOSE_REASON_LEN {
+            return Err(WebSocketError::protocol(format!(
+                "Close reason exceeds {MAX_CLOSE_REASON_LEN} bytes"
+            )));
+        }
+        Ok(self.into_frame())
+    }
+
+    /// Gets the close status code.
+    ///
+    /// # Returns
+    ///
+    /// - `u16` - The status code, or 1005 if the frame carried none.
+    pub fn get_code(&self) -> u16 {
+        self.code
+    }
+
+    /// Gets the close reason.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The reason, possibly empty.
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+}
@@ -3,6 +3,8 @@ mod r#struct;
 mod r#trait;
 mod r#type;
 
+use http_type::HTTP_LOWERCASE;
+
 pub use {r#struct::*, r#trait::*, r#type::*};
 
 use super::*;
@@ -7,6 +7,7 @@ use super::*;
 #[derive(Debug)]
 pub enum WebSocketConnectionType {
     Direct(WebSocketStream<MaybeTlsStream<http_type::tokio::net::TcpStream>>),
+    #[cfg(feature = "proxy")]
     Proxy(WebSocketStream<WebSocketProxyTunnelStream>),
 }
 
@@ -26,15 +27,82 @@ pub struct WebSocket {
     pub(crate) config: ArcRwLock<WebSocketConfig>,
     /// Atomic flag indicating connection status.
     pub(crate) connected: Arc<AtomicBool>,
-    /// The underlying WebSocket connection.
-    pub(crate) connection: WebSocketConnection,
+    /// Number of messages queued since the last flush.
+    pub(crate) queued: Arc<AtomicUsize>,
+    /// The sending half of the underlying WebSocket connection.
+    pub(crate) writer: WebSocketWriter,
+    /// The receiving half of the underlying WebSocket connection.
+    pub(crate) reader: WebSocketReader,
+    /// Callbacks notified of lifecycle transitions.
+    pub(crate) callbacks: WebSocketCallbacks,
+    /// Messages received while closing, returned by later receives.
+    pub(crate) pending: Arc<StdMutex<VecDeque<Message>>>,
+    /// Close frame sent by the peer, once received.
+    pub(crate) peer_close: Arc<StdMutex<Option<WebSocketCloseFrame>>>,
+}
+
+/// Status code and reason of a WebSocket Close frame.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebSocketCloseFrame {
+    /// The close status code.
+    pub(crate) code: u16,
+    /// The close reason, possibly empty.
+    pub(crate) reason: String,
+}
+
+/// Lifecycle callbacks registered on a WebSocket.
+#[derive(Clone, Default)]
+pub(crate) struct WebSocketCallbacks {
+    /// Called once a connection is established.
+    pub(crate) on_connect: Option<WebSocketEventFn>,
+    /// Called once an established connection is closed by either side.
+    pub(crate) on_close: Option<WebSocketEventFn>,
+    /// Called when an operation fails.
+    pub(crate) on_error: Option<WebSocketErrorFn>,
+    /// Called before each connection retry.
+    pub(crate) on_reconnect_attempt: Option<WebSocketReconnectFn>,
+    /// Called once a lost connection is restored by `receive_async`.
+    pub(crate) on_reconnect: Option<WebSocketResubscribeFn>,
+}
+
+/// Sending half of a WebSocket connection.
+///
+/// Created by `WebSocket::split`. Sends do not wait for a receive pending on the
+/// matching `WebSocketReceiver`.
+#[derive(Debug)]
+pub struct WebSocketSender {
+    /// The connection shared with the receiving half.
+    pub(crate) websocket: WebSocket,
+}
+
+/// Receiving half of a WebSocket connection.
+///
+/// Created by `WebSocket::split`.
+#[derive(Debug)]
+pub struct WebSocketReceiver {
+    /// The connection shared with the sending half.
+    pub(crate) websocket: WebSocket,
+}
+
+/// Blocking iterator over the text messages of a WebSocket connection.
+///
+/// Created by `WebSocket::into_text_iter`. Owns a runtime that drives the
+/// connection for the whole iteration.
+#[derive(Debug)]
+pub struct WebSocketTextIter {
+    /// The connection being read.
+    pub(crate) websocket: WebSocket,
+    /// Runtime driving the connection, created on the first read.
+    pub(crate) runtime: Option<Runtime>,
+    /// Whether the iteration has ended.
+    pub(crate) done: bool,
 }
 
 /// Clone implementation for WebSocket.
 ///
 /// Creates a new WebSocket instance with cloned configuration but resets:
 /// - Connection status to false
-/// - Connection to None
+/// - Connection halves to None
 impl Clone for WebSocket {
     fn clone(&self) -> Self {
         Self {
@@ -42,7 +110,12 @@ impl Clone for WebSocket {
             header: self.header.clone(),
             config: self.config.clone(),
             connected: Arc::new(AtomicBool::new(false)),
-            connection: Arc::new(http_type::tokio::sync::Mutex::new(None)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            writer: Arc::new(Mutex::new(None)),
+            reader: Arc::new(Mutex::new(None)),
+            callbacks: self.callbacks.clone(),
+            pending: Arc::new(StdMutex::new(VecDeque::new())),
+            peer_close: Arc::new(StdMutex::new(None)),
         }
     }
 }
@@ -63,7 +136,12 @@ impl Default for WebSocket {
             header: Arc::new(hash_map_xx_hash3_64()),
             config: Arc::new(RwLock::new(WebSocketConfig::default())),
             connected: Arc::new(AtomicBool::new(false)),
-            connection: Arc::new(http_type::tokio::sync::Mutex::new(None)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            writer: Arc::new(Mutex::new(None)),
+            reader: Arc::new(Mutex::new(None)),
+            callbacks: WebSocketCallbacks::default(),
+            pending: Arc::new(StdMutex::new(VecDeque::new())),
+            peer_close: Arc::new(StdMutex::new(None)),
         }
     }
 }
@@ -79,6 +157,7 @@ impl Stream for WebSocketConnectionType {
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_next(cx),
+            #[cfg(feature = "proxy")]
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_next(cx),
         }
     }
@@ -94,6 +173,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
     fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_ready(cx),
+            #[cfg(feature = "proxy")]
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_ready(cx),
         }
     }
@@ -104,6 +184,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
     ) -> Result<(), Self::Error> {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).start_send(item),
+            #[cfg(feature = "proxy")]
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).start_send(item),
         }
     }
@@ -111,6 +192,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_flush(cx),
+            #[cfg(feature = "proxy")]
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_flush(cx),
         }
     }
@@ -118,6 +200,7 @@ impl Sink<tokio_tungstenite::tungstenite::Message> for WebSocketConnectionType {
     fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         match &mut *self {
             WebSocketConnectionType::Direct(stream) => Pin::new(stream).poll_close(cx),
+            #[cfg(feature = "proxy")]
             WebSocketConnectionType::Proxy(stream) => Pin::new(stream).poll_close(cx),
         }
     }
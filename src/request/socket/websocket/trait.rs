@@ -7,7 +7,7 @@ use super::*;
 /// - Receiving messages
 /// - Closing connections
 /// - Checking connection status
-pub trait WebSocketTrait: Send + Sync {
+pub trait WebSocketTrait: Sealed + Send + Sync {
     /// Sends a text message synchronously.
     ///
     /// # Arguments
@@ -48,6 +48,22 @@ pub trait WebSocketTrait: Send + Sync {
     ///
     /// - `WebSocketResult` - Result indicating success or failure.
     fn send_pong(&mut self, data: &[u8]) -> WebSocketResult;
+    /// Queues a message synchronously without flushing it to the socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketMessage` - The message to queue.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn feed(&mut self, message: WebSocketMessage) -> WebSocketResult;
+    /// Flushes all queued messages to the socket synchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn flush(&mut self) -> WebSocketResult;
     /// Receives a message synchronously.
     ///
     /// # Returns
@@ -60,6 +76,17 @@ pub trait WebSocketTrait: Send + Sync {
     ///
     /// - `WebSocketResult` - Result indicating success or failure.
     fn close(&mut self) -> WebSocketResult;
+    /// Closes the WebSocket connection synchronously with a status code and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `&str` - The close reason, at most 123 bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn close_with(&mut self, code: u16, reason: &str) -> WebSocketResult;
     /// Checks if the WebSocket is currently connected.
     ///
     /// # Returns
@@ -75,7 +102,7 @@ pub trait WebSocketTrait: Send + Sync {
 /// - Receiving messages
 /// - Closing connections
 /// - Checking connection status
-pub trait AsyncWebSocketTrait: Send + Sync {
+pub trait AsyncWebSocketTrait: Sealed + Send + Sync {
     /// Sends a text message asynchronously.
     ///
     /// # Arguments
@@ -128,6 +155,25 @@ pub trait AsyncWebSocketTrait: Send + Sync {
         &'a mut self,
         data: &'a [u8],
     ) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
+    /// Queues a message asynchronously without flushing it to the socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketMessage` - The message to queue.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn feed<'a>(
+        &'a mut self,
+        message: WebSocketMessage,
+    ) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
+    /// Flushes all queued messages to the socket asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn flush<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
     /// Receives a message asynchronously.
     ///
     /// # Returns
@@ -142,6 +188,21 @@ pub trait AsyncWebSocketTrait: Send + Sync {
     ///
     /// - `WebSocketResult` - Result indicating success or failure.
     fn close<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
+    /// Closes the WebSocket connection asynchronously with a status code and reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `&str` - The close reason, at most 123 bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketResult` - Result indicating success or failure.
+    fn close_with<'a>(
+        &'a mut self,
+        code: u16,
+        reason: &'a str,
+    ) -> Pin<Box<dyn Future<Output = WebSocketResult> + Send + 'a>>;
     /// Checks if the WebSocket is currently connected.
     ///
     /// # Returns
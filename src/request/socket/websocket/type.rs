@@ -5,7 +5,23 @@ pub type BoxWebSocketTrait = Box<dyn WebSocketTrait>;
 /// Boxed asynchronous WebSocket trait object.
 pub type BoxAsyncWebSocketTrait = Box<dyn AsyncWebSocketTrait>;
 
-/// Internal WebSocket connection type.
+/// Sending half of the internal WebSocket connection.
 ///
-/// Wraps the WebSocket connection in an Arc<AsyncMutex> for thread-safe sharing.
-pub(crate) type WebSocketConnection = Arc<Mutex<Option<WebSocketConnectionType>>>;
+/// Locked independently of the receiving half, so a send does not wait for a
+/// pending receive.
+pub(crate) type WebSocketWriter = Arc<Mutex<Option<SplitSink<WebSocketConnectionType, Message>>>>;
+
+/// Receiving half of the internal WebSocket connection.
+pub(crate) type WebSocketReader = Arc<Mutex<Option<SplitStream<WebSocketConnectionType>>>>;
+
+/// Callback invoked without arguments on a lifecycle transition.
+pub(crate) type WebSocketEventFn = Arc<dyn Fn() + Send + Sync>;
+
+/// Callback invoked with an error raised by a WebSocket operation.
+pub(crate) type WebSocketErrorFn = Arc<dyn Fn(&WebSocketError) + Send + Sync>;
+
+/// Callback invoked with the attempt number and the error before a connection retry.
+pub(crate) type WebSocketReconnectFn = Arc<dyn Fn(usize, &WebSocketError) + Send + Sync>;
+
+/// Callback invoked after a lost connection is restored, returning the messages to resend.
+pub(crate) type WebSocketResubscribeFn = Arc<dyn Fn() -> Vec<WebSocketMessage> + Send + Sync>;
@@ -46,6 +46,48 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn connect_timeout(&mut self, connect_timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.connect_timeout = Some(connect_timeout);
+        }
+        self
+    }
+
+    pub fn handshake_timeout(&mut self, handshake_timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.handshake_timeout = Some(handshake_timeout);
+        }
+        self
+    }
+
+    pub fn message_timeout(&mut self, message_timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.message_timeout = Some(message_timeout);
+        }
+        self
+    }
+
+    pub fn close_timeout(&mut self, close_timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.close_timeout = Some(close_timeout);
+        }
+        self
+    }
+
+    pub fn insecure_skip_tls_verify(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.insecure_skip_tls_verify = true;
+        }
+        self
+    }
+
+    pub fn with_resolved_addrs(&mut self, addrs: Vec<IpAddr>) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.resolved_addrs = addrs;
+        }
+        self
+    }
+
     pub fn buffer(&mut self, buffer: usize) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.buffer = buffer;
@@ -63,6 +105,91 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn auto_flush(&mut self, auto_flush: bool) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.auto_flush = auto_flush;
+        }
+        self
+    }
+
+    pub fn write_buffer_size(&mut self, write_buffer_size: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.write_buffer_size = Some(write_buffer_size);
+        }
+        self
+    }
+
+    pub fn max_write_buffer_size(&mut self, max_write_buffer_size: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.max_write_buffer_size = Some(max_write_buffer_size);
+        }
+        self
+    }
+
+    pub fn max_message_size(&mut self, max_message_size: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.max_message_size = Some(max_message_size.max(1));
+        }
+        self
+    }
+
+    pub fn max_frame_size(&mut self, max_frame_size: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.max_frame_size = Some(max_frame_size.max(1));
+        }
+        self
+    }
+
+    pub fn send_queue_limit(&mut self, send_queue_limit: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.send_queue_limit = Some(send_queue_limit.max(1));
+        }
+        self
+    }
+
+    pub fn fail_on_backpressure(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.fail_on_backpressure = true;
+        }
+        self
+    }
+
+    pub fn connect_retries(&mut self, connect_retries: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.connect_retries = connect_retries;
+        }
+        self
+    }
+
+    pub fn connect_retry_backoff(&mut self, connect_retry_backoff: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.connect_retry_backoff = connect_retry_backoff;
+        }
+        self
+    }
+
+    pub fn reconnect(&mut self, reconnect: bool) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.reconnect = reconnect;
+        }
+        self
+    }
+
+    pub fn reconnect_max_attempts(&mut self, reconnect_max_attempts: usize) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.reconnect_max_attempts = reconnect_max_attempts;
+        }
+        self
+    }
+
+    pub fn reconnect_backoff(&mut self, reconnect_backoff: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.reconnect_backoff = reconnect_backoff;
+        }
+        self
+    }
+
+    #[cfg(feature = "proxy")]
     pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.proxy = Some(ProxyConfig {
@@ -76,6 +203,7 @@ impl WebSocketBuilder {
         self
     }
 
+    #[cfg(feature = "proxy")]
     pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.proxy = Some(ProxyConfig {
@@ -89,6 +217,7 @@ impl WebSocketBuilder {
         self
     }
 
+    #[cfg(feature = "proxy")]
     pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.proxy = Some(ProxyConfig {
@@ -102,6 +231,7 @@ impl WebSocketBuilder {
         self
     }
 
+    #[cfg(feature = "proxy")]
     pub fn http_proxy_auth(
         &mut self,
         host: &str,
@@ -121,6 +251,7 @@ impl WebSocketBuilder {
         self
     }
 
+    #[cfg(feature = "proxy")]
     pub fn https_proxy_auth(
         &mut self,
         host: &str,
@@ -140,6 +271,7 @@ impl WebSocketBuilder {
         self
     }
 
+    #[cfg(feature = "proxy")]
     pub fn socks5_proxy_auth(
         &mut self,
         host: &str,
@@ -159,15 +291,57 @@ impl WebSocketBuilder {
         self
     }
 
-    pub fn build_sync(&mut self) -> WebSocket {
-        self.builder = self.websocket.clone();
-        self.websocket = WebSocket::default();
-        self.builder.clone()
+    pub fn on_connect<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.websocket.callbacks.on_connect = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn on_close<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.websocket.callbacks.on_close = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn on_error<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&WebSocketError) + Send + Sync + 'static,
+    {
+        self.websocket.callbacks.on_error = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn on_reconnect_attempt<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(usize, &WebSocketError) + Send + Sync + 'static,
+    {
+        self.websocket.callbacks.on_reconnect_attempt = Some(Arc::new(callback));
+        self
     }
 
-    pub fn build_async(&mut self) -> WebSocket {
+    pub fn on_reconnect<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn() -> Vec<WebSocketMessage> + Send + Sync + 'static,
+    {
+        self.websocket.callbacks.on_reconnect = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn build(&mut self) -> WebSocket {
         self.builder = self.websocket.clone();
         self.websocket = WebSocket::default();
         self.builder.clone()
     }
+
+    pub fn build_sync(&mut self) -> BoxWebSocketTrait {
+        Box::new(self.build())
+    }
+
+    pub fn build_async(&mut self) -> BoxAsyncWebSocketTrait {
+        Box::new(self.build())
+    }
 }
@@ -1,5 +1,11 @@
 use super::*;
 
+/// Builder for WebSocket connections.
+///
+/// No WebSocket extensions are offered during the handshake. In particular
+/// `permessage-deflate` compression is not available: tungstenite 0.29 has no
+/// extension support and fails the connection on any frame with a reserved bit
+/// set, which compressed messages require.
 #[derive(Clone, Debug, Default)]
 pub struct WebSocketBuilder {
     pub(crate) websocket: WebSocket,
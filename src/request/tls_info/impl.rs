@@ -0,0 +1,213 @@
+use super::*;
+
+impl TlsInfo {
+    /// Returns the certificate chain presented by the server.
+    ///
+    /// # Returns
+    ///
+    /// - `&[TlsCertificate]` - The chain, leaf certificate first.
+    pub fn get_certificates(&self) -> &[TlsCertificate] {
+        &self.certificates
+    }
+
+    /// Returns the leaf certificate of the server.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&TlsCertificate>` - The leaf certificate, or `None` if no certificate was presented.
+    pub fn get_leaf(&self) -> Option<&TlsCertificate> {
+        self.certificates.first()
+    }
+
+    /// Returns the negotiated protocol version.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The protocol version, e.g. `TLSv1_3`.
+    pub fn get_protocol_version(&self) -> Option<&str> {
+        self.protocol_version.as_deref()
+    }
+
+    /// Returns the negotiated cipher suite.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The cipher suite, e.g. `TLS13_AES_128_GCM_SHA256`.
+    pub fn get_cipher_suite(&self) -> Option<&str> {
+        self.cipher_suite.as_deref()
+    }
+
+    /// Returns the application protocol the server selected through ALPN.
+    ///
+    /// The inspection offers `h2` and `http/1.1`, so `h2` means the server supports HTTP/2.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The protocol, e.g. `h2`, or None if the server did not select one.
+    pub fn get_alpn_protocol(&self) -> Option<&str> {
+        self.alpn_protocol.as_deref()
+    }
+}
+
+impl TlsCertificate {
+    /// Parses a DER-encoded X.509 certificate.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The DER-encoded certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TlsCertificate>` - The parsed certificate, or `None` if it is malformed.
+    pub(crate) fn from_der(der: Vec<u8>) -> Option<Self> {
+        let certificate: &[u8] = DerReader::new(&der).read_expected(DER_SEQUENCE)?;
+        let tbs: &[u8] = DerReader::new(certificate).read_expected(DER_SEQUENCE)?;
+        let mut reader: DerReader<'_> = DerReader::new(tbs);
+        reader.read_optional(DER_CONTEXT_0_CONSTRUCTED);
+        let serial_number: String = hex_encode(reader.read_expected(DER_INTEGER)?);
+        reader.read_expected(DER_SEQUENCE)?;
+        let issuer: String = format_der_name(reader.read_expected(DER_SEQUENCE)?)?;
+        let mut validity: DerReader<'_> = DerReader::new(reader.read_expected(DER_SEQUENCE)?);
+        let (not_before_tag, not_before): (u8, &[u8]) = validity.read()?;
+        let (not_after_tag, not_after): (u8, &[u8]) = validity.read()?;
+        let subject: String = format_der_name(reader.read_expected(DER_SEQUENCE)?)?;
+        reader.read_expected(DER_SEQUENCE)?;
+        let mut subject_alt_names: Vec<String> = Vec::new();
+        while let Some((tag, content)) = reader.read() {
+            if tag != DER_CONTEXT_3_CONSTRUCTED {
+                continue;
+            }
+            let mut extensions: DerReader<'_> =
+                DerReader::new(DerReader::new(content).read_expected(DER_SEQUENCE)?);
+            while extensions.peek_tag().is_some() {
+                let mut extension: DerReader<'_> =
+                    DerReader::new(extensions.read_expected(DER_SEQUENCE)?);
+                let oid: &[u8] = extension.read_expected(DER_OBJECT_IDENTIFIER)?;
+                extension.read_optional(DER_BOOLEAN);
+                let value: &[u8] = extension.read_expected(DER_OCTET_STRING)?;
+                if oid == OID_SUBJECT_ALT_NAME {
+                    subject_alt_names = parse_subject_alt_names(value)?;
+                }
+            }
+        }
+        Some(Self {
+            not_before: parse_der_time(not_before_tag, not_before)?,
+            not_after: parse_der_time(not_after_tag, not_after)?,
+            serial_number,
+            subject,
+            issuer,
+            subject_alt_names,
+            der,
+        })
+    }
+
+    /// Returns the DER encoding of the certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The DER-encoded certificate.
+    pub fn get_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Returns the serial number.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The serial number as lowercase hex.
+    pub fn get_serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    /// Returns the subject distinguished name.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The subject, e.g. `C=US, O=Example, CN=example.com`.
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the issuer distinguished name.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The issuer, in the same format as the subject.
+    pub fn get_issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Returns the subject alternative names.
+    ///
+    /// # Returns
+    ///
+    /// - `&[String]` - DNS names, email addresses, URIs and IP addresses of the certificate.
+    pub fn get_subject_alt_names(&self) -> &[String] {
+        &self.subject_alt_names
+    }
+
+    /// Returns the start of the validity period.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - The `notBefore` date in Unix seconds.
+    pub fn get_not_before(&self) -> i64 {
+        self.not_before
+    }
+
+    /// Returns the end of the validity period.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - The `notAfter` date in Unix seconds.
+    pub fn get_not_after(&self) -> i64 {
+        self.not_after
+    }
+
+    /// Returns the number of whole days until the certificate expires.
+    ///
+    /// # Returns
+    ///
+    /// - `i64` - The days left, negative once the certificate has expired.
+    pub fn get_days_until_expiry(&self) -> i64 {
+        let now: i64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        (self.not_after - now).div_euclid(SECONDS_PER_DAY)
+    }
+}
+
+impl ServerCertVerifier for InspectionVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+/// Details of a TLS endpoint gathered from a handshake.
+///
+/// Produced by `RequestBuilder::inspect_tls` without sending an HTTP request.
+#[derive(Clone, Debug)]
+pub struct TlsInfo {
+    /// The certificate chain presented by the server, leaf first.
+    pub(crate) certificates: Vec<TlsCertificate>,
+    /// The negotiated protocol version, e.g. `TLSv1_3`.
+    pub(crate) protocol_version: Option<String>,
+    /// The negotiated cipher suite, e.g. `TLS13_AES_128_GCM_SHA256`.
+    pub(crate) cipher_suite: Option<String>,
+    /// The application protocol selected through ALPN, e.g. `h2`.
+    pub(crate) alpn_protocol: Option<String>,
+}
+
+/// A parsed X.509 certificate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TlsCertificate {
+    /// The DER encoding of the certificate.
+    pub(crate) der: Vec<u8>,
+    /// The serial number as lowercase hex.
+    pub(crate) serial_number: String,
+    /// The subject distinguished name.
+    pub(crate) subject: String,
+    /// The issuer distinguished name.
+    pub(crate) issuer: String,
+    /// Names from the subjectAltName extension.
+    pub(crate) subject_alt_names: Vec<String>,
+    /// Start of the validity period, in Unix seconds.
+    pub(crate) not_before: i64,
+    /// End of the validity period, in Unix seconds.
+    pub(crate) not_after: i64,
+}
+
+/// Certificate verifier used when inspecting an endpoint or skipping verification.
+///
+/// Accepts any certificate chain so that expired, self-signed or mismatched
+/// certificates can still be reported or connected to, while handshake signatures
+/// are checked as usual.
+#[derive(Debug)]
+pub(crate) struct InspectionVerifier {
+    /// Signature algorithms of the crypto provider.
+    pub(crate) algorithms: WebPkiSupportedAlgorithms,
+}
@@ -5,9 +5,18 @@ impl Default for Tmp {
     fn default() -> Self {
         Self {
             visit_url: HashSet::new(),
+            #[cfg(feature = "tls")]
             root_cert: RootCertStore {
                 roots: TLS_SERVER_ROOTS.to_vec(),
             },
+            keep_alive: false,
+            attempts: 0,
+            redirect_chain: Vec::new(),
+            auth_retried: false,
+            metrics: RequestMetrics::default(),
+            started_at: None,
+            in_flight: None,
+            state: InFlightState::Connecting,
         }
     }
 }
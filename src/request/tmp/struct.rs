@@ -3,5 +3,14 @@ use super::*;
 #[derive(Clone, Debug)]
 pub struct Tmp {
     pub visit_url: HashSet<String>,
+    #[cfg(feature = "tls")]
     pub root_cert: RootCertStore,
+    pub keep_alive: bool,
+    pub attempts: usize,
+    pub redirect_chain: Vec<String>,
+    pub auth_retried: bool,
+    pub metrics: RequestMetrics,
+    pub started_at: Option<Instant>,
+    pub in_flight: Option<u64>,
+    pub state: InFlightState,
 }
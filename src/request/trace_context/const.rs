@@ -0,0 +1,11 @@
+/// Header carrying the W3C trace parent of a request.
+pub(crate) const TRACEPARENT: &str = "traceparent";
+
+/// Header carrying vendor-specific W3C trace state.
+pub(crate) const TRACESTATE: &str = "tracestate";
+
+/// The `traceparent` version emitted by this crate.
+pub(crate) const TRACEPARENT_VERSION: &str = "00";
+
+/// Trace flag marking a trace as sampled.
+pub(crate) const TRACE_FLAG_SAMPLED: u8 = 0x01;
@@ -0,0 +1,197 @@
+use super::*;
+
+impl TraceContext {
+    /// Starts a new sampled trace with random trace and span ids.
+    ///
+    /// # Returns
+    ///
+    /// - `TraceContext` - The root context.
+    pub fn new_root() -> Self {
+        let mut trace_id: [u8; 16] = [0; 16];
+        fill_random(&mut trace_id);
+        Self {
+            trace_id,
+            parent_id: Self::new_span_id(),
+            flags: TRACE_FLAG_SAMPLED,
+            trace_state: None,
+        }
+    }
+
+    /// Creates a child span in the same trace.
+    ///
+    /// # Returns
+    ///
+    /// - `TraceContext` - A context with the same trace id, flags and state and a new span id.
+    pub fn child(&self) -> Self {
+        Self {
+            parent_id: Self::new_span_id(),
+            ..self.clone()
+        }
+    }
+
+    /// Sets the vendor-specific `tracestate` value forwarded with requests.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `tracestate` header value.
+    ///
+    /// # Returns
+    ///
+    /// - `TraceContext` - The context with the trace state set.
+    pub fn with_trace_state(mut self, trace_state: &str) -> Self {
+        self.trace_state = Some(trace_state.to_string());
+        self
+    }
+
+    /// Gets the trace id.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The trace id as 32 lowercase hex digits.
+    pub fn get_trace_id(&self) -> String {
+        hex_encode(&self.trace_id)
+    }
+
+    /// Gets the id of the span this context represents.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The span id as 16 lowercase hex digits.
+    pub fn get_parent_id(&self) -> String {
+        hex_encode(&self.parent_id)
+    }
+
+    /// Gets the `tracestate` value.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The trace state, if any.
+    pub fn get_trace_state(&self) -> Option<&str> {
+        self.trace_state.as_deref()
+    }
+
+    /// Checks whether the trace is sampled.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the sampled flag is set.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & TRACE_FLAG_SAMPLED != 0
+    }
+
+    /// Gets the context made current on this thread by `scope`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TraceContext>` - The ambient context, or `None` outside any scope.
+    pub fn current() -> Option<Self> {
+        CURRENT_TRACE_CONTEXT.with(|current| current.borrow().clone())
+    }
+
+    /// Runs a closure with this context as the ambient context of the thread.
+    ///
+    /// Requests built inside the closure with `RequestBuilder::propagate_trace`
+    /// become children of this context. The previous context is restored afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure to run.
+    ///
+    /// # Returns
+    ///
+    /// - `R` - The value returned by the closure.
+    pub fn scope<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let previous: Option<Self> =
+            CURRENT_TRACE_CONTEXT.with(|current| current.replace(Some(self)));
+        let result: R = f();
+        CURRENT_TRACE_CONTEXT.with(|current| current.replace(previous));
+        result
+    }
+
+    /// Generates a random non-zero span id.
+    ///
+    /// # Returns
+    ///
+    /// - `[u8; 8]` - The span id.
+    fn new_span_id() -> [u8; 8] {
+        loop {
+            let span_id: [u8; 8] = random_u64().to_be_bytes();
+            if span_id != [0; 8] {
+                return span_id;
+            }
+        }
+    }
+
+    /// Decodes a fixed number of lowercase hex digits.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The hex digits.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<[u8; N]>` - The bytes, or `None` if the length or a digit is invalid.
+    fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+        if hex.len() != N * 2 || hex.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            return None;
+        }
+        let mut bytes: [u8; N] = [0; N];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(hex.get(index * 2..index * 2 + 2)?, 16).ok()?;
+        }
+        Some(bytes)
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = RequestError;
+
+    /// Parses a `traceparent` header value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value, e.g. `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TraceContext, RequestError>` - The context, or an error if the value is malformed.
+    fn from_str(traceparent: &str) -> Result<Self, Self::Err> {
+        let invalid = || RequestError::Request(format!("Invalid traceparent: {traceparent}"));
+        let fields: Vec<&str> = traceparent.trim().split('-').collect();
+        let [version, trace_id, parent_id, flags, ..] = fields.as_slice() else {
+            return Err(invalid());
+        };
+        let version: [u8; 1] = Self::decode_hex(version).ok_or_else(invalid)?;
+        if version[0] == 0xFF || (version[0] == 0 && fields.len() != 4) {
+            return Err(invalid());
+        }
+        let trace_id: [u8; 16] = Self::decode_hex(trace_id).ok_or_else(invalid)?;
+        let parent_id: [u8; 8] = Self::decode_hex(parent_id).ok_or_else(invalid)?;
+        let flags: [u8; 1] = Self::decode_hex(flags).ok_or_else(invalid)?;
+        if trace_id == [0; 16] || parent_id == [0; 8] {
+            return Err(invalid());
+        }
+        Ok(Self {
+            trace_id,
+            parent_id,
+            flags: flags[0],
+            trace_state: None,
+        })
+    }
+}
+
+impl Display for TraceContext {
+    /// Formats the context as a `traceparent` header value.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{TRACEPARENT_VERSION}-{}-{}-{:02x}",
+            self.get_trace_id(),
+            self.get_parent_id(),
+            self.flags
+        )
+    }
+}
@@ -0,0 +1,10 @@
+mod r#const;
+mod r#impl;
+mod r#static;
+mod r#struct;
+
+pub use r#struct::*;
+
+pub(crate) use {r#const::*, r#static::*};
+
+use super::*;
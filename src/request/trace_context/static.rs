@@ -0,0 +1,6 @@
+use super::*;
+
+thread_local! {
+    /// The trace context made current on this thread by `TraceContext::scope`.
+    pub(crate) static CURRENT_TRACE_CONTEXT: RefCell<Option<TraceContext>> = const { RefCell::new(None) };
+}
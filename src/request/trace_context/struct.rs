@@ -0,0 +1,16 @@
+/// A W3C trace context identifying the span a request belongs to.
+///
+/// Requests configured with `RequestBuilder::trace_context` or
+/// `RequestBuilder::propagate_trace` send a `traceparent` header naming a new
+/// child span of this context on every attempt, and forward its `tracestate`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceContext {
+    /// The 16-byte trace id shared by every span of the trace.
+    pub(crate) trace_id: [u8; 16],
+    /// The 8-byte id of the span this context represents.
+    pub(crate) parent_id: [u8; 8],
+    /// The trace flags, such as sampled.
+    pub(crate) flags: u8,
+    /// The vendor-specific `tracestate` value, if any.
+    pub(crate) trace_state: Option<String>,
+}
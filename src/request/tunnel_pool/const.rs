@@ -0,0 +1,5 @@
+/// How long an idle proxy tunnel is kept before it is discarded, in milliseconds.
+pub(crate) const DEFAULT_TUNNEL_IDLE_TIMEOUT_MS: u64 = 30_000;
+
+/// Maximum number of idle tunnels kept per proxy and target.
+pub(crate) const MAX_IDLE_TUNNELS_PER_KEY: usize = 8;
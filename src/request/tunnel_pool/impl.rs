@@ -0,0 +1,86 @@
+use super::*;
+
+impl TunnelPool {
+    /// Takes the most recently used live tunnel, discarding expired ones.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<IdleTunnel<S>>` - The idle tunnels of one key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<S>` - A tunnel stream, or None if no live tunnel is available.
+    fn take_from<S>(idle: &mut Vec<IdleTunnel<S>>) -> Option<S> {
+        let idle_timeout: Duration = Duration::from_millis(DEFAULT_TUNNEL_IDLE_TIMEOUT_MS);
+        idle.retain(|tunnel: &IdleTunnel<S>| tunnel.idle_since.elapsed() < idle_timeout);
+        idle.pop().map(|tunnel: IdleTunnel<S>| tunnel.stream)
+    }
+
+    /// Adds a tunnel to the idle tunnels of one key, unless the key is full.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<IdleTunnel<S>>` - The idle tunnels of one key.
+    /// - `S` - The tunnel stream.
+    fn release_into<S>(idle: &mut Vec<IdleTunnel<S>>, stream: S) {
+        if idle.len() < MAX_IDLE_TUNNELS_PER_KEY {
+            idle.push(IdleTunnel {
+                stream,
+                idle_since: Instant::now(),
+            });
+        }
+    }
+
+    /// Takes an idle synchronous tunnel.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TunnelKey` - The proxy and target of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<BoxReadWrite>` - A tunnel opened by this thread, or None if none is idle.
+    pub(crate) fn take_sync(key: &TunnelKey) -> Option<BoxReadWrite> {
+        SYNC_TUNNELS.with(|tunnels| tunnels.borrow_mut().get_mut(key).and_then(Self::take_from))
+    }
+
+    /// Returns a synchronous tunnel to the pool after a complete response.
+    ///
+    /// # Arguments
+    ///
+    /// - `TunnelKey` - The proxy and target of the request.
+    /// - `BoxReadWrite` - The tunnel stream.
+    pub(crate) fn release_sync(key: TunnelKey, stream: BoxReadWrite) {
+        SYNC_TUNNELS.with(|tunnels| {
+            Self::release_into(tunnels.borrow_mut().entry(key).or_default(), stream);
+        });
+    }
+
+    /// Takes an idle asynchronous tunnel.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TunnelKey` - The proxy and target of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<BoxAsyncReadWrite>` - A tunnel, or None if none is idle.
+    #[cfg(feature = "async")]
+    pub(crate) fn take_async(key: &TunnelKey) -> Option<BoxAsyncReadWrite> {
+        let mut tunnels = ASYNC_TUNNELS.lock().ok()?;
+        tunnels.get_mut(key).and_then(Self::take_from)
+    }
+
+    /// Returns an asynchronous tunnel to the pool after a complete response.
+    ///
+    /// # Arguments
+    ///
+    /// - `TunnelKey` - The proxy and target of the request.
+    /// - `BoxAsyncReadWrite` - The tunnel stream.
+    #[cfg(feature = "async")]
+    pub(crate) fn release_async(key: TunnelKey, stream: BoxAsyncReadWrite) {
+        if let Ok(mut tunnels) = ASYNC_TUNNELS.lock() {
+            Self::release_into(tunnels.entry(key).or_default(), stream);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod r#const;
+mod r#impl;
+mod r#static;
+mod r#struct;
+
+pub(crate) use {r#const::*, r#static::*, r#struct::*};
+
+use super::*;
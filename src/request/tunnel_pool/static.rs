@@ -0,0 +1,13 @@
+use super::*;
+
+thread_local! {
+    /// Idle synchronous tunnels of the current thread.
+    pub(crate) static SYNC_TUNNELS: RefCell<HashMapXxHash3_64<TunnelKey, Vec<IdleTunnel<BoxReadWrite>>>> =
+        RefCell::new(hash_map_xx_hash3_64());
+}
+
+/// Idle asynchronous tunnels shared by all tasks.
+#[cfg(feature = "async")]
+pub(crate) static ASYNC_TUNNELS: LazyLock<
+    StdMutex<HashMapXxHash3_64<TunnelKey, Vec<IdleTunnel<BoxAsyncReadWrite>>>>,
+> = LazyLock::new(|| StdMutex::new(hash_map_xx_hash3_64()));
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Identifies the proxy tunnels that may carry a request.
+///
+/// Tunnels are only shared between requests using the same proxy, credentials,
+/// target, scheme, and TLS settings, so a pooled TLS session always belongs to the
+/// target and was verified the way the request expects.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct TunnelKey {
+    /// The proxy the tunnel traverses, including its credentials.
+    pub(crate) proxy: ProxyConfig,
+    /// The target host the tunnel was opened to.
+    pub(crate) target_host: String,
+    /// The target port the tunnel was opened to.
+    pub(crate) target_port: u16,
+    /// Whether a TLS session with the target runs inside the tunnel.
+    pub(crate) https: bool,
+    /// Whether certificates of the proxy and target were accepted without verification.
+    pub(crate) insecure_skip_tls_verify: bool,
+    /// The server name the TLS session with the target was verified against.
+    pub(crate) tls_server_name: String,
+}
+
+/// A pooled tunnel waiting for its next request.
+pub(crate) struct IdleTunnel<S> {
+    /// The established tunnel stream.
+    pub(crate) stream: S,
+    /// When the tunnel was returned to the pool.
+    pub(crate) idle_since: Instant,
+}
+
+/// Keep-alive pool of established proxy tunnels.
+///
+/// Synchronous tunnels are pooled per thread, asynchronous tunnels process-wide.
+pub(crate) struct TunnelPool;
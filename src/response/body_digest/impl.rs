@@ -0,0 +1,59 @@
+use super::*;
+
+impl BodyDigest {
+    /// Creates a digest over a complete body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The body bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `BodyDigest` - The digest of the body.
+    pub(crate) fn of(body: &[u8]) -> Self {
+        let mut digest: Self = Self::default();
+        digest.update(body);
+        digest
+    }
+
+    /// Feeds body bytes into both digests.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The next body bytes.
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        self.sha256.update(bytes);
+        self.md5.update(bytes);
+    }
+
+    /// Gets the SHA-256 digest of the bytes seen so far.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The lowercase hex encoded digest.
+    pub(crate) fn sha256_hex(&self) -> String {
+        hex_encode(&self.sha256.clone().finalize())
+    }
+
+    /// Gets the MD5 digest of the bytes seen so far.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The lowercase hex encoded digest.
+    pub(crate) fn md5_hex(&self) -> String {
+        hex_encode(&self.md5.clone().finalize())
+    }
+
+    /// Compares the MD5 digest with a `Content-MD5` header value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base64 encoded `Content-MD5` header value.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the digests match, false otherwise.
+    pub(crate) fn matches_content_md5(&self, content_md5: &str) -> bool {
+        base64_encode(&self.md5.clone().finalize()) == content_md5.trim()
+    }
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+/// Running SHA-256 and MD5 digests of a response body.
+///
+/// Updated with the payload bytes as they are read, so the hashes of a
+/// streamed body are available without buffering it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BodyDigest {
+    /// SHA-256 state over the bytes seen so far.
+    pub(crate) sha256: Sha256,
+    /// MD5 state over the bytes seen so far.
+    pub(crate) md5: Md5,
+}
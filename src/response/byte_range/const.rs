@@ -0,0 +1,19 @@
+use super::*;
+
+/// Status code of a partial content response.
+pub(crate) const PARTIAL_CONTENT: ResponseStatusCode = 206;
+
+/// Media type of a response carrying several byte ranges.
+pub(crate) const MULTIPART_BYTERANGES: &str = "multipart/byteranges";
+
+/// Header describing which bytes of the full representation a body carries.
+pub(crate) const CONTENT_RANGE: &str = "Content-Range";
+
+/// Header requesting part of a representation.
+pub(crate) const RANGE: &str = "Range";
+
+/// Status code of a response to a range that cannot be satisfied.
+pub(crate) const RANGE_NOT_SATISFIABLE: ResponseStatusCode = 416;
+
+/// The only range unit defined by HTTP.
+pub(crate) const BYTES_UNIT: &str = "bytes";
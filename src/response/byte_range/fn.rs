@@ -0,0 +1,86 @@
+use super::*;
+
+/// Parses a `Content-Range` header value such as `bytes 0-499/1234`.
+///
+/// # Arguments
+///
+/// - `&str` - The header value.
+///
+/// # Returns
+///
+/// - `Option<(u64, u64, Option<u64>)>` - The inclusive start and end offsets and the
+///   total length (`None` for `*`), or `None` if the value is malformed or unsatisfied.
+pub(crate) fn parse_content_range(value: &str) -> Option<(u64, u64, Option<u64>)> {
+    let (unit, range): (&str, &str) = value.trim().split_once(' ')?;
+    if !unit.eq_ignore_ascii_case(BYTES_UNIT) {
+        return None;
+    }
+    let (span, total): (&str, &str) = range.trim().split_once('/')?;
+    let (start, end): (&str, &str) = span.split_once('-')?;
+    let start: u64 = start.trim().parse().ok()?;
+    let end: u64 = end.trim().parse().ok()?;
+    let total: Option<u64> = match total.trim() {
+        "*" => None,
+        total => Some(total.parse().ok()?),
+    };
+    if end < start || total.is_some_and(|total| end >= total) {
+        return None;
+    }
+    Some((start, end, total))
+}
+
+/// Splits a `multipart/byteranges` body into its parts.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response body.
+/// - `&str` - The multipart boundary.
+///
+/// # Returns
+///
+/// - `Result<Vec<ByteRangePart>, RequestError>` - The parts in body order, or an error if
+///   the body is truncated or a part lacks a valid `Content-Range` header.
+pub(crate) fn parse_byte_ranges(
+    body: &[u8],
+    boundary: &str,
+) -> Result<Vec<ByteRangePart>, RequestError> {
+    let mut data: Vec<u8> = Vec::with_capacity(body.len() + BR_BYTES.len());
+    data.extend_from_slice(BR_BYTES);
+    data.extend_from_slice(body);
+    let delimiter: Vec<u8> = format!("\r\n--{boundary}").into_bytes();
+    let mut parts: Vec<ByteRangePart> = Vec::new();
+    for part in split_multi_byte(&data, &delimiter).into_iter().skip(1) {
+        if part.starts_with(b"--") {
+            return Ok(parts);
+        }
+        let malformed = || RequestError::Request("Malformed multipart/byteranges part".to_string());
+        let line_end: usize = SharedResponseHandler::find_crlf(part, 0).ok_or_else(malformed)?;
+        let headers_end: usize =
+            SharedResponseHandler::find_double_crlf(part, line_end).ok_or_else(malformed)?;
+        let mut range: Option<(u64, u64, Option<u64>)> = None;
+        let mut content_type: Option<String> = None;
+        for line in String::from_utf8_lossy(&part[line_end..headers_end]).split("\r\n") {
+            let Some((name, value)) = line.split_once(':') else {
+                continue;
+            };
+            if name.trim().eq_ignore_ascii_case(CONTENT_RANGE) {
+                range = parse_content_range(value);
+            } else if name.trim().eq_ignore_ascii_case(CONTENT_TYPE) {
+                content_type = Some(value.trim().to_string());
+            }
+        }
+        let (start, end, total): (u64, u64, Option<u64>) = range.ok_or_else(|| {
+            RequestError::Request("Byte range part without a valid Content-Range".to_string())
+        })?;
+        parts.push(ByteRangePart {
+            start,
+            end,
+            total,
+            content_type,
+            body: part[headers_end + 4..].to_vec(),
+        });
+    }
+    Err(RequestError::Request(
+        "Truncated multipart/byteranges body".to_string(),
+    ))
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+impl ContentRange {
+    /// Returns the offset of the first byte of the range.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The inclusive start offset.
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the offset of the last byte of the range.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The inclusive end offset.
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns the length of the full representation.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The total length, or `None` if the server sent `*`.
+    pub fn get_total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns the number of bytes in the range.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The range length.
+    pub fn get_len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Returns whether the range reaches the end of the representation.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the total length is known and the range ends at its last byte.
+    pub fn is_last(&self) -> bool {
+        self.total.is_some_and(|total: u64| self.end + 1 == total)
+    }
+}
+
+impl ByteRangePart {
+    /// Returns the offset of the first byte of the range.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The inclusive start offset.
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the offset of the last byte of the range.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The inclusive end offset.
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns the length of the full representation.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The total length, or `None` if the server sent `*`.
+    pub fn get_total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns the media type of the part.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The `Content-Type` of the part, if given.
+    pub fn get_content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Returns the bytes of the range.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The range body.
+    pub fn get_body(&self) -> &[u8] {
+        &self.body
+    }
+}
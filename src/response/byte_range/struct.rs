@@ -0,0 +1,31 @@
+/// The byte range a response body carries, parsed from its `Content-Range` header.
+///
+/// Returned by `HttpResponseBinary::get_content_range`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentRange {
+    /// Offset of the first byte, inclusive.
+    pub(crate) start: u64,
+    /// Offset of the last byte, inclusive.
+    pub(crate) end: u64,
+    /// Length of the full representation, if known.
+    pub(crate) total: Option<u64>,
+}
+
+/// One range of a partial content (`206`) response.
+///
+/// Returned by `HttpResponseBinary::byte_ranges`, either for each part of a
+/// `multipart/byteranges` body or for the single range described by the
+/// `Content-Range` header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ByteRangePart {
+    /// Offset of the first byte, inclusive.
+    pub(crate) start: u64,
+    /// Offset of the last byte, inclusive.
+    pub(crate) end: u64,
+    /// Length of the full representation, if known.
+    pub(crate) total: Option<u64>,
+    /// Media type of the part, if given.
+    pub(crate) content_type: Option<String>,
+    /// The bytes of the range.
+    pub(crate) body: Vec<u8>,
+}
@@ -0,0 +1,10 @@
+/// Number of leading body bytes scanned for an HTML `<meta>` charset declaration.
+///
+/// Matches the prescan length of the HTML encoding sniffing algorithm.
+pub(crate) const META_CHARSET_PRESCAN_SIZE: usize = 1024;
+
+/// Opening of an HTML `<meta>` tag, in lowercase.
+pub(crate) const META_TAG_OPEN: &[u8] = b"<meta";
+
+/// Name of the attribute or parameter declaring a charset, in lowercase.
+pub(crate) const CHARSET_NAME: &[u8] = b"charset";
@@ -0,0 +1,103 @@
+use super::*;
+
+/// Finds the charset declared by a `<meta>` tag near the start of an HTML body.
+///
+/// Both `<meta charset="...">` and `<meta http-equiv="Content-Type"
+/// content="...; charset=...">` are recognized. A declared UTF-16 charset is read
+/// as UTF-8, since a document that can be scanned as ASCII is not UTF-16.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response body.
+///
+/// # Returns
+///
+/// - `Option<&'static Encoding>` - The declared encoding, or None if no supported
+///   charset is declared in the first `META_CHARSET_PRESCAN_SIZE` bytes.
+pub(crate) fn sniff_meta_charset(body: &[u8]) -> Option<&'static Encoding> {
+    let prefix: Vec<u8> = body[..body.len().min(META_CHARSET_PRESCAN_SIZE)].to_ascii_lowercase();
+    let mut offset: usize = 0;
+    while let Some(start) = find_bytes(&prefix[offset..], META_TAG_OPEN) {
+        let tag_start: usize = offset + start + META_TAG_OPEN.len();
+        let tag_end: usize = prefix[tag_start..]
+            .iter()
+            .position(|&byte: &u8| byte == b'>')
+            .map_or(prefix.len(), |end: usize| tag_start + end);
+        if let Some(encoding) = parse_charset_attribute(&prefix[tag_start..tag_end]) {
+            if encoding == UTF_16LE || encoding == UTF_16BE {
+                return Some(UTF_8);
+            }
+            return Some(encoding);
+        }
+        offset = tag_end;
+    }
+    None
+}
+
+/// Reads the value following the first `charset=` inside a tag.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The lowercase attributes of the tag.
+///
+/// # Returns
+///
+/// - `Option<&'static Encoding>` - The encoding named by the value, or None if there
+///   is no `charset=` or the label is unknown.
+fn parse_charset_attribute(attributes: &[u8]) -> Option<&'static Encoding> {
+    let mut offset: usize = 0;
+    while let Some(start) = find_bytes(&attributes[offset..], CHARSET_NAME) {
+        let rest: &[u8] = attributes[offset + start + CHARSET_NAME.len()..].trim_ascii_start();
+        offset += start + CHARSET_NAME.len();
+        let Some(value) = rest.strip_prefix(b"=") else {
+            continue;
+        };
+        let value: &[u8] = value.trim_ascii_start();
+        let value: &[u8] = value
+            .strip_prefix(b"\"")
+            .or_else(|| value.strip_prefix(b"'"))
+            .unwrap_or(value);
+        let end: usize = value
+            .iter()
+            .position(|&byte: &u8| {
+                matches!(byte, b'"' | b'\'' | b';' | b'/') || byte.is_ascii_whitespace()
+            })
+            .unwrap_or(value.len());
+        return Encoding::for_label(&value[..end]);
+    }
+    None
+}
+
+/// Finds the first occurrence of a byte pattern.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The bytes to search.
+/// - `&[u8]` - The non-empty pattern.
+///
+/// # Returns
+///
+/// - `Option<usize>` - The index of the first match, or None if absent.
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window: &[u8]| window == needle)
+}
+
+/// Decodes a body to UTF-8 text.
+///
+/// A byte order mark overrides the given encoding, and malformed sequences are
+/// replaced with U+FFFD.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response body.
+/// - `&'static Encoding` - The encoding of the body.
+///
+/// # Returns
+///
+/// - `String` - The decoded text.
+pub(crate) fn decode_charset(body: &[u8], encoding: &'static Encoding) -> String {
+    let (text, _, _): (Cow<'_, str>, &'static Encoding, bool) = encoding.decode(body);
+    text.into_owned()
+}
@@ -0,0 +1,6 @@
+mod r#const;
+mod r#fn;
+
+pub(crate) use {r#const::*, r#fn::*};
+
+use super::*;
@@ -0,0 +1,21 @@
+/// Number of leading body bytes passed to a `ResponseClassifier`.
+///
+/// Large enough to cover the title and banner of typical maintenance and challenge pages.
+pub const CLASSIFIER_SNIPPET_SIZE: usize = 2048;
+
+/// Lowercase body markers of bot-challenge and captcha pages.
+pub(crate) const CAPTCHA_MARKERS: [&str; 5] = [
+    "captcha",
+    "cf-chl-",
+    "challenge-platform",
+    "are you a robot",
+    "verify you are human",
+];
+
+/// Lowercase body markers of maintenance pages.
+pub(crate) const MAINTENANCE_MARKERS: [&str; 4] = [
+    "down for maintenance",
+    "under maintenance",
+    "maintenance mode",
+    "scheduled maintenance",
+];
@@ -0,0 +1,12 @@
+/// The class of a response as judged by a `ResponseClassifier`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ResponseClass {
+    /// A regular response.
+    Ok,
+    /// The server asks the client to slow down.
+    RateLimited,
+    /// The service is degraded or serving a maintenance page.
+    Maintenance,
+    /// The server answered with a bot challenge or captcha page.
+    Captcha,
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// Truncates a body to the snippet passed to classifiers.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response body.
+///
+/// # Returns
+///
+/// - `&[u8]` - At most `CLASSIFIER_SNIPPET_SIZE` leading bytes.
+pub(crate) fn body_snippet(body: &[u8]) -> &[u8] {
+    &body[..body.len().min(CLASSIFIER_SNIPPET_SIZE)]
+}
@@ -0,0 +1,89 @@
+use super::*;
+
+impl ResponseClass {
+    /// Checks if the response is a regular response.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for `ResponseClass::Ok`, false otherwise.
+    pub fn is_ok(&self) -> bool {
+        *self == Self::Ok
+    }
+
+    /// Checks if the client should back off before sending more requests.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for rate limited and maintenance responses, false otherwise.
+    pub fn should_back_off(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Maintenance)
+    }
+
+    /// Checks if the request may succeed when retried later.
+    ///
+    /// Captcha pages are not retryable without solving the challenge.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for rate limited and maintenance responses, false otherwise.
+    pub fn is_retryable(&self) -> bool {
+        self.should_back_off()
+    }
+}
+
+impl Display for ResponseClass {
+    /// Formats the class as a snake case name, such as `rate_limited`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - Result of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            Self::Ok => "ok",
+            Self::RateLimited => "rate_limited",
+            Self::Maintenance => "maintenance",
+            Self::Captcha => "captcha",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl<F> ResponseClassifier for F
+where
+    F: Fn(ResponseStatusCode, &ResponseHeaders, &[u8]) -> ResponseClass + Send + Sync,
+{
+    fn classify(
+        &self,
+        status_code: ResponseStatusCode,
+        headers: &ResponseHeaders,
+        body_snippet: &[u8],
+    ) -> ResponseClass {
+        self(status_code, headers, body_snippet)
+    }
+}
+
+impl ResponseClassifier for DefaultResponseClassifier {
+    fn classify(
+        &self,
+        status_code: ResponseStatusCode,
+        _headers: &ResponseHeaders,
+        body_snippet: &[u8],
+    ) -> ResponseClass {
+        let snippet: String = String::from_utf8_lossy(body_snippet).to_lowercase();
+        let contains_any =
+            |markers: &[&str]| markers.iter().any(|marker: &&str| snippet.contains(marker));
+        if contains_any(&CAPTCHA_MARKERS) {
+            return ResponseClass::Captcha;
+        }
+        match status_code {
+            429 => ResponseClass::RateLimited,
+            502..=504 | 520..=530 => ResponseClass::Maintenance,
+            _ if contains_any(&MAINTENANCE_MARKERS) => ResponseClass::Maintenance,
+            _ => ResponseClass::Ok,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod r#const;
+mod r#enum;
+mod r#fn;
+mod r#impl;
+mod r#struct;
+mod r#trait;
+
+pub use {r#const::*, r#enum::*, r#struct::*, r#trait::*};
+
+pub(crate) use r#fn::*;
+
+use super::*;
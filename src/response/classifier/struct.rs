@@ -0,0 +1,7 @@
+/// The built-in response classifier.
+///
+/// Flags `429` as rate limited, `502`-`504` and the `520`-`530` edge errors as
+/// maintenance, and recognizes common captcha and maintenance page markers in
+/// the body snippet.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DefaultResponseClassifier;
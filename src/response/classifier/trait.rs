@@ -0,0 +1,24 @@
+use super::*;
+
+/// Classifies responses so callers can back off from degraded services.
+///
+/// Implemented for closures taking the same arguments as `classify`.
+pub trait ResponseClassifier: Send + Sync {
+    /// Classifies a response.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    /// - `&ResponseHeaders` - The response headers.
+    /// - `&[u8]` - At most `CLASSIFIER_SNIPPET_SIZE` leading bytes of the body.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseClass` - The class of the response.
+    fn classify(
+        &self,
+        status_code: ResponseStatusCode,
+        headers: &ResponseHeaders,
+        body_snippet: &[u8],
+    ) -> ResponseClass;
+}
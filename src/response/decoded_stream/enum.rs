@@ -0,0 +1,16 @@
+use super::*;
+
+/// One content coding removed by a `ContentDecoder`.
+pub(crate) enum DecoderStage {
+    /// The `gzip` coding, decompressed as data arrives.
+    #[cfg(feature = "compress")]
+    Gzip(MultiGzDecoder<Vec<u8>>),
+    /// The `deflate` coding, decompressed as data arrives.
+    #[cfg(feature = "compress")]
+    Deflate(ZlibDecoder<Vec<u8>>),
+    /// The `br` coding, decompressed as data arrives.
+    #[cfg(feature = "compress")]
+    Brotli(Box<DecompressorWriter<Vec<u8>>>),
+    /// A registered codec, which decodes the buffered data once it is complete.
+    Buffered(Arc<dyn ContentCodec>, Vec<u8>),
+}
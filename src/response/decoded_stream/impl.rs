@@ -0,0 +1,445 @@
+use super::*;
+
+impl DecoderStage {
+    /// Creates the stage removing a content coding.
+    ///
+    /// A codec registered for the coding takes precedence over the built-in decoders.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase coding token.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<DecoderStage>` - The stage, or None if the coding is unknown.
+    fn new(encoding: &str) -> Option<Self> {
+        if let Some(codec) = CodecRegistry::get(encoding) {
+            return Some(Self::Buffered(codec, Vec::new()));
+        }
+        match encoding {
+            #[cfg(feature = "compress")]
+            "gzip" | "x-gzip" => Some(Self::Gzip(MultiGzDecoder::new(Vec::new()))),
+            #[cfg(feature = "compress")]
+            "deflate" => Some(Self::Deflate(ZlibDecoder::new(Vec::new()))),
+            #[cfg(feature = "compress")]
+            "br" => Some(Self::Brotli(Box::new(DecompressorWriter::new(
+                Vec::new(),
+                DEFAULT_BUFFER_SIZE,
+            )))),
+            _ => None,
+        }
+    }
+
+    /// Decodes the next part of the coded data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The coded data.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Vec<u8>>` - The data decoded so far, or an error if the data is malformed.
+    fn feed(&mut self, input: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            #[cfg(feature = "compress")]
+            Self::Gzip(decoder) => {
+                decoder.write_all(input)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            #[cfg(feature = "compress")]
+            Self::Deflate(decoder) => {
+                decoder.write_all(input)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            #[cfg(feature = "compress")]
+            Self::Brotli(decoder) => {
+                decoder.write_all(input)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Buffered(_, buffer) => {
+                buffer.extend_from_slice(input);
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// Decodes the rest of the coded data once all of it was fed.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Vec<u8>>` - The remaining decoded data, or an error if the
+    ///   data is malformed or truncated.
+    fn finish(&mut self) -> std::io::Result<Vec<u8>> {
+        match self {
+            #[cfg(feature = "compress")]
+            Self::Gzip(decoder) => {
+                decoder.try_finish()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            #[cfg(feature = "compress")]
+            Self::Deflate(decoder) => {
+                decoder.try_finish()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            #[cfg(feature = "compress")]
+            Self::Brotli(decoder) => {
+                decoder.close()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Buffered(codec, buffer) => {
+                codec
+                    .decode(&std::mem::take(buffer))
+                    .map_err(|error: RequestError| {
+                        std::io::Error::new(ErrorKind::InvalidData, error.to_string())
+                    })
+            }
+        }
+    }
+}
+
+impl ContentDecoder {
+    /// Creates a decoder for the codings listed in `Content-Encoding`.
+    ///
+    /// Codings are removed in the reverse order they were applied. Decoding stops at
+    /// the first unknown coding, leaving the data as decoded so far.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseHeaders` - The response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `ContentDecoder` - The decoder, passing data through unchanged if the body has no coding.
+    pub(crate) fn new(headers: &ResponseHeaders) -> Self {
+        let encodings: Vec<String> = headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(CONTENT_ENCODING))
+            .flat_map(|(_, values)| values.iter())
+            .flat_map(|value: &String| value.split(','))
+            .map(|token: &str| token.trim().to_ascii_lowercase())
+            .filter(|token: &String| !token.is_empty() && token != IDENTITY_ENCODING)
+            .collect();
+        let stages: Vec<DecoderStage> = encodings
+            .iter()
+            .rev()
+            .map_while(|token: &String| DecoderStage::new(token))
+            .collect();
+        Self { stages }
+    }
+
+    /// Decodes the next part of the body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The coded body bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Vec<u8>>` - The body bytes decoded so far, or an error.
+    pub(crate) fn feed(&mut self, input: &[u8]) -> std::io::Result<Vec<u8>> {
+        let mut data: Vec<u8> = input.to_vec();
+        for stage in &mut self.stages {
+            data = stage.feed(&data)?;
+        }
+        Ok(data)
+    }
+
+    /// Decodes the rest of the body once all of it was fed.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Vec<u8>>` - The remaining body bytes, or an error.
+    pub(crate) fn finish(&mut self) -> std::io::Result<Vec<u8>> {
+        let mut data: Vec<u8> = Vec::new();
+        for stage in &mut self.stages {
+            if !data.is_empty() {
+                data = stage.feed(&data)?;
+            }
+            data.extend(stage.finish()?);
+        }
+        Ok(data)
+    }
+}
+
+impl DecodedStream {
+    /// Creates a decoded stream over a body reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `BodyReader` - The body to decode.
+    /// - `&ResponseHeaders` - The response headers naming the content codings.
+    ///
+    /// # Returns
+    ///
+    /// - `DecodedStream` - The reader over the decoded body.
+    pub(crate) fn new(body: BodyReader, headers: &ResponseHeaders) -> Self {
+        let chunk_size: usize = body.raw.len();
+        Self {
+            body,
+            content_decoder: ContentDecoder::new(headers),
+            chunk: vec![0; chunk_size],
+            decoded: Vec::new(),
+            position: 0,
+            eof: false,
+        }
+    }
+
+    /// Turns the stream into an iterator over the lines of the decoded body.
+    ///
+    /// # Returns
+    ///
+    /// - `DecodedLines` - An iterator over the lines.
+    pub fn lines(self) -> DecodedLines {
+        DecodedLines {
+            stream: self,
+            buffer: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl Read for DecodedStream {
+    /// Reads decoded body bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut [u8]` - The buffer to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<usize>` - The number of bytes read, 0 at the end of the body.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if self.position < self.decoded.len() {
+                let n: usize = buf.len().min(self.decoded.len() - self.position);
+                buf[..n].copy_from_slice(&self.decoded[self.position..self.position + n]);
+                self.position += n;
+                return Ok(n);
+            }
+            if self.eof || buf.is_empty() {
+                return Ok(0);
+            }
+            let n: usize = self.body.read(&mut self.chunk)?;
+            self.decoded = if n == 0 {
+                self.eof = true;
+                self.content_decoder.finish()?
+            } else {
+                self.content_decoder.feed(&self.chunk[..n])?
+            };
+            self.position = 0;
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncDecodedStream {
+    /// Creates a decoded stream over an async body reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsyncBodyReader` - The body to decode.
+    /// - `&ResponseHeaders` - The response headers naming the content codings.
+    ///
+    /// # Returns
+    ///
+    /// - `AsyncDecodedStream` - The reader over the decoded body.
+    pub(crate) fn new(body: AsyncBodyReader, headers: &ResponseHeaders) -> Self {
+        let chunk_size: usize = body.raw.len();
+        Self {
+            body,
+            content_decoder: ContentDecoder::new(headers),
+            chunk: vec![0; chunk_size],
+            decoded: Vec::new(),
+            position: 0,
+            eof: false,
+        }
+    }
+
+    /// Turns the stream into a stream of the lines of the decoded body.
+    ///
+    /// Behaves like `DecodedStream::lines`, reading the body asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<String, RequestError>>` - A stream of the lines.
+    pub fn lines(self) -> impl Stream<Item = Result<String, RequestError>> + Send {
+        let chunk: Vec<u8> = vec![0; self.chunk.len()];
+        let state: (Self, Vec<u8>, Vec<u8>, bool) = (self, Vec::new(), chunk, false);
+        unfold(
+            state,
+            |(mut stream, mut buffer, mut chunk, finished)| async move {
+                if finished {
+                    return None;
+                }
+                loop {
+                    if let Some(line) = DecodedLines::take_line(&mut buffer, false) {
+                        return Some((Ok(line), (stream, buffer, chunk, false)));
+                    }
+                    match stream.read(&mut chunk).await {
+                        Ok(0) => {
+                            let line: String = DecodedLines::take_line(&mut buffer, true)?;
+                            return Some((Ok(line), (stream, buffer, chunk, true)));
+                        }
+                        Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+                        Err(error) => {
+                            let error: RequestError = RequestError::from(error);
+                            return Some((Err(error), (stream, buffer, chunk, true)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRead for AsyncDecodedStream {
+    /// Polls for decoded body bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Context<'_>` - The task context.
+    /// - `&mut ReadBuf<'_>` - The buffer to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `Poll<std::io::Result<()>>` - Ready once bytes were written or the body ended.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this: &mut Self = self.get_mut();
+        loop {
+            if this.position < this.decoded.len() {
+                let n: usize = buf.remaining().min(this.decoded.len() - this.position);
+                buf.put_slice(&this.decoded[this.position..this.position + n]);
+                this.position += n;
+                return Poll::Ready(Ok(()));
+            }
+            if this.eof || buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            let mut chunk_buf: ReadBuf<'_> = ReadBuf::new(&mut this.chunk);
+            match Pin::new(&mut this.body).poll_read(cx, &mut chunk_buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Ready(Ok(())) => {}
+            }
+            let decoded: std::io::Result<Vec<u8>> = if chunk_buf.filled().is_empty() {
+                this.eof = true;
+                this.content_decoder.finish()
+            } else {
+                this.content_decoder.feed(chunk_buf.filled())
+            };
+            this.decoded = decoded?;
+            this.position = 0;
+        }
+    }
+}
+
+impl DecodedLines {
+    /// Takes the next complete line from the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<u8>` - The bytes read but not returned yet.
+    /// - `bool` - Whether the body has ended, making the remaining bytes the last line.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The line without its line break, or None if no line is complete.
+    pub(crate) fn take_line(buffer: &mut Vec<u8>, eof: bool) -> Option<String> {
+        let mut line: Vec<u8> = match buffer.iter().position(|&byte| byte == b'\n') {
+            Some(pos) => {
+                let mut line: Vec<u8> = buffer.drain(..=pos).collect();
+                line.pop();
+                line
+            }
+            None if eof && !buffer.is_empty() => std::mem::take(buffer),
+            None => return None,
+        };
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Some(String::from_utf8_lossy(&line).into_owned())
+    }
+}
+
+impl Iterator for DecodedLines {
+    type Item = Result<String, RequestError>;
+
+    /// Reads from the body until the next line is complete.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<String, RequestError>>` - The next line, an error, or None at the end of the body.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let mut chunk: [u8; DEFAULT_BUFFER_SIZE] = [0; DEFAULT_BUFFER_SIZE];
+        loop {
+            if let Some(line) = Self::take_line(&mut self.buffer, false) {
+                return Some(Ok(line));
+            }
+            match self.stream.read(&mut chunk) {
+                Ok(0) => {
+                    self.finished = true;
+                    return Self::take_line(&mut self.buffer, true).map(Ok);
+                }
+                Ok(n) => self.buffer.extend_from_slice(&chunk[..n]),
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(RequestError::from(error)));
+                }
+            }
+        }
+    }
+}
+
+impl HttpResponseStream {
+    /// Removes the content codings of the body as it is read.
+    ///
+    /// # Returns
+    ///
+    /// - `DecodedStream` - A reader over the decoded body.
+    pub fn decode(self) -> DecodedStream {
+        let headers: ResponseHeaders = self.head.get_headers();
+        DecodedStream::new(self.body, &headers)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncHttpResponseStream {
+    /// Removes the content codings of the body as it is read.
+    ///
+    /// # Returns
+    ///
+    /// - `AsyncDecodedStream` - An async reader over the decoded body.
+    pub fn decode(self) -> AsyncDecodedStream {
+        let headers: ResponseHeaders = self.head.get_headers();
+        AsyncDecodedStream::new(self.body, &headers)
+    }
+}
+
+impl Debug for DecodedStream {
+    /// Formats the body reader; the decoder state is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodedStream")
+            .field("body", &self.body)
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Debug for AsyncDecodedStream {
+    /// Formats the body reader; the decoder state is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncDecodedStream")
+            .field("body", &self.body)
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,9 @@
+mod r#enum;
+mod r#impl;
+mod r#struct;
+
+pub use r#struct::*;
+
+pub(crate) use r#enum::*;
+
+use super::*;
@@ -0,0 +1,61 @@
+use super::*;
+
+/// Reader over a streaming response body with its content codings removed.
+///
+/// With the `compress` feature, the `gzip`, `deflate`, and `br` codings are
+/// decompressed as the body arrives, so the body is never held in memory as a
+/// whole; without it they are passed through as received. A coding handled by a
+/// codec registered with `CodecRegistry` is decoded once the body is complete.
+pub struct DecodedStream {
+    /// The body being decoded.
+    pub(crate) body: BodyReader,
+    /// Decoder removing the content codings.
+    pub(crate) content_decoder: ContentDecoder,
+    /// Buffer for bytes read from the body.
+    pub(crate) chunk: Vec<u8>,
+    /// Decoded bytes not yet returned to the caller.
+    pub(crate) decoded: Vec<u8>,
+    /// Read position within `decoded`.
+    pub(crate) position: usize,
+    /// Whether the end of the body has been reached.
+    pub(crate) eof: bool,
+}
+
+/// Asynchronous reader over a streaming response body with its content codings removed.
+///
+/// The async counterpart of `DecodedStream`.
+#[cfg(feature = "async")]
+pub struct AsyncDecodedStream {
+    /// The body being decoded.
+    pub(crate) body: AsyncBodyReader,
+    /// Decoder removing the content codings.
+    pub(crate) content_decoder: ContentDecoder,
+    /// Buffer for bytes read from the body.
+    pub(crate) chunk: Vec<u8>,
+    /// Decoded bytes not yet returned to the caller.
+    pub(crate) decoded: Vec<u8>,
+    /// Read position within `decoded`.
+    pub(crate) position: usize,
+    /// Whether the end of the body has been reached.
+    pub(crate) eof: bool,
+}
+
+/// Iterator over the lines of a decoded response body.
+///
+/// Lines end with `\n` or `\r\n`, which are not included. Each line is converted
+/// from UTF-8 once it is complete, so characters split across reads are kept intact;
+/// invalid sequences are replaced.
+pub struct DecodedLines {
+    /// The decoded body.
+    pub(crate) stream: DecodedStream,
+    /// Bytes of the line being read.
+    pub(crate) buffer: Vec<u8>,
+    /// Whether the last line was returned or reading failed.
+    pub(crate) finished: bool,
+}
+
+/// Incremental decoder removing the content codings of a response body.
+pub(crate) struct ContentDecoder {
+    /// The codings to remove, in the order they are removed.
+    pub(crate) stages: Vec<DecoderStage>,
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+impl HealthCheck {
+    /// Returns whether a response was received.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the server answered, whatever the status.
+    pub fn is_reachable(&self) -> bool {
+        self.reachable
+    }
+
+    /// Returns whether the server answered with the expected status.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the response status matches the expected status.
+    pub fn is_healthy(&self) -> bool {
+        self.status == Some(self.expected_status)
+    }
+
+    /// Returns the status code of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseStatusCode>` - The status code, or `None` if the server was unreachable.
+    pub fn get_status(&self) -> Option<ResponseStatusCode> {
+        self.status
+    }
+
+    /// Returns the status code the probe expected.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The expected status code.
+    pub fn get_expected_status(&self) -> ResponseStatusCode {
+        self.expected_status
+    }
+
+    /// Returns the time taken to receive the response headers.
+    ///
+    /// For an unreachable server this is the time spent before the request failed.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The probe latency.
+    pub fn get_latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// Returns the number of whole days until the server certificate expires.
+    ///
+    /// Negative once the certificate has expired.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<i64>` - The days left, or `None` for plain HTTP targets or when the
+    ///   certificate could not be retrieved.
+    pub fn get_tls_expiry_days(&self) -> Option<i64> {
+        self.tls_expiry_days
+    }
+
+    /// Returns the error that made the server unreachable.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&RequestError>` - The error, or `None` if a response was received.
+    pub fn get_error(&self) -> Option<&RequestError> {
+        self.error.as_ref()
+    }
+}
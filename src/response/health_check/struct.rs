@@ -0,0 +1,22 @@
+use super::*;
+
+/// Outcome of a health check probe.
+///
+/// Produced by `RequestBuilder::health_check`. A probe that cannot reach the
+/// server is still reported as a value rather than an error, so readiness and
+/// uptime tooling can record every attempt uniformly.
+#[derive(Clone, Debug)]
+pub struct HealthCheck {
+    /// Whether a response was received.
+    pub(crate) reachable: bool,
+    /// The status code of the response, if one was received.
+    pub(crate) status: Option<ResponseStatusCode>,
+    /// The status code the probe expected.
+    pub(crate) expected_status: ResponseStatusCode,
+    /// Time from starting the request until the response headers were received.
+    pub(crate) latency: Duration,
+    /// Whole days until the server certificate expires, for HTTPS targets.
+    pub(crate) tls_expiry_days: Option<i64>,
+    /// The error that made the server unreachable, if any.
+    pub(crate) error: Option<RequestError>,
+}
@@ -0,0 +1,2 @@
+/// Record separator starting each JSON text of an `application/json-seq` body.
+pub(crate) const JSON_SEQ_RECORD_SEPARATOR: u8 = 0x1E;
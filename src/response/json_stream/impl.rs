@@ -0,0 +1,315 @@
+use super::*;
+
+impl JsonStreamParser {
+    /// Appends received bytes to the parse buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received bytes.
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Removes leading whitespace from the parse buffer.
+    fn skip_whitespace(&mut self) {
+        let start: usize = self
+            .buffer
+            .iter()
+            .position(|byte: &u8| !byte.is_ascii_whitespace())
+            .unwrap_or(self.buffer.len());
+        self.buffer.drain(..start);
+    }
+
+    /// Attempts to parse the next value from the buffered bytes.
+    ///
+    /// Values are parsed with serde_json's `StreamDeserializer`; a value cut off at the
+    /// end of the buffer is left in place until more bytes arrive.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the body has ended and no more bytes will arrive.
+    ///
+    /// # Returns
+    ///
+    /// - `JsonStreamStep<T>` - The outcome of the attempt.
+    pub(crate) fn next_value<T: DeserializeOwned>(&mut self, eof: bool) -> JsonStreamStep<T> {
+        if self.json_seq {
+            return self.next_record(eof);
+        }
+        loop {
+            if self.finished {
+                return JsonStreamStep::Done;
+            }
+            self.skip_whitespace();
+            let Some(&first) = self.buffer.first() else {
+                if !eof {
+                    return JsonStreamStep::NeedMore;
+                }
+                self.finished = true;
+                if self.array_mode == Some(true) {
+                    return JsonStreamStep::Item(Err(RequestError::Request(
+                        "Unterminated JSON array in response body".to_string(),
+                    )));
+                }
+                return JsonStreamStep::Done;
+            };
+            match self.array_mode {
+                None => {
+                    self.array_mode = Some(first == b'[');
+                    if first == b'[' {
+                        self.buffer.drain(..1);
+                    }
+                    continue;
+                }
+                Some(true) if first == b']' => {
+                    self.finished = true;
+                    return JsonStreamStep::Done;
+                }
+                Some(true) if self.expect_separator => {
+                    if first != b',' {
+                        self.finished = true;
+                        return JsonStreamStep::Item(Err(RequestError::Request(format!(
+                            "Expected ',' or ']' in JSON array, found {:?}",
+                            first as char
+                        ))));
+                    }
+                    self.buffer.drain(..1);
+                    self.expect_separator = false;
+                    continue;
+                }
+                _ => {}
+            }
+            let mut values: StreamDeserializer<'_, SliceRead<'_>, T> =
+                Deserializer::from_slice(&self.buffer).into_iter::<T>();
+            return match values.next() {
+                Some(Ok(value)) => {
+                    let offset: usize = values.byte_offset();
+                    if !eof
+                        && offset == self.buffer.len()
+                        && (first == b'-' || first.is_ascii_digit())
+                    {
+                        return JsonStreamStep::NeedMore;
+                    }
+                    self.buffer.drain(..offset);
+                    self.expect_separator = self.array_mode == Some(true);
+                    JsonStreamStep::Item(Ok(value))
+                }
+                Some(Err(error)) if error.is_eof() && !eof => JsonStreamStep::NeedMore,
+                Some(Err(error)) => {
+                    self.finished = true;
+                    JsonStreamStep::Item(Err(RequestError::Request(error.to_string())))
+                }
+                None if eof => {
+                    self.finished = true;
+                    JsonStreamStep::Done
+                }
+                None => JsonStreamStep::NeedMore,
+            };
+        }
+    }
+
+    /// Attempts to parse the next record of a JSON text sequence.
+    ///
+    /// Each record starts with a record separator. A record is parsed once the next
+    /// separator or the end of the body arrives, or earlier if it already ends with
+    /// a line feed and holds a complete value. Bytes before the first separator and
+    /// empty records are skipped, and a record that is not valid JSON yields an
+    /// error without ending the sequence.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the body has ended and no more bytes will arrive.
+    ///
+    /// # Returns
+    ///
+    /// - `JsonStreamStep<T>` - The outcome of the attempt.
+    fn next_record<T: DeserializeOwned>(&mut self, eof: bool) -> JsonStreamStep<T> {
+        loop {
+            let Some(start) = self
+                .buffer
+                .iter()
+                .position(|byte: &u8| *byte == JSON_SEQ_RECORD_SEPARATOR)
+            else {
+                self.buffer.clear();
+                if eof {
+                    self.finished = true;
+                    return JsonStreamStep::Done;
+                }
+                return JsonStreamStep::NeedMore;
+            };
+            self.buffer.drain(..start);
+            let next_separator: Option<usize> = self.buffer[1..]
+                .iter()
+                .position(|byte: &u8| *byte == JSON_SEQ_RECORD_SEPARATOR);
+            let (end, complete): (usize, bool) = match next_separator {
+                Some(pos) => (pos + 1, true),
+                None => (self.buffer.len(), eof),
+            };
+            let text: &[u8] = &self.buffer[1..end];
+            if !complete && !text.ends_with(b"\n") {
+                return JsonStreamStep::NeedMore;
+            }
+            let is_empty: bool = text.iter().all(|byte: &u8| byte.is_ascii_whitespace());
+            let value: Result<T, serde_json::Error> = from_slice(text);
+            if value.is_err() && !complete {
+                return JsonStreamStep::NeedMore;
+            }
+            self.buffer.drain(..end);
+            if is_empty {
+                continue;
+            }
+            return JsonStreamStep::Item(
+                value.map_err(|error: serde_json::Error| RequestError::Request(error.to_string())),
+            );
+        }
+    }
+}
+
+impl<T: DeserializeOwned> JsonStream<T> {
+    /// Creates a JSON stream over a body reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `BodyReader` - The body to parse.
+    /// - `bool` - Whether the body is a JSON text sequence.
+    ///
+    /// # Returns
+    ///
+    /// - `JsonStream<T>` - The iterator over parsed values.
+    pub(crate) fn new(body: BodyReader, json_seq: bool) -> Self {
+        let chunk_size: usize = body.raw.len();
+        Self {
+            body,
+            parser: JsonStreamParser {
+                json_seq,
+                ..JsonStreamParser::default()
+            },
+            chunk: vec![0; chunk_size],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for JsonStream<T> {
+    type Item = Result<T, RequestError>;
+
+    /// Reads from the body until the next value is complete.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Result<T, RequestError>>` - The next value, an error, or None at the end of the body.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut eof: bool = false;
+        loop {
+            match self.parser.next_value::<T>(eof) {
+                JsonStreamStep::Item(item) => return Some(item),
+                JsonStreamStep::Done => return None,
+                JsonStreamStep::NeedMore => {}
+            }
+            match self.body.read(&mut self.chunk) {
+                Ok(0) => eof = true,
+                Ok(n) => self.parser.push(&self.chunk[..n]),
+                Err(error) => {
+                    self.parser.finished = true;
+                    return Some(Err(RequestError::from(error)));
+                }
+            }
+        }
+    }
+}
+
+impl HttpResponseStream {
+    /// Parses the body as a sequence of JSON values, yielding each one as it arrives.
+    ///
+    /// Supports concatenated or newline-delimited JSON values and a single top-level
+    /// array, whose elements are yielded individually.
+    ///
+    /// # Returns
+    ///
+    /// - `JsonStream<T>` - An iterator over the parsed values.
+    pub fn json_stream<T: DeserializeOwned>(self) -> JsonStream<T> {
+        JsonStream::new(self.body, false)
+    }
+
+    /// Parses an `application/json-seq` body, yielding each JSON text as it arrives.
+    ///
+    /// The body is a sequence of RFC 7464 records, each a record separator followed
+    /// by a JSON text. A truncated or malformed record yields an error and parsing
+    /// continues with the next record.
+    ///
+    /// # Returns
+    ///
+    /// - `JsonStream<T>` - An iterator over the parsed values.
+    pub fn json_seq_stream<T: DeserializeOwned>(self) -> JsonStream<T> {
+        JsonStream::new(self.body, true)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncHttpResponseStream {
+    /// Parses the body as a sequence of JSON values, yielding each one as it arrives.
+    ///
+    /// Supports concatenated or newline-delimited JSON values and a single top-level
+    /// array, whose elements are yielded individually.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<T, RequestError>>` - A stream of the parsed values.
+    pub fn json_stream<T: DeserializeOwned + Send>(
+        self,
+    ) -> impl Stream<Item = Result<T, RequestError>> + Send {
+        self.parse_json(false)
+    }
+
+    /// Parses an `application/json-seq` body, yielding each JSON text as it arrives.
+    ///
+    /// Behaves like `HttpResponseStream::json_seq_stream`, reading the body asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<T, RequestError>>` - A stream of the parsed values.
+    pub fn json_seq_stream<T: DeserializeOwned + Send>(
+        self,
+    ) -> impl Stream<Item = Result<T, RequestError>> + Send {
+        self.parse_json(true)
+    }
+
+    /// Parses the body as JSON values or as a JSON text sequence.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the body is a JSON text sequence.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Stream<Item = Result<T, RequestError>>` - A stream of the parsed values.
+    fn parse_json<T: DeserializeOwned + Send>(
+        self,
+        json_seq: bool,
+    ) -> impl Stream<Item = Result<T, RequestError>> + Send {
+        let chunk: Vec<u8> = vec![0; self.body.raw.len()];
+        let parser: JsonStreamParser = JsonStreamParser {
+            json_seq,
+            ..JsonStreamParser::default()
+        };
+        let state: (AsyncBodyReader, JsonStreamParser, Vec<u8>) = (self.body, parser, chunk);
+        unfold(state, |(mut body, mut parser, mut chunk)| async move {
+            let mut eof: bool = false;
+            loop {
+                match parser.next_value::<T>(eof) {
+                    JsonStreamStep::Item(item) => return Some((item, (body, parser, chunk))),
+                    JsonStreamStep::Done => return None,
+                    JsonStreamStep::NeedMore => {}
+                }
+                match body.read(&mut chunk).await {
+                    Ok(0) => eof = true,
+                    Ok(n) => parser.push(&chunk[..n]),
+                    Err(error) => {
+                        parser.finished = true;
+                        return Some((Err(RequestError::from(error)), (body, parser, chunk)));
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Iterator over JSON values parsed incrementally from a streaming response body.
+///
+/// Accepts concatenated or newline-delimited JSON values as well as a single
+/// top-level JSON array, whose elements are yielded one by one. Streams created
+/// with `json_seq_stream` parse RFC 7464 JSON text sequences instead.
+pub struct JsonStream<T> {
+    /// The body being parsed.
+    pub(crate) body: BodyReader,
+    /// Incremental parser state.
+    pub(crate) parser: JsonStreamParser,
+    /// Buffer for bytes read from the body.
+    pub(crate) chunk: Vec<u8>,
+    /// The type of the yielded values.
+    pub(crate) _marker: PhantomData<fn() -> T>,
+}
+
+/// Incremental parser splitting buffered bytes into JSON values.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct JsonStreamParser {
+    /// Bytes received but not parsed yet.
+    pub(crate) buffer: Vec<u8>,
+    /// Whether the body is a top-level array, once known.
+    pub(crate) array_mode: Option<bool>,
+    /// Whether a `,` or `]` is expected before the next array element.
+    pub(crate) expect_separator: bool,
+    /// Whether parsing has ended, either at the end of the values or after an error.
+    pub(crate) finished: bool,
+    /// Whether the body is a JSON text sequence of record separator delimited values.
+    pub(crate) json_seq: bool,
+}
+
+/// Outcome of one parsing attempt.
+pub(crate) enum JsonStreamStep<T> {
+    /// More input is needed to complete the next value.
+    NeedMore,
+    /// A value was parsed, or parsing failed.
+    Item(Result<T, RequestError>),
+    /// No more values follow.
+    Done,
+}
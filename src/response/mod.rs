@@ -1,10 +1,31 @@
+mod body_digest;
+mod byte_range;
+mod charset;
+mod classifier;
+mod decoded_stream;
+mod health_check;
+#[cfg(feature = "json")]
+mod json_stream;
+mod rate_limit;
 mod response_binary;
+mod response_stream;
 mod response_text;
+mod spool;
 mod r#trait;
 mod r#type;
 
+pub use byte_range::*;
+pub use classifier::*;
+pub use decoded_stream::*;
+pub use health_check::*;
+#[cfg(feature = "json")]
+pub use json_stream::*;
+pub use rate_limit::*;
 pub use response_binary::*;
+pub use response_stream::*;
 pub use response_text::*;
 pub use {r#trait::*, r#type::*};
 
+pub(crate) use {body_digest::*, charset::*, spool::*};
+
 use super::*;
@@ -0,0 +1,29 @@
+/// Header giving the time to wait before retrying.
+pub(crate) const RETRY_AFTER: &str = "Retry-After";
+
+/// Standardized header carrying the remaining quota and reset time.
+pub(crate) const RATELIMIT: &str = "RateLimit";
+
+/// Standardized header carrying the quota policy.
+pub(crate) const RATELIMIT_POLICY: &str = "RateLimit-Policy";
+
+/// Standardized header carrying the request quota.
+pub(crate) const RATELIMIT_LIMIT: &str = "RateLimit-Limit";
+
+/// Standardized header carrying the remaining quota.
+pub(crate) const RATELIMIT_REMAINING: &str = "RateLimit-Remaining";
+
+/// Standardized header carrying the seconds until the quota resets.
+pub(crate) const RATELIMIT_RESET: &str = "RateLimit-Reset";
+
+/// Conventional header carrying the request quota.
+pub(crate) const X_RATELIMIT_LIMIT: &str = "X-RateLimit-Limit";
+
+/// Conventional header carrying the remaining quota.
+pub(crate) const X_RATELIMIT_REMAINING: &str = "X-RateLimit-Remaining";
+
+/// Conventional header carrying the quota reset time.
+pub(crate) const X_RATELIMIT_RESET: &str = "X-RateLimit-Reset";
+
+/// Reset values at or above this are Unix timestamps rather than seconds to wait.
+pub(crate) const RATELIMIT_RESET_EPOCH_THRESHOLD: u64 = 1_000_000_000;
@@ -0,0 +1,194 @@
+use super::*;
+
+impl RateLimitInfo {
+    /// Reads the rate limit headers of a response.
+    ///
+    /// Standardized headers take precedence over the `X-RateLimit-*` headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseHeaders` - The response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimitInfo>` - The rate limit state, or None if the response
+    ///   carries no rate limit headers.
+    pub(crate) fn from_headers(headers: &ResponseHeaders) -> Option<Self> {
+        let header = |name: &str| -> Option<&str> {
+            headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .and_then(|(_, values)| values.front())
+                .map(|value: &String| value.trim())
+        };
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let ratelimit: Option<&str> = header(RATELIMIT);
+        let policy: Option<&str> = header(RATELIMIT_POLICY);
+        let info: Self = Self {
+            limit: policy
+                .and_then(|policy: &str| Self::get_parameter(policy, "q"))
+                .or_else(|| header(RATELIMIT_LIMIT).and_then(Self::parse_first_number))
+                .or_else(|| header(X_RATELIMIT_LIMIT).and_then(Self::parse_first_number)),
+            remaining: ratelimit
+                .and_then(|ratelimit: &str| Self::get_parameter(ratelimit, "r"))
+                .or_else(|| header(RATELIMIT_REMAINING).and_then(Self::parse_first_number))
+                .or_else(|| header(X_RATELIMIT_REMAINING).and_then(Self::parse_first_number)),
+            reset: ratelimit
+                .and_then(|ratelimit: &str| Self::get_parameter(ratelimit, "t"))
+                .or_else(|| header(RATELIMIT_RESET).and_then(Self::parse_first_number))
+                .map(Duration::from_secs)
+                .or_else(|| {
+                    header(X_RATELIMIT_RESET)
+                        .and_then(Self::parse_first_number)
+                        .map(|reset: u64| Self::get_reset_delay(reset, now))
+                }),
+            window: policy
+                .and_then(|policy: &str| Self::get_parameter(policy, "w"))
+                .or_else(|| {
+                    header(RATELIMIT_LIMIT).and_then(|limit: &str| Self::get_parameter(limit, "w"))
+                })
+                .map(Duration::from_secs),
+            retry_after: header(RETRY_AFTER)
+                .and_then(|retry_after: &str| Self::parse_retry_after(retry_after, now)),
+        };
+        (info != Self::default()).then_some(info)
+    }
+
+    /// Parses the leading integer of a header value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value, such as `100` or `100, 100;w=60`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The integer, or None if the value does not start with one.
+    fn parse_first_number(value: &str) -> Option<u64> {
+        value.split([',', ';']).next()?.trim().parse::<u64>().ok()
+    }
+
+    /// Gets an integer parameter of a structured header value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value, such as `"default";r=50;t=30`.
+    /// - `&str` - The parameter name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The parameter value of the first list member that has it.
+    fn get_parameter(value: &str, name: &str) -> Option<u64> {
+        value
+            .split([',', ';'])
+            .filter_map(|item: &str| item.trim().split_once('='))
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+    }
+
+    /// Converts an `X-RateLimit-Reset` value into the time until the reset.
+    ///
+    /// Servers send either the seconds to wait or a Unix timestamp; large values
+    /// are taken as timestamps.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The header value.
+    /// - `u64` - The current Unix time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The time until the quota resets.
+    fn get_reset_delay(reset: u64, now: u64) -> Duration {
+        if reset >= RATELIMIT_RESET_EPOCH_THRESHOLD {
+            Duration::from_secs(reset.saturating_sub(now))
+        } else {
+            Duration::from_secs(reset)
+        }
+    }
+
+    /// Parses a `Retry-After` value given in seconds or as an HTTP date.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    /// - `u64` - The current Unix time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The time to wait, or None if the value is malformed.
+    fn parse_retry_after(value: &str, now: u64) -> Option<Duration> {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        let date: u64 = u64::try_from(parse_http_date(value)?).ok()?;
+        Some(Duration::from_secs(date.saturating_sub(now)))
+    }
+
+    /// Gets the number of requests allowed in the current window.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The quota, or None if the server did not report it.
+    pub fn get_limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Gets the number of requests left in the current window.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The remaining quota, or None if the server did not report it.
+    pub fn get_remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Gets the time until the quota resets.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The time until the reset, or None if the server did not report it.
+    pub fn get_reset(&self) -> Option<Duration> {
+        self.reset
+    }
+
+    /// Gets the length of the quota window.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The window, or None if the server did not report it.
+    pub fn get_window(&self) -> Option<Duration> {
+        self.window
+    }
+
+    /// Gets the time the server asked to wait before retrying.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The `Retry-After` delay, or None if the header is absent.
+    pub fn get_retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    /// Checks if the quota is used up.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if no requests are left in the current window.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Gets the time to wait before sending the next request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The `Retry-After` delay, else the time until the reset
+    ///   if the quota is used up, or None if no wait is needed.
+    pub fn get_wait(&self) -> Option<Duration> {
+        self.retry_after
+            .or_else(|| self.reset.filter(|_| self.is_exhausted()))
+    }
+}
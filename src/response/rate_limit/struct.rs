@@ -0,0 +1,19 @@
+use super::*;
+
+/// Rate limit state reported by a server through response headers.
+///
+/// Read from the standardized `RateLimit`, `RateLimit-Policy`, and `RateLimit-*`
+/// headers, the conventional `X-RateLimit-*` headers, and `Retry-After`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window.
+    pub(crate) limit: Option<u64>,
+    /// Requests left in the current window.
+    pub(crate) remaining: Option<u64>,
+    /// Time until the quota resets.
+    pub(crate) reset: Option<Duration>,
+    /// Length of the quota window.
+    pub(crate) window: Option<Duration>,
+    /// Time to wait before retrying.
+    pub(crate) retry_after: Option<Duration>,
+}
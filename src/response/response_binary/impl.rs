@@ -1,5 +1,7 @@
 use super::*;
 
+impl Sealed for HttpResponseBinary {}
+
 /// Implements the `ResponseTrait` trait for `HttpResponseBinary`.
 ///
 /// This implementation specifies the associated types for binary and text representations
@@ -13,6 +15,9 @@ impl ResponseTrait for HttpResponseBinary {
     type OutputBinary = HttpResponseBinary;
     /// Creates a new HttpResponseBinary from raw response bytes.
     ///
+    /// Only the status line and headers, up to the first empty line, are split into
+    /// lines. The bytes after the empty line are taken verbatim as the body.
+    ///
     /// # Arguments
     ///
     /// - `&[u8]` - The raw HTTP response bytes.
@@ -24,7 +29,12 @@ impl ResponseTrait for HttpResponseBinary {
     where
         Self: Sized,
     {
-        let split_lines: Vec<&[u8]> = split_multi_byte(response, HTTP_BR_BYTES);
+        let (head, body): (&[u8], &[u8]) =
+            match SharedResponseHandler::find_double_crlf(response, 0) {
+                Some(pos) => (&response[..pos], &response[pos + 4..]),
+                None => (response, &[]),
+            };
+        let split_lines: Vec<&[u8]> = split_multi_byte(head, HTTP_BR_BYTES);
         let mut lines: IntoIter<&[u8]> = split_lines.into_iter();
         let status_line: &[u8] = lines.next().unwrap_or(&[]);
         let status_parts: Vec<&[u8]> = split_whitespace(status_line);
@@ -60,9 +70,9 @@ impl ResponseTrait for HttpResponseBinary {
             },
         );
         let mut headers: HashMapXxHash3_64<String, VecDeque<String>> = hash_map_xx_hash3_64();
-        for line in lines.by_ref() {
+        for line in lines {
             if line.is_empty() {
-                break;
+                continue;
             }
             let mut colon_pos: Option<usize> = None;
             for (i, &byte) in line.iter().enumerate() {
@@ -84,45 +94,29 @@ impl ResponseTrait for HttpResponseBinary {
                 let value_bytes: &[u8] = &line[value_start..];
                 if let (Ok(key_str), Ok(value_str)) = (from_utf8(key_bytes), from_utf8(value_bytes))
                 {
-                    let mut value_deque: VecDeque<String> = VecDeque::new();
-                    value_deque.push_front(value_str.trim().to_string());
-                    headers.insert(key_str.trim().to_string(), value_deque);
+                    let key_str: &str = key_str.trim();
+                    let key: String = headers
+                        .keys()
+                        .find(|key: &&String| key.eq_ignore_ascii_case(key_str))
+                        .cloned()
+                        .unwrap_or_else(|| key_str.to_string());
+                    headers
+                        .entry(key)
+                        .or_default()
+                        .push_back(value_str.trim().to_string());
                 }
             }
         }
-        let body: Vec<u8> = match lines.len() {
-            0 => Vec::new(),
-            1 => {
-                let line: &[u8] = lines.next().unwrap_or(&[]);
-                let mut body = Vec::with_capacity(line.len());
-                body.extend_from_slice(line);
-                body
-            }
-            _ => {
-                let lines_slice: &[&[u8]] = lines.as_slice();
-                let total_size: usize = lines_slice
-                    .iter()
-                    .map(|line: &&[u8]| line.len())
-                    .sum::<usize>()
-                    + lines_slice.len().saturating_sub(1) * BR_BYTES.len();
-                let mut body: Vec<u8> = Vec::with_capacity(total_size);
-                let mut first: bool = true;
-                for line in lines {
-                    if !first {
-                        body.extend_from_slice(BR_BYTES);
-                    }
-                    body.extend_from_slice(line);
-                    first = false;
-                }
-                body
-            }
-        };
         HttpResponseBinary {
             http_version: Arc::new(RwLock::new(http_version)),
             status_code,
             status_text: Arc::new(RwLock::new(status_text)),
             headers: Arc::new(RwLock::new(headers)),
-            body: Arc::new(RwLock::new(body)),
+            trailers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
+            body: Arc::new(RwLock::new(body.to_vec())),
+            decoded: false,
+            spool: None,
+            metrics: RequestMetrics::default(),
         }
     }
 
@@ -137,20 +131,19 @@ impl ResponseTrait for HttpResponseBinary {
 
     /// Converts the response to text format.
     ///
+    /// If the body is still compressed according to `Content-Encoding`, it is
+    /// decoded first, so turning off automatic decoding never yields compressed
+    /// bytes as text. The body is then transcoded to UTF-8 from the charset
+    /// reported by `get_charset`.
+    ///
     /// # Returns
     ///
     /// - `HttpResponseText` - The text representation of the response.
     fn text(&self) -> HttpResponseText {
-        let body: String = self.body.read().map_or(String::new(), |body_ref| {
-            String::from_utf8_lossy(&body_ref).into_owned()
-        });
-        HttpResponseText {
-            http_version: Arc::clone(&self.http_version),
-            status_code: self.status_code,
-            status_text: Arc::clone(&self.status_text),
-            headers: Arc::clone(&self.headers),
-            body: Arc::new(RwLock::new(body)),
+        if !self.decoded && self.has_content_encoding() {
+            return self.decode(DEFAULT_BUFFER_SIZE).text();
         }
+        self.text_with_encoding(self.get_encoding())
     }
 
     /// Decodes the response body using the specified buffer size.
@@ -171,13 +164,13 @@ impl ResponseTrait for HttpResponseBinary {
                     let mut string_headers: HashMapXxHash3_64<String, String> =
                         hash_map_xx_hash3_64();
                     for (key, value_deque) in headers_ref.iter() {
-                        if let Some(first_value) = value_deque.front() {
-                            string_headers.insert(key.clone(), first_value.clone());
+                        if !value_deque.is_empty() {
+                            let values: Vec<&str> =
+                                value_deque.iter().map(String::as_str).collect();
+                            string_headers.insert(key.clone(), values.join(", "));
                         }
                     }
-                    Compress::from(&string_headers)
-                        .decode(&body_ref, buffer_size)
-                        .into_owned()
+                    CodecRegistry::decode_body(&string_headers, &body_ref, buffer_size)
                 }
                 _ => Vec::new(),
             }
@@ -187,12 +180,92 @@ impl ResponseTrait for HttpResponseBinary {
             status_code: self.status_code,
             status_text: Arc::clone(&self.status_text),
             headers: Arc::clone(&self.headers),
+            trailers: Arc::clone(&self.trailers),
             body: Arc::new(RwLock::new(decoded_body)),
+            decoded: true,
+            spool: self.spool.clone(),
+            metrics: self.metrics,
         }
     }
+
+    /// Deserializes the JSON body into a typed value.
+    ///
+    /// A body still compressed according to `Content-Encoding` is decoded first, and
+    /// a body spooled to disk is read from its file.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized value, or an error if the body is not valid JSON for `T`.
+    #[cfg(feature = "json")]
+    fn json<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        if self.is_spooled() {
+            return from_reader(self.open_body()?).map_err(RequestError::from);
+        }
+        if !self.decoded && self.has_content_encoding() {
+            return self.decode(DEFAULT_BUFFER_SIZE).json();
+        }
+        let body: RequestBody = self.get_body();
+        from_slice(&body).map_err(RequestError::from)
+    }
 }
 
 impl HttpResponseBinary {
+    /// Creates a response that was not read from the network, such as one returned
+    /// by middleware.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    /// - `&str` - The status text.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpResponseBinary` - An HTTP/1.1 response without headers or body.
+    pub fn new(status_code: ResponseStatusCode, status_text: &str) -> Self {
+        Self {
+            http_version: Arc::new(RwLock::new(HttpVersion::Http1_1)),
+            status_code,
+            status_text: Arc::new(RwLock::new(status_text.to_owned())),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a header to the response, replacing any existing value regardless of case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpResponseBinary` - The response with the header.
+    pub fn with_header(self, key: &str, value: &str) -> Self {
+        if let Ok(mut headers) = self.headers.write() {
+            headers.retain(|existing_key: &String, _| !existing_key.eq_ignore_ascii_case(key));
+            let mut value_deque: VecDeque<String> = VecDeque::new();
+            value_deque.push_front(value.to_owned());
+            headers.insert(key.to_owned(), value_deque);
+        }
+        self
+    }
+
+    /// Sets the body of the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The body (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `HttpResponseBinary` - The response with the body.
+    pub fn with_body<T: Into<Vec<u8>>>(self, body: T) -> Self {
+        if let Ok(mut current) = self.body.write() {
+            *current = body.into();
+        }
+        self
+    }
+
     /// Retrieves the HTTP version associated with this response.
     ///
     /// # Returns
@@ -261,6 +334,18 @@ impl HttpResponseBinary {
         hash_map_xx_hash3_64()
     }
 
+    /// Gets the trailer fields sent after a chunked response body.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeaders` - The response trailers, empty if none were sent.
+    pub fn get_trailers(&self) -> ResponseHeaders {
+        if let Ok(trailers) = self.trailers.read() {
+            return trailers.clone();
+        }
+        hash_map_xx_hash3_64()
+    }
+
     /// Retrieves the body content of the HTTP response.
     ///
     /// # Returns
@@ -277,6 +362,400 @@ impl HttpResponseBinary {
         }
         RequestBody::new()
     }
+
+    /// Gets the timings and transfer sizes of the request that produced the response.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestMetrics` - The request metrics.
+    pub fn get_metrics(&self) -> RequestMetrics {
+        self.metrics
+    }
+
+    /// Gets the first value of a response header, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The header value, or None if the header is absent.
+    pub fn get_header(&self, name: &str) -> Option<String> {
+        let headers: RwLockReadGuard<'_, ResponseHeaders> = self.headers.read().ok()?;
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.front().cloned())
+    }
+
+    /// Gets every value of a response header, matching the name case-insensitively.
+    ///
+    /// Repeated header lines, such as several `Set-Cookie` headers, each yield one
+    /// value, in the order they were received.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The header values, empty if the header is absent.
+    pub fn get_header_all(&self, name: &str) -> Vec<String> {
+        self.headers.read().map_or(Vec::new(), |headers| {
+            headers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+                .flat_map(|(_, value)| value.iter().cloned())
+                .collect()
+        })
+    }
+
+    /// Checks whether the response declares a compressed body.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if a `Content-Encoding` other than `identity` is present.
+    pub(crate) fn has_content_encoding(&self) -> bool {
+        self.get_header(CONTENT_ENCODING).is_some_and(|encoding| {
+            let encoding: &str = encoding.trim();
+            !encoding.is_empty() && !encoding.eq_ignore_ascii_case(IDENTITY_ENCODING)
+        })
+    }
+
+    /// Gets the byte range the body carries, from the `Content-Range` header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ContentRange>` - The range, or `None` if the header is absent, malformed,
+    ///   or describes an unsatisfied range.
+    pub fn get_content_range(&self) -> Option<ContentRange> {
+        self.get_header(CONTENT_RANGE)
+            .as_deref()
+            .and_then(parse_content_range)
+            .map(|(start, end, total): (u64, u64, Option<u64>)| ContentRange { start, end, total })
+    }
+
+    /// Gets the length of the full representation from the `Content-Range` header of
+    /// a `416 Range Not Satisfiable` response, such as `bytes */1234`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The total length, or `None` for other responses.
+    pub fn get_unsatisfied_range_total(&self) -> Option<u64> {
+        if self.status_code != RANGE_NOT_SATISFIABLE {
+            return None;
+        }
+        let value: String = self.get_header(CONTENT_RANGE)?;
+        let (unit, total): (&str, &str) = value.trim().split_once(' ')?;
+        if !unit.eq_ignore_ascii_case(BYTES_UNIT) {
+            return None;
+        }
+        total.trim().strip_prefix("*/")?.trim().parse().ok()
+    }
+
+    /// Checks whether the server answered a range request with partial content.
+    ///
+    /// A `200` response to a range request means the server ignored the range and
+    /// sent the full representation, so the body starts at offset zero.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` for a `206` response, `false` otherwise.
+    pub fn is_partial_content(&self) -> bool {
+        self.status_code == PARTIAL_CONTENT
+    }
+
+    /// Splits a partial content response into its byte ranges.
+    ///
+    /// A `multipart/byteranges` body is parsed into one part per range. Any other
+    /// `206` response yields a single part described by its `Content-Range` header.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<ByteRangePart>, RequestError>` - The ranges in body order, or an error
+    ///   if the status is not `206` or the ranges cannot be parsed.
+    pub fn byte_ranges(&self) -> Result<Vec<ByteRangePart>, RequestError> {
+        if !self.is_partial_content() {
+            return Err(RequestError::Request(format!(
+                "Expected a 206 Partial Content response, got {}",
+                self.status_code
+            )));
+        }
+        let content_type: Option<Mime> = self.content_type_parsed();
+        if let Some(mime) = content_type.filter(|mime| mime.essence() == MULTIPART_BYTERANGES) {
+            let boundary: &str = mime.get_parameter("boundary").ok_or_else(|| {
+                RequestError::Request("multipart/byteranges without a boundary".to_string())
+            })?;
+            return parse_byte_ranges(&self.get_body(), boundary);
+        }
+        let ContentRange { start, end, total } = self.get_content_range().ok_or_else(|| {
+            RequestError::Request("Missing or invalid Content-Range header".to_string())
+        })?;
+        Ok(vec![ByteRangePart {
+            start,
+            end,
+            total,
+            content_type: self.get_header(CONTENT_TYPE),
+            body: self.get_body(),
+        }])
+    }
+
+    /// Returns whether the body has already been decompressed.
+    ///
+    /// `false` for responses received with automatic decoding turned off, whose
+    /// body is still in the form given by `Content-Encoding`.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the body was decoded.
+    pub fn is_decoded(&self) -> bool {
+        self.decoded
+    }
+
+    /// Checks if the body was written to a temporary file instead of memory.
+    ///
+    /// A spooled response has an empty in-memory body; read it with `open_body`.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the body is spooled to disk, false otherwise.
+    pub fn is_spooled(&self) -> bool {
+        self.spool.is_some()
+    }
+
+    /// Gets the path of the temporary file holding a spooled body.
+    ///
+    /// The file is removed when the last clone of the response is dropped.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&Path>` - The file path, or None if the body is in memory.
+    pub fn get_spool_path(&self) -> Option<&Path> {
+        self.spool
+            .as_deref()
+            .map(|spool: &SpoolFile| spool.path.as_path())
+    }
+
+    /// Gets the size of the body, whether it is spooled or in memory.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The body size in bytes.
+    pub fn get_body_len(&self) -> u64 {
+        match &self.spool {
+            Some(spool) => spool.len,
+            None => self.body.read().map_or(0, |body| body.len() as u64),
+        }
+    }
+
+    /// Opens a reader over the body, whether it is spooled or in memory.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Box<dyn Read + Send>, RequestError>` - The body reader, or an error if
+    ///   the spool file cannot be opened.
+    pub fn open_body(&self) -> Result<Box<dyn Read + Send>, RequestError> {
+        match &self.spool {
+            Some(spool) => Ok(Box::new(
+                File::open(&spool.path).map_err(RequestError::from)?,
+            )),
+            None => Ok(Box::new(Cursor::new(self.get_body()))),
+        }
+    }
+
+    /// Converts the response to text, decoding the body from the given charset.
+    ///
+    /// Overrides the charset detected by `text` for servers that declare a wrong
+    /// charset or none at all. A byte order mark in the body still takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The charset label, such as `gbk`, `shift_jis` or `iso-8859-1`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseText, RequestError>` - The text representation of the
+    ///   response, or an error if the charset is unknown.
+    pub fn text_with_charset(&self, charset: &str) -> Result<HttpResponseText, RequestError> {
+        let encoding: &'static Encoding = Encoding::for_label(charset.as_bytes())
+            .ok_or_else(|| RequestError::Request(format!("Unknown charset: {charset}")))?;
+        if !self.decoded && self.has_content_encoding() {
+            return Ok(self
+                .decode(DEFAULT_BUFFER_SIZE)
+                .text_with_encoding(encoding));
+        }
+        Ok(self.text_with_encoding(encoding))
+    }
+
+    /// Gets the name of the charset `text` decodes the body with.
+    ///
+    /// The `charset` parameter of `Content-Type` is used when it names a known
+    /// encoding. Otherwise an HTML body, or one without a `Content-Type`, is scanned
+    /// for a `<meta>` charset declaration. UTF-8 is the fallback.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The canonical name of the charset, such as `GBK` or `windows-1252`.
+    pub fn get_charset(&self) -> String {
+        self.get_encoding().name().to_owned()
+    }
+
+    /// Detects the encoding of the body.
+    ///
+    /// # Returns
+    ///
+    /// - `&'static Encoding` - The encoding named by `Content-Type` or a `<meta>` tag,
+    ///   or UTF-8.
+    pub(crate) fn get_encoding(&self) -> &'static Encoding {
+        let content_type: Option<Mime> = self.content_type_parsed();
+        if let Some(encoding) = content_type
+            .as_ref()
+            .and_then(Mime::get_charset)
+            .and_then(|charset: &str| Encoding::for_label(charset.as_bytes()))
+        {
+            return encoding;
+        }
+        let is_html: bool = content_type.as_ref().is_none_or(|mime: &Mime| {
+            matches!(
+                mime.essence().as_str(),
+                "text/html" | "application/xhtml+xml"
+            )
+        });
+        if !is_html {
+            return UTF_8;
+        }
+        self.body
+            .read()
+            .ok()
+            .and_then(|body| sniff_meta_charset(&body))
+            .unwrap_or(UTF_8)
+    }
+
+    /// Converts the response to text, decoding the body from an encoding.
+    ///
+    /// # Arguments
+    ///
+    /// - `&'static Encoding` - The encoding of the body.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpResponseText` - The text representation of the response.
+    fn text_with_encoding(&self, encoding: &'static Encoding) -> HttpResponseText {
+        let body: String = self.body.read().map_or(String::new(), |body_ref| {
+            decode_charset(&body_ref, encoding)
+        });
+        HttpResponseText {
+            http_version: Arc::clone(&self.http_version),
+            status_code: self.status_code,
+            status_text: Arc::clone(&self.status_text),
+            headers: Arc::clone(&self.headers),
+            trailers: Arc::clone(&self.trailers),
+            body: Arc::new(RwLock::new(body)),
+            metrics: self.metrics,
+        }
+    }
+
+    /// Parses the `Content-Type` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed media type, or None if the header is absent or malformed.
+    pub fn content_type_parsed(&self) -> Option<Mime> {
+        self.get_header(CONTENT_TYPE)?.parse::<Mime>().ok()
+    }
+
+    /// Gets the response media type if it is acceptable under weighted media ranges.
+    ///
+    /// Pass the same ranges given to `RequestBuilder::accepts` to learn which format
+    /// the server chose.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed `Content-Type`, or None if it is absent or not acceptable.
+    pub fn negotiated_type(&self, accepts: &[(&str, f32)]) -> Option<Mime> {
+        let mime: Mime = self.content_type_parsed()?;
+        let quality: f32 = mime.quality_in(accepts)?;
+        (quality > 0.0).then_some(mime)
+    }
+
+    /// Reads the rate limit state reported by the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimitInfo>` - The rate limit state, or None if the response
+    ///   carries no rate limit headers.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.get_headers())
+    }
+
+    /// Classifies the response with the built-in `DefaultResponseClassifier`.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseClass` - The class of the response.
+    pub fn classify(&self) -> ResponseClass {
+        self.classify_with(&DefaultResponseClassifier)
+    }
+
+    /// Classifies the response with a custom classifier.
+    ///
+    /// # Arguments
+    ///
+    /// - `&C` - The classifier inspecting the status, headers, and body snippet.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseClass` - The class of the response.
+    pub fn classify_with<C: ResponseClassifier + ?Sized>(&self, classifier: &C) -> ResponseClass {
+        let headers: ResponseHeaders = self.get_headers();
+        let body: RequestBody = self.get_body();
+        classifier.classify(self.status_code, &headers, body_snippet(&body))
+    }
+
+    /// Gets the SHA-256 digest of the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The lowercase hex encoded digest.
+    pub fn body_sha256(&self) -> String {
+        BodyDigest::of(&self.get_body()).sha256_hex()
+    }
+
+    /// Gets the MD5 digest of the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The lowercase hex encoded digest.
+    pub fn body_md5(&self) -> String {
+        BodyDigest::of(&self.get_body()).md5_hex()
+    }
+
+    /// Verifies the response body against the `Content-MD5` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - Whether the digests match, or None if the header is absent.
+    pub fn verify_content_md5(&self) -> Option<bool> {
+        let content_md5: String = self.get_header(CONTENT_MD5)?;
+        Some(BodyDigest::of(&self.get_body()).matches_content_md5(&content_md5))
+    }
+
+    /// Decodes the response body as a protobuf message.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<M, RequestError>` - The decoded message, or an error if the body is not a valid `M`.
+    #[cfg(feature = "prost")]
+    pub fn protobuf<M: ProtobufMessage + Default>(&self) -> Result<M, RequestError> {
+        let body: RequestBody = self.get_body();
+        M::decode(body.as_slice())
+            .map_err(|error| RequestError::Request(format!("Protobuf decode error: {error}")))
+    }
 }
 
 /// Default implementation for HttpResponseBinary.
@@ -292,7 +771,11 @@ impl Default for HttpResponseBinary {
             status_code: HttpStatus::Unknown.code(),
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
+            trailers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
             body: Arc::new(RwLock::new(Vec::new())),
+            decoded: false,
+            spool: None,
+            metrics: RequestMetrics::default(),
         }
     }
 }
@@ -23,6 +23,14 @@ pub struct HttpResponseBinary {
     pub(crate) status_text: ArcRwLock<String>,
     /// HTTP response headers.
     pub(crate) headers: ArcRwLock<ResponseHeaders>,
+    /// Trailer fields sent after a chunked response body.
+    pub(crate) trailers: ArcRwLock<ResponseHeaders>,
     /// HTTP response body content.
     pub(crate) body: ArcRwLock<RequestBody>,
+    /// Whether the body has already been decompressed according to `Content-Encoding`.
+    pub(crate) decoded: bool,
+    /// Temporary file holding a body too large to keep in memory.
+    pub(crate) spool: Option<Arc<SpoolFile>>,
+    /// Timings and transfer sizes of the request that produced the response.
+    pub(crate) metrics: RequestMetrics,
 }
@@ -0,0 +1,984 @@
+use super::*;
+
+impl BodyDecoder {
+    /// Creates a decoder for the body following the given response headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `bool` - Whether the response answers a HEAD request and therefore has no body.
+    ///
+    /// # Returns
+    ///
+    /// - `BodyDecoder` - A decoder matching the framing announced by the headers.
+    pub(crate) fn new(headers_bytes: &[u8], is_head: bool) -> Self {
+        let framing: BodyFraming =
+            if SharedResponseHandler::is_bodyless_response(headers_bytes, is_head) {
+                BodyFraming::Length(0)
+            } else if SharedResponseHandler::is_chunked_encoding(headers_bytes) {
+                BodyFraming::Chunked(ChunkState::Size)
+            } else if SharedResponseHandler::is_close_delimited(headers_bytes, is_head) {
+                BodyFraming::UntilClose
+            } else {
+                BodyFraming::Length(
+                    SharedResponseHandler::get_content_length(headers_bytes).unwrap_or(usize::MAX),
+                )
+            };
+        Self {
+            done: framing == BodyFraming::Length(0),
+            framing,
+            line: Vec::new(),
+            received: 0,
+            max_body: None,
+            trailers: hash_map_xx_hash3_64(),
+        }
+    }
+
+    /// Limits the number of body bytes accepted from the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The maximum body size in bytes, including chunk framing, or
+    ///   None for no limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BodyDecoder, RequestError>` - The decoder, or
+    ///   `RequestError::ContentLengthTooLarge` if the announced length exceeds the limit.
+    pub(crate) fn with_max_body(mut self, max_body: Option<usize>) -> Result<Self, RequestError> {
+        if let (Some(max), BodyFraming::Length(length)) = (max_body, self.framing)
+            && length > max
+        {
+            return Err(RequestError::ContentLengthTooLarge(max));
+        }
+        self.max_body = max_body;
+        Ok(self)
+    }
+
+    /// Checks if the end of the body has been reached.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the body is complete, false otherwise.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Gets the trailer fields, once the body has been read to the end.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseHeaders>` - The trailers, empty if none were sent, or None until
+    ///   the body is complete.
+    pub(crate) fn get_trailers(&self) -> Option<ResponseHeaders> {
+        self.done.then(|| self.trailers.clone())
+    }
+
+    /// Buffers bytes until a complete line is available.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The input bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `(usize, bool)` - The number of bytes consumed and whether a line was completed.
+    fn take_line(&mut self, input: &[u8]) -> (usize, bool) {
+        match input.iter().position(|&byte| byte == b'\n') {
+            Some(pos) => {
+                self.line.extend_from_slice(&input[..pos]);
+                if self.line.last() == Some(&b'\r') {
+                    self.line.pop();
+                }
+                (pos + 1, true)
+            }
+            None => {
+                self.line.extend_from_slice(input);
+                (input.len(), false)
+            }
+        }
+    }
+
+    /// Decodes raw body bytes, appending the payload to `output`.
+    ///
+    /// Bytes past the end of the body are not consumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - Raw bytes read from the connection.
+    /// - `&mut Vec<u8>` - The buffer receiving the decoded payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, RequestError>` - The number of input bytes consumed, or an error if the
+    ///   framing is malformed or the body exceeds its size limit.
+    pub(crate) fn feed(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<usize, RequestError> {
+        let mut pos: usize = 0;
+        while pos < input.len() && !self.done {
+            let rest: &[u8] = &input[pos..];
+            match self.framing {
+                BodyFraming::Length(remaining) => {
+                    let take: usize = remaining.min(rest.len());
+                    output.extend_from_slice(&rest[..take]);
+                    pos += take;
+                    self.framing = BodyFraming::Length(remaining - take);
+                    self.done = remaining == take;
+                }
+                BodyFraming::UntilClose => {
+                    output.extend_from_slice(rest);
+                    pos += rest.len();
+                }
+                BodyFraming::Chunked(ChunkState::Data(remaining)) => {
+                    let take: usize = remaining.min(rest.len());
+                    output.extend_from_slice(&rest[..take]);
+                    pos += take;
+                    self.framing = BodyFraming::Chunked(if remaining == take {
+                        ChunkState::DataEnd
+                    } else {
+                        ChunkState::Data(remaining - take)
+                    });
+                }
+                BodyFraming::Chunked(state) => {
+                    let (consumed, complete) = self.take_line(rest);
+                    pos += consumed;
+                    if !complete {
+                        continue;
+                    }
+                    let line: Vec<u8> = std::mem::take(&mut self.line);
+                    match state {
+                        ChunkState::Size => {
+                            let size_bytes: &[u8] = match line.iter().position(|&b| b == b';') {
+                                Some(p) => &line[..p],
+                                None => &line,
+                            };
+                            let chunk_size: usize = from_utf8(size_bytes)
+                                .ok()
+                                .and_then(|s: &str| usize::from_str_radix(s.trim(), 16).ok())
+                                .ok_or_else(|| {
+                                    RequestError::Request("Invalid chunk size line".to_string())
+                                })?;
+                            self.framing = BodyFraming::Chunked(if chunk_size == 0 {
+                                ChunkState::Trailers
+                            } else {
+                                ChunkState::Data(chunk_size)
+                            });
+                        }
+                        ChunkState::DataEnd => {
+                            if !line.is_empty() {
+                                return Err(RequestError::Request(
+                                    "Missing line break after chunk data".to_string(),
+                                ));
+                            }
+                            self.framing = BodyFraming::Chunked(ChunkState::Size);
+                        }
+                        _ => {
+                            self.done = line.is_empty();
+                            for (name, values) in SharedResponseHandler::parse_trailer_fields(&line)
+                            {
+                                self.trailers.entry(name).or_default().extend(values);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self.received += pos;
+        if let Some(max) = self.max_body
+            && self.received > max
+        {
+            return Err(RequestError::BodyTooLarge(max));
+        }
+        Ok(pos)
+    }
+
+    /// Signals that the connection reached EOF.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the body is complete, or an error if it was truncated.
+    pub(crate) fn finish(&mut self) -> Result<(), RequestError> {
+        if self.done {
+            return Ok(());
+        }
+        if self.framing == BodyFraming::UntilClose {
+            self.done = true;
+            return Ok(());
+        }
+        Err(RequestError::ConnectionReset(IoError::new(
+            ErrorKind::UnexpectedEof,
+            "Connection closed before the response body was complete",
+        )))
+    }
+}
+
+/// Converts a `RequestError` raised while decoding into an I/O error.
+///
+/// # Arguments
+///
+/// - `RequestError` - The decoding error.
+///
+/// # Returns
+///
+/// - `std::io::Error` - The equivalent I/O error.
+fn decode_io_error(error: RequestError) -> std::io::Error {
+    let kind: ErrorKind = match error {
+        RequestError::ConnectionReset(_) => ErrorKind::UnexpectedEof,
+        _ => ErrorKind::InvalidData,
+    };
+    std::io::Error::new(kind, error.to_string())
+}
+
+impl BodyReader {
+    /// Creates a reader over the body remaining on the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxReadWrite` - The connection stream, positioned after the bytes already read.
+    /// - `BodyDecoder` - The decoder matching the response framing.
+    /// - `&[u8]` - Body bytes already read together with the headers.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BodyReader, RequestError>` - The reader, or an error if the buffered bytes are malformed.
+    pub(crate) fn new(
+        stream: BoxReadWrite,
+        mut decoder: BodyDecoder,
+        leftover: &[u8],
+        buffer_size: usize,
+    ) -> Result<Self, RequestError> {
+        let mut decoded: Vec<u8> = Vec::with_capacity(leftover.len());
+        decoder.feed(leftover, &mut decoded)?;
+        Ok(Self {
+            stream,
+            decoder,
+            digest: BodyDigest::of(&decoded),
+            decoded,
+            position: 0,
+            raw: vec![0; buffer_size.max(1)],
+            tee: None,
+            tee_position: 0,
+        })
+    }
+
+    /// Gets the SHA-256 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_sha256(&self) -> Option<String> {
+        self.decoder.is_done().then(|| self.digest.sha256_hex())
+    }
+
+    /// Gets the MD5 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_md5(&self) -> Option<String> {
+        self.decoder.is_done().then(|| self.digest.md5_hex())
+    }
+
+    /// Gets the trailer fields sent after a chunked body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseHeaders>` - The trailers, empty if none were sent, or None until the body has been read to the end.
+    pub fn get_trailers(&self) -> Option<ResponseHeaders> {
+        self.decoder.get_trailers()
+    }
+
+    /// Sets the writer receiving a copy of the payload not read yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxBodyWrite` - The writer.
+    pub(crate) fn set_tee(&mut self, tee: BoxBodyWrite) {
+        self.tee_position = self.position;
+        self.tee = Some(tee);
+    }
+
+    /// Flushes the writer receiving a copy of the payload, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok once flushed, or the error of the writer.
+    fn flush_tee(&mut self) -> std::io::Result<()> {
+        match &mut self.tee {
+            Some(tee) => tee.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Read for BodyReader {
+    /// Reads decoded body bytes into the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut [u8]` - The buffer to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<usize>` - The number of bytes read, 0 at the end of the body.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if let Some(tee) = &mut self.tee
+                && self.tee_position < self.decoded.len()
+            {
+                tee.write_all(&self.decoded[self.tee_position..])?;
+                self.tee_position = self.decoded.len();
+            }
+            if self.position < self.decoded.len() {
+                let n: usize = buf.len().min(self.decoded.len() - self.position);
+                buf[..n].copy_from_slice(&self.decoded[self.position..self.position + n]);
+                self.position += n;
+                return Ok(n);
+            }
+            self.decoded.clear();
+            self.position = 0;
+            self.tee_position = 0;
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            if self.decoder.is_done() {
+                return self.flush_tee().map(|_| 0);
+            }
+            let n: usize = self.stream.read(&mut self.raw)?;
+            if n == 0 {
+                self.decoder.finish().map_err(decode_io_error)?;
+                return self.flush_tee().map(|_| 0);
+            }
+            self.decoder
+                .feed(&self.raw[..n], &mut self.decoded)
+                .map_err(decode_io_error)?;
+            self.digest.update(&self.decoded);
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncBodyReader {
+    /// Creates an async reader over the body remaining on the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxAsyncReadWrite` - The connection stream, positioned after the bytes already read.
+    /// - `BodyDecoder` - The decoder matching the response framing.
+    /// - `&[u8]` - Body bytes already read together with the headers.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<AsyncBodyReader, RequestError>` - The reader, or an error if the buffered bytes are malformed.
+    pub(crate) fn new(
+        stream: BoxAsyncReadWrite,
+        mut decoder: BodyDecoder,
+        leftover: &[u8],
+        buffer_size: usize,
+    ) -> Result<Self, RequestError> {
+        let mut decoded: Vec<u8> = Vec::with_capacity(leftover.len());
+        decoder.feed(leftover, &mut decoded)?;
+        Ok(Self {
+            stream,
+            decoder,
+            digest: BodyDigest::of(&decoded),
+            decoded,
+            position: 0,
+            raw: vec![0; buffer_size.max(1)],
+            tee: None,
+            tee_position: 0,
+        })
+    }
+
+    /// Gets the SHA-256 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_sha256(&self) -> Option<String> {
+        self.decoder.is_done().then(|| self.digest.sha256_hex())
+    }
+
+    /// Gets the MD5 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_md5(&self) -> Option<String> {
+        self.decoder.is_done().then(|| self.digest.md5_hex())
+    }
+
+    /// Gets the trailer fields sent after a chunked body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseHeaders>` - The trailers, empty if none were sent, or None until the body has been read to the end.
+    pub fn get_trailers(&self) -> Option<ResponseHeaders> {
+        self.decoder.get_trailers()
+    }
+
+    /// Sets the writer receiving a copy of the payload not read yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxBodyAsyncWrite` - The writer.
+    pub(crate) fn set_tee(&mut self, tee: BoxBodyAsyncWrite) {
+        self.tee_position = self.position;
+        self.tee = Some(tee);
+    }
+
+    /// Polls the writer receiving a copy of the payload, if any, to flush it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Context<'_>` - The task context.
+    ///
+    /// # Returns
+    ///
+    /// - `Poll<std::io::Result<()>>` - Ready once flushed, or with the error of the writer.
+    fn poll_flush_tee(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match &mut self.tee {
+            Some(tee) => tee.as_mut().poll_flush(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRead for AsyncBodyReader {
+    /// Polls for decoded body bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Context<'_>` - The task context.
+    /// - `&mut ReadBuf<'_>` - The buffer to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `Poll<std::io::Result<()>>` - Ready once bytes were written or the body ended.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this: &mut Self = self.get_mut();
+        loop {
+            if let Some(tee) = &mut this.tee {
+                while this.tee_position < this.decoded.len() {
+                    match tee
+                        .as_mut()
+                        .poll_write(cx, &this.decoded[this.tee_position..])
+                    {
+                        Poll::Pending => return Poll::Pending,
+                        Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                        Poll::Ready(Ok(0)) => {
+                            return Poll::Ready(Err(std::io::Error::from(ErrorKind::WriteZero)));
+                        }
+                        Poll::Ready(Ok(n)) => this.tee_position += n,
+                    }
+                }
+            }
+            if this.position < this.decoded.len() {
+                let n: usize = buf.remaining().min(this.decoded.len() - this.position);
+                buf.put_slice(&this.decoded[this.position..this.position + n]);
+                this.position += n;
+                return Poll::Ready(Ok(()));
+            }
+            this.decoded.clear();
+            this.position = 0;
+            this.tee_position = 0;
+            if buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            if this.decoder.is_done() {
+                return this.poll_flush_tee(cx);
+            }
+            let mut raw_buf: ReadBuf<'_> = ReadBuf::new(&mut this.raw);
+            match Pin::new(&mut this.stream).poll_read(cx, &mut raw_buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Ready(Ok(())) => {}
+            }
+            let n: usize = raw_buf.filled().len();
+            if n == 0 {
+                if let Err(error) = this.decoder.finish() {
+                    return Poll::Ready(Err(decode_io_error(error)));
+                }
+                return this.poll_flush_tee(cx);
+            }
+            if let Err(error) = this.decoder.feed(&this.raw[..n], &mut this.decoded) {
+                return Poll::Ready(Err(decode_io_error(error)));
+            }
+            this.digest.update(&this.decoded);
+        }
+    }
+}
+
+impl HttpResponseStream {
+    /// Gets the HTTP version of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpVersion` - The HTTP version.
+    pub fn get_http_version(&self) -> HttpVersion {
+        self.head.get_http_version()
+    }
+
+    /// Gets the HTTP status code of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.head.get_status_code()
+    }
+
+    /// Gets the HTTP status text of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The status text.
+    pub fn get_status_text(&self) -> String {
+        self.head.get_status_text()
+    }
+
+    /// Gets the HTTP response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeaders` - The response headers.
+    pub fn get_headers(&self) -> ResponseHeaders {
+        self.head.get_headers()
+    }
+
+    /// Parses the `Content-Type` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed media type, or None if the header is absent or malformed.
+    pub fn content_type_parsed(&self) -> Option<Mime> {
+        self.head.content_type_parsed()
+    }
+
+    /// Gets the response media type if it is acceptable under weighted media ranges.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed `Content-Type`, or None if it is absent or not acceptable.
+    pub fn negotiated_type(&self, accepts: &[(&str, f32)]) -> Option<Mime> {
+        self.head.negotiated_type(accepts)
+    }
+
+    /// Reads the rate limit state reported by the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimitInfo>` - The rate limit state, or None if the response
+    ///   carries no rate limit headers.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.head.rate_limit()
+    }
+
+    /// Gets the body length announced by the `Content-Length` response header.
+    ///
+    /// Together with the status and the other headers, this lets the caller decide
+    /// whether to buffer the body, stream it, or abort before any of it is read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The announced length, or None if the header is absent or invalid.
+    pub fn get_content_length(&self) -> Option<usize> {
+        self.head
+            .get_header(CONTENT_LENGTH)
+            .and_then(|value: String| value.trim().parse().ok())
+    }
+
+    /// Closes the connection without reading the rest of the body.
+    ///
+    /// The connection is not reused, so a large or unwanted body is never downloaded.
+    pub fn abort(self) {
+        drop(self.body.stream);
+    }
+
+    /// Gets the body reader.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut BodyReader` - The reader over the remaining body.
+    pub fn body(&mut self) -> &mut BodyReader {
+        &mut self.body
+    }
+
+    /// Consumes the response and returns the body reader.
+    ///
+    /// # Returns
+    ///
+    /// - `BodyReader` - The reader over the remaining body.
+    pub fn into_body(self) -> BodyReader {
+        self.body
+    }
+
+    /// Copies the body to a writer as it is read.
+    ///
+    /// Every byte read from the body is written to the writer first, so the body
+    /// can be saved to disk while it is parsed, without buffering it or
+    /// downloading it twice. The writer is flushed when the body ends, and a write
+    /// error fails the read.
+    ///
+    /// # Arguments
+    ///
+    /// - `W` - The writer receiving the body (must implement Write + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `HttpResponseStream` - The response copying its body to the writer.
+    pub fn tee<W: Write + Send + 'static>(mut self, writer: W) -> Self {
+        self.body.set_tee(Box::new(writer));
+        self
+    }
+
+    /// Gets the SHA-256 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_sha256(&self) -> Option<String> {
+        self.body.body_sha256()
+    }
+
+    /// Gets the MD5 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_md5(&self) -> Option<String> {
+        self.body.body_md5()
+    }
+
+    /// Gets the trailer fields sent after a chunked body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseHeaders>` - The trailers, empty if none were sent, or None until the body has been read to the end.
+    pub fn get_trailers(&self) -> Option<ResponseHeaders> {
+        self.body.get_trailers()
+    }
+
+    /// Verifies the body against the `Content-MD5` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - Whether the digests match, or None if the header is absent or the body has not been read to the end.
+    pub fn verify_content_md5(&self) -> Option<bool> {
+        let content_md5: String = self.head.get_header(CONTENT_MD5)?;
+        self.body
+            .decoder
+            .is_done()
+            .then(|| self.body.digest.matches_content_md5(&content_md5))
+    }
+
+    /// Reads the remaining body and returns a fully buffered response.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The buffered response, or an error if reading fails.
+    pub fn into_binary(mut self) -> Result<HttpResponseBinary, RequestError> {
+        let mut body: Vec<u8> = Vec::new();
+        self.body
+            .read_to_end(&mut body)
+            .map_err(RequestError::from)?;
+        if let Ok(mut head_body) = self.head.body.write() {
+            *head_body = body;
+        }
+        if let Ok(mut head_trailers) = self.head.trailers.write() {
+            *head_trailers = self.body.decoder.trailers.clone();
+        }
+        Ok(self.head)
+    }
+
+    /// Reads the remaining body, writing it to a temporary file if it exceeds a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The largest body kept in memory.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The response with the body in memory
+    ///   or spooled to disk, or an error if reading or writing fails.
+    pub(crate) fn into_spooled(
+        mut self,
+        threshold: usize,
+        buffer_size: usize,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        let (body, spool): (Vec<u8>, Option<SpoolFile>) =
+            SpoolFile::read_body(&mut self.body, threshold, buffer_size)?;
+        if let Ok(mut head_body) = self.head.body.write() {
+            *head_body = body;
+        }
+        if let Ok(mut head_trailers) = self.head.trailers.write() {
+            *head_trailers = self.body.decoder.trailers.clone();
+        }
+        self.head.spool = spool.map(Arc::new);
+        Ok(self.head)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncHttpResponseStream {
+    /// Gets the HTTP version of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpVersion` - The HTTP version.
+    pub fn get_http_version(&self) -> HttpVersion {
+        self.head.get_http_version()
+    }
+
+    /// Gets the HTTP status code of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.head.get_status_code()
+    }
+
+    /// Gets the HTTP status text of the response.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The status text.
+    pub fn get_status_text(&self) -> String {
+        self.head.get_status_text()
+    }
+
+    /// Gets the HTTP response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeaders` - The response headers.
+    pub fn get_headers(&self) -> ResponseHeaders {
+        self.head.get_headers()
+    }
+
+    /// Parses the `Content-Type` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed media type, or None if the header is absent or malformed.
+    pub fn content_type_parsed(&self) -> Option<Mime> {
+        self.head.content_type_parsed()
+    }
+
+    /// Gets the response media type if it is acceptable under weighted media ranges.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed `Content-Type`, or None if it is absent or not acceptable.
+    pub fn negotiated_type(&self, accepts: &[(&str, f32)]) -> Option<Mime> {
+        self.head.negotiated_type(accepts)
+    }
+
+    /// Reads the rate limit state reported by the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimitInfo>` - The rate limit state, or None if the response
+    ///   carries no rate limit headers.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.head.rate_limit()
+    }
+
+    /// Gets the body length announced by the `Content-Length` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The announced length, or None if the header is absent or invalid.
+    pub fn get_content_length(&self) -> Option<usize> {
+        self.head
+            .get_header(CONTENT_LENGTH)
+            .and_then(|value: String| value.trim().parse().ok())
+    }
+
+    /// Shuts down the connection without reading the rest of the body.
+    ///
+    /// The connection is not reused, so a large or unwanted body is never downloaded.
+    pub async fn abort(mut self) {
+        let _ = self.body.stream.shutdown().await;
+    }
+
+    /// Gets the async body reader.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut AsyncBodyReader` - The reader over the remaining body.
+    pub fn body(&mut self) -> &mut AsyncBodyReader {
+        &mut self.body
+    }
+
+    /// Consumes the response and returns the async body reader.
+    ///
+    /// # Returns
+    ///
+    /// - `AsyncBodyReader` - The reader over the remaining body.
+    pub fn into_body(self) -> AsyncBodyReader {
+        self.body
+    }
+
+    /// Copies the body to an async writer as it is read.
+    ///
+    /// Behaves like `HttpResponseStream::tee`, writing to the writer asynchronously.
+    ///
+    /// # Arguments
+    ///
+    /// - `W` - The writer receiving the body (must implement AsyncWrite + Send).
+    ///
+    /// # Returns
+    ///
+    /// - `AsyncHttpResponseStream` - The response copying its body to the writer.
+    pub fn tee<W: AsyncWrite + Send + 'static>(mut self, writer: W) -> Self {
+        self.body.set_tee(Box::pin(writer));
+        self
+    }
+
+    /// Gets the SHA-256 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_sha256(&self) -> Option<String> {
+        self.body.body_sha256()
+    }
+
+    /// Gets the MD5 digest of the body, computed while it was read.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The lowercase hex encoded digest, or None until the body has been read to the end.
+    pub fn body_md5(&self) -> Option<String> {
+        self.body.body_md5()
+    }
+
+    /// Gets the trailer fields sent after a chunked body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResponseHeaders>` - The trailers, empty if none were sent, or None until the body has been read to the end.
+    pub fn get_trailers(&self) -> Option<ResponseHeaders> {
+        self.body.get_trailers()
+    }
+
+    /// Verifies the body against the `Content-MD5` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - Whether the digests match, or None if the header is absent or the body has not been read to the end.
+    pub fn verify_content_md5(&self) -> Option<bool> {
+        let content_md5: String = self.head.get_header(CONTENT_MD5)?;
+        self.body
+            .decoder
+            .is_done()
+            .then(|| self.body.digest.matches_content_md5(&content_md5))
+    }
+
+    /// Reads the remaining body and returns a fully buffered response.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The buffered response, or an error if reading fails.
+    pub async fn into_binary(mut self) -> Result<HttpResponseBinary, RequestError> {
+        let mut body: Vec<u8> = Vec::new();
+        self.body
+            .read_to_end(&mut body)
+            .await
+            .map_err(RequestError::from)?;
+        if let Ok(mut head_body) = self.head.body.write() {
+            *head_body = body;
+        }
+        if let Ok(mut head_trailers) = self.head.trailers.write() {
+            *head_trailers = self.body.decoder.trailers.clone();
+        }
+        Ok(self.head)
+    }
+
+    /// Reads the remaining body, writing it to a temporary file if it exceeds a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The largest body kept in memory.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<HttpResponseBinary, RequestError>` - The response with the body in memory
+    ///   or spooled to disk, or an error if reading or writing fails.
+    pub(crate) async fn into_spooled(
+        mut self,
+        threshold: usize,
+        buffer_size: usize,
+    ) -> Result<HttpResponseBinary, RequestError> {
+        let (body, spool): (Vec<u8>, Option<SpoolFile>) =
+            SpoolFile::read_body_async(&mut self.body, threshold, buffer_size).await?;
+        if let Ok(mut head_body) = self.head.body.write() {
+            *head_body = body;
+        }
+        if let Ok(mut head_trailers) = self.head.trailers.write() {
+            *head_trailers = self.body.decoder.trailers.clone();
+        }
+        self.head.spool = spool.map(Arc::new);
+        Ok(self.head)
+    }
+}
+
+impl Debug for HttpResponseStream {
+    /// Formats the response head; the body reader is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResponseStream")
+            .field("head", &self.head)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Debug for AsyncHttpResponseStream {
+    /// Formats the response head; the body reader is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncHttpResponseStream")
+            .field("head", &self.head)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for BodyReader {
+    /// Formats the framing state; the connection stream is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BodyReader")
+            .field("decoder", &self.decoder)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Debug for AsyncBodyReader {
+    /// Formats the framing state; the connection stream is omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncBodyReader")
+            .field("decoder", &self.decoder)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,6 @@
+mod r#impl;
+mod r#struct;
+
+pub use r#struct::*;
+
+use super::*;
@@ -0,0 +1,114 @@
+use super::*;
+
+/// An HTTP response whose body is read incrementally from the connection.
+///
+/// The status line and headers are parsed when the response is returned; the
+/// body is only read as the caller consumes it through `BodyReader`. Response
+/// bodies are not decompressed on this path unless read through `decode`.
+///
+/// After inspecting the head, the caller can buffer the body with `into_binary`,
+/// stream it through `body`, or close the connection with `abort`.
+pub struct HttpResponseStream {
+    /// Status line and headers of the response, with an empty body.
+    pub(crate) head: HttpResponseBinary,
+    /// Reader over the response body.
+    pub(crate) body: BodyReader,
+}
+
+/// An asynchronous HTTP response whose body is read incrementally from the connection.
+///
+/// The async counterpart of `HttpResponseStream`.
+#[cfg(feature = "async")]
+pub struct AsyncHttpResponseStream {
+    /// Status line and headers of the response, with an empty body.
+    pub(crate) head: HttpResponseBinary,
+    /// Async reader over the response body.
+    pub(crate) body: AsyncBodyReader,
+}
+
+/// Synchronous reader over a response body.
+///
+/// Removes the transfer framing (Content-Length, chunked encoding, or
+/// read-until-close) and yields the payload bytes as they arrive.
+pub struct BodyReader {
+    /// The underlying connection stream.
+    pub(crate) stream: BoxReadWrite,
+    /// Framing state of the body.
+    pub(crate) decoder: BodyDecoder,
+    /// Payload bytes decoded but not yet returned to the caller.
+    pub(crate) decoded: Vec<u8>,
+    /// Read position within `decoded`.
+    pub(crate) position: usize,
+    /// Buffer for raw bytes read from the connection.
+    pub(crate) raw: Vec<u8>,
+    /// Running digests of the payload decoded so far.
+    pub(crate) digest: BodyDigest,
+    /// Writer receiving a copy of the payload, if any.
+    pub(crate) tee: Option<BoxBodyWrite>,
+    /// Position within `decoded` up to which the payload was copied to `tee`.
+    pub(crate) tee_position: usize,
+}
+
+/// Asynchronous reader over a response body.
+///
+/// The async counterpart of `BodyReader`.
+#[cfg(feature = "async")]
+pub struct AsyncBodyReader {
+    /// The underlying connection stream.
+    pub(crate) stream: BoxAsyncReadWrite,
+    /// Framing state of the body.
+    pub(crate) decoder: BodyDecoder,
+    /// Payload bytes decoded but not yet returned to the caller.
+    pub(crate) decoded: Vec<u8>,
+    /// Read position within `decoded`.
+    pub(crate) position: usize,
+    /// Buffer for raw bytes read from the connection.
+    pub(crate) raw: Vec<u8>,
+    /// Running digests of the payload decoded so far.
+    pub(crate) digest: BodyDigest,
+    /// Writer receiving a copy of the payload, if any.
+    pub(crate) tee: Option<BoxBodyAsyncWrite>,
+    /// Position within `decoded` up to which the payload was copied to `tee`.
+    pub(crate) tee_position: usize,
+}
+
+/// Incremental decoder removing the transfer framing of a response body.
+#[derive(Clone, Debug)]
+pub(crate) struct BodyDecoder {
+    /// How the end of the body is determined.
+    pub(crate) framing: BodyFraming,
+    /// Partial line buffered while decoding chunk size lines and trailers.
+    pub(crate) line: Vec<u8>,
+    /// Whether the end of the body has been reached.
+    pub(crate) done: bool,
+    /// Number of raw body bytes consumed so far.
+    pub(crate) received: usize,
+    /// Maximum number of raw body bytes accepted, unlimited if None.
+    pub(crate) max_body: Option<usize>,
+    /// Trailer fields read after the last chunk.
+    pub(crate) trailers: ResponseHeaders,
+}
+
+/// How the end of a response body is determined.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum BodyFraming {
+    /// A fixed number of bytes remain.
+    Length(usize),
+    /// Chunked transfer encoding, in the given decoding state.
+    Chunked(ChunkState),
+    /// The body ends when the server closes the connection.
+    UntilClose,
+}
+
+/// Decoding state within a chunked body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ChunkState {
+    /// Reading a chunk size line.
+    Size,
+    /// Reading chunk data, with the given number of bytes remaining.
+    Data(usize),
+    /// Reading the line break that ends chunk data.
+    DataEnd,
+    /// Reading the trailer section after the last chunk.
+    Trailers,
+}
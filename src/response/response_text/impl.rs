@@ -1,5 +1,7 @@
 use super::*;
 
+impl Sealed for HttpResponseText {}
+
 /// Implements the `ResponseTrait` trait for `HttpResponseText`.
 ///
 /// This implementation allows `HttpResponseText` to convert between text and binary
@@ -53,7 +55,11 @@ impl ResponseTrait for HttpResponseText {
             status_code: self.status_code,
             status_text: self.status_text.clone(),
             headers: self.headers.clone(),
+            trailers: self.trailers.clone(),
             body: Arc::new(RwLock::new(body)),
+            decoded: true,
+            spool: None,
+            metrics: self.metrics,
         }
     }
 
@@ -80,23 +86,38 @@ impl ResponseTrait for HttpResponseText {
                     let mut string_headers: HashMapXxHash3_64<String, String> =
                         hash_map_xx_hash3_64();
                     for (key, value_deque) in headers_ref.iter() {
-                        if let Some(first_value) = value_deque.front() {
-                            string_headers.insert(key.clone(), first_value.clone());
+                        if !value_deque.is_empty() {
+                            let values: Vec<&str> =
+                                value_deque.iter().map(String::as_str).collect();
+                            string_headers.insert(key.clone(), values.join(", "));
                         }
                     }
                     string_headers
                 });
-        let body: Vec<u8> = Compress::from(&headers)
-            .decode(&tmp_body, buffer_size)
-            .into_owned();
+        let body: Vec<u8> = CodecRegistry::decode_body(&headers, &tmp_body, buffer_size);
         HttpResponseBinary {
             http_version: http_response.http_version,
             status_code: http_response.status_code,
             status_text: http_response.status_text,
             headers: http_response.headers,
+            trailers: http_response.trailers,
             body: Arc::new(RwLock::new(body)),
+            decoded: true,
+            spool: None,
+            metrics: self.metrics,
         }
     }
+
+    /// Deserializes the JSON body into a typed value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized value, or an error if the body is not valid JSON for `T`.
+    #[cfg(feature = "json")]
+    fn json<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        let body: RequestBodyString = self.get_body();
+        from_str(&body).map_err(RequestError::from)
+    }
 }
 
 impl HttpResponseText {
@@ -168,6 +189,18 @@ impl HttpResponseText {
         hash_map_xx_hash3_64()
     }
 
+    /// Gets the trailer fields sent after a chunked response body.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeaders` - The response trailers, empty if none were sent.
+    pub fn get_trailers(&self) -> ResponseHeaders {
+        if let Ok(trailers) = self.trailers.read() {
+            return trailers.clone();
+        }
+        hash_map_xx_hash3_64()
+    }
+
     /// Retrieves the body content of the HTTP response as a `String`.
     ///
     /// This method attempts to read the body of the response. If the body can be successfully read,
@@ -188,6 +221,115 @@ impl HttpResponseText {
         }
         RequestBodyString::new()
     }
+
+    /// Gets the timings and transfer sizes of the request that produced the response.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestMetrics` - The request metrics.
+    pub fn get_metrics(&self) -> RequestMetrics {
+        self.metrics
+    }
+
+    /// Gets the first value of a response header, matching the name case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The header value, or None if the header is absent.
+    pub fn get_header(&self, name: &str) -> Option<String> {
+        let headers: RwLockReadGuard<'_, ResponseHeaders> = self.headers.read().ok()?;
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.front().cloned())
+    }
+
+    /// Gets every value of a response header, matching the name case-insensitively.
+    ///
+    /// Repeated header lines, such as several `Set-Cookie` headers, each yield one
+    /// value, in the order they were received.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The header values, empty if the header is absent.
+    pub fn get_header_all(&self, name: &str) -> Vec<String> {
+        self.headers.read().map_or(Vec::new(), |headers| {
+            headers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+                .flat_map(|(_, value)| value.iter().cloned())
+                .collect()
+        })
+    }
+
+    /// Parses the `Content-Type` response header.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed media type, or None if the header is absent or malformed.
+    pub fn content_type_parsed(&self) -> Option<Mime> {
+        self.get_header(CONTENT_TYPE)?.parse::<Mime>().ok()
+    }
+
+    /// Gets the response media type if it is acceptable under weighted media ranges.
+    ///
+    /// Pass the same ranges given to `RequestBuilder::accepts` to learn which format
+    /// the server chose.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(&str, f32)]` - The accepted media ranges and their quality values.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Mime>` - The parsed `Content-Type`, or None if it is absent or not acceptable.
+    pub fn negotiated_type(&self, accepts: &[(&str, f32)]) -> Option<Mime> {
+        let mime: Mime = self.content_type_parsed()?;
+        let quality: f32 = mime.quality_in(accepts)?;
+        (quality > 0.0).then_some(mime)
+    }
+
+    /// Reads the rate limit state reported by the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimitInfo>` - The rate limit state, or None if the response
+    ///   carries no rate limit headers.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.get_headers())
+    }
+
+    /// Classifies the response with the built-in `DefaultResponseClassifier`.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseClass` - The class of the response.
+    pub fn classify(&self) -> ResponseClass {
+        self.classify_with(&DefaultResponseClassifier)
+    }
+
+    /// Classifies the response with a custom classifier.
+    ///
+    /// # Arguments
+    ///
+    /// - `&C` - The classifier inspecting the status, headers, and body snippet.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseClass` - The class of the response.
+    pub fn classify_with<C: ResponseClassifier + ?Sized>(&self, classifier: &C) -> ResponseClass {
+        let headers: ResponseHeaders = self.get_headers();
+        let body: RequestBodyString = self.get_body();
+        classifier.classify(self.status_code, &headers, body_snippet(body.as_bytes()))
+    }
 }
 
 /// Default implementation for HttpResponseText.
@@ -203,7 +345,9 @@ impl Default for HttpResponseText {
             status_code: HttpStatus::Unknown.code(),
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
+            trailers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
             body: Arc::new(RwLock::new(String::new())),
+            metrics: RequestMetrics::default(),
         }
     }
 }
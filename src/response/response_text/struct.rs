@@ -23,6 +23,10 @@ pub struct HttpResponseText {
     pub(crate) status_text: ArcRwLock<String>,
     /// HTTP response headers.
     pub(crate) headers: ArcRwLock<ResponseHeaders>,
+    /// Trailer fields sent after a chunked response body.
+    pub(crate) trailers: ArcRwLock<ResponseHeaders>,
     /// HTTP response body content.
     pub(crate) body: ArcRwLock<RequestBodyString>,
+    /// Timings and transfer sizes of the request that produced the response.
+    pub(crate) metrics: RequestMetrics,
 }
@@ -0,0 +1,5 @@
+/// File name prefix of spooled response bodies in the temporary directory.
+pub(crate) const SPOOL_FILE_PREFIX: &str = "http-request-";
+
+/// File name extension of spooled response bodies.
+pub(crate) const SPOOL_FILE_EXTENSION: &str = "body";
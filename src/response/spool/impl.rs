@@ -0,0 +1,155 @@
+use super::*;
+
+impl SpoolFile {
+    /// Creates an empty temporary file for a response body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(SpoolFile, File), RequestError>` - The spool file and a handle to write
+    ///   the body, or an error if the file cannot be created.
+    fn create() -> Result<(Self, File), RequestError> {
+        let path: PathBuf = env::temp_dir().join(format!(
+            "{SPOOL_FILE_PREFIX}{:016x}.{SPOOL_FILE_EXTENSION}",
+            random_u64()
+        ));
+        let file: File = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(RequestError::from)?;
+        Ok((Self { path, len: 0 }, file))
+    }
+
+    /// Moves the buffered body into a new spool file.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Vec<u8>` - The body read so far, emptied once written.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(SpoolFile, File), RequestError>` - The spool file and a handle to
+    ///   append the rest of the body.
+    fn spill(buffered: &mut Vec<u8>) -> Result<(Self, File), RequestError> {
+        let (mut spool, mut file): (Self, File) = Self::create()?;
+        file.write_all(buffered).map_err(RequestError::from)?;
+        spool.len = buffered.len() as u64;
+        *buffered = Vec::new();
+        Ok((spool, file))
+    }
+
+    /// Reads a body, moving it into a spool file once it exceeds a size threshold.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut R` - The body reader.
+    /// - `usize` - The largest body kept in memory.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Option<SpoolFile>), RequestError>` - The body if it stayed
+    ///   in memory, or an empty body and the spool file.
+    pub(crate) fn read_body<R: Read>(
+        reader: &mut R,
+        threshold: usize,
+        buffer_size: usize,
+    ) -> Result<(Vec<u8>, Option<Self>), RequestError> {
+        let mut buffer: Vec<u8> = vec![0; buffer_size.max(1)];
+        let mut body: Vec<u8> = Vec::new();
+        let mut spool: Option<(Self, File)> = None;
+        loop {
+            let n: usize = reader.read(&mut buffer).map_err(RequestError::from)?;
+            if n == 0 {
+                break;
+            }
+            match &mut spool {
+                Some((spool, file)) => {
+                    file.write_all(&buffer[..n]).map_err(RequestError::from)?;
+                    spool.len += n as u64;
+                }
+                None => {
+                    body.extend_from_slice(&buffer[..n]);
+                    if body.len() > threshold {
+                        spool = Some(Self::spill(&mut body)?);
+                    }
+                }
+            }
+        }
+        Self::finish(body, spool)
+    }
+
+    /// Reads an async body, moving it into a spool file once it exceeds a size threshold.
+    ///
+    /// The file is written with blocking I/O on the current task.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut R` - The async body reader.
+    /// - `usize` - The largest body kept in memory.
+    /// - `usize` - The read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Option<SpoolFile>), RequestError>` - The body if it stayed
+    ///   in memory, or an empty body and the spool file.
+    #[cfg(feature = "async")]
+    pub(crate) async fn read_body_async<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        threshold: usize,
+        buffer_size: usize,
+    ) -> Result<(Vec<u8>, Option<Self>), RequestError> {
+        let mut buffer: Vec<u8> = vec![0; buffer_size.max(1)];
+        let mut body: Vec<u8> = Vec::new();
+        let mut spool: Option<(Self, File)> = None;
+        loop {
+            let n: usize = reader.read(&mut buffer).await.map_err(RequestError::from)?;
+            if n == 0 {
+                break;
+            }
+            match &mut spool {
+                Some((spool, file)) => {
+                    file.write_all(&buffer[..n]).map_err(RequestError::from)?;
+                    spool.len += n as u64;
+                }
+                None => {
+                    body.extend_from_slice(&buffer[..n]);
+                    if body.len() > threshold {
+                        spool = Some(Self::spill(&mut body)?);
+                    }
+                }
+            }
+        }
+        Self::finish(body, spool)
+    }
+
+    /// Flushes the spool file, if any, once the body has been read.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The in-memory body.
+    /// - `Option<(SpoolFile, File)>` - The spool file and its write handle.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Option<SpoolFile>), RequestError>` - The body and spool file.
+    fn finish(
+        body: Vec<u8>,
+        spool: Option<(Self, File)>,
+    ) -> Result<(Vec<u8>, Option<Self>), RequestError> {
+        match spool {
+            Some((spool, mut file)) => {
+                file.flush().map_err(RequestError::from)?;
+                Ok((body, Some(spool)))
+            }
+            None => Ok((body, None)),
+        }
+    }
+}
+
+impl Drop for SpoolFile {
+    /// Removes the temporary file.
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
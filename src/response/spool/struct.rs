@@ -0,0 +1,12 @@
+use super::*;
+
+/// A response body stored in a temporary file.
+///
+/// The file is removed when the last response referring to it is dropped.
+#[derive(Debug)]
+pub(crate) struct SpoolFile {
+    /// Path of the temporary file.
+    pub(crate) path: PathBuf,
+    /// Number of body bytes in the file.
+    pub(crate) len: u64,
+}
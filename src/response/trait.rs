@@ -11,7 +11,7 @@ use super::*;
 /// # Associated Types
 /// - `OutputText`: The type returned by the `text` method, typically a text-based HTTP response.
 /// - `OutputBinary`: The type returned by the `binary` method, typically a binary-based HTTP response.
-pub trait ResponseTrait: Send + Debug {
+pub trait ResponseTrait: Sealed + Send + Debug {
     type OutputText: Clone + Sized;
     type OutputBinary: Clone + Sized;
 
@@ -56,4 +56,15 @@ pub trait ResponseTrait: Send + Debug {
     /// # Returns
     /// Returns the decoded data as `Self::OutputBinary`. The exact type of `OutputBinary` depends on the implementation of the `Self` type.
     fn decode(&self, buffer_size: usize) -> Self::OutputBinary;
+
+    /// Deserializes the JSON body of the HTTP response into a typed value.
+    ///
+    /// A body still compressed according to `Content-Encoding` is decoded first.
+    ///
+    /// # Returns
+    /// - `Result<T, RequestError>`: The deserialized value, or an error if the body is not valid JSON for `T`.
+    #[cfg(feature = "json")]
+    fn json<T: DeserializeOwned>(&self) -> Result<T, RequestError>
+    where
+        Self: Sized;
 }
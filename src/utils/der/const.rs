@@ -0,0 +1,63 @@
+/// DER tag of a BOOLEAN.
+pub(crate) const DER_BOOLEAN: u8 = 0x01;
+
+/// DER tag of an INTEGER.
+pub(crate) const DER_INTEGER: u8 = 0x02;
+
+/// DER tag of an OCTET STRING.
+pub(crate) const DER_OCTET_STRING: u8 = 0x04;
+
+/// DER tag of an OBJECT IDENTIFIER.
+pub(crate) const DER_OBJECT_IDENTIFIER: u8 = 0x06;
+
+/// DER tag of a UTCTime (two-digit year).
+pub(crate) const DER_UTC_TIME: u8 = 0x17;
+
+/// DER tag of a GeneralizedTime (four-digit year).
+pub(crate) const DER_GENERALIZED_TIME: u8 = 0x18;
+
+/// DER tag of a BMPString (UTF-16 big endian).
+pub(crate) const DER_BMP_STRING: u8 = 0x1E;
+
+/// DER tag of a constructed SEQUENCE.
+pub(crate) const DER_SEQUENCE: u8 = 0x30;
+
+/// DER tag of a constructed SET.
+pub(crate) const DER_SET: u8 = 0x31;
+
+/// DER tag of the explicit `[0]` version field of a TBSCertificate.
+pub(crate) const DER_CONTEXT_0_CONSTRUCTED: u8 = 0xA0;
+
+/// DER tag of the explicit `[3]` extensions field of a TBSCertificate.
+pub(crate) const DER_CONTEXT_3_CONSTRUCTED: u8 = 0xA3;
+
+/// GeneralName tag of an `rfc822Name` (email address).
+pub(crate) const GENERAL_NAME_EMAIL: u8 = 0x81;
+
+/// GeneralName tag of a `dNSName`.
+pub(crate) const GENERAL_NAME_DNS: u8 = 0x82;
+
+/// GeneralName tag of a `uniformResourceIdentifier`.
+pub(crate) const GENERAL_NAME_URI: u8 = 0x86;
+
+/// GeneralName tag of an `iPAddress`.
+pub(crate) const GENERAL_NAME_IP: u8 = 0x87;
+
+/// Encoded OID of the subjectAltName extension (2.5.29.17).
+pub(crate) const OID_SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1D, 0x11];
+
+/// Encoded OIDs of common name attributes and their short names.
+pub(crate) const OID_ATTRIBUTE_NAMES: [(&[u8], &str); 9] = [
+    (&[0x55, 0x04, 0x03], "CN"),
+    (&[0x55, 0x04, 0x05], "serialNumber"),
+    (&[0x55, 0x04, 0x06], "C"),
+    (&[0x55, 0x04, 0x07], "L"),
+    (&[0x55, 0x04, 0x08], "ST"),
+    (&[0x55, 0x04, 0x09], "street"),
+    (&[0x55, 0x04, 0x0A], "O"),
+    (&[0x55, 0x04, 0x0B], "OU"),
+    (
+        &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x01],
+        "emailAddress",
+    ),
+];
@@ -0,0 +1,168 @@
+use super::*;
+
+/// Parses a DER `UTCTime` or `GeneralizedTime` into Unix seconds.
+///
+/// Only the `Z`-terminated forms required by RFC 5280 are accepted.
+///
+/// # Arguments
+///
+/// - `u8` - The tag of the time element.
+/// - `&[u8]` - The content of the time element.
+///
+/// # Returns
+///
+/// - `Option<i64>` - Seconds since the Unix epoch, or `None` if the value is malformed.
+pub(crate) fn parse_der_time(tag: u8, content: &[u8]) -> Option<i64> {
+    let digits: &[u8] = content.strip_suffix(b"Z")?;
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        digits.get(range)?.iter().try_fold(0i64, |value, &digit| {
+            Some(value * 10 + (digit - b'0') as i64)
+        })
+    };
+    let (year, rest): (i64, usize) = match (tag, digits.len()) {
+        (DER_UTC_TIME, 12) => {
+            let short: i64 = number(0..2)?;
+            (
+                if short >= 50 {
+                    1900 + short
+                } else {
+                    2000 + short
+                },
+                2,
+            )
+        }
+        (DER_GENERALIZED_TIME, 14) => (number(0..4)?, 4),
+        _ => return None,
+    };
+    let month: i64 = number(rest..rest + 2)?;
+    let day: i64 = number(rest + 2..rest + 4)?;
+    let hour: i64 = number(rest + 4..rest + 6)?;
+    let minute: i64 = number(rest + 6..rest + 8)?;
+    let second: i64 = number(rest + 8..rest + 10)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+/// Formats an encoded object identifier in dotted decimal notation.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The content of the OBJECT IDENTIFIER element.
+///
+/// # Returns
+///
+/// - `String` - The dotted form, e.g. `2.5.4.3`.
+pub(crate) fn format_der_oid(content: &[u8]) -> String {
+    let mut arcs: Vec<String> = Vec::new();
+    let mut value: u64 = 0;
+    for &byte in content {
+        value = (value << 7) | (byte & 0x7F) as u64;
+        if byte & 0x80 != 0 {
+            continue;
+        }
+        if arcs.is_empty() {
+            let first: u64 = (value / 40).min(2);
+            arcs.push(first.to_string());
+            arcs.push((value - first * 40).to_string());
+        } else {
+            arcs.push(value.to_string());
+        }
+        value = 0;
+    }
+    arcs.join(".")
+}
+
+/// Decodes a DER string value into text.
+///
+/// `BMPString` values are decoded from UTF-16; every other string type is
+/// decoded as UTF-8, replacing invalid sequences.
+///
+/// # Arguments
+///
+/// - `u8` - The tag of the string element.
+/// - `&[u8]` - The content of the string element.
+///
+/// # Returns
+///
+/// - `String` - The decoded text.
+pub(crate) fn decode_der_string(tag: u8, content: &[u8]) -> String {
+    if tag == DER_BMP_STRING {
+        let units: Vec<u16> = content
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    String::from_utf8_lossy(content).into_owned()
+}
+
+/// Formats an X.509 distinguished name.
+///
+/// Attributes are listed in encoding order as `KEY=value` pairs separated by
+/// `, `, using short names for common attributes and dotted OIDs otherwise.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The content of the Name SEQUENCE.
+///
+/// # Returns
+///
+/// - `Option<String>` - The formatted name, or `None` if the encoding is malformed.
+pub(crate) fn format_der_name(content: &[u8]) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut names: DerReader<'_> = DerReader::new(content);
+    while names.peek_tag().is_some() {
+        let mut set: DerReader<'_> = DerReader::new(names.read_expected(DER_SET)?);
+        while set.peek_tag().is_some() {
+            let mut attribute: DerReader<'_> = DerReader::new(set.read_expected(DER_SEQUENCE)?);
+            let oid: &[u8] = attribute.read_expected(DER_OBJECT_IDENTIFIER)?;
+            let (tag, value): (u8, &[u8]) = attribute.read()?;
+            let key: String = OID_ATTRIBUTE_NAMES
+                .iter()
+                .find(|(known, _)| *known == oid)
+                .map_or_else(|| format_der_oid(oid), |(_, name)| name.to_string());
+            parts.push(format!("{key}={}", decode_der_string(tag, value)));
+        }
+    }
+    Some(parts.join(", "))
+}
+
+/// Extracts the names listed in a subjectAltName extension value.
+///
+/// DNS names, email addresses, URIs and IP addresses are returned as text;
+/// other name forms are skipped.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The content of the extension's OCTET STRING.
+///
+/// # Returns
+///
+/// - `Option<Vec<String>>` - The names, or `None` if the encoding is malformed.
+pub(crate) fn parse_subject_alt_names(content: &[u8]) -> Option<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+    let mut reader: DerReader<'_> =
+        DerReader::new(DerReader::new(content).read_expected(DER_SEQUENCE)?);
+    while reader.peek_tag().is_some() {
+        let (tag, value): (u8, &[u8]) = reader.read()?;
+        match tag {
+            GENERAL_NAME_EMAIL | GENERAL_NAME_DNS | GENERAL_NAME_URI => {
+                names.push(String::from_utf8_lossy(value).into_owned());
+            }
+            GENERAL_NAME_IP => {
+                if let Ok(octets) = <[u8; 4]>::try_from(value) {
+                    names.push(Ipv4Addr::from(octets).to_string());
+                } else if let Ok(octets) = <[u8; 16]>::try_from(value) {
+                    names.push(Ipv6Addr::from(octets).to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(names)
+}
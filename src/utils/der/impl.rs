@@ -0,0 +1,85 @@
+use super::*;
+
+impl<'a> DerReader<'a> {
+    /// Creates a reader positioned at the start of the data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&'a [u8]` - The DER-encoded data.
+    ///
+    /// # Returns
+    ///
+    /// - `DerReader<'a>` - The reader.
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Returns the tag of the next element without consuming it.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u8>` - The tag, or `None` at the end of the data.
+    pub(crate) fn peek_tag(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    /// Reads the next element.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(u8, &'a [u8])>` - The tag and content of the element, or `None` at the
+    ///   end of the data or when the encoding is malformed.
+    pub(crate) fn read(&mut self) -> Option<(u8, &'a [u8])> {
+        let tag: u8 = self.peek_tag()?;
+        let first: u8 = *self.data.get(self.position + 1)?;
+        let mut offset: usize = self.position + 2;
+        let length: usize = if first < 0x80 {
+            first as usize
+        } else {
+            let count: usize = (first & 0x7F) as usize;
+            if count == 0 || count > 4 {
+                return None;
+            }
+            let bytes: &[u8] = self.data.get(offset..offset + count)?;
+            offset += count;
+            bytes
+                .iter()
+                .fold(0usize, |length, &byte| (length << 8) | byte as usize)
+        };
+        let content: &'a [u8] = self.data.get(offset..offset.checked_add(length)?)?;
+        self.position = offset + length;
+        Some((tag, content))
+    }
+
+    /// Reads the next element, requiring the given tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The expected tag.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'a [u8]>` - The content, or `None` if the tag differs or the encoding is malformed.
+    pub(crate) fn read_expected(&mut self, tag: u8) -> Option<&'a [u8]> {
+        match self.read()? {
+            (read_tag, content) if read_tag == tag => Some(content),
+            _ => None,
+        }
+    }
+
+    /// Consumes the next element if it carries the given tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The tag of the optional element.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'a [u8]>` - The content if the element was present.
+    pub(crate) fn read_optional(&mut self, tag: u8) -> Option<&'a [u8]> {
+        if self.peek_tag()? == tag {
+            return self.read_expected(tag);
+        }
+        None
+    }
+}
@@ -0,0 +1,8 @@
+mod r#const;
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub(crate) use {r#const::*, r#fn::*, r#struct::*};
+
+use super::*;
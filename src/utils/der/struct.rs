@@ -0,0 +1,11 @@
+/// Minimal cursor over DER-encoded data.
+///
+/// Only definite-length encodings with single-byte tags are supported, which
+/// covers everything needed to walk an X.509 certificate.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DerReader<'a> {
+    /// The encoded data.
+    pub(crate) data: &'a [u8],
+    /// Offset of the next element within `data`.
+    pub(crate) position: usize,
+}
@@ -3,3 +3,48 @@
 /// Contains the 64 characters used in standard base64 encoding:
 /// A-Z, a-z, 0-9, '+', and '/'.
 pub(crate) const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Lowercase hexadecimal character set.
+///
+/// Contains the 16 characters used in hex encoding: 0-9 and a-f.
+pub(crate) const HEX_CHARS: &[u8] = b"0123456789abcdef";
+
+/// Uppercase hexadecimal character set used in percent-encoding.
+pub(crate) const PERCENT_HEX_CHARS: &[u8] = b"0123456789ABCDEF";
+
+/// Characters kept verbatim in a request path besides the unreserved characters.
+///
+/// Contains the RFC 3986 sub-delimiters, `:`, `@`, and the `/` segment separator.
+pub(crate) const PATH_SAFE_CHARS: &[u8] = b"!$&'()*+,;=:@/";
+
+/// Characters kept verbatim in a query string besides the unreserved characters.
+///
+/// Contains the path characters plus `?`, so `&` and `=` still separate parameters.
+pub(crate) const QUERY_SAFE_CHARS: &[u8] = b"!$&'()*+,;=:@/?";
+
+/// Punycode digit set, mapping digit values 0 to 35 to `a`-`z` and `0`-`9`.
+pub(crate) const PUNYCODE_DIGITS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Punycode base, the number of digit values.
+pub(crate) const PUNYCODE_BASE: u32 = 36;
+
+/// Punycode minimum threshold.
+pub(crate) const PUNYCODE_TMIN: u32 = 1;
+
+/// Punycode maximum threshold.
+pub(crate) const PUNYCODE_TMAX: u32 = 26;
+
+/// Punycode bias adaptation skew.
+pub(crate) const PUNYCODE_SKEW: u32 = 38;
+
+/// Punycode damping factor applied after the first code point.
+pub(crate) const PUNYCODE_DAMP: u32 = 700;
+
+/// Punycode initial bias.
+pub(crate) const PUNYCODE_INITIAL_BIAS: u32 = 72;
+
+/// Punycode initial code point, the first non-basic code point.
+pub(crate) const PUNYCODE_INITIAL_N: u32 = 128;
+
+/// Prefix marking an ASCII-compatible encoded label of an internationalized host name.
+pub(crate) const IDNA_PREFIX: &str = "xn--";
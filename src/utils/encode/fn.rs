@@ -32,3 +32,239 @@ pub(crate) fn base64_encode(input: &[u8]) -> String {
     }
     result
 }
+
+/// Encodes binary data into a lowercase hex string.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The binary data to encode.
+///
+/// # Returns
+///
+/// - `String` - The hex encoded string.
+pub(crate) fn hex_encode(input: &[u8]) -> String {
+    let mut result: String = String::with_capacity(input.len() * 2);
+    for &byte in input {
+        result.push(HEX_CHARS[(byte >> 4) as usize] as char);
+        result.push(HEX_CHARS[(byte & 15) as usize] as char);
+    }
+    result
+}
+
+/// Percent-encodes a URL component.
+///
+/// Every byte except the RFC 3986 unreserved characters (letters, digits, `-`, `.`,
+/// `_`, and `~`) is encoded, so the result is safe inside a path segment or a
+/// query name or value.
+///
+/// # Arguments
+///
+/// - `&str` - The component to encode.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded component.
+pub(crate) fn percent_encode(input: &str) -> String {
+    let mut result: String = String::with_capacity(input.len());
+    for &byte in input.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            result.push(byte as char);
+        } else {
+            result.push('%');
+            result.push(PERCENT_HEX_CHARS[(byte >> 4) as usize] as char);
+            result.push(PERCENT_HEX_CHARS[(byte & 15) as usize] as char);
+        }
+    }
+    result
+}
+
+/// Percent-encodes the bytes of a URL part that are not allowed verbatim.
+///
+/// RFC 3986 unreserved characters and the given safe characters are kept, and
+/// existing `%XX` escapes are preserved so pre-encoded input is not encoded twice.
+///
+/// # Arguments
+///
+/// - `&str` - The URL part to encode.
+/// - `&[u8]` - The characters kept verbatim besides the unreserved characters.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded URL part.
+fn encode_url_part(input: &str, safe_chars: &[u8]) -> String {
+    let bytes: &[u8] = input.as_bytes();
+    let mut result: String = String::with_capacity(bytes.len());
+    for (index, &byte) in bytes.iter().enumerate() {
+        let escape: bool = byte == b'%'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_hexdigit)
+            && bytes.get(index + 2).is_some_and(u8::is_ascii_hexdigit);
+        if escape
+            || byte.is_ascii_alphanumeric()
+            || matches!(byte, b'-' | b'.' | b'_' | b'~')
+            || safe_chars.contains(&byte)
+        {
+            result.push(byte as char);
+        } else {
+            result.push('%');
+            result.push(PERCENT_HEX_CHARS[(byte >> 4) as usize] as char);
+            result.push(PERCENT_HEX_CHARS[(byte & 15) as usize] as char);
+        }
+    }
+    result
+}
+
+/// Percent-encodes a request path.
+///
+/// Segment separators and characters allowed in path segments are kept, as are
+/// existing `%XX` escapes.
+///
+/// # Arguments
+///
+/// - `&str` - The path to encode.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded path.
+pub(crate) fn encode_path(input: &str) -> String {
+    encode_url_part(input, PATH_SAFE_CHARS)
+}
+
+/// Percent-encodes a query string.
+///
+/// The `&` and `=` separators and characters allowed in a query are kept, as are
+/// existing `%XX` escapes.
+///
+/// # Arguments
+///
+/// - `&str` - The query string to encode, without the leading `?`.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded query string.
+pub(crate) fn encode_query(input: &str) -> String {
+    encode_url_part(input, QUERY_SAFE_CHARS)
+}
+
+/// Adapts the Punycode bias after a code point is encoded.
+///
+/// # Arguments
+///
+/// - `u32` - The delta encoded for the code point.
+/// - `u32` - The number of code points handled so far, including this one.
+/// - `bool` - Whether this is the first encoded code point.
+///
+/// # Returns
+///
+/// - `u32` - The new bias.
+fn punycode_adapt(delta: u32, handled: u32, first: bool) -> u32 {
+    let mut delta: u32 = if first {
+        delta / PUNYCODE_DAMP
+    } else {
+        delta / 2
+    };
+    delta += delta / handled;
+    let mut k: u32 = 0;
+    while delta > ((PUNYCODE_BASE - PUNYCODE_TMIN) * PUNYCODE_TMAX) / 2 {
+        delta /= PUNYCODE_BASE - PUNYCODE_TMIN;
+        k += PUNYCODE_BASE;
+    }
+    k + (PUNYCODE_BASE - PUNYCODE_TMIN + 1) * delta / (delta + PUNYCODE_SKEW)
+}
+
+/// Encodes a label into Punycode as described in RFC 3492.
+///
+/// # Arguments
+///
+/// - `&str` - The label to encode.
+///
+/// # Returns
+///
+/// - `Option<String>` - The Punycode label without the `xn--` prefix, or `None` on overflow.
+pub(crate) fn punycode_encode(input: &str) -> Option<String> {
+    let code_points: Vec<u32> = input
+        .chars()
+        .map(|code_point: char| code_point as u32)
+        .collect();
+    let mut result: String = input.chars().filter(char::is_ascii).collect();
+    let basic: u32 = result.len() as u32;
+    if basic > 0 {
+        result.push('-');
+    }
+    let total: u32 = code_points.len() as u32;
+    let mut handled: u32 = basic;
+    let mut n: u32 = PUNYCODE_INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias: u32 = PUNYCODE_INITIAL_BIAS;
+    while handled < total {
+        let next: u32 = code_points
+            .iter()
+            .copied()
+            .filter(|&code_point: &u32| code_point >= n)
+            .min()?;
+        delta = delta.checked_add((next - n).checked_mul(handled + 1)?)?;
+        n = next;
+        for &code_point in &code_points {
+            if code_point < n {
+                delta = delta.checked_add(1)?;
+            }
+            if code_point != n {
+                continue;
+            }
+            let mut q: u32 = delta;
+            let mut k: u32 = PUNYCODE_BASE;
+            loop {
+                let threshold: u32 = if k <= bias {
+                    PUNYCODE_TMIN
+                } else if k >= bias + PUNYCODE_TMAX {
+                    PUNYCODE_TMAX
+                } else {
+                    k - bias
+                };
+                if q < threshold {
+                    break;
+                }
+                let digit: u32 = threshold + (q - threshold) % (PUNYCODE_BASE - threshold);
+                result.push(PUNYCODE_DIGITS[digit as usize] as char);
+                q = (q - threshold) / (PUNYCODE_BASE - threshold);
+                k += PUNYCODE_BASE;
+            }
+            result.push(PUNYCODE_DIGITS[q as usize] as char);
+            bias = punycode_adapt(delta, handled + 1, handled == basic);
+            delta = 0;
+            handled += 1;
+        }
+        delta = delta.checked_add(1)?;
+        n = n.checked_add(1)?;
+    }
+    Some(result)
+}
+
+/// Converts an internationalized host name into its ASCII form.
+///
+/// Labels with non-ASCII characters are lowercased and Punycode-encoded with the
+/// `xn--` prefix, while ASCII labels, IP literals, and ASCII hosts are unchanged.
+///
+/// # Arguments
+///
+/// - `&str` - The host name to convert.
+///
+/// # Returns
+///
+/// - `Option<String>` - The ASCII host name, or `None` if a label cannot be encoded.
+pub(crate) fn host_to_ascii(host: &str) -> Option<String> {
+    if host.is_ascii() {
+        return Some(host.to_string());
+    }
+    let labels: Vec<String> = host
+        .split(['.', '\u{3002}', '\u{FF0E}', '\u{FF61}'])
+        .map(|label: &str| {
+            if label.is_ascii() {
+                Some(label.to_string())
+            } else {
+                punycode_encode(&label.to_lowercase())
+                    .map(|encoded: String| format!("{IDNA_PREFIX}{encoded}"))
+            }
+        })
+        .collect::<Option<Vec<String>>>()?;
+    Some(labels.join("."))
+}
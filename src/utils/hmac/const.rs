@@ -0,0 +1,8 @@
+/// Block size of SHA-256 in bytes, to which HMAC keys are padded.
+pub(crate) const HMAC_SHA256_BLOCK_SIZE: usize = 64;
+
+/// Byte XORed with the key to form the inner HMAC padding.
+pub(crate) const HMAC_INNER_PAD: u8 = 0x36;
+
+/// Byte XORed with the key to form the outer HMAC padding.
+pub(crate) const HMAC_OUTER_PAD: u8 = 0x5c;
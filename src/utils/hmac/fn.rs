@@ -0,0 +1,28 @@
+use super::*;
+
+/// Computes the HMAC-SHA256 of a message as described in RFC 2104.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The secret key.
+/// - `&[u8]` - The message to authenticate.
+///
+/// # Returns
+///
+/// - `Vec<u8>` - The 32-byte message authentication code.
+pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut block: [u8; HMAC_SHA256_BLOCK_SIZE] = [0; HMAC_SHA256_BLOCK_SIZE];
+    if key.len() > HMAC_SHA256_BLOCK_SIZE {
+        let digest: Vec<u8> = Sha256::digest(key).to_vec();
+        block[..digest.len()].copy_from_slice(&digest);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner: Sha256 = Sha256::new();
+    inner.update(block.map(|byte: u8| byte ^ HMAC_INNER_PAD));
+    inner.update(message);
+    let mut outer: Sha256 = Sha256::new();
+    outer.update(block.map(|byte: u8| byte ^ HMAC_OUTER_PAD));
+    outer.update(inner.finalize());
+    outer.finalize().to_vec()
+}
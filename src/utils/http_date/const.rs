@@ -0,0 +1,7 @@
+/// Month abbreviations of HTTP dates, in calendar order.
+pub(crate) const HTTP_DATE_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Number of seconds in one day.
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
@@ -0,0 +1,120 @@
+use super::*;
+
+/// Converts a proleptic Gregorian calendar date to days since the Unix epoch.
+///
+/// # Arguments
+///
+/// - `i64` - The year.
+/// - `i64` - The month, from 1 to 12.
+/// - `i64` - The day of the month.
+///
+/// # Returns
+///
+/// - `i64` - Days since 1970-01-01, negative for earlier dates.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let year_of_era: i64 = year - era * 400;
+    let month_index: i64 = (month + 9) % 12;
+    let day_of_year: i64 = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian calendar date.
+///
+/// # Arguments
+///
+/// - `i64` - Days since 1970-01-01, negative for earlier dates.
+///
+/// # Returns
+///
+/// - `(i64, i64, i64)` - The year, the month from 1 to 12, and the day of the month.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days: i64 = days + 719_468;
+    let era: i64 = days.div_euclid(146_097);
+    let day_of_era: i64 = days - era * 146_097;
+    let year_of_era: i64 =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index: i64 = (5 * day_of_year + 2) / 153;
+    let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month: i64 = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Parses an HTTP date in the IMF-fixdate format into Unix seconds.
+///
+/// For example `Sun, 06 Nov 1994 08:49:37 GMT`. The obsolete RFC 850 and asctime
+/// formats are not accepted.
+///
+/// # Arguments
+///
+/// - `&str` - The date value.
+///
+/// # Returns
+///
+/// - `Option<i64>` - Seconds since the Unix epoch, or `None` if the value is malformed.
+pub(crate) fn parse_http_date(value: &str) -> Option<i64> {
+    let (_, date) = value.trim().split_once(", ")?;
+    let parts: Vec<&str> = date.split(' ').collect();
+    let [day, month, year, time, "GMT"] = parts.as_slice() else {
+        return None;
+    };
+    let day: i64 = day.parse().ok()?;
+    let month: i64 = HTTP_DATE_MONTHS
+        .iter()
+        .position(|name: &&str| name == month)? as i64
+        + 1;
+    let year: i64 = year.parse().ok()?;
+    let time: Vec<i64> = time
+        .split(':')
+        .map(|part: &str| part.parse::<i64>().ok())
+        .collect::<Option<Vec<i64>>>()?;
+    let [hour, minute, second] = time.as_slice() else {
+        return None;
+    };
+    if !(1..=31).contains(&day) || *hour > 23 || *minute > 59 || *second > 60 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+/// Formats Unix seconds as a compact ISO 8601 UTC timestamp.
+///
+/// For example `20150830T123600Z`, the form used by signing schemes such as AWS
+/// Signature Version 4.
+///
+/// # Arguments
+///
+/// - `i64` - Seconds since the Unix epoch.
+///
+/// # Returns
+///
+/// - `String` - The timestamp.
+pub(crate) fn format_basic_timestamp(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let time: i64 = seconds.rem_euclid(SECONDS_PER_DAY);
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Gets the current time as Unix seconds.
+///
+/// # Returns
+///
+/// - `i64` - Seconds since the Unix epoch, or 0 if the clock is before it.
+pub(crate) fn get_unix_seconds() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed: Duration| elapsed.as_secs() as i64)
+}
@@ -1,6 +1,14 @@
+#[cfg(feature = "tls")]
+mod der;
 mod encode;
+mod hmac;
+mod http_date;
+mod random;
 mod vec;
 
-pub(crate) use {encode::*, vec::*};
+pub(crate) use {encode::*, hmac::*, http_date::*, random::*, vec::*};
+
+#[cfg(feature = "tls")]
+pub(crate) use der::*;
 
 use super::*;
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Generates a non-cryptographic random 64-bit value.
+///
+/// Combines the randomly seeded keys of the standard library's `RandomState`
+/// with the current time and a process-wide counter. Suitable for identifiers,
+/// not for secrets.
+///
+/// # Returns
+///
+/// - `u64` - The random value.
+pub(crate) fn random_u64() -> u64 {
+    let mut hasher: DefaultHasher = RandomState::new().build_hasher();
+    hasher.write_u64(RANDOM_COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    hasher.finish()
+}
+
+/// Fills a buffer with non-cryptographic random bytes.
+///
+/// # Arguments
+///
+/// - `&mut [u8]` - The buffer to fill.
+#[cfg(feature = "trace-context")]
+pub(crate) fn fill_random(buffer: &mut [u8]) {
+    for chunk in buffer.chunks_mut(8) {
+        let bytes: [u8; 8] = random_u64().to_be_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
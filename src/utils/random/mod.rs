@@ -0,0 +1,6 @@
+mod r#fn;
+mod r#static;
+
+pub(crate) use {r#fn::*, r#static::*};
+
+use super::*;
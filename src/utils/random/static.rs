@@ -0,0 +1,4 @@
+use super::*;
+
+/// Counter mixed into every generated value so consecutive calls never repeat.
+pub(crate) static RANDOM_COUNTER: AtomicU64 = AtomicU64::new(0);
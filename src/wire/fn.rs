@@ -0,0 +1,150 @@
+use super::*;
+
+/// Serializes a request into HTTP/1.x bytes.
+///
+/// A `Content-Length` header is added for a body unless the request already sets
+/// `Content-Length` or `Transfer-Encoding`. No other header is added, so the
+/// request must set `Host` itself.
+///
+/// # Arguments
+///
+/// - `&RequestParts` - The request to serialize.
+///
+/// # Returns
+///
+/// - `Vec<u8>` - The complete request bytes.
+pub fn build_request(parts: &RequestParts) -> Vec<u8> {
+    let mut header_bytes: Vec<u8> = Vec::new();
+    for (name, value) in &parts.headers {
+        header_bytes.extend_from_slice(format!("{name}: {value}").as_bytes());
+        header_bytes.extend_from_slice(HTTP_BR_BYTES);
+    }
+    if let Some(body) = &parts.body
+        && !parts.has_header(CONTENT_LENGTH)
+        && !parts.has_header(TRANSFER_ENCODING)
+    {
+        header_bytes.extend_from_slice(format!("{CONTENT_LENGTH}: {}", body.len()).as_bytes());
+        header_bytes.extend_from_slice(HTTP_BR_BYTES);
+    }
+    SharedRequestBuilder::build_http_request(
+        &parts.method.to_string(),
+        parts.target.clone(),
+        header_bytes,
+        parts.body.clone(),
+        parts.version.to_string(),
+    )
+}
+
+/// Determines the length of the first complete response in a buffer.
+///
+/// Transports read until this returns a length, then pass that many bytes to
+/// `parse_response`. Bytes past the length belong to the next response.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The bytes received so far.
+/// - `bool` - Whether the response answers a HEAD request and therefore has no body.
+///
+/// # Returns
+///
+/// - `Option<usize>` - The length of the complete response, or None if more bytes are
+///   needed or the body is delimited by the connection closing.
+pub fn response_length(bytes: &[u8], is_head: bool) -> Option<usize> {
+    SharedResponseHandler::get_response_frame_length(bytes, is_head)
+}
+
+/// Parses a complete HTTP/1.x response.
+///
+/// The body is framed by chunked transfer coding or `Content-Length`, and otherwise
+/// extends to the end of the bytes. Bytes past the end of the framed body are ignored.
+/// Headers allowing the response to be framed more than one way are rejected.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response bytes.
+///
+/// # Returns
+///
+/// - `Result<ResponseParts, RequestError>` - The parsed response, or an error if it is
+///   incomplete or malformed.
+pub fn parse_response(bytes: &[u8]) -> Result<ResponseParts, RequestError> {
+    parse_response_with(bytes, false)
+}
+
+/// Parses a complete HTTP/1.x response to a HEAD request.
+///
+/// Behaves like `parse_response`, except that the response has no body whatever its
+/// framing headers say.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response bytes.
+///
+/// # Returns
+///
+/// - `Result<ResponseParts, RequestError>` - The parsed response, or an error if it is
+///   incomplete or malformed.
+pub fn parse_head_response(bytes: &[u8]) -> Result<ResponseParts, RequestError> {
+    parse_response_with(bytes, true)
+}
+
+/// Parses a complete HTTP/1.x response.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The response bytes.
+/// - `bool` - Whether the response answers a HEAD request.
+///
+/// # Returns
+///
+/// - `Result<ResponseParts, RequestError>` - The parsed response, or an error.
+fn parse_response_with(bytes: &[u8], is_head: bool) -> Result<ResponseParts, RequestError> {
+    let incomplete = || RequestError::Request("Incomplete response".to_string());
+    let headers_end: usize =
+        SharedResponseHandler::find_double_crlf(bytes, 0).ok_or_else(incomplete)? + 4;
+    let headers_bytes: &[u8] = &bytes[..headers_end];
+    SharedResponseHandler::check_strict_headers(headers_bytes)?;
+    let head: String = String::from_utf8_lossy(&headers_bytes[..headers_end - 4]).into_owned();
+    let mut lines: std::str::Split<'_, &str> = head.split("\r\n");
+    let status_line: &str = lines.next().unwrap_or_default();
+    let mut status_parts: std::str::SplitN<'_, char> = status_line.splitn(3, ' ');
+    let version: HttpVersion = status_parts
+        .next()
+        .and_then(|version: &str| version.parse::<HttpVersion>().ok())
+        .unwrap_or_default();
+    let status_code: ResponseStatusCode = status_parts
+        .next()
+        .and_then(|code: &str| code.parse::<ResponseStatusCode>().ok())
+        .ok_or_else(|| RequestError::Request("Invalid status line".to_string()))?;
+    let status_text: String = status_parts.next().unwrap_or_default().to_owned();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line: &str| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .collect();
+    let body_bytes: &[u8] = &bytes[headers_end..];
+    let body: Vec<u8> = if SharedResponseHandler::is_bodyless_response(headers_bytes, is_head) {
+        Vec::new()
+    } else if SharedResponseHandler::is_chunked_encoding(headers_bytes) {
+        SharedResponseHandler::find_chunked_body_end(body_bytes).ok_or_else(incomplete)?;
+        SharedResponseHandler::parse_chunked_body(body_bytes)
+    } else if headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(CONTENT_LENGTH))
+    {
+        let content_length: usize =
+            SharedResponseHandler::get_content_length(headers_bytes).ok_or_else(incomplete)?;
+        body_bytes
+            .get(..content_length)
+            .ok_or_else(incomplete)?
+            .to_vec()
+    } else {
+        body_bytes.to_vec()
+    };
+    Ok(ResponseParts {
+        version,
+        status_code,
+        status_text,
+        headers,
+        body,
+    })
+}
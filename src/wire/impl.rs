@@ -0,0 +1,198 @@
+use super::*;
+
+impl RequestParts {
+    /// Creates an HTTP/1.1 request without headers or body.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The request method.
+    /// - `&str` - The request target, such as `/path?query`.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestParts` - The request parts.
+    pub fn new(method: Method, target: &str) -> Self {
+        Self {
+            method,
+            target: target.to_owned(),
+            version: HttpVersion::Http1_1,
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Sets the HTTP version.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpVersion` - The HTTP version.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestParts` - The request parts with the version set.
+    pub fn with_version(mut self, version: HttpVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Adds a header. Headers are sent in the order they are added.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    /// - `&str` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestParts` - The request parts with the header added.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets the body.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The body (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestParts` - The request parts with the body set.
+    pub fn with_body<T: Into<Vec<u8>>>(mut self, body: T) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Gets the request method.
+    ///
+    /// # Returns
+    ///
+    /// - `&Method` - The request method.
+    pub fn get_method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Gets the request target.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The request target.
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+
+    /// Gets the HTTP version.
+    ///
+    /// # Returns
+    ///
+    /// - `&HttpVersion` - The HTTP version.
+    pub fn get_version(&self) -> &HttpVersion {
+        &self.version
+    }
+
+    /// Gets the headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&[(String, String)]` - The header names and values in the order they are sent.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Gets the body.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&[u8]>` - The body, or None if the request has none.
+    pub fn get_body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+
+    /// Checks if a header is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the header is set, false otherwise.
+    pub(crate) fn has_header(&self, name: &str) -> bool {
+        self.headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case(name))
+    }
+}
+
+impl ResponseParts {
+    /// Gets the HTTP version of the status line.
+    ///
+    /// # Returns
+    ///
+    /// - `&HttpVersion` - The HTTP version.
+    pub fn get_version(&self) -> &HttpVersion {
+        &self.version
+    }
+
+    /// Gets the status code.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.status_code
+    }
+
+    /// Gets the status text.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The status text.
+    pub fn get_status_text(&self) -> &str {
+        &self.status_text
+    }
+
+    /// Gets the headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&[(String, String)]` - The header names and values in the order they were received.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Gets the first value of a header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The header value, or None if the header is absent.
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Gets the body.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The body, with any chunked transfer coding removed.
+    pub fn get_body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Consumes the parts and returns the body.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The body, with any chunked transfer coding removed.
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+}
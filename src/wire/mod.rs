@@ -0,0 +1,13 @@
+//! Sans-IO HTTP/1.x message formatting and parsing.
+//!
+//! These functions build request bytes and parse response bytes without touching
+//! a socket, so the crate's HTTP handling can be reused over transports it does
+//! not support itself, such as serial links or SSH channels.
+
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub use {r#fn::*, r#struct::*};
+
+use super::*;
@@ -0,0 +1,31 @@
+use super::*;
+
+/// The parts of an HTTP request to serialize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestParts {
+    /// The request method.
+    pub(crate) method: Method,
+    /// The request target, such as `/path?query`.
+    pub(crate) target: String,
+    /// The HTTP version.
+    pub(crate) version: HttpVersion,
+    /// The headers in the order they are sent.
+    pub(crate) headers: Vec<(String, String)>,
+    /// The request body, if any.
+    pub(crate) body: Option<Vec<u8>>,
+}
+
+/// The parts of a parsed HTTP response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseParts {
+    /// The HTTP version of the status line.
+    pub(crate) version: HttpVersion,
+    /// The status code.
+    pub(crate) status_code: ResponseStatusCode,
+    /// The status text.
+    pub(crate) status_text: String,
+    /// The headers in the order they were received.
+    pub(crate) headers: Vec<(String, String)>,
+    /// The body, with any chunked transfer coding removed.
+    pub(crate) body: Vec<u8>,
+}
@@ -2,8 +2,35 @@ mod request_builder;
 
 use http_request::*;
 
+#[cfg(feature = "async")]
+use std::net::{IpAddr, SocketAddr};
+
+#[cfg(all(
+    feature = "async",
+    any(feature = "compress", feature = "json", feature = "websocket")
+))]
+use futures::StreamExt;
+
+#[cfg(all(feature = "async", feature = "compress"))]
+use flate2::{Compression, write::GzEncoder};
+
+#[cfg(feature = "websocket")]
+use {futures::SinkExt, std::net::Ipv4Addr};
+
+#[cfg(feature = "tls")]
+use {
+    rustls::pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    std::net::TcpStream,
+};
+
 use std::{
-    sync::{Arc, Mutex},
+    io::{Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
     thread::{JoinHandle, spawn},
     time::{Duration, Instant},
 };